@@ -4,6 +4,16 @@ export const A320Failure = Object.freeze({
     TransformerRectifier1: 24000,
     TransformerRectifier2: 24001,
     TransformerRectifierEssential: 24002,
+    Idg1OilLeak: 24003,
+    Idg2OilLeak: 24004,
+    GroundPowerUnitOvervoltage: 24005,
+    GroundPowerUnitWrongFrequency: 24006,
+    GroundPowerUnitIncorrectPhaseSequence: 24007,
+    GroundPowerUnitOpenPhase: 24008,
+    LeftFlapTransmission: 27000,
+    RightFlapTransmission: 27001,
+    LeftSlatTransmission: 27002,
+    RightSlatTransmission: 27003,
     GreenReservoirLeak: 29000,
     BlueReservoirLeak: 29001,
     YellowReservoirLeak: 29002,
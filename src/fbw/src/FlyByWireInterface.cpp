@@ -433,6 +433,12 @@ void FlyByWireInterface::setupLocalVariables() {
   idAileronPositionLeft = make_unique<LocalVariable>("A32NX_AILERON_LEFT_DEFLECTION_DEMAND");
   idAileronPositionRight = make_unique<LocalVariable>("A32NX_AILERON_RIGHT_DEFLECTION_DEMAND");
 
+  idElevatorPositionDemand = make_unique<LocalVariable>("A32NX_ELEVATOR_DEFLECTION_DEMAND");
+  idElevatorPositionLeft = make_unique<LocalVariable>("A32NX_HYD_ELEVATOR_LEFT_DEFLECTION");
+  idElevatorPositionRight = make_unique<LocalVariable>("A32NX_HYD_ELEVATOR_RIGHT_DEFLECTION");
+  idRudderPositionDemand = make_unique<LocalVariable>("A32NX_RUDDER_DEFLECTION_DEMAND");
+  idRudderPosition = make_unique<LocalVariable>("A32NX_HYD_RUDDER_DEFLECTION");
+
   idRadioReceiverLocalizerValid = make_unique<LocalVariable>("A32NX_DEV_RADIO_RECEIVER_LOC_IS_VALID");
   idRadioReceiverLocalizerDeviation = make_unique<LocalVariable>("A32NX_DEV_RADIO_RECEIVER_LOC_DEVIATION");
   idRadioReceiverLocalizerDistance = make_unique<LocalVariable>("A32NX_DEV_RADIO_RECEIVER_LOC_DISTANCE");
@@ -1402,10 +1408,21 @@ bool FlyByWireInterface::updateFlyByWire(double sampleTime) {
   idRudderPedalPosition->set(max(-100, min(100, (-100.0 * simInput.inputs[2]))));
   idRudderPedalAnimationPosition->set(max(-100, min(100, (-100.0 * simInput.inputs[2]) + (100.0 * simData.zeta_trim_pos))));
 
+  // elevator and rudder demands are sent to the hydraulic system
+  if (!flyByWireOutput.sim.data_computed.tracking_mode_on) {
+    idElevatorPositionDemand->set(flyByWireOutput.output.eta_pos);
+    idRudderPositionDemand->set(flyByWireOutput.output.zeta_pos);
+  } else {
+    idElevatorPositionDemand->set(simData.eta_pos);
+    idRudderPositionDemand->set(simData.zeta_pos);
+  }
+
   // set outputs
   if (!flyByWireOutput.sim.data_computed.tracking_mode_on) {
     // object to write with trim
-    SimOutput output = {flyByWireOutput.output.eta_pos, flyByWireOutput.output.xi_pos, flyByWireOutput.output.zeta_pos};
+    // elevator and rudder are written with the position reached by their hydraulic actuators
+    SimOutput output = {(idElevatorPositionLeft->get() + idElevatorPositionRight->get()) / 2.0, flyByWireOutput.output.xi_pos,
+                        idRudderPosition->get()};
 
     // send data via sim connect
     if (!simConnectInterface.sendData(output)) {
@@ -313,6 +313,12 @@ class FlyByWireInterface {
   std::unique_ptr<LocalVariable> idAileronPositionRight;
   std::shared_ptr<AnimationAileronHandler> animationAileronHandler;
 
+  std::unique_ptr<LocalVariable> idElevatorPositionDemand;
+  std::unique_ptr<LocalVariable> idElevatorPositionLeft;
+  std::unique_ptr<LocalVariable> idElevatorPositionRight;
+  std::unique_ptr<LocalVariable> idRudderPositionDemand;
+  std::unique_ptr<LocalVariable> idRudderPosition;
+
   std::unique_ptr<LocalVariable> idRadioReceiverLocalizerValid;
   std::unique_ptr<LocalVariable> idRadioReceiverLocalizerDeviation;
   std::unique_ptr<LocalVariable> idRadioReceiverLocalizerDistance;
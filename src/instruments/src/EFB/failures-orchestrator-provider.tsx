@@ -16,6 +16,16 @@ const createOrchestrator = () => new FailuresOrchestrator('A32NX', [
     [A320Failure.TransformerRectifier1, 'TR 1'],
     [A320Failure.TransformerRectifier2, 'TR 2'],
     [A320Failure.TransformerRectifierEssential, 'ESS TR'],
+    [A320Failure.Idg1OilLeak, 'IDG 1 oil leak'],
+    [A320Failure.Idg2OilLeak, 'IDG 2 oil leak'],
+    [A320Failure.GroundPowerUnitOvervoltage, 'GPU overvoltage'],
+    [A320Failure.GroundPowerUnitWrongFrequency, 'GPU wrong frequency'],
+    [A320Failure.GroundPowerUnitIncorrectPhaseSequence, 'GPU incorrect phase sequence'],
+    [A320Failure.GroundPowerUnitOpenPhase, 'GPU open phase'],
+    [A320Failure.LeftFlapTransmission, 'Left flap transmission'],
+    [A320Failure.RightFlapTransmission, 'Right flap transmission'],
+    [A320Failure.LeftSlatTransmission, 'Left slat transmission'],
+    [A320Failure.RightSlatTransmission, 'Right slat transmission'],
     [A320Failure.GreenReservoirLeak, 'Green reservoir leak'],
     [A320Failure.BlueReservoirLeak, 'Blue reservoir leak'],
     [A320Failure.YellowReservoirLeak, 'Yellow reservoir leak'],
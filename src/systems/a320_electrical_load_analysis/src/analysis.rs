@@ -0,0 +1,319 @@
+use std::time::Duration;
+
+use a320_systems::A320;
+use systems::{
+    electrical::consumption::PowerConsumerFlightPhase,
+    shared::{ElectricalBusType, PotentialOrigin, PowerConsumptionReport},
+    simulation::test::{SimulationTestBed, TestBed},
+};
+use uom::si::{f64::*, power::watt};
+
+use crate::configuration::{flight_phase_name, set_flight_phase, PowerSourceConfiguration};
+
+/// The sources for which loads are reported.
+pub const SOURCES: [PotentialOrigin; 11] = [
+    PotentialOrigin::EngineGenerator(1),
+    PotentialOrigin::EngineGenerator(2),
+    PotentialOrigin::ApuGenerator(1),
+    PotentialOrigin::External,
+    PotentialOrigin::EmergencyGenerator,
+    PotentialOrigin::TransformerRectifier(1),
+    PotentialOrigin::TransformerRectifier(2),
+    PotentialOrigin::TransformerRectifier(3),
+    PotentialOrigin::StaticInverter,
+    PotentialOrigin::Battery(1),
+    PotentialOrigin::Battery(2),
+];
+
+/// The rated output of the given source, as used for determining margins.
+/// Batteries have no rated output, as their limit is their charge.
+pub fn rating_of(origin: PotentialOrigin) -> Option<Power> {
+    match origin {
+        // 90 kVA, considering a power factor of 1, just like the generator load calculation.
+        PotentialOrigin::EngineGenerator(_)
+        | PotentialOrigin::ApuGenerator(_)
+        | PotentialOrigin::External => Some(Power::new::<watt>(90000.)),
+        PotentialOrigin::EmergencyGenerator => Some(Power::new::<watt>(5000.)),
+        // 200 A at 28 V.
+        PotentialOrigin::TransformerRectifier(_) => Some(Power::new::<watt>(5600.)),
+        PotentialOrigin::StaticInverter => Some(Power::new::<watt>(1000.)),
+        PotentialOrigin::Battery(_) => None,
+    }
+}
+
+/// The peak loads found while sampling a single power source configuration and flight phase.
+pub struct LoadAnalysisResult {
+    configuration: PowerSourceConfiguration,
+    phase: PowerConsumerFlightPhase,
+    bus_loads: Vec<(ElectricalBusType, Power)>,
+    source_loads: Vec<(PotentialOrigin, Power)>,
+}
+impl LoadAnalysisResult {
+    pub fn new(configuration: PowerSourceConfiguration, phase: PowerConsumerFlightPhase) -> Self {
+        Self {
+            configuration,
+            phase,
+            bus_loads: Vec::new(),
+            source_loads: Vec::new(),
+        }
+    }
+
+    pub fn configuration(&self) -> PowerSourceConfiguration {
+        self.configuration
+    }
+
+    pub fn phase_name(&self) -> &'static str {
+        flight_phase_name(self.phase)
+    }
+
+    /// The peak load of the named power consumers on each bus.
+    pub fn bus_loads(&self) -> &[(ElectricalBusType, Power)] {
+        &self.bus_loads
+    }
+
+    /// The peak load of each source which supplied power.
+    pub fn source_loads(&self) -> &[(PotentialOrigin, Power)] {
+        &self.source_loads
+    }
+
+    fn record_bus_load(&mut self, bus_type: ElectricalBusType, load: Power) {
+        record_peak(&mut self.bus_loads, bus_type, load);
+    }
+
+    fn record_source_load(&mut self, origin: PotentialOrigin, load: Power) {
+        record_peak(&mut self.source_loads, origin, load);
+    }
+}
+
+fn record_peak<T: PartialEq>(loads: &mut Vec<(T, Power)>, key: T, load: Power) {
+    match loads.iter_mut().find(|(existing, _)| *existing == key) {
+        Some((_, peak)) => {
+            if load > *peak {
+                *peak = load;
+            }
+        }
+        None => loads.push((key, load)),
+    }
+}
+
+/// The smallest margin found for a source across all analysed configurations and phases.
+pub struct WorstCaseMargin {
+    origin: PotentialOrigin,
+    configuration: PowerSourceConfiguration,
+    phase_name: &'static str,
+    load: Power,
+    rating: Power,
+}
+impl WorstCaseMargin {
+    pub fn origin(&self) -> PotentialOrigin {
+        self.origin
+    }
+
+    pub fn configuration(&self) -> PowerSourceConfiguration {
+        self.configuration
+    }
+
+    pub fn phase_name(&self) -> &'static str {
+        self.phase_name
+    }
+
+    pub fn load(&self) -> Power {
+        self.load
+    }
+
+    pub fn rating(&self) -> Power {
+        self.rating
+    }
+
+    pub fn margin(&self) -> Power {
+        self.rating - self.load
+    }
+}
+
+/// Determines the smallest margin for every rated source.
+pub fn worst_case_margins(results: &[LoadAnalysisResult]) -> Vec<WorstCaseMargin> {
+    let mut margins: Vec<WorstCaseMargin> = Vec::new();
+    for result in results {
+        for &(origin, load) in result.source_loads() {
+            let rating = match rating_of(origin) {
+                Some(rating) => rating,
+                None => continue,
+            };
+
+            let candidate = WorstCaseMargin {
+                origin,
+                configuration: result.configuration(),
+                phase_name: result.phase_name(),
+                load,
+                rating,
+            };
+
+            match margins.iter_mut().find(|margin| margin.origin == origin) {
+                Some(margin) => {
+                    if candidate.margin() < margin.margin() {
+                        *margin = candidate;
+                    }
+                }
+                None => margins.push(candidate),
+            }
+        }
+    }
+
+    margins
+}
+
+/// Runs the A320 natively through every power source configuration and flight phase.
+pub struct LoadAnalysis {
+    sample_duration: Duration,
+    sample_interval: Duration,
+}
+impl LoadAnalysis {
+    pub fn new(sample_duration: Duration) -> Self {
+        Self {
+            sample_duration,
+            sample_interval: Duration::from_millis(100),
+        }
+    }
+
+    pub fn run(&self) -> Vec<LoadAnalysisResult> {
+        let mut results = Vec::new();
+        for &configuration in PowerSourceConfiguration::ALL.iter() {
+            for &phase in crate::configuration::FLIGHT_PHASES.iter() {
+                match self.analyse(configuration, phase) {
+                    Ok(result) => results.push(result),
+                    Err(error) => eprintln!(
+                        "Skipped {} / {}: {}.",
+                        configuration.name(),
+                        flight_phase_name(phase),
+                        error
+                    ),
+                }
+            }
+        }
+
+        results
+    }
+
+    fn analyse(
+        &self,
+        configuration: PowerSourceConfiguration,
+        phase: PowerConsumerFlightPhase,
+    ) -> Result<LoadAnalysisResult, String> {
+        let mut test_bed = SimulationTestBed::new(A320::new);
+        set_flight_phase(&mut test_bed, phase);
+        configuration.establish(&mut test_bed)?;
+
+        let mut result = LoadAnalysisResult::new(configuration, phase);
+        let mut sampled = Duration::from_secs(0);
+        while sampled < self.sample_duration {
+            test_bed.run_with_delta(self.sample_interval);
+            sampled += self.sample_interval;
+
+            self.sample(&mut test_bed, &mut result);
+        }
+
+        Ok(result)
+    }
+
+    fn sample(&self, test_bed: &mut SimulationTestBed<A320>, result: &mut LoadAnalysisResult) {
+        let breakdown = test_bed.power_consumption_breakdown();
+        for bus_type in breakdown.buses() {
+            result.record_bus_load(bus_type, breakdown.consumption_of_bus(bus_type));
+        }
+
+        for &origin in SOURCES.iter() {
+            let load =
+                test_bed.query_elec(|_, electricity| electricity.total_consumption_of(origin));
+            if load > Power::new::<watt>(0.) {
+                result.record_source_load(origin, load);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result_with_source_load(
+        configuration: PowerSourceConfiguration,
+        phase: PowerConsumerFlightPhase,
+        origin: PotentialOrigin,
+        load: f64,
+    ) -> LoadAnalysisResult {
+        let mut result = LoadAnalysisResult::new(configuration, phase);
+        result.record_source_load(origin, Power::new::<watt>(load));
+
+        result
+    }
+
+    #[test]
+    fn records_the_peak_load() {
+        let mut result = LoadAnalysisResult::new(
+            PowerSourceConfiguration::EngineGenerators,
+            PowerConsumerFlightPhase::Flight,
+        );
+        result.record_bus_load(
+            ElectricalBusType::AlternatingCurrent(1),
+            Power::new::<watt>(1000.),
+        );
+        result.record_bus_load(
+            ElectricalBusType::AlternatingCurrent(1),
+            Power::new::<watt>(3000.),
+        );
+        result.record_bus_load(
+            ElectricalBusType::AlternatingCurrent(1),
+            Power::new::<watt>(2000.),
+        );
+
+        assert_eq!(
+            result.bus_loads(),
+            &[(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(3000.)
+            )]
+        );
+    }
+
+    #[test]
+    fn worst_case_margin_is_the_smallest_margin_of_a_source() {
+        let results = vec![
+            result_with_source_load(
+                PowerSourceConfiguration::EngineGenerators,
+                PowerConsumerFlightPhase::Takeoff,
+                PotentialOrigin::TransformerRectifier(1),
+                2000.,
+            ),
+            result_with_source_load(
+                PowerSourceConfiguration::EngineGenerators,
+                PowerConsumerFlightPhase::Flight,
+                PotentialOrigin::TransformerRectifier(1),
+                4000.,
+            ),
+            result_with_source_load(
+                PowerSourceConfiguration::ApuGeneratorOnly,
+                PowerConsumerFlightPhase::BeforeStart,
+                PotentialOrigin::TransformerRectifier(1),
+                3000.,
+            ),
+        ];
+
+        let margins = worst_case_margins(&results);
+
+        assert_eq!(margins.len(), 1);
+        assert_eq!(margins[0].phase_name(), "FLIGHT");
+        assert_eq!(margins[0].margin(), Power::new::<watt>(1600.));
+    }
+
+    #[test]
+    fn sources_without_rating_have_no_margin() {
+        let results = vec![result_with_source_load(
+            PowerSourceConfiguration::BatteryOnly,
+            PowerConsumerFlightPhase::BeforeStart,
+            PotentialOrigin::Battery(1),
+            500.,
+        )];
+
+        assert!(worst_case_margins(&results).is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use a320_systems::A320;
+use systems::{
+    electrical::consumption::PowerConsumerFlightPhase,
+    simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+};
+use uom::si::{f64::*, length::foot, ratio::percent, velocity::knot};
+
+/// The power source configurations which are analysed. Each configuration
+/// brings a cold and dark aircraft into a state in which only the given
+/// sources supply the electrical network.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PowerSourceConfiguration {
+    EngineGenerators,
+    ApuGeneratorOnly,
+    EmergencyGenerator,
+    BatteryOnly,
+}
+impl PowerSourceConfiguration {
+    pub const ALL: [PowerSourceConfiguration; 4] = [
+        PowerSourceConfiguration::EngineGenerators,
+        PowerSourceConfiguration::ApuGeneratorOnly,
+        PowerSourceConfiguration::EmergencyGenerator,
+        PowerSourceConfiguration::BatteryOnly,
+    ];
+
+    const ENGINE_RUNNING_N2_PERCENT: f64 = 80.;
+    const MAXIMUM_APU_START_DURATION: Duration = Duration::from_secs(180);
+    const FRAME: Duration = Duration::from_millis(100);
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            PowerSourceConfiguration::EngineGenerators => "ENGINE GENERATORS",
+            PowerSourceConfiguration::ApuGeneratorOnly => "APU GENERATOR ONLY",
+            PowerSourceConfiguration::EmergencyGenerator => "EMERGENCY GENERATOR",
+            PowerSourceConfiguration::BatteryOnly => "BATTERY ONLY",
+        }
+    }
+
+    /// Brings the aircraft into this configuration. Returns an error when the configuration
+    /// couldn't be established, for example because the APU didn't start.
+    pub fn establish(&self, test_bed: &mut SimulationTestBed<A320>) -> Result<(), String> {
+        match self {
+            PowerSourceConfiguration::EngineGenerators => {
+                Self::on_ground(test_bed);
+                test_bed.write_by_name(
+                    "TURB ENG CORRECTED N2:1",
+                    Ratio::new::<percent>(Self::ENGINE_RUNNING_N2_PERCENT),
+                );
+                test_bed.write_by_name(
+                    "TURB ENG CORRECTED N2:2",
+                    Ratio::new::<percent>(Self::ENGINE_RUNNING_N2_PERCENT),
+                );
+                test_bed.run_with_fixed_frames(Duration::from_secs(30), Self::FRAME);
+            }
+            PowerSourceConfiguration::ApuGeneratorOnly => {
+                Self::on_ground(test_bed);
+                test_bed.write_by_name("UNLIMITED FUEL", true);
+                test_bed.write_by_name("OVHD_APU_MASTER_SW_PB_IS_ON", true);
+                test_bed.run_with_fixed_frames(Duration::from_secs(1), Self::FRAME);
+                test_bed.write_by_name("OVHD_APU_START_PB_IS_ON", true);
+
+                let mut start_duration = Duration::from_secs(0);
+                loop {
+                    let apu_is_available: bool =
+                        test_bed.read_by_name("OVHD_APU_START_PB_IS_AVAILABLE");
+                    if apu_is_available {
+                        break;
+                    }
+
+                    if start_duration >= Self::MAXIMUM_APU_START_DURATION {
+                        return Err("the APU didn't become available".to_owned());
+                    }
+
+                    test_bed.run_with_fixed_frames(Duration::from_secs(1), Self::FRAME);
+                    start_duration += Duration::from_secs(1);
+                }
+
+                test_bed.run_with_fixed_frames(Duration::from_secs(10), Self::FRAME);
+            }
+            PowerSourceConfiguration::EmergencyGenerator => {
+                // Without any AC source in flight, the RAT deploys and drives the emergency generator.
+                test_bed.set_on_ground(false);
+                test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+                test_bed.set_indicated_altitude(Length::new::<foot>(20000.));
+                test_bed.run_with_fixed_frames(Duration::from_secs(60), Self::FRAME);
+            }
+            PowerSourceConfiguration::BatteryOnly => {
+                Self::on_ground(test_bed);
+                test_bed.run_with_fixed_frames(Duration::from_secs(10), Self::FRAME);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn on_ground(test_bed: &mut SimulationTestBed<A320>) {
+        test_bed.set_on_ground(true);
+        test_bed.set_indicated_airspeed(Velocity::new::<knot>(0.));
+        test_bed.set_indicated_altitude(Length::new::<foot>(0.));
+    }
+}
+
+/// The flight phases for which the A320 power consumption defines demand.
+pub const FLIGHT_PHASES: [PowerConsumerFlightPhase; 6] = [
+    PowerConsumerFlightPhase::BeforeStart,
+    PowerConsumerFlightPhase::AfterStart,
+    PowerConsumerFlightPhase::Takeoff,
+    PowerConsumerFlightPhase::Flight,
+    PowerConsumerFlightPhase::Landing,
+    PowerConsumerFlightPhase::TaxiIn,
+];
+
+pub fn flight_phase_name(phase: PowerConsumerFlightPhase) -> &'static str {
+    match phase {
+        PowerConsumerFlightPhase::BeforeStart => "BEFORE START",
+        PowerConsumerFlightPhase::AfterStart => "AFTER START",
+        PowerConsumerFlightPhase::Takeoff => "TAKEOFF",
+        PowerConsumerFlightPhase::Flight => "FLIGHT",
+        PowerConsumerFlightPhase::Landing => "LANDING",
+        PowerConsumerFlightPhase::TaxiIn => "TAXI IN",
+    }
+}
+
+/// Sets the FWC flight phase which makes the power consumers demand
+/// the power of the given phase.
+pub fn set_flight_phase(test_bed: &mut SimulationTestBed<A320>, phase: PowerConsumerFlightPhase) {
+    let fwc_flight_phase = match phase {
+        PowerConsumerFlightPhase::BeforeStart => 1.,
+        PowerConsumerFlightPhase::AfterStart => 2.,
+        PowerConsumerFlightPhase::Takeoff => 3.,
+        PowerConsumerFlightPhase::Flight => 6.,
+        PowerConsumerFlightPhase::Landing => 7.,
+        PowerConsumerFlightPhase::TaxiIn => 9.,
+    };
+
+    test_bed.write_by_name("FWC_FLIGHT_PHASE", fwc_flight_phase);
+}
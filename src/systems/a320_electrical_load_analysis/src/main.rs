@@ -0,0 +1,174 @@
+//! Electrical load analysis of the A320.
+//!
+//! Runs the A320 natively through every power source configuration and flight phase and
+//! prints the peak load of each bus and source, together with the worst-case margin of
+//! every rated source. Use this to check changes to the A320 power consumption against
+//! the aircraft's electrical load analysis without flying.
+//!
+//! Usage: `cargo run -p a320_electrical_load_analysis -- [--csv] [--sample-seconds <seconds>]`
+
+mod analysis;
+mod configuration;
+
+use std::{env, process, time::Duration};
+
+use analysis::{rating_of, worst_case_margins, LoadAnalysis, LoadAnalysisResult, WorstCaseMargin};
+use uom::si::power::watt;
+
+struct Arguments {
+    csv: bool,
+    sample_duration: Duration,
+}
+impl Arguments {
+    const DEFAULT_SAMPLE_SECONDS: u64 = 60;
+
+    fn parse() -> Result<Self, String> {
+        let mut arguments = Self {
+            csv: false,
+            sample_duration: Duration::from_secs(Self::DEFAULT_SAMPLE_SECONDS),
+        };
+
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--csv" => arguments.csv = true,
+                "--sample-seconds" => {
+                    let seconds = args
+                        .next()
+                        .ok_or_else(|| "--sample-seconds requires a value".to_owned())?;
+                    arguments.sample_duration = Duration::from_secs(
+                        seconds
+                            .parse()
+                            .map_err(|_| format!("invalid number of seconds: {}", seconds))?,
+                    );
+                }
+                _ => return Err(format!("unknown argument: {}", arg)),
+            }
+        }
+
+        Ok(arguments)
+    }
+}
+
+fn main() {
+    let arguments = match Arguments::parse() {
+        Ok(arguments) => arguments,
+        Err(error) => {
+            eprintln!("{}", error);
+            eprintln!("Usage: a320_electrical_load_analysis [--csv] [--sample-seconds <seconds>]");
+            process::exit(1);
+        }
+    };
+
+    let results = LoadAnalysis::new(arguments.sample_duration).run();
+    let margins = worst_case_margins(&results);
+
+    if arguments.csv {
+        print_csv(&results);
+    } else {
+        for result in &results {
+            print_result(result);
+        }
+
+        print_worst_case_margins(&margins);
+    }
+}
+
+fn print_result(result: &LoadAnalysisResult) {
+    println!(
+        "== {} / {} ==",
+        result.configuration().name(),
+        result.phase_name()
+    );
+
+    println!("{:<24}{:>12}", "BUS", "LOAD (W)");
+    for (bus_type, load) in result.bus_loads() {
+        println!("{:<24}{:>12.1}", bus_type.to_string(), load.get::<watt>());
+    }
+
+    println!();
+    println!(
+        "{:<24}{:>12}{:>12}{:>12}{:>10}",
+        "SOURCE", "LOAD (W)", "RATING (W)", "MARGIN (W)", "MARGIN %"
+    );
+    for (origin, load) in result.source_loads() {
+        match rating_of(*origin) {
+            Some(rating) => println!(
+                "{:<24}{:>12.1}{:>12.1}{:>12.1}{:>10.1}",
+                origin.to_string(),
+                load.get::<watt>(),
+                rating.get::<watt>(),
+                (rating - *load).get::<watt>(),
+                margin_percentage(rating.get::<watt>(), load.get::<watt>())
+            ),
+            None => println!(
+                "{:<24}{:>12.1}{:>12}{:>12}{:>10}",
+                origin.to_string(),
+                load.get::<watt>(),
+                "-",
+                "-",
+                "-"
+            ),
+        }
+    }
+
+    println!();
+}
+
+fn print_worst_case_margins(margins: &[WorstCaseMargin]) {
+    println!("== WORST-CASE MARGINS ==");
+    println!(
+        "{:<24}{:>12}{:>12}{:>10}  CONFIGURATION / PHASE",
+        "SOURCE", "LOAD (W)", "MARGIN (W)", "MARGIN %"
+    );
+    for margin in margins {
+        println!(
+            "{:<24}{:>12.1}{:>12.1}{:>10.1}  {} / {}",
+            margin.origin().to_string(),
+            margin.load().get::<watt>(),
+            margin.margin().get::<watt>(),
+            margin_percentage(margin.rating().get::<watt>(), margin.load().get::<watt>()),
+            margin.configuration().name(),
+            margin.phase_name()
+        );
+    }
+}
+
+fn print_csv(results: &[LoadAnalysisResult]) {
+    println!("configuration,phase,kind,name,load_w,rating_w,margin_w");
+    for result in results {
+        for (bus_type, load) in result.bus_loads() {
+            println!(
+                "{},{},bus,{},{:.1},,",
+                result.configuration().name(),
+                result.phase_name(),
+                bus_type,
+                load.get::<watt>()
+            );
+        }
+
+        for (origin, load) in result.source_loads() {
+            let (rating, margin) = match rating_of(*origin) {
+                Some(rating) => (
+                    format!("{:.1}", rating.get::<watt>()),
+                    format!("{:.1}", (rating - *load).get::<watt>()),
+                ),
+                None => (String::new(), String::new()),
+            };
+
+            println!(
+                "{},{},source,{},{:.1},{},{}",
+                result.configuration().name(),
+                result.phase_name(),
+                origin,
+                load.get::<watt>(),
+                rating,
+                margin
+            );
+        }
+    }
+}
+
+fn margin_percentage(rating: f64, load: f64) -> f64 {
+    (rating - load) / rating * 100.
+}
@@ -0,0 +1,169 @@
+use plotlib::page::Page;
+use plotlib::repr::Plot;
+use plotlib::style::LineStyle;
+use plotlib::view::ContinuousView;
+use std::{fs, io::Write, path::Path};
+
+/// History class to record a simulation
+pub struct History {
+    /// Simulation time starting from 0
+    time_vector: Vec<f64>,
+    /// Name of each var saved
+    name_vector: Vec<String>,
+    /// Vector data for each var saved
+    data_vector: Vec<Vec<f64>>,
+}
+impl History {
+    pub fn new(names: Vec<String>) -> History {
+        History {
+            time_vector: Vec::new(),
+            name_vector: names,
+            data_vector: Vec::new(),
+        }
+    }
+
+    /// Sets initialisation values of each data before first step
+    pub fn init(&mut self, start_time: f64, values: Vec<f64>) {
+        self.time_vector.push(start_time);
+        for v in values {
+            self.data_vector.push(vec![v]);
+        }
+    }
+
+    /// Updates all values and time vector
+    pub fn update(&mut self, delta_time: f64, values: Vec<f64>) {
+        self.time_vector
+            .push(self.time_vector.last().unwrap() + delta_time);
+        self.push_data(values);
+    }
+
+    fn push_data(&mut self, values: Vec<f64>) {
+        for (idx, v) in values.iter().enumerate() {
+            self.data_vector[idx].push(*v);
+        }
+    }
+
+    /// Number of recorded samples, including the initial one
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.time_vector.len()
+    }
+
+    #[cfg(test)]
+    pub fn last_value(&self, name: &str) -> Option<f64> {
+        self.data(name).and_then(|data| data.last().copied())
+    }
+
+    /// Value of the last sample recorded at or before the given time
+    #[cfg(test)]
+    pub fn value_at(&self, name: &str, time: f64) -> Option<f64> {
+        let idx = self.time_vector.iter().rposition(|t| *t <= time)?;
+
+        self.data(name).map(|data| data[idx])
+    }
+
+    #[cfg(test)]
+    fn data(&self, name: &str) -> Option<&Vec<f64>> {
+        self.name_vector
+            .iter()
+            .position(|n| n == name)
+            .map(|idx| &self.data_vector[idx])
+    }
+
+    /// Writes one line per sample, time first then every var in recording order
+    pub fn write_csv(&self, path: &Path) -> Result<(), String> {
+        let mut file = fs::File::create(path)
+            .map_err(|error| format!("cannot create {}: {}", path.display(), error))?;
+
+        let mut content = String::from("Time (s)");
+        for name in &self.name_vector {
+            content.push(',');
+            content.push_str(name);
+        }
+        content.push('\n');
+
+        for (idx, time) in self.time_vector.iter().enumerate() {
+            content.push_str(&format!("{:.3}", time));
+            for data in &self.data_vector {
+                content.push(',');
+                content.push_str(&data[idx].to_string());
+            }
+            content.push('\n');
+        }
+
+        file.write_all(content.as_bytes())
+            .map_err(|error| format!("cannot write {}: {}", path.display(), error))
+    }
+
+    /// Builds one graph per var using rust crate plotlib, saved as SVG files in the given directory
+    pub fn write_svg(&self, directory: &Path, file_prefix: &str) -> Result<(), String> {
+        for (name, data) in self.name_vector.iter().zip(&self.data_vector) {
+            let (min, max) = y_range(data);
+
+            let plot = Plot::new(
+                self.time_vector
+                    .iter()
+                    .copied()
+                    .zip(data.iter().copied())
+                    .collect(),
+            )
+            .line_style(LineStyle::new().colour("#DD3355"));
+
+            let view = ContinuousView::new()
+                .add(plot)
+                .x_range(0.0, *self.time_vector.last().unwrap())
+                .y_range(min, max)
+                .x_label("Time (s)")
+                .y_label(name.as_str());
+
+            let path = directory.join(format!("{}_{}.svg", file_prefix, file_name(name)));
+            Page::single(&view)
+                .save(&path)
+                .map_err(|error| format!("cannot write {}: {}", path.display(), error))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Range of the data with a margin, so that flat lines are not drawn on the graph border
+fn y_range(data: &[f64]) -> (f64, f64) {
+    let min = data.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let margin = ((max - min) * 0.05).max(1.);
+
+    (min - margin, max + margin)
+}
+
+fn file_name(name: &str) -> String {
+    name.split(" (")
+        .next()
+        .unwrap()
+        .to_lowercase()
+        .replace(' ', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_at_returns_last_sample_before_time() {
+        let mut history = History::new(vec!["Pressure (psi)".to_owned()]);
+        history.init(0., vec![0.]);
+        history.update(1., vec![1000.]);
+        history.update(1., vec![2000.]);
+
+        assert_eq!(history.value_at("Pressure (psi)", 1.5), Some(1000.));
+        assert_eq!(history.last_value("Pressure (psi)"), Some(2000.));
+        assert_eq!(history.value_at("Flow (gpm)", 1.5), None);
+    }
+
+    #[test]
+    fn file_name_drops_unit_and_spaces() {
+        assert_eq!(
+            file_name("YELLOW system section pressure (psi)"),
+            "yellow_system_section_pressure"
+        );
+    }
+}
@@ -0,0 +1,550 @@
+use serde::Deserialize;
+use std::{collections::HashSet, fs, path::Path};
+use systems::{
+    hydraulic::config::{
+        parse_toml, validate_accumulator, validate_circuit, validate_pressure_switch,
+        validate_reservoir, HydraulicConfigError,
+    },
+    shared::HydraulicColor,
+};
+use uom::si::{f64::*, pressure::psi, ratio::percent, volume::gallon};
+
+/// Description of a hydraulic simulation run, deserialised from a TOML scenario file.
+///
+/// A scenario describes one or more hydraulic circuits with their reservoir, accumulator,
+/// pumps and actuator loads, an optional PTU between two circuits, and a list of timed
+/// events commanding pumps, PTU and loads during the run.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    pub duration_s: f64,
+    #[serde(default = "Scenario::default_time_step_s")]
+    pub time_step_s: f64,
+    pub circuits: Vec<CircuitDescription>,
+    pub ptu: Option<PtuDescription>,
+    #[serde(default)]
+    pub events: Vec<Event>,
+}
+impl Scenario {
+    fn default_time_step_s() -> f64 {
+        0.05
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| format!("cannot read {}: {}", path.display(), error))?;
+
+        Self::parse(&content).map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut scenario: Scenario = parse_toml(content).map_err(|error| error.to_string())?;
+
+        scenario.validate()?;
+        scenario
+            .events
+            .sort_by_key(|event| total_order_key(event.time_s));
+
+        Ok(scenario)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        validate_finite("duration_s", self.duration_s)?;
+        validate_finite("time_step_s", self.time_step_s)?;
+
+        if self.duration_s <= 0. {
+            return Err("duration_s must be positive".to_owned());
+        }
+
+        if self.time_step_s <= 0. {
+            return Err("time_step_s must be positive".to_owned());
+        }
+
+        if self.circuits.is_empty() {
+            return Err("at least one circuit is required".to_owned());
+        }
+
+        let mut colors = HashSet::new();
+        let mut pump_ids = HashSet::new();
+        let mut actuator_ids = HashSet::new();
+        for circuit in &self.circuits {
+            circuit.validate()?;
+
+            if !colors.insert(circuit.color()?.to_string()) {
+                return Err(format!("circuit {} is described twice", circuit.color));
+            }
+
+            for pump in &circuit.pumps {
+                if !pump_ids.insert(pump.id.as_str()) {
+                    return Err(format!("pump {} is described twice", pump.id));
+                }
+            }
+
+            for actuator in &circuit.actuators {
+                if !actuator_ids.insert(actuator.id.as_str()) {
+                    return Err(format!("actuator {} is described twice", actuator.id));
+                }
+            }
+        }
+
+        if let Some(ptu) = &self.ptu {
+            let left = self.circuit_index(&ptu.left)?;
+            let right = self.circuit_index(&ptu.right)?;
+            if left == right {
+                return Err("ptu must connect two different circuits".to_owned());
+            }
+        }
+
+        for event in &self.events {
+            validate_finite("event time_s", event.time_s)?;
+            if event.time_s < 0. {
+                return Err(format!("event time {} must not be negative", event.time_s));
+            }
+
+            match &event.action {
+                Action::PressurisePump { pump } | Action::DepressurisePump { pump } => {
+                    self.pump(pump)?;
+                }
+                Action::SetPumpRpm { pump, rpm } => {
+                    validate_finite("set_pump_rpm rpm", *rpm)?;
+                    if self.pump(pump)?.kind != PumpKind::EngineDriven {
+                        return Err(format!(
+                            "set_pump_rpm requires an engine driven pump, {} is not",
+                            pump
+                        ));
+                    }
+                }
+                Action::EnablePtu | Action::DisablePtu => {
+                    if self.ptu.is_none() {
+                        return Err("ptu event without a ptu in the scenario".to_owned());
+                    }
+                }
+                Action::SetActuatorFlow {
+                    actuator,
+                    flow_gal_per_s,
+                } => {
+                    validate_finite("set_actuator_flow flow_gal_per_s", *flow_gal_per_s)?;
+                    if !actuator_ids.contains(actuator.as_str()) {
+                        return Err(format!("unknown actuator {}", actuator));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn circuit_index(&self, color: &str) -> Result<usize, String> {
+        let color = parse_color(color)?;
+        self.circuits
+            .iter()
+            .position(|circuit| circuit.color().ok() == Some(color))
+            .ok_or_else(|| format!("unknown circuit {}", color))
+    }
+
+    fn pump(&self, id: &str) -> Result<&PumpDescription, String> {
+        self.circuits
+            .iter()
+            .flat_map(|circuit| circuit.pumps.iter())
+            .find(|pump| pump.id == id)
+            .ok_or_else(|| format!("unknown pump {}", id))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CircuitDescription {
+    pub color: String,
+    #[serde(default = "CircuitDescription::default_priming_percent")]
+    pub priming_percent: f64,
+    pub high_pressure_volume_gal: f64,
+    pub system_pressure_switch_psi: PressureSwitchDescription,
+    pub pump_pressure_switch_psi: PressureSwitchDescription,
+    #[serde(default = "CircuitDescription::default_reservoir_air_pressure_psi")]
+    pub reservoir_air_pressure_psi: f64,
+    pub reservoir: ReservoirDescription,
+    pub accumulator: Option<AccumulatorDescription>,
+    pub pumps: Vec<PumpDescription>,
+    #[serde(default)]
+    pub actuators: Vec<ActuatorDescription>,
+}
+impl CircuitDescription {
+    fn default_priming_percent() -> f64 {
+        100.
+    }
+
+    fn default_reservoir_air_pressure_psi() -> f64 {
+        50.
+    }
+
+    pub fn color(&self) -> Result<HydraulicColor, String> {
+        parse_color(&self.color)
+    }
+
+    pub fn pump_section_pumps(&self) -> impl Iterator<Item = &PumpDescription> {
+        self.pumps
+            .iter()
+            .filter(|pump| pump.section == PumpSection::Pump)
+    }
+
+    pub fn system_section_pump(&self) -> Option<&PumpDescription> {
+        self.pumps
+            .iter()
+            .find(|pump| pump.section == PumpSection::System)
+    }
+
+    /// Validation common to every hydraulic circuit description, including aircraft configurations
+    fn validate_shared(&self) -> Result<(), HydraulicConfigError> {
+        validate_circuit(
+            self.pump_section_pumps().count(),
+            Ratio::new::<percent>(self.priming_percent),
+            Volume::new::<gallon>(self.high_pressure_volume_gal),
+        )?;
+        self.system_pressure_switch_psi
+            .validate("system section pressure switch")?;
+        self.pump_pressure_switch_psi
+            .validate("pump section pressure switch")?;
+        self.reservoir.validate()?;
+        if let Some(accumulator) = &self.accumulator {
+            accumulator.validate()?;
+        }
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let color = self.color()?;
+
+        if self.pump_section_pumps().count() == 0 {
+            return Err(format!("circuit {} requires a pump section pump", color));
+        }
+
+        if self
+            .pumps
+            .iter()
+            .filter(|pump| pump.section == PumpSection::System)
+            .count()
+            > 1
+        {
+            return Err(format!(
+                "circuit {} can only have one system section pump",
+                color
+            ));
+        }
+
+        self.validate_shared()
+            .map_err(|error| format!("circuit {}: {}", color, error))?;
+
+        for pump in &self.pumps {
+            if pump.kind == PumpKind::EngineDriven && pump.max_current_a.is_some() {
+                return Err(format!(
+                    "pump {} is engine driven and cannot have a max_current_a",
+                    pump.id
+                ));
+            }
+
+            validate_finite("pump wear_percent", pump.wear_percent)?;
+            if let Some(max_current_a) = pump.max_current_a {
+                validate_finite("pump max_current_a", max_current_a)?;
+            }
+
+            if !(0. ..=100.).contains(&pump.wear_percent) {
+                return Err(format!(
+                    "pump {} wear_percent must be between 0 and 100",
+                    pump.id
+                ));
+            }
+        }
+
+        for actuator in &self.actuators {
+            validate_finite("actuator flow_gal_per_s", actuator.flow_gal_per_s)?;
+            if actuator.flow_gal_per_s < 0. {
+                return Err(format!(
+                    "actuator {} flow_gal_per_s must not be negative",
+                    actuator.id
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PressureSwitchDescription {
+    pub low: f64,
+    pub high: f64,
+}
+impl PressureSwitchDescription {
+    fn validate(&self, name: &str) -> Result<(), HydraulicConfigError> {
+        validate_pressure_switch(
+            name,
+            Pressure::new::<psi>(self.low),
+            Pressure::new::<psi>(self.high),
+        )
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReservoirDescription {
+    pub max_capacity_gal: f64,
+    pub max_gaugeable_gal: f64,
+    pub level_gal: f64,
+    pub low_level_threshold_gal: f64,
+}
+impl ReservoirDescription {
+    fn validate(&self) -> Result<(), HydraulicConfigError> {
+        validate_reservoir(
+            Volume::new::<gallon>(self.max_capacity_gal),
+            Volume::new::<gallon>(self.max_gaugeable_gal),
+            Volume::new::<gallon>(self.level_gal),
+            Volume::new::<gallon>(self.low_level_threshold_gal),
+        )
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccumulatorDescription {
+    pub gas_precharge_psi: f64,
+    pub total_volume_gal: f64,
+    #[serde(default)]
+    pub fluid_volume_gal: f64,
+}
+impl AccumulatorDescription {
+    fn validate(&self) -> Result<(), HydraulicConfigError> {
+        validate_accumulator(
+            Pressure::new::<psi>(self.gas_precharge_psi),
+            Volume::new::<gallon>(self.total_volume_gal),
+            Volume::new::<gallon>(self.fluid_volume_gal),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PumpKind {
+    EngineDriven,
+    Electric,
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PumpSection {
+    Pump,
+    System,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PumpDescription {
+    pub id: String,
+    pub kind: PumpKind,
+    pub section: PumpSection,
+    pub max_current_a: Option<f64>,
+    #[serde(default)]
+    pub wear_percent: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ActuatorDescription {
+    pub id: String,
+    #[serde(default)]
+    pub flow_gal_per_s: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PtuDescription {
+    pub left: String,
+    pub right: String,
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+#[derive(Deserialize)]
+pub struct Event {
+    pub time_s: f64,
+    #[serde(flatten)]
+    pub action: Action,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Action {
+    PressurisePump {
+        pump: String,
+    },
+    DepressurisePump {
+        pump: String,
+    },
+    SetPumpRpm {
+        pump: String,
+        rpm: f64,
+    },
+    EnablePtu,
+    DisablePtu,
+    SetActuatorFlow {
+        actuator: String,
+        flow_gal_per_s: f64,
+    },
+}
+
+fn validate_finite(name: &str, value: f64) -> Result<(), String> {
+    if !value.is_finite() {
+        return Err(format!("{} {} must be a finite number", name, value));
+    }
+
+    Ok(())
+}
+
+/// Key ordering floats like `f64::total_cmp`, which the toolchain doesn't provide yet.
+fn total_order_key(value: f64) -> i64 {
+    let bits = value.to_bits() as i64;
+    bits ^ (((bits >> 63) as u64) >> 1) as i64
+}
+
+fn parse_color(color: &str) -> Result<HydraulicColor, String> {
+    match color.to_lowercase().as_str() {
+        "green" => Ok(HydraulicColor::Green),
+        "blue" => Ok(HydraulicColor::Blue),
+        "yellow" => Ok(HydraulicColor::Yellow),
+        _ => Err(format!("unknown hydraulic color {}", color)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINIMAL_CIRCUIT: &str = r#"
+        duration_s = 10.0
+
+        [[circuits]]
+        color = "yellow"
+        high_pressure_volume_gal = 10.0
+        system_pressure_switch_psi = { low = 1450.0, high = 1750.0 }
+        pump_pressure_switch_psi = { low = 1450.0, high = 1750.0 }
+        reservoir = { max_capacity_gal = 5.0, max_gaugeable_gal = 4.0, level_gal = 3.0, low_level_threshold_gal = 0.5 }
+
+        [[circuits.pumps]]
+        id = "EDP"
+        kind = "engine_driven"
+        section = "pump"
+    "#;
+
+    #[test]
+    fn minimal_scenario_uses_defaults() {
+        let scenario = Scenario::parse(MINIMAL_CIRCUIT).unwrap();
+
+        assert!((scenario.time_step_s - 0.05).abs() < f64::EPSILON);
+        assert!(scenario.ptu.is_none());
+        assert!(scenario.events.is_empty());
+        assert!((scenario.circuits[0].priming_percent - 100.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn events_are_sorted_by_time() {
+        let content = format!(
+            "{}{}",
+            MINIMAL_CIRCUIT,
+            r#"
+            [[events]]
+            time_s = 20.0
+            action = "depressurise_pump"
+            pump = "EDP"
+
+            [[events]]
+            time_s = 5.0
+            action = "set_pump_rpm"
+            pump = "EDP"
+            rpm = 4000
+            "#
+        );
+
+        let scenario = Scenario::parse(&content).unwrap();
+
+        assert!(matches!(
+            scenario.events[0].action,
+            Action::SetPumpRpm { .. }
+        ));
+        assert!(matches!(
+            scenario.events[1].action,
+            Action::DepressurisePump { .. }
+        ));
+    }
+
+    #[test]
+    fn event_at_non_finite_time_is_rejected() {
+        let content = format!(
+            "{}{}",
+            MINIMAL_CIRCUIT,
+            r#"
+            [[events]]
+            time_s = inf
+            action = "depressurise_pump"
+            pump = "EDP"
+            "#
+        );
+
+        assert!(Scenario::parse(&content).is_err());
+    }
+
+    #[test]
+    fn event_on_unknown_pump_is_rejected() {
+        let content = format!(
+            "{}{}",
+            MINIMAL_CIRCUIT,
+            r#"
+            [[events]]
+            time_s = 5.0
+            action = "pressurise_pump"
+            pump = "EPUMP"
+            "#
+        );
+
+        assert!(Scenario::parse(&content).is_err());
+    }
+
+    #[test]
+    fn pump_wear_above_fully_worn_is_rejected() {
+        let content = MINIMAL_CIRCUIT.replace(
+            r#"section = "pump""#,
+            "section = \"pump\"\n        wear_percent = 150.0",
+        );
+
+        assert!(Scenario::parse(&content).is_err());
+    }
+
+    #[test]
+    fn ptu_without_second_circuit_is_rejected() {
+        let content = format!(
+            "{}{}",
+            MINIMAL_CIRCUIT,
+            r#"
+            [ptu]
+            left = "green"
+            right = "yellow"
+            "#
+        );
+
+        assert!(Scenario::parse(&content).is_err());
+    }
+
+    #[test]
+    fn non_finite_reservoir_level_is_rejected() {
+        let content = MINIMAL_CIRCUIT.replace("level_gal = 3.0", "level_gal = nan");
+
+        assert!(Scenario::parse(&content).is_err());
+    }
+
+    #[test]
+    fn circuit_without_pump_section_pump_is_rejected() {
+        let content = MINIMAL_CIRCUIT.replace(r#"section = "pump""#, r#"section = "system""#);
+
+        assert!(Scenario::parse(&content).is_err());
+    }
+}
@@ -0,0 +1,751 @@
+use std::time::Duration;
+
+use systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    hydraulic::{
+        linear_actuator::Actuator, ElectricPump, EngineDrivenPump, HydraulicCircuit,
+        HydraulicCircuitController, PowerTransferUnit, PowerTransferUnitController, PressureSource,
+        PressureSwitch, PressureSwitchType, PumpController, Reservoir, SectionPressure,
+    },
+    shared::{ElectricalBusType, HydraulicColor, PotentialOrigin},
+    simulation::{
+        test::{SimulationTestBed, TestBed},
+        Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+    },
+};
+use uom::si::{
+    angular_velocity::revolution_per_minute, electric_current::ampere, electric_potential::volt,
+    f64::*, pressure::psi, ratio::percent, thermodynamic_temperature::degree_celsius,
+    volume::gallon, volume_rate::gallon_per_minute, volume_rate::gallon_per_second,
+};
+
+use crate::{
+    history::History,
+    scenario::{
+        Action, CircuitDescription, PumpDescription, PumpKind, ReservoirDescription, Scenario,
+    },
+};
+
+/// Runs the given scenario and records every output channel at each time step.
+pub fn run(scenario: &Scenario) -> History {
+    let mut test_bed = SimulationTestBed::new(|context| ScenarioAircraft::new(context, scenario));
+    let time_step = Duration::from_secs_f64(scenario.time_step_s);
+    let steps = (scenario.duration_s / scenario.time_step_s).round() as usize;
+
+    let mut history = History::new(test_bed.query(|a| a.channel_names()));
+    history.init(0., test_bed.query(|a| a.samples()));
+
+    let mut events = scenario.events.iter().peekable();
+    for step in 0..steps {
+        let time_s = step as f64 * scenario.time_step_s;
+        while let Some(event) = events.next_if(|event| event.time_s <= time_s) {
+            test_bed.command(|a| a.apply(&event.action));
+        }
+
+        test_bed.run_with_delta(time_step);
+
+        history.update(scenario.time_step_s, test_bed.query(|a| a.samples()));
+    }
+
+    history
+}
+
+struct ScenarioPumpController {
+    should_pressurise: bool,
+}
+impl PumpController for ScenarioPumpController {
+    fn should_pressurise(&self) -> bool {
+        self.should_pressurise
+    }
+}
+
+struct ScenarioPtuController {
+    should_enable: bool,
+}
+impl PowerTransferUnitController for ScenarioPtuController {
+    fn should_enable(&self) -> bool {
+        self.should_enable
+    }
+}
+
+struct FireValvesOpenController;
+impl HydraulicCircuitController for FireValvesOpenController {
+    fn should_open_fire_shutoff_valve(&self, _: usize) -> bool {
+        true
+    }
+}
+
+enum PumpType {
+    EngineDriven(EngineDrivenPump),
+    Electric(ElectricPump),
+}
+
+/// A pump of the scenario. Engine driven and electric pumps are wrapped in the same type so
+/// that any mix of them can be connected to the pump sections of a circuit.
+struct ScenarioPump {
+    id: String,
+    pump: PumpType,
+    controller: ScenarioPumpController,
+    rpm: AngularVelocity,
+}
+impl ScenarioPump {
+    const DEFAULT_MAX_CURRENT_AMPERE: f64 = 45.;
+
+    fn new(context: &mut InitContext, description: &PumpDescription) -> Self {
+        let mut pump = Self {
+            id: description.id.clone(),
+            pump: match description.kind {
+                PumpKind::EngineDriven => {
+                    PumpType::EngineDriven(EngineDrivenPump::new(context, &description.id))
+                }
+                PumpKind::Electric => PumpType::Electric(ElectricPump::new(
+                    context,
+                    &description.id,
+                    ScenarioAircraft::ELECTRIC_PUMP_BUS,
+                    ElectricCurrent::new::<ampere>(
+                        description
+                            .max_current_a
+                            .unwrap_or(Self::DEFAULT_MAX_CURRENT_AMPERE),
+                    ),
+                )),
+            },
+            controller: ScenarioPumpController {
+                should_pressurise: false,
+            },
+            rpm: AngularVelocity::new::<revolution_per_minute>(0.),
+        };
+
+        let wear = Ratio::new::<percent>(description.wear_percent);
+        match &mut pump.pump {
+            PumpType::EngineDriven(pump) => pump.set_wear(wear),
+            PumpType::Electric(pump) => pump.set_wear(wear),
+        }
+
+        pump
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        section: &impl SectionPressure,
+        reservoir: &Reservoir,
+    ) {
+        match &mut self.pump {
+            PumpType::EngineDriven(pump) => {
+                pump.update(context, section, reservoir, self.rpm, &self.controller)
+            }
+            PumpType::Electric(pump) => pump.update(context, section, reservoir, &self.controller),
+        }
+    }
+
+    fn source(&self) -> &dyn PressureSource {
+        match &self.pump {
+            PumpType::EngineDriven(pump) => pump,
+            PumpType::Electric(pump) => pump,
+        }
+    }
+
+    fn source_mut(&mut self) -> &mut dyn PressureSource {
+        match &mut self.pump {
+            PumpType::EngineDriven(pump) => pump,
+            PumpType::Electric(pump) => pump,
+        }
+    }
+}
+impl PressureSource for ScenarioPump {
+    fn delta_vol_max(&self) -> Volume {
+        self.source().delta_vol_max()
+    }
+
+    fn update_after_pressure_regulation(
+        &mut self,
+        context: &UpdateContext,
+        volume_required: Volume,
+        reservoir: &mut Reservoir,
+        is_pump_connected_to_reservoir: bool,
+    ) {
+        self.source_mut().update_after_pressure_regulation(
+            context,
+            volume_required,
+            reservoir,
+            is_pump_connected_to_reservoir,
+        );
+    }
+
+    fn flow(&self) -> VolumeRate {
+        self.source().flow()
+    }
+
+    fn case_drain_flow(&self) -> VolumeRate {
+        self.source().case_drain_flow()
+    }
+
+    fn displacement(&self) -> Volume {
+        self.source().displacement()
+    }
+
+    fn heat_generated(&self) -> Power {
+        self.source().heat_generated()
+    }
+}
+impl SimulationElement for ScenarioPump {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        match &mut self.pump {
+            PumpType::EngineDriven(pump) => pump.accept(visitor),
+            PumpType::Electric(pump) => pump.accept(visitor),
+        }
+
+        visitor.visit(self);
+    }
+}
+
+/// A consumer drawing a flow from the system section and returning it to the reservoir.
+/// The flow is the one demanded at nominal pressure, it reduces linearly with pressure.
+struct ActuatorLoad {
+    id: String,
+    flow_at_nominal_pressure: VolumeRate,
+    volume_to_actuator: Volume,
+    volume_to_reservoir: Volume,
+}
+impl ActuatorLoad {
+    const NOMINAL_PRESSURE_PSI: f64 = 3000.;
+
+    fn update(&mut self, context: &UpdateContext, section: &impl SectionPressure) {
+        let pressure_ratio =
+            (section.pressure().get::<psi>() / Self::NOMINAL_PRESSURE_PSI).clamp(0., 1.);
+        let volume = self.flow_at_nominal_pressure * context.delta_as_time() * pressure_ratio;
+
+        self.volume_to_actuator += volume;
+        self.volume_to_reservoir += volume;
+    }
+}
+impl Actuator for ActuatorLoad {
+    fn used_volume(&self) -> Volume {
+        self.volume_to_actuator
+    }
+
+    fn reservoir_return(&self) -> Volume {
+        self.volume_to_reservoir
+    }
+
+    fn reset_volumes(&mut self) {
+        self.volume_to_actuator = Volume::new::<gallon>(0.);
+        self.volume_to_reservoir = Volume::new::<gallon>(0.);
+    }
+}
+
+struct ScenarioCircuit {
+    color: HydraulicColor,
+    circuit: HydraulicCircuit,
+    pump_section_pumps: Vec<ScenarioPump>,
+    system_section_pump: Option<ScenarioPump>,
+    actuators: Vec<ActuatorLoad>,
+    reservoir_air_pressure: Pressure,
+}
+impl ScenarioCircuit {
+    fn new(
+        context: &mut InitContext,
+        description: &CircuitDescription,
+        connected_to_ptu_left_side: bool,
+        connected_to_ptu_right_side: bool,
+    ) -> Self {
+        let color = description.color().unwrap();
+        let pump_section_pumps: Vec<ScenarioPump> = description
+            .pump_section_pumps()
+            .map(|pump| ScenarioPump::new(context, pump))
+            .collect();
+
+        let reservoir = reservoir(context, color, &description.reservoir);
+
+        let mut circuit = HydraulicCircuit::new(
+            context,
+            color,
+            pump_section_pumps.len(),
+            Ratio::new::<percent>(description.priming_percent),
+            Volume::new::<gallon>(description.high_pressure_volume_gal),
+            reservoir,
+            Pressure::new::<psi>(description.system_pressure_switch_psi.low),
+            Pressure::new::<psi>(description.system_pressure_switch_psi.high),
+            Pressure::new::<psi>(description.pump_pressure_switch_psi.low),
+            Pressure::new::<psi>(description.pump_pressure_switch_psi.high),
+            connected_to_ptu_left_side,
+            connected_to_ptu_right_side,
+        );
+
+        if let Some(accumulator) = &description.accumulator {
+            circuit = circuit.with_system_accumulator(
+                Pressure::new::<psi>(accumulator.gas_precharge_psi),
+                Volume::new::<gallon>(accumulator.total_volume_gal),
+                Volume::new::<gallon>(accumulator.fluid_volume_gal),
+            );
+        }
+
+        Self {
+            color,
+            circuit,
+            pump_section_pumps,
+            system_section_pump: description
+                .system_section_pump()
+                .map(|pump| ScenarioPump::new(context, pump)),
+            actuators: description
+                .actuators
+                .iter()
+                .map(|actuator| ActuatorLoad {
+                    id: actuator.id.clone(),
+                    flow_at_nominal_pressure: VolumeRate::new::<gallon_per_second>(
+                        actuator.flow_gal_per_s,
+                    ),
+                    volume_to_actuator: Volume::new::<gallon>(0.),
+                    volume_to_reservoir: Volume::new::<gallon>(0.),
+                })
+                .collect(),
+            reservoir_air_pressure: Pressure::new::<psi>(description.reservoir_air_pressure_psi),
+        }
+    }
+
+    fn pumps_mut(&mut self) -> impl Iterator<Item = &mut ScenarioPump> {
+        self.pump_section_pumps
+            .iter_mut()
+            .chain(self.system_section_pump.iter_mut())
+    }
+
+    fn pumps(&self) -> impl Iterator<Item = &ScenarioPump> {
+        self.pump_section_pumps
+            .iter()
+            .chain(self.system_section_pump.iter())
+    }
+
+    fn update(&mut self, context: &UpdateContext, ptu: Option<&PowerTransferUnit>) {
+        for actuator in &mut self.actuators {
+            actuator.update(context, self.circuit.system_section());
+            self.circuit.update_actuator_volumes(actuator);
+        }
+
+        for (index, pump) in self.pump_section_pumps.iter_mut().enumerate() {
+            pump.update(
+                context,
+                self.circuit.pump_section(index),
+                self.circuit.reservoir(),
+            );
+        }
+
+        if let Some(pump) = &mut self.system_section_pump {
+            pump.update(
+                context,
+                self.circuit.system_section(),
+                self.circuit.reservoir(),
+            );
+        }
+
+        self.circuit.update(
+            context,
+            &mut self.pump_section_pumps.iter_mut().collect::<Vec<_>>(),
+            self.system_section_pump.as_mut(),
+            ptu,
+            &FireValvesOpenController,
+            self.reservoir_air_pressure,
+        );
+    }
+}
+impl SimulationElement for ScenarioCircuit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.circuit.accept(visitor);
+
+        for pump in self.pumps_mut() {
+            pump.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
+
+struct ScenarioPtu {
+    ptu: PowerTransferUnit,
+    controller: ScenarioPtuController,
+    left_circuit: usize,
+    right_circuit: usize,
+}
+
+/// Aircraft made only of the circuits of a scenario. Every electrical bus a pump or fire
+/// valve may need is permanently powered.
+struct ScenarioAircraft {
+    circuits: Vec<ScenarioCircuit>,
+    ptu: Option<ScenarioPtu>,
+
+    electricity_source: TestElectricitySource,
+    electric_pump_bus: ElectricalBus,
+    fire_valve_bus: ElectricalBus,
+}
+impl ScenarioAircraft {
+    const ELECTRIC_PUMP_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrentGndFltService;
+    const FIRE_VALVE_BUS: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
+
+    fn new(context: &mut InitContext, scenario: &Scenario) -> Self {
+        let ptu_sides = scenario.ptu.as_ref().map(|ptu| {
+            (
+                scenario.circuit_index(&ptu.left).unwrap(),
+                scenario.circuit_index(&ptu.right).unwrap(),
+            )
+        });
+
+        Self {
+            circuits: scenario
+                .circuits
+                .iter()
+                .enumerate()
+                .map(|(index, circuit)| {
+                    ScenarioCircuit::new(
+                        context,
+                        circuit,
+                        matches!(ptu_sides, Some((left, _)) if left == index),
+                        matches!(ptu_sides, Some((_, right)) if right == index),
+                    )
+                })
+                .collect(),
+            ptu: scenario.ptu.as_ref().map(|description| ScenarioPtu {
+                ptu: PowerTransferUnit::new(context),
+                controller: ScenarioPtuController {
+                    should_enable: description.enabled,
+                },
+                left_circuit: ptu_sides.unwrap().0,
+                right_circuit: ptu_sides.unwrap().1,
+            }),
+            electricity_source: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            electric_pump_bus: ElectricalBus::new(context, Self::ELECTRIC_PUMP_BUS),
+            fire_valve_bus: ElectricalBus::new(context, Self::FIRE_VALVE_BUS),
+        }
+    }
+
+    fn pump_mut(&mut self, id: &str) -> &mut ScenarioPump {
+        self.circuits
+            .iter_mut()
+            .flat_map(|circuit| circuit.pumps_mut())
+            .find(|pump| pump.id == id)
+            .unwrap()
+    }
+
+    fn apply(&mut self, action: &Action) {
+        match action {
+            Action::PressurisePump { pump } => {
+                self.pump_mut(pump).controller.should_pressurise = true
+            }
+            Action::DepressurisePump { pump } => {
+                self.pump_mut(pump).controller.should_pressurise = false
+            }
+            Action::SetPumpRpm { pump, rpm } => {
+                self.pump_mut(pump).rpm = AngularVelocity::new::<revolution_per_minute>(*rpm)
+            }
+            Action::EnablePtu => self.ptu.as_mut().unwrap().controller.should_enable = true,
+            Action::DisablePtu => self.ptu.as_mut().unwrap().controller.should_enable = false,
+            Action::SetActuatorFlow {
+                actuator,
+                flow_gal_per_s,
+            } => {
+                self.circuits
+                    .iter_mut()
+                    .flat_map(|circuit| circuit.actuators.iter_mut())
+                    .find(|load| &load.id == actuator)
+                    .unwrap()
+                    .flow_at_nominal_pressure =
+                    VolumeRate::new::<gallon_per_second>(*flow_gal_per_s)
+            }
+        }
+    }
+
+    fn channel_names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+
+        for circuit in &self.circuits {
+            let color = circuit.color;
+            for index in 0..circuit.pump_section_pumps.len() {
+                names.push(format!(
+                    "{} pump section {} pressure (psi)",
+                    color,
+                    index + 1
+                ));
+            }
+            names.push(format!("{} system section pressure (psi)", color));
+            names.push(format!("{} accumulator fluid volume (gal)", color));
+            names.push(format!("{} reservoir level (gal)", color));
+            names.push(format!("{} fluid temperature (degC)", color));
+
+            for pump in circuit.pumps() {
+                names.push(format!("{} flow (gpm)", pump.id));
+                names.push(format!("{} case drain flow (gpm)", pump.id));
+            }
+        }
+
+        if self.ptu.is_some() {
+            names.push("PTU flow (gpm)".to_owned());
+        }
+
+        names
+    }
+
+    fn samples(&self) -> Vec<f64> {
+        let mut samples = Vec::new();
+
+        for circuit in &self.circuits {
+            for index in 0..circuit.pump_section_pumps.len() {
+                samples.push(circuit.circuit.pump_pressure(index).get::<psi>());
+            }
+            samples.push(circuit.circuit.system_pressure().get::<psi>());
+            samples.push(
+                circuit
+                    .circuit
+                    .system_accumulator_fluid_volume()
+                    .get::<gallon>(),
+            );
+            samples.push(circuit.circuit.reservoir_level().get::<gallon>());
+            samples.push(
+                circuit
+                    .circuit
+                    .reservoir()
+                    .fluid_temperature()
+                    .get::<degree_celsius>(),
+            );
+
+            for pump in circuit.pumps() {
+                samples.push(pump.flow().get::<gallon_per_minute>());
+                samples.push(pump.case_drain_flow().get::<gallon_per_minute>());
+            }
+        }
+
+        if let Some(ptu) = &self.ptu {
+            samples.push(ptu.ptu.flow().get::<gallon_per_minute>());
+        }
+
+        samples
+    }
+}
+impl Aircraft for ScenarioAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        self.electricity_source
+            .power_with_potential(ElectricPotential::new::<volt>(115.));
+        electricity.supplied_by(&self.electricity_source);
+        electricity.flow(&self.electricity_source, &self.electric_pump_bus);
+        electricity.flow(&self.electricity_source, &self.fire_valve_bus);
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        if let Some(ptu) = &mut self.ptu {
+            ptu.ptu.update(
+                context,
+                self.circuits[ptu.left_circuit].circuit.system_section(),
+                self.circuits[ptu.right_circuit].circuit.system_section(),
+                &ptu.controller,
+            );
+        }
+
+        for (index, circuit) in self.circuits.iter_mut().enumerate() {
+            let ptu = self
+                .ptu
+                .as_ref()
+                .filter(|ptu| ptu.left_circuit == index || ptu.right_circuit == index)
+                .map(|ptu| &ptu.ptu);
+
+            circuit.update(context, ptu);
+        }
+    }
+}
+impl SimulationElement for ScenarioAircraft {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for circuit in &mut self.circuits {
+            circuit.accept(visitor);
+        }
+
+        if let Some(ptu) = &mut self.ptu {
+            ptu.ptu.accept(visitor);
+        }
+
+        self.electric_pump_bus.accept(visitor);
+        self.fire_valve_bus.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+fn reservoir(
+    context: &mut InitContext,
+    color: HydraulicColor,
+    description: &ReservoirDescription,
+) -> Reservoir {
+    Reservoir::new(
+        context,
+        color,
+        Volume::new::<gallon>(description.max_capacity_gal),
+        Volume::new::<gallon>(description.max_gaugeable_gal),
+        Volume::new::<gallon>(description.level_gal),
+        vec![PressureSwitch::new(
+            Pressure::new::<psi>(23.45),
+            Pressure::new::<psi>(20.55),
+            PressureSwitchType::Relative,
+        )],
+        Volume::new::<gallon>(description.low_level_threshold_gal),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YELLOW_AND_GREEN: &str = r#"
+        duration_s = 20.0
+
+        [[circuits]]
+        color = "green"
+        high_pressure_volume_gal = 10.0
+        system_pressure_switch_psi = { low = 1450.0, high = 1750.0 }
+        pump_pressure_switch_psi = { low = 1450.0, high = 1750.0 }
+        reservoir = { max_capacity_gal = 5.0, max_gaugeable_gal = 4.0, level_gal = 3.0, low_level_threshold_gal = 0.5 }
+
+        [[circuits.pumps]]
+        id = "EDP1"
+        kind = "engine_driven"
+        section = "pump"
+
+        [[circuits]]
+        color = "yellow"
+        high_pressure_volume_gal = 10.0
+        system_pressure_switch_psi = { low = 1450.0, high = 1750.0 }
+        pump_pressure_switch_psi = { low = 1450.0, high = 1750.0 }
+        reservoir = { max_capacity_gal = 5.0, max_gaugeable_gal = 4.0, level_gal = 3.0, low_level_threshold_gal = 0.5 }
+
+        [[circuits.pumps]]
+        id = "EDP2"
+        kind = "engine_driven"
+        section = "pump"
+
+        [[circuits.pumps]]
+        id = "EPUMP"
+        kind = "electric"
+        section = "system"
+
+        [[circuits.actuators]]
+        id = "CARGO_DOOR"
+
+        [ptu]
+        left = "green"
+        right = "yellow"
+    "#;
+
+    fn last_sample(history: &History, name: &str) -> f64 {
+        history.last_value(name).unwrap()
+    }
+
+    #[test]
+    fn history_records_every_step() {
+        let scenario = Scenario::parse(YELLOW_AND_GREEN).unwrap();
+
+        let history = run(&scenario);
+
+        assert_eq!(history.len(), 401);
+    }
+
+    #[test]
+    fn electric_pump_pressurises_its_circuit() {
+        let scenario = Scenario::parse(&format!(
+            "{}{}",
+            YELLOW_AND_GREEN,
+            r#"
+            [[events]]
+            time_s = 1.0
+            action = "pressurise_pump"
+            pump = "EPUMP"
+            "#
+        ))
+        .unwrap();
+
+        let history = run(&scenario);
+
+        assert!(last_sample(&history, "YELLOW system section pressure (psi)") > 2800.);
+        assert!(last_sample(&history, "GREEN system section pressure (psi)") < 50.);
+    }
+
+    #[test]
+    fn ptu_pressurises_other_circuit_when_enabled() {
+        let scenario = Scenario::parse(&format!(
+            "{}{}",
+            YELLOW_AND_GREEN,
+            r#"
+            [[events]]
+            time_s = 1.0
+            action = "pressurise_pump"
+            pump = "EPUMP"
+
+            [[events]]
+            time_s = 5.0
+            action = "enable_ptu"
+            "#
+        ))
+        .unwrap();
+
+        let history = run(&scenario);
+
+        assert!(last_sample(&history, "GREEN system section pressure (psi)") > 2000.);
+    }
+
+    #[test]
+    fn engine_driven_pump_needs_rpm() {
+        let scenario = Scenario::parse(&format!(
+            "{}{}",
+            YELLOW_AND_GREEN,
+            r#"
+            [[events]]
+            time_s = 1.0
+            action = "pressurise_pump"
+            pump = "EDP1"
+
+            [[events]]
+            time_s = 10.0
+            action = "set_pump_rpm"
+            pump = "EDP1"
+            rpm = 4000.0
+            "#
+        ))
+        .unwrap();
+
+        let history = run(&scenario);
+
+        assert!(
+            history
+                .value_at("GREEN system section pressure (psi)", 9.)
+                .unwrap()
+                < 50.
+        );
+        assert!(last_sample(&history, "GREEN system section pressure (psi)") > 2800.);
+    }
+
+    #[test]
+    fn actuator_load_draws_flow_from_pump() {
+        let scenario = Scenario::parse(&format!(
+            "{}{}",
+            YELLOW_AND_GREEN,
+            r#"
+            [[events]]
+            time_s = 0.0
+            action = "pressurise_pump"
+            pump = "EPUMP"
+
+            [[events]]
+            time_s = 10.0
+            action = "set_actuator_flow"
+            actuator = "CARGO_DOOR"
+            flow_gal_per_s = 0.1
+            "#
+        ))
+        .unwrap();
+
+        let history = run(&scenario);
+
+        let flow_without_load = history.value_at("EPUMP flow (gpm)", 9.).unwrap();
+        assert!(last_sample(&history, "EPUMP flow (gpm)") > flow_without_load + 4.);
+    }
+}
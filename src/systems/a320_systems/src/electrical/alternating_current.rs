@@ -7,11 +7,12 @@ use systems::simulation::InitContext;
 use systems::{
     electrical::{
         AlternatingCurrentElectricalSystem, Contactor, ElectricalBus, Electricity,
-        EmergencyGenerator, EngineGenerator, ExternalPowerSource, TransformerRectifier,
+        EmergencyGenerator, EngineGenerator, ExternalPowerSource, PowerTransfer,
+        TransformerRectifier,
     },
     shared::{
         AuxiliaryPowerUnitElectrical, DelayedTrueLogicGate, ElectricalBusType, EngineCorrectedN2,
-        EngineFirePushButtons,
+        EngineFirePushButtons, PotentialOrigin,
     },
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
@@ -115,7 +116,7 @@ impl A320AlternatingCurrentElectrical {
             .close_when(
                 !electricity.is_powered(&self.ac_bus_2)
                     && !self.tr_2.has_failed()
-                    && electricity.is_powered(ext_pwr),
+                    && ext_pwr.output_within_normal_parameters(),
             );
         electricity.flow(
             ext_pwr,
@@ -274,6 +275,15 @@ impl A320AlternatingCurrentElectrical {
         self.main_power_sources.gen_contactor_open(number)
     }
 
+    pub fn engine_generator_requires_load_shedding(&self) -> bool {
+        self.main_power_sources
+            .engine_generator_requires_load_shedding()
+    }
+
+    pub fn idg_has_fault(&self, number: usize) -> bool {
+        self.main_power_sources.idg_has_fault(number)
+    }
+
     pub fn emergency_generator_contactor_is_closed(&self) -> bool {
         self.emergency_gen_contactor.is_closed()
     }
@@ -344,8 +354,11 @@ struct A320MainPowerSources {
     bus_tie_2_contactor: Contactor,
     apu_gen_contactor: Contactor,
     ext_pwr_contactor: Contactor,
+    ac_bus_power_transfers: [PowerTransfer<PotentialOrigin>; 2],
 }
 impl A320MainPowerSources {
+    const POWER_TRANSFER_BREAK_DURATION: Duration = Duration::from_millis(50);
+
     fn new(context: &mut InitContext) -> Self {
         A320MainPowerSources {
             engine_1_gen: EngineGenerator::new(context, 1),
@@ -358,6 +371,10 @@ impl A320MainPowerSources {
             bus_tie_2_contactor: Contactor::new(context, "11XU2"),
             apu_gen_contactor: Contactor::new(context, "3XS"),
             ext_pwr_contactor: Contactor::new(context, "3XG"),
+            ac_bus_power_transfers: [
+                PowerTransfer::new(Self::POWER_TRANSFER_BREAK_DURATION),
+                PowerTransfer::new(Self::POWER_TRANSFER_BREAK_DURATION),
+            ],
         }
     }
 
@@ -400,21 +417,46 @@ impl A320MainPowerSources {
             && !ext_pwr_provides_power
             && !both_engine_gens_provide_power;
 
-        self.engine_generator_contactors[0].close_when(gen_1_provides_power);
-        self.engine_generator_contactors[1].close_when(gen_2_provides_power);
+        let apu_or_ext_pwr_provides_power = ext_pwr_provides_power || apu_gen_provides_power;
+        let bus_tie_1_should_close = overhead.bus_tie_is_auto()
+            && ((only_one_engine_gen_is_powered && !apu_or_ext_pwr_provides_power)
+                || (apu_or_ext_pwr_provides_power && !gen_1_provides_power));
+        let bus_tie_2_should_close = overhead.bus_tie_is_auto()
+            && ((only_one_engine_gen_is_powered && !apu_or_ext_pwr_provides_power)
+                || (apu_or_ext_pwr_provides_power && !gen_2_provides_power));
+
+        let tied_source = if ext_pwr_provides_power {
+            Some(PotentialOrigin::External)
+        } else if apu_gen_provides_power {
+            Some(PotentialOrigin::ApuGenerator(1))
+        } else if gen_1_provides_power {
+            Some(PotentialOrigin::EngineGenerator(1))
+        } else if gen_2_provides_power {
+            Some(PotentialOrigin::EngineGenerator(2))
+        } else {
+            None
+        };
+        self.update_ac_bus_power_transfers(
+            context,
+            ext_pwr,
+            apu,
+            [gen_1_provides_power, gen_2_provides_power],
+            [bus_tie_1_should_close, bus_tie_2_should_close],
+            tied_source,
+        );
+
+        self.engine_generator_contactors[0]
+            .close_when(gen_1_provides_power && !self.ac_bus_power_transfers[0].is_interrupting());
+        self.engine_generator_contactors[1]
+            .close_when(gen_2_provides_power && !self.ac_bus_power_transfers[1].is_interrupting());
         self.apu_gen_contactor.close_when(apu_gen_provides_power);
         self.ext_pwr_contactor.close_when(ext_pwr_provides_power);
 
-        let apu_or_ext_pwr_provides_power = ext_pwr_provides_power || apu_gen_provides_power;
         self.bus_tie_1_contactor.close_when(
-            overhead.bus_tie_is_auto()
-                && ((only_one_engine_gen_is_powered && !apu_or_ext_pwr_provides_power)
-                    || (apu_or_ext_pwr_provides_power && !gen_1_provides_power)),
+            bus_tie_1_should_close && !self.ac_bus_power_transfers[0].is_interrupting(),
         );
         self.bus_tie_2_contactor.close_when(
-            overhead.bus_tie_is_auto()
-                && ((only_one_engine_gen_is_powered && !apu_or_ext_pwr_provides_power)
-                    || (apu_or_ext_pwr_provides_power && !gen_2_provides_power)),
+            bus_tie_2_should_close && !self.ac_bus_power_transfers[1].is_interrupting(),
         );
 
         electricity.flow(apu, &self.apu_gen_contactor);
@@ -439,6 +481,45 @@ impl A320MainPowerSources {
         electricity.flow(&self.bus_tie_1_contactor, &self.bus_tie_2_contactor);
     }
 
+    /// Determines the source which should power each AC bus and detects when a bus
+    /// is transferred from one source to another. The tied source is the source
+    /// powering the bus tie, which feeds an AC bus when its bus tie contactor closes.
+    fn update_ac_bus_power_transfers(
+        &mut self,
+        context: &UpdateContext,
+        ext_pwr: &ExternalPowerSource,
+        apu: &impl AuxiliaryPowerUnitElectrical,
+        gen_provides_power: [bool; 2],
+        bus_tie_should_close: [bool; 2],
+        tied_source: Option<PotentialOrigin>,
+    ) {
+        let within_normal_parameters = [
+            self.engine_1_gen.output_within_normal_parameters(),
+            self.engine_2_gen.output_within_normal_parameters(),
+            apu.output_within_normal_parameters(),
+            ext_pwr.output_within_normal_parameters(),
+        ];
+        let is_within_normal_parameters = |origin: PotentialOrigin| match origin {
+            PotentialOrigin::EngineGenerator(1) => within_normal_parameters[0],
+            PotentialOrigin::EngineGenerator(_) => within_normal_parameters[1],
+            PotentialOrigin::ApuGenerator(_) => within_normal_parameters[2],
+            PotentialOrigin::External => within_normal_parameters[3],
+            _ => false,
+        };
+
+        for (index, transfer) in self.ac_bus_power_transfers.iter_mut().enumerate() {
+            let source = if gen_provides_power[index] {
+                Some(PotentialOrigin::EngineGenerator(index + 1))
+            } else if bus_tie_should_close[index] {
+                tied_source
+            } else {
+                None
+            };
+
+            transfer.update(context, source, is_within_normal_parameters);
+        }
+    }
+
     fn power_ac_bus_1(&self, electricity: &mut Electricity, bus: &ElectricalBus) {
         electricity.flow(&self.engine_generator_contactors[0], bus);
         electricity.flow(&self.bus_tie_1_contactor, bus);
@@ -452,6 +533,17 @@ impl A320MainPowerSources {
     pub fn gen_contactor_open(&self, number: usize) -> bool {
         self.engine_generator_contactors[number - 1].is_open()
     }
+
+    fn engine_generator_requires_load_shedding(&self) -> bool {
+        self.engine_1_gen.requires_load_shedding() || self.engine_2_gen.requires_load_shedding()
+    }
+
+    fn idg_has_fault(&self, number: usize) -> bool {
+        match number {
+            1 => self.engine_1_gen.idg_has_fault(),
+            _ => self.engine_2_gen.idg_has_fault(),
+        }
+    }
 }
 impl SimulationElement for A320MainPowerSources {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
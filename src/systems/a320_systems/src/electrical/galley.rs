@@ -1,15 +1,44 @@
 use super::{alternating_current::A320AlternatingCurrentElectrical, A320ElectricalOverheadPanel};
 use systems::{
-    electrical::{AlternatingCurrentElectricalSystem, Electricity},
-    simulation::UpdateContext,
+    electrical::{
+        consumption::PowerConsumer, AlternatingCurrentElectricalSystem, CircuitBreaker, Electricity,
+    },
+    shared::{AuxiliaryPowerUnitElectrical, ElectricalBusType},
+    simulation::{InitContext, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
+use uom::si::{electric_current::ampere, f64::*, power::watt};
 
+/// Generators request shedding of the commercial loads when they are overloaded.
+fn generator_requires_load_shedding(
+    alternating_current: &A320AlternatingCurrentElectrical,
+    apu: &impl AuxiliaryPowerUnitElectrical,
+) -> bool {
+    alternating_current.engine_generator_requires_load_shedding() || apu.requires_load_shedding()
+}
+
+fn demand_unless_shed(is_shed: bool, demand_watt: f64) -> Power {
+    Power::new::<watt>(if is_shed { 0. } else { demand_watt })
+}
+
+/// The main galley also includes the in-seat power supply. Besides being shed
+/// depending on the available power sources, it is shed automatically when
+/// a generator is overloaded. A shed galley no longer consumes power.
 pub(super) struct MainGalley {
     is_shed: bool,
+    consumer: PowerConsumer,
 }
 impl MainGalley {
-    pub fn new() -> Self {
-        Self { is_shed: false }
+    const DEMAND_WATT: f64 = 15000.;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        let circuit_breaker =
+            CircuitBreaker::new(context, "MAIN_GALLEY", ElectricCurrent::new::<ampere>(150.));
+
+        Self {
+            is_shed: false,
+            consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(2))
+                .protected_by(context, circuit_breaker),
+        }
     }
 
     pub fn is_shed(&self) -> bool {
@@ -22,23 +51,49 @@ impl MainGalley {
         electricity: &Electricity,
         alternating_current: &A320AlternatingCurrentElectrical,
         overhead: &A320ElectricalOverheadPanel,
+        apu: &impl AuxiliaryPowerUnitElectrical,
     ) {
         self.is_shed = !alternating_current.any_non_essential_bus_powered(electricity)
+            || generator_requires_load_shedding(alternating_current, apu)
             || alternating_current
                 .main_ac_buses_powered_by_single_engine_generator_only(electricity)
             || (alternating_current.main_ac_buses_powered_by_apu_generator_only(electricity)
                 && context.is_in_flight())
             || overhead.commercial_is_off()
             || overhead.galy_and_cab_is_off();
+
+        self.consumer
+            .demand(demand_unless_shed(self.is_shed, MainGalley::DEMAND_WATT));
+    }
+}
+impl SimulationElement for MainGalley {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.consumer.accept(visitor);
+
+        visitor.visit(self);
     }
 }
 
+/// The secondary galley is shed automatically when a generator is overloaded.
 pub(super) struct SecondaryGalley {
     is_shed: bool,
+    consumer: PowerConsumer,
 }
 impl SecondaryGalley {
-    pub fn new() -> Self {
-        Self { is_shed: false }
+    const DEMAND_WATT: f64 = 5000.;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        let circuit_breaker = CircuitBreaker::new(
+            context,
+            "SECONDARY_GALLEY",
+            ElectricCurrent::new::<ampere>(50.),
+        );
+
+        Self {
+            is_shed: false,
+            consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1))
+                .protected_by(context, circuit_breaker),
+        }
     }
 
     pub fn is_shed(&self) -> bool {
@@ -50,9 +105,73 @@ impl SecondaryGalley {
         electricity: &Electricity,
         alternating_current: &A320AlternatingCurrentElectrical,
         overhead: &A320ElectricalOverheadPanel,
+        apu: &impl AuxiliaryPowerUnitElectrical,
     ) {
         self.is_shed = !alternating_current.any_non_essential_bus_powered(electricity)
+            || generator_requires_load_shedding(alternating_current, apu)
             || overhead.commercial_is_off()
             || overhead.galy_and_cab_is_off();
+
+        self.consumer.demand(demand_unless_shed(
+            self.is_shed,
+            SecondaryGalley::DEMAND_WATT,
+        ));
+    }
+}
+impl SimulationElement for SecondaryGalley {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.consumer.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// The commercial loads other than the galleys, such as cabin lighting and in-flight
+/// entertainment. They are switched off by the COMMERCIAL push button and are shed
+/// automatically when a generator is overloaded.
+pub(super) struct CommercialLoads {
+    is_shed: bool,
+    consumer: PowerConsumer,
+}
+impl CommercialLoads {
+    const DEMAND_WATT: f64 = 5000.;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        let circuit_breaker =
+            CircuitBreaker::new(context, "COMMERCIAL", ElectricCurrent::new::<ampere>(50.));
+
+        Self {
+            is_shed: false,
+            consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1))
+                .protected_by(context, circuit_breaker),
+        }
+    }
+
+    pub fn is_shed(&self) -> bool {
+        self.is_shed
+    }
+
+    pub fn update(
+        &mut self,
+        electricity: &Electricity,
+        alternating_current: &A320AlternatingCurrentElectrical,
+        overhead: &A320ElectricalOverheadPanel,
+        apu: &impl AuxiliaryPowerUnitElectrical,
+    ) {
+        self.is_shed = !alternating_current.any_non_essential_bus_powered(electricity)
+            || generator_requires_load_shedding(alternating_current, apu)
+            || overhead.commercial_is_off();
+
+        self.consumer.demand(demand_unless_shed(
+            self.is_shed,
+            CommercialLoads::DEMAND_WATT,
+        ));
+    }
+}
+impl SimulationElement for CommercialLoads {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.consumer.accept(visitor);
+
+        visitor.visit(self);
     }
 }
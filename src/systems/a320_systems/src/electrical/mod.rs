@@ -5,7 +5,7 @@ mod galley;
 use self::{
     alternating_current::A320AlternatingCurrentElectrical,
     direct_current::A320DirectCurrentElectrical,
-    galley::{MainGalley, SecondaryGalley},
+    galley::{CommercialLoads, MainGalley, SecondaryGalley},
 };
 pub(super) use direct_current::APU_START_MOTOR_BUS_TYPE;
 
@@ -39,11 +39,13 @@ use systems::{
 
 pub(super) struct A320Electrical {
     galley_is_shed_id: VariableIdentifier,
+    commercial_is_shed_id: VariableIdentifier,
 
     alternating_current: A320AlternatingCurrentElectrical,
     direct_current: A320DirectCurrentElectrical,
     main_galley: MainGalley,
     secondary_galley: SecondaryGalley,
+    commercial_loads: CommercialLoads,
     emergency_elec: EmergencyElectrical,
     emergency_gen: EmergencyGenerator,
 }
@@ -51,10 +53,12 @@ impl A320Electrical {
     pub fn new(context: &mut InitContext) -> A320Electrical {
         A320Electrical {
             galley_is_shed_id: context.get_identifier("ELEC_GALLEY_IS_SHED".to_owned()),
+            commercial_is_shed_id: context.get_identifier("ELEC_COMMERCIAL_IS_SHED".to_owned()),
             alternating_current: A320AlternatingCurrentElectrical::new(context),
             direct_current: A320DirectCurrentElectrical::new(context),
-            main_galley: MainGalley::new(),
-            secondary_galley: SecondaryGalley::new(),
+            main_galley: MainGalley::new(context),
+            secondary_galley: SecondaryGalley::new(context),
+            commercial_loads: CommercialLoads::new(context),
             emergency_elec: EmergencyElectrical::new(),
             emergency_gen: EmergencyGenerator::new(context),
         }
@@ -117,10 +121,17 @@ impl A320Electrical {
             &self.direct_current,
         );
 
-        self.main_galley
-            .update(context, electricity, &self.alternating_current, overhead);
+        self.main_galley.update(
+            context,
+            electricity,
+            &self.alternating_current,
+            overhead,
+            apu,
+        );
         self.secondary_galley
-            .update(electricity, &self.alternating_current, overhead);
+            .update(electricity, &self.alternating_current, overhead, apu);
+        self.commercial_loads
+            .update(electricity, &self.alternating_current, overhead, apu);
 
         self.debug_assert_invariants();
     }
@@ -138,6 +149,16 @@ impl A320Electrical {
         self.main_galley.is_shed() || self.secondary_galley.is_shed()
     }
 
+    #[cfg(test)]
+    fn main_galley_is_shed(&self) -> bool {
+        self.main_galley.is_shed()
+    }
+
+    #[cfg(test)]
+    fn secondary_galley_is_shed(&self) -> bool {
+        self.secondary_galley.is_shed()
+    }
+
     fn debug_assert_invariants(&self) {
         self.alternating_current.debug_assert_invariants();
         self.direct_current.debug_assert_invariants();
@@ -182,6 +203,10 @@ impl A320Electrical {
         self.alternating_current.gen_contactor_open(number)
     }
 
+    pub fn idg_has_fault(&self, number: usize) -> bool {
+        self.alternating_current.idg_has_fault(number)
+    }
+
     pub fn in_emergency_elec(&self) -> bool {
         self.emergency_elec.is_active()
     }
@@ -190,13 +215,17 @@ impl SimulationElement for A320Electrical {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.alternating_current.accept(visitor);
         self.direct_current.accept(visitor);
+        self.main_galley.accept(visitor);
+        self.secondary_galley.accept(visitor);
+        self.commercial_loads.accept(visitor);
         self.emergency_gen.accept(visitor);
 
         visitor.visit(self);
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
-        writer.write(&self.galley_is_shed_id, self.galley_is_shed())
+        writer.write(&self.galley_is_shed_id, self.galley_is_shed());
+        writer.write(&self.commercial_is_shed_id, self.commercial_loads.is_shed());
     }
 }
 impl EmergencyElectricalState for A320Electrical {
@@ -260,8 +289,12 @@ impl A320ElectricalOverheadPanel {
     pub fn update_after_electrical(
         &mut self,
         electrical: &A320Electrical,
+        ext_pwr: &ExternalPowerSource,
         electricity: &Electricity,
     ) {
+        self.ext_pwr
+            .set_available(ext_pwr.output_within_normal_parameters());
+
         self.ac_ess_feed
             .set_fault(!electrical.ac_ess_bus_is_powered(electricity));
 
@@ -271,6 +304,10 @@ impl A320ElectricalOverheadPanel {
             .for_each(|(index, gen)| {
                 gen.set_fault(electrical.gen_contactor_open(index + 1) && gen.is_on());
             });
+
+        self.idgs.iter_mut().enumerate().for_each(|(index, idg)| {
+            idg.set_fault(electrical.idg_has_fault(index + 1));
+        });
     }
 
     fn generator_is_on(&self, number: usize) -> bool {
@@ -417,8 +454,9 @@ mod a320_electrical_circuit_tests {
     use std::{cell::Ref, time::Duration};
     use systems::{
         electrical::{
-            ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-            Electricity, ElectricitySource, ExternalPowerSource, Potential,
+            consumption::PowerConsumer, ElectricalElement, ElectricalElementIdentifier,
+            ElectricalElementIdentifierProvider, Electricity, ElectricitySource,
+            ExternalPowerSource, Potential,
             INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
         },
         failures::FailureType,
@@ -1776,8 +1814,100 @@ mod a320_electrical_circuit_tests {
     }
 
     #[test]
-    #[ignore = "Generator overloading is not yet supported."]
-    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {}
+    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .and()
+            .on_the_ground()
+            .and()
+            .overloaded_apu_gen()
+            .run();
+
+        assert!(test_bed.galley_is_shed());
+        assert!(test_bed.main_galley_is_shed());
+    }
+
+    #[test]
+    fn when_aircraft_on_the_ground_and_apu_gen_only_secondary_galley_is_not_shed() {
+        let mut test_bed = test_bed_with().running_apu().and().on_the_ground().run();
+
+        assert!(!test_bed.secondary_galley_is_shed());
+    }
+
+    #[test]
+    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_secondary_galley_is_shed() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .and()
+            .on_the_ground()
+            .and()
+            .overloaded_apu_gen()
+            .run();
+
+        assert!(test_bed.secondary_galley_is_shed());
+    }
+
+    #[test]
+    fn when_aircraft_on_the_ground_and_apu_gen_only_commercial_loads_are_not_shed() {
+        let mut test_bed = test_bed_with().running_apu().and().on_the_ground().run();
+
+        assert!(!test_bed.commercial_is_shed());
+    }
+
+    #[test]
+    fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_commercial_loads_are_shed() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .and()
+            .on_the_ground()
+            .and()
+            .overloaded_apu_gen()
+            .run();
+
+        assert!(test_bed.commercial_is_shed());
+    }
+
+    #[test]
+    fn shedding_galley_and_commercial_loads_relieves_an_overloaded_engine_gen() {
+        let mut test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .ac_bus_1_loaded_with(Power::new::<watt>(105000.))
+            .run();
+
+        assert!(!test_bed.secondary_galley_is_shed());
+        assert!(!test_bed.commercial_is_shed());
+        assert!(test_bed.engine_gen_load(1) > Ratio::new::<percent>(100.));
+
+        test_bed.run_with_fixed_frames(Duration::from_secs(30), Duration::from_secs(1));
+
+        assert!(test_bed.secondary_galley_is_shed());
+        assert!(test_bed.commercial_is_shed());
+        assert!(test_bed.engine_gen_load(1) < Ratio::new::<percent>(100.));
+    }
+
+    #[test]
+    fn pulling_the_commercial_circuit_breaker_removes_the_commercial_load() {
+        let mut test_bed = test_bed_with().running_engines().run();
+        let load_with_commercial = test_bed.engine_gen_load(1);
+
+        test_bed.write_by_name("ELEC_CB_COMMERCIAL_IS_PULLED", true);
+        test_bed = test_bed.run();
+
+        assert!(!test_bed.commercial_is_shed());
+        assert!(test_bed.engine_gen_load(1) < load_with_commercial);
+    }
+
+    #[test]
+    fn when_commercial_pb_off_commercial_loads_are_shed() {
+        let mut test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .commercial_off()
+            .run();
+
+        assert!(test_bed.commercial_is_shed());
+    }
 
     #[rstest]
     #[case(1)]
@@ -2033,6 +2163,161 @@ mod a320_electrical_circuit_tests {
         assert!(test_bed.gen_has_fault(number));
     }
 
+    #[test]
+    fn electrical_topology_shows_engine_generator_feeding_ac_bus() {
+        let mut test_bed = test_bed_with().running_engine(1);
+        test_bed.start_recording_electrical_topology();
+        let mut test_bed = test_bed.run();
+
+        let topology = test_bed.electrical_topology();
+
+        assert!(topology.is_conducting_between("ENG_GEN_1", "9XU1"));
+        assert!(topology.is_conducting_between("9XU1", "AC_1"));
+        assert_eq!(
+            topology.node("AC_1").unwrap().origins(),
+            &[PotentialOrigin::EngineGenerator(1)]
+        );
+        assert!(!topology.node("3XS").unwrap().is_conductive());
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn when_idg_leaks_oil_idg_push_button_has_fault(#[case] number: usize) {
+        let mut test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .idg_oil_leak(number)
+            .run_waiting_for(Duration::from_secs(110));
+
+        assert!(test_bed.idg_has_fault(number));
+        assert!(!test_bed.idg_has_fault(if number == 1 { 2 } else { 1 }));
+    }
+
+    #[test]
+    fn when_idgs_operate_normally_idg_push_buttons_have_no_fault() {
+        let mut test_bed = test_bed_with().running_engines().run();
+
+        assert!(!test_bed.idg_has_fault(1));
+        assert!(!test_bed.idg_has_fault(2));
+    }
+
+    #[test]
+    fn when_external_power_connected_ext_pwr_push_button_is_available() {
+        let mut test_bed = test_bed_with().connected_external_power().run();
+
+        assert!(test_bed.ext_pwr_is_available());
+    }
+
+    #[rstest]
+    #[case(FailureType::GroundPowerUnitOvervoltage)]
+    #[case(FailureType::GroundPowerUnitWrongFrequency)]
+    #[case(FailureType::GroundPowerUnitIncorrectPhaseSequence)]
+    #[case(FailureType::GroundPowerUnitOpenPhase)]
+    fn when_ground_power_unit_faulty_ext_pwr_push_button_is_not_available(
+        #[case] failure_type: FailureType,
+    ) {
+        let mut test_bed = test_bed_with()
+            .failed_ground_power_unit(failure_type)
+            .connected_external_power()
+            .run();
+
+        assert!(!test_bed.ext_pwr_is_available());
+    }
+
+    #[rstest]
+    #[case(FailureType::GroundPowerUnitOvervoltage)]
+    #[case(FailureType::GroundPowerUnitWrongFrequency)]
+    #[case(FailureType::GroundPowerUnitIncorrectPhaseSequence)]
+    #[case(FailureType::GroundPowerUnitOpenPhase)]
+    fn when_ground_power_unit_faulty_external_power_is_rejected(#[case] failure_type: FailureType) {
+        let test_bed = test_bed_with()
+            .connected_external_power()
+            .airspeed(Velocity::new::<knot>(0.))
+            .on_the_ground()
+            .and()
+            .ext_pwr_on()
+            .run()
+            .then_continue_with()
+            .failed_ground_power_unit(failure_type)
+            .run();
+
+        assert!(test_bed.ac_bus_output(1).is_unpowered());
+        assert!(test_bed.ac_bus_output(2).is_unpowered());
+        assert!(test_bed.ac_gnd_flt_service_bus_output().is_unpowered());
+    }
+
+    #[test]
+    fn ac_bus_is_briefly_unpowered_when_transferred_from_lost_engine_generator() {
+        let test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .stopped_engine(1)
+            .run_waiting_for(Duration::from_millis(20));
+
+        assert!(test_bed.ac_bus_output(1).is_unpowered());
+        assert!(test_bed
+            .ac_bus_output(2)
+            .is_single(PotentialOrigin::EngineGenerator(2)));
+    }
+
+    #[test]
+    fn ac_bus_is_powered_by_other_engine_generator_after_transfer_break() {
+        let test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .stopped_engine(1)
+            .run_waiting_for(Duration::from_millis(20))
+            .run_waiting_for(Duration::from_millis(40));
+
+        assert!(test_bed
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::EngineGenerator(2)));
+    }
+
+    #[test]
+    fn ac_bus_is_not_interrupted_when_engine_generator_takes_over_from_external_power() {
+        let mut test_bed = test_bed_with()
+            .connected_external_power()
+            .and()
+            .ext_pwr_on()
+            .run();
+
+        test_bed.command(|a| a.running_engine(1));
+
+        // Check every tick until well after the generator took over.
+        let tick = Duration::from_millis(5);
+        let ticks = (INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS + 100) / 5;
+        for _ in 0..ticks {
+            test_bed.run_with_delta(tick);
+
+            assert!(test_bed.ac_bus_output(1).is_powered());
+            assert!(test_bed.ac_bus_output(2).is_powered());
+        }
+
+        assert!(test_bed
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::EngineGenerator(1)));
+        assert!(test_bed
+            .ac_bus_output(2)
+            .is_single(PotentialOrigin::External));
+    }
+
+    #[test]
+    fn ac_bus_is_not_interrupted_when_external_power_is_switched_off_with_apu_running() {
+        let test_bed = test_bed_with()
+            .running_apu()
+            .and()
+            .connected_external_power()
+            .run()
+            .ext_pwr_off()
+            .run_waiting_for(Duration::from_millis(20));
+
+        assert!(test_bed
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::ApuGenerator(1)));
+    }
+
     fn test_bed_with() -> A320ElectricalTestBed {
         test_bed()
     }
@@ -2044,6 +2329,7 @@ mod a320_electrical_circuit_tests {
     struct TestApu {
         identifier: ElectricalElementIdentifier,
         is_available: bool,
+        is_overloaded: bool,
         start_motor_is_powered: bool,
         should_close_start_contactor: bool,
     }
@@ -2052,6 +2338,7 @@ mod a320_electrical_circuit_tests {
             Self {
                 identifier: context.next_electrical_identifier(),
                 is_available: false,
+                is_overloaded: false,
                 start_motor_is_powered: false,
                 should_close_start_contactor: false,
             }
@@ -2061,6 +2348,10 @@ mod a320_electrical_circuit_tests {
             self.is_available = available;
         }
 
+        fn set_overloaded(&mut self, overloaded: bool) {
+            self.is_overloaded = overloaded;
+        }
+
         fn command_closing_of_start_contactors(&mut self) {
             self.should_close_start_contactor = true;
         }
@@ -2078,6 +2369,10 @@ mod a320_electrical_circuit_tests {
         fn output_within_normal_parameters(&self) -> bool {
             self.is_available
         }
+
+        fn requires_load_shedding(&self) -> bool {
+            self.is_available && self.is_overloaded
+        }
     }
     impl ElectricitySource for TestApu {
         fn output_potential(&self) -> Potential {
@@ -2267,6 +2562,7 @@ mod a320_electrical_circuit_tests {
         apu_overhead: TestApuOverhead,
         engine_fire_push_buttons: TestEngineFirePushButtons,
         hydraulics: TestHydraulicSystem,
+        ac_bus_1_loads: PowerConsumer,
         force_run_emergency_gen: bool,
     }
     impl A320ElectricalTestAircraft {
@@ -2281,6 +2577,7 @@ mod a320_electrical_circuit_tests {
                 apu_overhead: TestApuOverhead::new(),
                 engine_fire_push_buttons: TestEngineFirePushButtons::new(),
                 hydraulics: TestHydraulicSystem::new(),
+                ac_bus_1_loads: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
                 force_run_emergency_gen: false,
             }
         }
@@ -2297,6 +2594,14 @@ mod a320_electrical_circuit_tests {
             self.apu.set_available(true);
         }
 
+        fn overloaded_apu_gen(&mut self) {
+            self.apu.set_overloaded(true);
+        }
+
+        fn load_ac_bus_1(&mut self, power: Power) {
+            self.ac_bus_1_loads.demand(power);
+        }
+
         fn set_apu_master_sw_pb_on(&mut self) {
             self.apu_overhead.set_apu_master_sw_pb_on();
         }
@@ -2380,7 +2685,7 @@ mod a320_electrical_circuit_tests {
                 &TestLandingGear::new(),
             );
             self.overhead
-                .update_after_electrical(&self.elec, electricity);
+                .update_after_electrical(&self.elec, &self.ext_pwr, electricity);
             self.emergency_overhead
                 .update_after_electrical(context, &self.elec);
         }
@@ -2392,6 +2697,7 @@ mod a320_electrical_circuit_tests {
             self.overhead.accept(visitor);
             self.emergency_overhead.accept(visitor);
             self.apu.accept(visitor);
+            self.ac_bus_1_loads.accept(visitor);
 
             visitor.visit(self);
         }
@@ -2433,6 +2739,16 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        fn overloaded_apu_gen(mut self) -> Self {
+            self.command(|a| a.overloaded_apu_gen());
+            self
+        }
+
+        fn ac_bus_1_loaded_with(mut self, power: Power) -> Self {
+            self.command(|a| a.load_ac_bus_1(power));
+            self
+        }
+
         fn connected_external_power(mut self) -> Self {
             self.write_by_name("EXTERNAL POWER AVAILABLE:1", true);
 
@@ -2477,6 +2793,16 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        fn idg_oil_leak(mut self, number: usize) -> Self {
+            self.test_bed.fail(FailureType::IdgOilLeak(number));
+            self
+        }
+
+        fn failed_ground_power_unit(mut self, failure_type: FailureType) -> Self {
+            self.test_bed.fail(failure_type);
+            self
+        }
+
         fn failed_tr_1(mut self) -> Self {
             self.test_bed.fail(FailureType::TransformerRectifier(1));
             self
@@ -2689,6 +3015,14 @@ mod a320_electrical_circuit_tests {
             self.read_by_name(&format!("OVHD_ELEC_ENG_GEN_{}_PB_HAS_FAULT", number))
         }
 
+        fn idg_has_fault(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("OVHD_ELEC_IDG_{}_PB_HAS_FAULT", number))
+        }
+
+        fn ext_pwr_is_available(&mut self) -> bool {
+            self.read_by_name("OVHD_ELEC_EXT_PWR_PB_IS_AVAILABLE")
+        }
+
         fn rat_and_emer_gen_has_fault(&mut self) -> bool {
             self.read_by_name("OVHD_EMER_ELEC_RAT_AND_EMER_GEN_HAS_FAULT")
         }
@@ -2697,6 +3031,22 @@ mod a320_electrical_circuit_tests {
             self.read_by_name("ELEC_GALLEY_IS_SHED")
         }
 
+        fn main_galley_is_shed(&self) -> bool {
+            self.query(|a| a.elec.main_galley_is_shed())
+        }
+
+        fn secondary_galley_is_shed(&self) -> bool {
+            self.query(|a| a.elec.secondary_galley_is_shed())
+        }
+
+        fn commercial_is_shed(&mut self) -> bool {
+            self.read_by_name("ELEC_COMMERCIAL_IS_SHED")
+        }
+
+        fn engine_gen_load(&mut self, number: usize) -> Ratio {
+            self.read_by_name(&format!("ELEC_ENG_GEN_{}_LOAD", number))
+        }
+
         fn both_ac_ess_feed_contactors_open(&mut self) -> bool {
             !ReadByName::<A320ElectricalTestBed, bool>::read_by_name(
                 self,
@@ -0,0 +1,264 @@
+#[cfg(feature = "config-files")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "config-files")]
+use std::path::Path;
+
+#[cfg(feature = "config-files")]
+use systems::hydraulic::config::{parse_toml, read_toml, to_toml};
+use systems::hydraulic::{
+    config::{
+        AccumulatorConfig, AirPressureSwitchConfig, HydraulicCircuitConfig, HydraulicConfigError,
+        PressureSwitchHysteresisConfig, ReservoirConfig,
+    },
+    PressureSwitchType,
+};
+
+/// Sizes and thresholds of the three A320 hydraulic circuits and their reservoirs.
+///
+/// The default is the real aircraft. With the `config-files` feature, alternative tunings
+/// can be loaded from a TOML file, for which [`Self::to_toml`] provides a template.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "config-files",
+    derive(Deserialize, Serialize),
+    serde(deny_unknown_fields)
+)]
+pub struct A320HydraulicConfig {
+    pub green: HydraulicCircuitConfig,
+    pub blue: HydraulicCircuitConfig,
+    pub yellow: HydraulicCircuitConfig,
+}
+impl A320HydraulicConfig {
+    const MIN_PRESS_EDP_SECTION_LO_HYST: f64 = 1740.0;
+    const MIN_PRESS_EDP_SECTION_HI_HYST: f64 = 2200.0;
+    const MIN_PRESS_PRESSURISED_LO_HYST: f64 = 1450.0;
+    const MIN_PRESS_PRESSURISED_HI_HYST: f64 = 1750.0;
+
+    const ACCUMULATOR_GAS_PRE_CHARGE_PSI: f64 = 1885.0;
+    const ACCUMULATOR_MAX_VOLUME_GALLONS: f64 = 0.264;
+
+    #[cfg(feature = "config-files")]
+    pub fn from_toml(content: &str) -> Result<Self, HydraulicConfigError> {
+        let config: Self = parse_toml(content)?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    #[cfg(feature = "config-files")]
+    pub fn from_file(path: &Path) -> Result<Self, HydraulicConfigError> {
+        let config: Self = read_toml(path)?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    #[cfg(feature = "config-files")]
+    pub fn to_toml(&self) -> Result<String, HydraulicConfigError> {
+        to_toml(self)
+    }
+
+    /// Each A320 circuit has a single pump section, and the PTU connects green to yellow.
+    pub fn validate(&self) -> Result<(), HydraulicConfigError> {
+        for (name, circuit, ptu_left_side, ptu_right_side) in [
+            ("green", &self.green, true, false),
+            ("blue", &self.blue, false, false),
+            ("yellow", &self.yellow, false, true),
+        ] {
+            circuit
+                .validate()
+                .map_err(|error| Self::invalid_circuit(name, error))?;
+
+            if circuit.pump_sections != 1 {
+                return Err(HydraulicConfigError::Invalid(format!(
+                    "{} circuit must have a single pump section",
+                    name
+                )));
+            }
+
+            if circuit.connected_to_ptu_left_side != ptu_left_side
+                || circuit.connected_to_ptu_right_side != ptu_right_side
+            {
+                return Err(HydraulicConfigError::Invalid(format!(
+                    "{} circuit PTU connection does not match the A320 PTU between green and yellow",
+                    name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn invalid_circuit(name: &str, error: HydraulicConfigError) -> HydraulicConfigError {
+        match error {
+            HydraulicConfigError::Invalid(message) => {
+                HydraulicConfigError::Invalid(format!("{} circuit: {}", name, message))
+            }
+            error => error,
+        }
+    }
+
+    fn circuit(
+        high_pressure_volume_gallon: f64,
+        connected_to_ptu_left_side: bool,
+        connected_to_ptu_right_side: bool,
+        reservoir: ReservoirConfig,
+    ) -> HydraulicCircuitConfig {
+        HydraulicCircuitConfig {
+            pump_sections: 1,
+            priming_percent: 100.,
+            high_pressure_volume_gallon,
+            system_pressure_switch: PressureSwitchHysteresisConfig {
+                low_psi: Self::MIN_PRESS_PRESSURISED_LO_HYST,
+                high_psi: Self::MIN_PRESS_PRESSURISED_HI_HYST,
+            },
+            pump_pressure_switch: PressureSwitchHysteresisConfig {
+                low_psi: Self::MIN_PRESS_EDP_SECTION_LO_HYST,
+                high_psi: Self::MIN_PRESS_EDP_SECTION_HI_HYST,
+            },
+            connected_to_ptu_left_side,
+            connected_to_ptu_right_side,
+            reservoir,
+            accumulator: AccumulatorConfig {
+                gas_precharge_psi: Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI,
+                total_volume_gallon: Self::ACCUMULATOR_MAX_VOLUME_GALLONS,
+                fluid_volume_gallon: 0.,
+            },
+        }
+    }
+
+    fn low_air_pressure_switch() -> AirPressureSwitchConfig {
+        AirPressureSwitchConfig {
+            low_psi: 22.,
+            high_psi: 25.,
+            kind: PressureSwitchType::Relative,
+        }
+    }
+}
+impl Default for A320HydraulicConfig {
+    fn default() -> Self {
+        Self {
+            green: Self::circuit(
+                10.,
+                true,
+                false,
+                ReservoirConfig {
+                    max_capacity_liter: 23.,
+                    max_gaugeable_liter: 18.,
+                    level_gallon: 3.6,
+                    low_level_threshold_liter: 3.,
+                    air_pressure_switches: vec![Self::low_air_pressure_switch()],
+                    fuel_heat_exchanger: true,
+                },
+            ),
+            blue: Self::circuit(
+                8.,
+                false,
+                false,
+                ReservoirConfig {
+                    max_capacity_liter: 10.,
+                    max_gaugeable_liter: 8.,
+                    level_gallon: 1.56,
+                    low_level_threshold_liter: 2.,
+                    air_pressure_switches: vec![
+                        Self::low_air_pressure_switch(),
+                        AirPressureSwitchConfig {
+                            low_psi: 45.,
+                            high_psi: 48.,
+                            kind: PressureSwitchType::Absolute,
+                        },
+                    ],
+                    fuel_heat_exchanger: false,
+                },
+            ),
+            yellow: Self::circuit(
+                10.,
+                false,
+                true,
+                ReservoirConfig {
+                    max_capacity_liter: 20.,
+                    max_gaugeable_liter: 18.,
+                    level_gallon: 3.6,
+                    low_level_threshold_liter: 3.,
+                    air_pressure_switches: vec![Self::low_air_pressure_switch()],
+                    fuel_heat_exchanger: true,
+                },
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_is_valid() {
+        assert_eq!(A320HydraulicConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    #[cfg(feature = "config-files")]
+    fn default_config_round_trips_through_toml() {
+        let content = A320HydraulicConfig::default().to_toml().unwrap();
+
+        assert_eq!(
+            A320HydraulicConfig::from_toml(&content),
+            Ok(A320HydraulicConfig::default())
+        );
+    }
+
+    #[test]
+    fn config_with_two_pump_sections_is_rejected() {
+        let mut config = A320HydraulicConfig::default();
+        config.green.pump_sections = 2;
+
+        assert!(matches!(
+            config.validate(),
+            Err(HydraulicConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn config_with_ptu_on_blue_is_rejected() {
+        let mut config = A320HydraulicConfig::default();
+        config.blue.connected_to_ptu_right_side = true;
+        config.yellow.connected_to_ptu_right_side = false;
+
+        assert!(matches!(
+            config.validate(),
+            Err(HydraulicConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_circuit_error_names_the_circuit() {
+        let mut config = A320HydraulicConfig::default();
+        config.yellow.reservoir.level_gallon = 10.;
+
+        match config.validate() {
+            Err(HydraulicConfigError::Invalid(message)) => assert!(message.starts_with("yellow")),
+            _ => panic!("yellow reservoir overfilled should be invalid"),
+        }
+    }
+
+    #[test]
+    fn non_finite_accumulator_precharge_is_rejected() {
+        let mut config = A320HydraulicConfig::default();
+        config.blue.accumulator.gas_precharge_psi = f64::NAN;
+
+        assert!(matches!(
+            config.validate(),
+            Err(HydraulicConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "config-files")]
+    fn missing_circuit_is_a_parse_error() {
+        assert!(matches!(
+            A320HydraulicConfig::from_toml("[green]\npump_sections = 1\n"),
+            Err(HydraulicConfigError::Parse(_))
+        ));
+    }
+}
@@ -1,12 +1,12 @@
-use systems::shared::FeedbackPositionPickoffUnit;
+use systems::shared::{AsymmetryPositionPickoffUnit, FeedbackPositionPickoffUnit};
 
 use systems::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
     SimulatorWriter, UpdateContext, VariableIdentifier, Write,
 };
 
-use std::panic;
-use uom::si::{angle::degree, f64::*, velocity::knot};
+use std::{panic, time::Duration};
+use uom::si::{angle::degree, angular_velocity::degree_per_second, f64::*, velocity::knot};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 enum FlapsConf {
@@ -64,7 +64,117 @@ impl SimulationElement for FlapsHandle {
     }
 }
 
+/// Wing tip brake channel of the SFCC for one surface transmission.
+///
+/// Monitors the Asymmetry Position Pickoff Units (APPU) against each other, and the Feedback Position
+/// Pickoff Unit (FPPU) for overspeed or for movement away from a position already reached.
+/// Any detected fault engages the wing tip brakes, which stay engaged until a maintenance reset on ground.
+struct WingTipBrakeChannel {
+    overspeed_threshold: AngularVelocity,
+    speed_monitoring_start_angle: Angle,
+    speed_monitoring_duration: Duration,
+    last_demanded_angle: Angle,
+    is_stopped: bool,
+    reached_feedback_angle: Option<Angle>,
+    is_locked: bool,
+}
+impl WingTipBrakeChannel {
+    const ASYMMETRY_THRESHOLD_DEGREE: f64 = 5.;
+    const UNCOMMANDED_MOVEMENT_THRESHOLD_DEGREE: f64 = 2.;
+    const SPEED_MONITORING_PERIOD: Duration = Duration::from_millis(500);
+    const STOPPED_SPEED_THRESHOLD_DEGREE_PER_SECOND: f64 = 0.1;
+
+    fn new(overspeed_threshold: AngularVelocity) -> Self {
+        Self {
+            overspeed_threshold,
+            speed_monitoring_start_angle: Angle::new::<degree>(0.),
+            speed_monitoring_duration: Duration::default(),
+            last_demanded_angle: Angle::new::<degree>(0.),
+            is_stopped: true,
+            reached_feedback_angle: None,
+            is_locked: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        demanded_angle: Angle,
+        movement_required: bool,
+        feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnit),
+    ) {
+        let feedback_angle = feedback.angle();
+
+        let overspeed_detected = self.overspeed_detected(context, feedback_angle);
+
+        // Position is only considered reached once the transmission has stopped,
+        // so that a surface overshooting its target is not seen as moving away from it
+        if demanded_angle != self.last_demanded_angle {
+            self.is_stopped = false;
+            self.reached_feedback_angle = None;
+        } else if !movement_required && self.is_stopped && self.reached_feedback_angle.is_none() {
+            self.reached_feedback_angle = Some(feedback_angle);
+        }
+        self.last_demanded_angle = demanded_angle;
+
+        self.is_locked = self.is_locked
+            || self.asymmetry_detected(feedback)
+            || overspeed_detected
+            || self.uncommanded_movement_detected(feedback_angle);
+    }
+
+    fn asymmetry_detected(&self, feedback: &impl AsymmetryPositionPickoffUnit) -> bool {
+        (feedback.left_angle() - feedback.right_angle())
+            .get::<degree>()
+            .abs()
+            > Self::ASYMMETRY_THRESHOLD_DEGREE
+    }
+
+    // Speed is averaged over a fixed period so it does not depend on simulation frame rate
+    fn overspeed_detected(&mut self, context: &UpdateContext, feedback_angle: Angle) -> bool {
+        self.speed_monitoring_duration += context.delta();
+        if self.speed_monitoring_duration < Self::SPEED_MONITORING_PERIOD {
+            return false;
+        }
+
+        let feedback_speed = AngularVelocity::new::<degree_per_second>(
+            (feedback_angle - self.speed_monitoring_start_angle).get::<degree>()
+                / self.speed_monitoring_duration.as_secs_f64(),
+        );
+
+        self.speed_monitoring_start_angle = feedback_angle;
+        self.speed_monitoring_duration = Duration::default();
+        self.is_stopped = feedback_speed.get::<degree_per_second>().abs()
+            < Self::STOPPED_SPEED_THRESHOLD_DEGREE_PER_SECOND;
+
+        feedback_speed.abs() > self.overspeed_threshold
+    }
+
+    fn uncommanded_movement_detected(&self, feedback_angle: Angle) -> bool {
+        match self.reached_feedback_angle {
+            Some(reached_angle) => {
+                (feedback_angle - reached_angle).get::<degree>().abs()
+                    > Self::UNCOMMANDED_MOVEMENT_THRESHOLD_DEGREE
+            }
+            None => false,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.is_locked = false;
+        self.reached_feedback_angle = None;
+    }
+
+    fn is_locked(&self) -> bool {
+        self.is_locked
+    }
+}
+
 struct SlatFlapControlComputer {
+    wing_tip_brakes_reset_id: VariableIdentifier,
+    angle_of_attack_id: VariableIdentifier,
+    flap_load_relief_active_id: VariableIdentifier,
+    alpha_lock_engaged_id: VariableIdentifier,
     left_flaps_target_angle_id: VariableIdentifier,
     right_flaps_target_angle_id: VariableIdentifier,
     left_slats_target_angle_id: VariableIdentifier,
@@ -76,6 +186,14 @@ struct SlatFlapControlComputer {
     flaps_feedback_angle: Angle,
     slats_feedback_angle: Angle,
     flaps_conf: FlapsConf,
+
+    angle_of_attack: Angle,
+    flap_load_relief_active: bool,
+    alpha_lock_engaged: bool,
+
+    flaps_wing_tip_brake_channel: WingTipBrakeChannel,
+    slats_wing_tip_brake_channel: WingTipBrakeChannel,
+    wing_tip_brakes_reset_requested: bool,
 }
 
 impl SlatFlapControlComputer {
@@ -83,8 +201,30 @@ impl SlatFlapControlComputer {
     const HANDLE_ONE_CONF_AIRSPEED_THRESHOLD_KNOTS: f64 = 100.;
     const CONF1F_TO_CONF1_AIRSPEED_THRESHOLD_KNOTS: f64 = 210.;
 
+    const CONF3_VFE_KNOTS: f64 = 185.;
+    const CONF_FULL_VFE_KNOTS: f64 = 177.;
+    const FLAP_LOAD_RELIEF_ENGAGE_MARGIN_KNOTS: f64 = 2.;
+    const FLAP_LOAD_RELIEF_RELEASE_MARGIN_KNOTS: f64 = 3.;
+
+    const ALPHA_LOCK_ENGAGE_ALPHA_DEGREE: f64 = 8.5;
+    const ALPHA_LOCK_ENGAGE_AIRSPEED_KNOTS: f64 = 148.;
+    const ALPHA_LOCK_RELEASE_ALPHA_DEGREE: f64 = 7.6;
+    const ALPHA_LOCK_RELEASE_AIRSPEED_KNOTS: f64 = 154.;
+    // Below this speed the aircraft is considered in ground operations and alpha lock is inhibited
+    const ALPHA_LOCK_INHIBIT_AIRSPEED_KNOTS: f64 = 60.;
+
+    // Feedback position pickoff unit speeds above which a transmission is considered running away
+    const FLAPS_OVERSPEED_THRESHOLD_DEGREE_PER_SECOND: f64 = 25.;
+    const SLATS_OVERSPEED_THRESHOLD_DEGREE_PER_SECOND: f64 = 18.;
+
     fn new(context: &mut InitContext) -> Self {
         Self {
+            wing_tip_brakes_reset_id: context
+                .get_identifier("SFCC_WING_TIP_BRAKES_RESET".to_owned()),
+            angle_of_attack_id: context.get_identifier("INCIDENCE ALPHA".to_owned()),
+            flap_load_relief_active_id: context
+                .get_identifier("SFCC_FLAP_LOAD_RELIEF_ACTIVE".to_owned()),
+            alpha_lock_engaged_id: context.get_identifier("SFCC_ALPHA_LOCK_ENGAGED".to_owned()),
             left_flaps_target_angle_id: context
                 .get_identifier("LEFT_FLAPS_TARGET_ANGLE".to_owned()),
             right_flaps_target_angle_id: context
@@ -100,6 +240,22 @@ impl SlatFlapControlComputer {
             flaps_feedback_angle: Angle::new::<degree>(0.),
             slats_feedback_angle: Angle::new::<degree>(0.),
             flaps_conf: FlapsConf::Conf0,
+
+            angle_of_attack: Angle::new::<degree>(0.),
+            flap_load_relief_active: false,
+            alpha_lock_engaged: false,
+
+            flaps_wing_tip_brake_channel: WingTipBrakeChannel::new(AngularVelocity::new::<
+                degree_per_second,
+            >(
+                Self::FLAPS_OVERSPEED_THRESHOLD_DEGREE_PER_SECOND,
+            )),
+            slats_wing_tip_brake_channel: WingTipBrakeChannel::new(AngularVelocity::new::<
+                degree_per_second,
+            >(
+                Self::SLATS_OVERSPEED_THRESHOLD_DEGREE_PER_SECOND,
+            )),
+            wing_tip_brakes_reset_requested: false,
         }
     }
 
@@ -166,25 +322,125 @@ impl SlatFlapControlComputer {
         &mut self,
         context: &UpdateContext,
         flaps_handle: &FlapsHandle,
-        flaps_feedback: &impl FeedbackPositionPickoffUnit,
-        slats_feedback: &impl FeedbackPositionPickoffUnit,
+        flaps_feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnit),
+        slats_feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnit),
     ) {
+        let previous_conf = self.flaps_conf;
         self.flaps_conf = self.generate_configuration(flaps_handle, context);
 
-        self.flaps_demanded_angle = Self::demanded_flaps_angle_from_conf(self.flaps_conf);
-        self.slats_demanded_angle = Self::demanded_slats_angle_from_conf(self.flaps_conf);
+        self.update_flap_load_relief(context, previous_conf);
+        self.update_alpha_lock(context, previous_conf);
+
+        self.flaps_demanded_angle = if self.flap_load_relief_active {
+            Self::demanded_flaps_angle_from_conf(Self::flap_load_relief_conf(self.flaps_conf))
+        } else {
+            Self::demanded_flaps_angle_from_conf(self.flaps_conf)
+        };
+        self.slats_demanded_angle = if self.alpha_lock_engaged {
+            Self::demanded_slats_angle_from_conf(FlapsConf::Conf1)
+        } else {
+            Self::demanded_slats_angle_from_conf(self.flaps_conf)
+        };
         self.flaps_feedback_angle = flaps_feedback.angle();
         self.slats_feedback_angle = slats_feedback.angle();
+
+        self.update_wing_tip_brake_channels(context, flaps_feedback, slats_feedback);
+    }
+
+    fn vfe(flaps_conf: FlapsConf) -> Option<Velocity> {
+        match flaps_conf {
+            FlapsConf::Conf3 => Some(Velocity::new::<knot>(Self::CONF3_VFE_KNOTS)),
+            FlapsConf::ConfFull => Some(Velocity::new::<knot>(Self::CONF_FULL_VFE_KNOTS)),
+            _ => None,
+        }
+    }
+
+    fn flap_load_relief_conf(flaps_conf: FlapsConf) -> FlapsConf {
+        match flaps_conf {
+            FlapsConf::ConfFull => FlapsConf::Conf3,
+            FlapsConf::Conf3 => FlapsConf::Conf2,
+            conf => conf,
+        }
+    }
+
+    // Retracts flaps to the next position when VFE is exceeded in CONF 3 or FULL,
+    // and extends them back once speed has decreased below VFE
+    fn update_flap_load_relief(&mut self, context: &UpdateContext, previous_conf: FlapsConf) {
+        if self.flaps_conf != previous_conf {
+            self.flap_load_relief_active = false;
+        }
+
+        self.flap_load_relief_active = match Self::vfe(self.flaps_conf) {
+            Some(vfe) => {
+                let airspeed = context.indicated_airspeed().get::<knot>();
+                if airspeed > vfe.get::<knot>() + Self::FLAP_LOAD_RELIEF_ENGAGE_MARGIN_KNOTS {
+                    true
+                } else if airspeed < vfe.get::<knot>() - Self::FLAP_LOAD_RELIEF_RELEASE_MARGIN_KNOTS
+                {
+                    false
+                } else {
+                    self.flap_load_relief_active
+                }
+            }
+            None => false,
+        };
+    }
+
+    // Inhibits slats retraction when handle is set to 0 at high angle of attack or low speed
+    fn update_alpha_lock(&mut self, context: &UpdateContext, previous_conf: FlapsConf) {
+        let airspeed = context.indicated_airspeed().get::<knot>();
+
+        if self.flaps_conf != FlapsConf::Conf0 || airspeed < Self::ALPHA_LOCK_INHIBIT_AIRSPEED_KNOTS
+        {
+            self.alpha_lock_engaged = false;
+        } else if previous_conf != FlapsConf::Conf0 {
+            self.alpha_lock_engaged = self.angle_of_attack.get::<degree>()
+                > Self::ALPHA_LOCK_ENGAGE_ALPHA_DEGREE
+                || airspeed < Self::ALPHA_LOCK_ENGAGE_AIRSPEED_KNOTS;
+        } else if self.alpha_lock_engaged
+            && self.angle_of_attack.get::<degree>() < Self::ALPHA_LOCK_RELEASE_ALPHA_DEGREE
+            && airspeed > Self::ALPHA_LOCK_RELEASE_AIRSPEED_KNOTS
+        {
+            self.alpha_lock_engaged = false;
+        }
+    }
+
+    fn update_wing_tip_brake_channels(
+        &mut self,
+        context: &UpdateContext,
+        flaps_feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnit),
+        slats_feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnit),
+    ) {
+        // Locked transmissions can only be released by maintenance on ground
+        if self.wing_tip_brakes_reset_requested && context.is_on_ground() {
+            self.flaps_wing_tip_brake_channel.reset();
+            self.slats_wing_tip_brake_channel.reset();
+        }
+
+        self.flaps_wing_tip_brake_channel.update(
+            context,
+            self.flaps_demanded_angle,
+            Self::surface_movement_required(self.flaps_demanded_angle, self.flaps_feedback_angle),
+            flaps_feedback,
+        );
+        self.slats_wing_tip_brake_channel.update(
+            context,
+            self.slats_demanded_angle,
+            Self::surface_movement_required(self.slats_demanded_angle, self.slats_feedback_angle),
+            slats_feedback,
+        );
     }
 }
 
 trait SlatFlapLane {
     fn signal_demanded_angle(&self, surface_type: &str) -> Option<Angle>;
+    fn wing_tip_brakes_engaged(&self, surface_type: &str) -> bool;
 }
 
 impl SlatFlapLane for SlatFlapControlComputer {
     fn signal_demanded_angle(&self, surface_type: &str) -> Option<Angle> {
         match surface_type {
+            _ if self.wing_tip_brakes_engaged(surface_type) => None,
             "FLAPS"
                 if Self::surface_movement_required(
                     self.flaps_demanded_angle,
@@ -205,9 +461,22 @@ impl SlatFlapLane for SlatFlapControlComputer {
             _ => panic!("Not a valid slat/flap surface"),
         }
     }
+
+    fn wing_tip_brakes_engaged(&self, surface_type: &str) -> bool {
+        match surface_type {
+            "FLAPS" => self.flaps_wing_tip_brake_channel.is_locked(),
+            "SLATS" => self.slats_wing_tip_brake_channel.is_locked(),
+            _ => panic!("Not a valid slat/flap surface"),
+        }
+    }
 }
 
 impl SimulationElement for SlatFlapControlComputer {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.wing_tip_brakes_reset_requested = reader.read(&self.wing_tip_brakes_reset_id);
+        self.angle_of_attack = reader.read(&self.angle_of_attack_id);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.left_flaps_target_angle_id, self.flaps_demanded_angle);
         writer.write(&self.right_flaps_target_angle_id, self.flaps_demanded_angle);
@@ -216,6 +485,12 @@ impl SimulationElement for SlatFlapControlComputer {
         writer.write(&self.right_slats_target_angle_id, self.slats_demanded_angle);
 
         writer.write(&self.flaps_conf_index_id, self.flaps_conf as u8);
+
+        writer.write(
+            &self.flap_load_relief_active_id,
+            self.flap_load_relief_active,
+        );
+        writer.write(&self.alpha_lock_engaged_id, self.alpha_lock_engaged);
     }
 }
 
@@ -235,8 +510,8 @@ impl SlatFlapComplex {
     pub fn update(
         &mut self,
         context: &UpdateContext,
-        flaps_feedback: &impl FeedbackPositionPickoffUnit,
-        slats_feedback: &impl FeedbackPositionPickoffUnit,
+        flaps_feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnit),
+        slats_feedback: &(impl FeedbackPositionPickoffUnit + AsymmetryPositionPickoffUnit),
     ) {
         self.sfcc
             .update(context, &self.flaps_handle, flaps_feedback, slats_feedback);
@@ -249,6 +524,14 @@ impl SlatFlapComplex {
     pub fn slat_demand(&self) -> Option<Angle> {
         self.sfcc.signal_demanded_angle("SLATS")
     }
+
+    pub fn flap_wing_tip_brakes_engaged(&self) -> bool {
+        self.sfcc.wing_tip_brakes_engaged("FLAPS")
+    }
+
+    pub fn slat_wing_tip_brakes_engaged(&self) -> bool {
+        self.sfcc.wing_tip_brakes_engaged("SLATS")
+    }
 }
 impl SimulationElement for SlatFlapComplex {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -272,6 +555,9 @@ mod tests {
 
     struct SlatFlapGear {
         current_angle: Angle,
+        left_angle: Angle,
+        right_angle: Angle,
+        left_transmission_failed: bool,
         speed: AngularVelocity,
         max_angle: Angle,
         left_position_percent_id: VariableIdentifier,
@@ -285,6 +571,15 @@ mod tests {
             self.current_angle
         }
     }
+    impl AsymmetryPositionPickoffUnit for SlatFlapGear {
+        fn left_angle(&self) -> Angle {
+            self.left_angle
+        }
+
+        fn right_angle(&self) -> Angle {
+            self.right_angle
+        }
+    }
 
     impl SlatFlapGear {
         const ANGLE_DELTA_DEGREE: f64 = 0.1;
@@ -297,6 +592,9 @@ mod tests {
         ) -> Self {
             Self {
                 current_angle: Angle::new::<degree>(0.),
+                left_angle: Angle::new::<degree>(0.),
+                right_angle: Angle::new::<degree>(0.),
+                left_transmission_failed: false,
                 speed,
                 max_angle,
 
@@ -321,8 +619,9 @@ mod tests {
             hydraulic_pressure_left_side: Pressure,
             hydraulic_pressure_right_side: Pressure,
         ) {
-            if hydraulic_pressure_left_side.get::<psi>() > 1500.
-                || hydraulic_pressure_right_side.get::<psi>() > 1500.
+            if !sfcc.wing_tip_brakes_engaged(&self.surface_type)
+                && (hydraulic_pressure_left_side.get::<psi>() > 1500.
+                    || hydraulic_pressure_right_side.get::<psi>() > 1500.)
             {
                 if let Some(demanded_angle) = sfcc.signal_demanded_angle(&self.surface_type) {
                     let actual_minus_target = demanded_angle - self.current_angle;
@@ -338,20 +637,42 @@ mod tests {
                     }
                 }
             }
+
+            self.update_wing_angles();
+        }
+
+        fn update_wing_angles(&mut self) {
+            if !self.left_transmission_failed {
+                self.left_angle = self.current_angle;
+            }
+            self.right_angle = self.current_angle;
+        }
+
+        fn fail_left_transmission(&mut self) {
+            self.left_transmission_failed = true;
+        }
+
+        fn set_speed(&mut self, speed: AngularVelocity) {
+            self.speed = speed;
+        }
+
+        fn move_uncommanded(&mut self, delta: Angle) {
+            self.current_angle += delta;
+            self.update_wing_angles();
         }
     }
     impl SimulationElement for SlatFlapGear {
         fn write(&self, writer: &mut SimulatorWriter) {
             writer.write(
                 &self.left_position_percent_id,
-                self.current_angle / self.max_angle,
+                self.left_angle / self.max_angle,
             );
             writer.write(
                 &self.right_position_percent_id,
-                self.current_angle / self.max_angle,
+                self.right_angle / self.max_angle,
             );
-            writer.write(&self.left_position_angle_id, self.current_angle);
-            writer.write(&self.right_position_angle_id, self.current_angle);
+            writer.write(&self.left_position_angle_id, self.left_angle);
+            writer.write(&self.right_position_angle_id, self.right_angle);
         }
     }
 
@@ -472,6 +793,19 @@ mod tests {
             self
         }
 
+        fn set_angle_of_attack(mut self, angle_of_attack: f64) -> Self {
+            self.write_by_name("INCIDENCE ALPHA", angle_of_attack);
+            self
+        }
+
+        fn is_flap_load_relief_active(&mut self) -> bool {
+            self.read_by_name("SFCC_FLAP_LOAD_RELIEF_ACTIVE")
+        }
+
+        fn is_alpha_lock_engaged(&mut self) -> bool {
+            self.read_by_name("SFCC_ALPHA_LOCK_ENGAGED")
+        }
+
         fn set_green_hyd_pressure(mut self) -> Self {
             self.write_by_name("HYD_GREEN_PRESSURE", 2500.);
             self
@@ -517,6 +851,55 @@ mod tests {
             self.query(|a| a.slat_gear.current_angle.get::<degree>())
         }
 
+        fn get_flaps_left_angle(&self) -> f64 {
+            self.query(|a| a.flap_gear.left_angle.get::<degree>())
+        }
+
+        fn get_flaps_right_angle(&self) -> f64 {
+            self.query(|a| a.flap_gear.right_angle.get::<degree>())
+        }
+
+        fn fail_left_flaps_transmission(mut self) -> Self {
+            self.command(|a| a.flap_gear.fail_left_transmission());
+            self
+        }
+
+        fn set_flaps_gear_speed(mut self, speed_degree_per_second: f64) -> Self {
+            self.command(|a| {
+                a.flap_gear
+                    .set_speed(AngularVelocity::new::<degree_per_second>(
+                        speed_degree_per_second,
+                    ))
+            });
+            self
+        }
+
+        fn move_slats_uncommanded(mut self, delta_degree: f64) -> Self {
+            self.command(|a| {
+                a.slat_gear
+                    .move_uncommanded(Angle::new::<degree>(delta_degree))
+            });
+            self
+        }
+
+        fn set_wing_tip_brakes_reset(mut self, is_requested: bool) -> Self {
+            self.write_by_name("SFCC_WING_TIP_BRAKES_RESET", is_requested);
+            self
+        }
+
+        fn set_on_ground(mut self, on_ground: bool) -> Self {
+            self.test_bed.set_on_ground(on_ground);
+            self
+        }
+
+        fn flaps_wing_tip_brakes_engaged(&self) -> bool {
+            self.query(|a| a.slat_flap_complex.flap_wing_tip_brakes_engaged())
+        }
+
+        fn slats_wing_tip_brakes_engaged(&self) -> bool {
+            self.query(|a| a.slat_flap_complex.slat_wing_tip_brakes_engaged())
+        }
+
         fn test_flap_conf(
             &mut self,
             handle_pos: u8,
@@ -721,13 +1104,14 @@ mod tests {
             .set_indicated_airspeed(220.)
             .run_one_tick();
 
+        // Above VFE of CONF 3 and FULL, flap load relief retracts flaps to the next position
         test_bed = test_bed.set_flaps_handle_position(4).run_one_tick();
 
-        test_bed.test_flap_conf(4, 40., 27., FlapsConf::ConfFull, angle_delta);
+        test_bed.test_flap_conf(4, 20., 27., FlapsConf::ConfFull, angle_delta);
 
         test_bed = test_bed.set_flaps_handle_position(3).run_one_tick();
 
-        test_bed.test_flap_conf(3, 20., 22., FlapsConf::Conf3, angle_delta);
+        test_bed.test_flap_conf(3, 15., 22., FlapsConf::Conf3, angle_delta);
 
         test_bed = test_bed.set_flaps_handle_position(2).run_one_tick();
 
@@ -1390,4 +1774,350 @@ mod tests {
         assert_about_eq!(test_bed.get_flaps_angle(), starting_flap_angle);
         assert!(test_bed.get_slats_angle() > starting_slat_angle);
     }
+
+    #[test]
+    fn flaps_asymmetry_engages_wing_tip_brakes() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_yellow_hyd_pressure()
+            .set_indicated_airspeed(150.)
+            .fail_left_flaps_transmission()
+            .run_one_tick();
+
+        test_bed = test_bed.set_flaps_handle_position(2);
+        for _ in 0..300 {
+            test_bed = test_bed.run_one_tick();
+        }
+
+        assert!(test_bed.flaps_wing_tip_brakes_engaged());
+        assert!(!test_bed.slats_wing_tip_brakes_engaged());
+        assert!(test_bed
+            .query(|a| a.slat_flap_complex.flap_demand())
+            .is_none());
+
+        assert_about_eq!(test_bed.get_flaps_left_angle(), 0.);
+        assert!(test_bed.get_flaps_right_angle() > 5.);
+        assert!(test_bed.get_flaps_right_angle() < 6.);
+    }
+
+    #[test]
+    fn flaps_remain_locked_after_handle_movement() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_yellow_hyd_pressure()
+            .set_indicated_airspeed(150.)
+            .fail_left_flaps_transmission()
+            .run_one_tick();
+
+        test_bed = test_bed.set_flaps_handle_position(2);
+        for _ in 0..300 {
+            test_bed = test_bed.run_one_tick();
+        }
+        assert!(test_bed.flaps_wing_tip_brakes_engaged());
+        let locked_flaps_angle = test_bed.get_flaps_angle();
+
+        test_bed = test_bed.set_flaps_handle_position(0);
+        for _ in 0..300 {
+            test_bed = test_bed.run_one_tick();
+        }
+
+        assert!(test_bed.flaps_wing_tip_brakes_engaged());
+        assert_about_eq!(test_bed.get_flaps_angle(), locked_flaps_angle);
+        assert!(test_bed.get_slats_angle() < 0.1);
+    }
+
+    #[test]
+    fn flaps_overspeed_engages_wing_tip_brakes() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_yellow_hyd_pressure()
+            .set_indicated_airspeed(150.)
+            .set_flaps_gear_speed(60.)
+            .run_one_tick();
+
+        test_bed = test_bed.set_flaps_handle_position(4);
+        for _ in 0..30 {
+            test_bed = test_bed.run_one_tick();
+        }
+
+        assert!(test_bed.flaps_wing_tip_brakes_engaged());
+        assert!(!test_bed.slats_wing_tip_brakes_engaged());
+    }
+
+    #[test]
+    fn slats_uncommanded_movement_engages_wing_tip_brakes() {
+        let mut test_bed = test_bed_with().run_one_tick();
+
+        assert!(test_bed
+            .query(|a| a.slat_flap_complex.slat_demand())
+            .is_none());
+
+        // Slow drift so that only the uncommanded movement monitoring can trip
+        for _ in 0..6 {
+            test_bed = test_bed.move_slats_uncommanded(0.3).run_one_tick();
+            assert!(!test_bed.slats_wing_tip_brakes_engaged());
+        }
+
+        test_bed = test_bed.move_slats_uncommanded(0.3).run_one_tick();
+        assert!(test_bed.slats_wing_tip_brakes_engaged());
+        assert!(!test_bed.flaps_wing_tip_brakes_engaged());
+    }
+
+    #[test]
+    fn slats_overshooting_their_target_do_not_engage_wing_tip_brakes() {
+        let mut test_bed = test_bed_with()
+            .set_blue_hyd_pressure()
+            .set_green_hyd_pressure()
+            .set_flaps_handle_position(1)
+            .run_one_tick();
+
+        for _ in 0..300 {
+            if (test_bed.get_slats_angle() - 18.).abs() < 0.01 {
+                break;
+            }
+            test_bed = test_bed.run_one_tick();
+        }
+        test_bed = test_bed.run_one_tick();
+
+        test_bed = test_bed
+            .move_slats_uncommanded(2.5)
+            .run_waiting_for(Duration::from_secs(3));
+
+        assert!(!test_bed.slats_wing_tip_brakes_engaged());
+        assert_about_eq!(test_bed.get_slats_angle(), 18., 0.1);
+    }
+
+    #[test]
+    fn wing_tip_brakes_reset_is_inhibited_in_flight() {
+        let mut test_bed = test_bed_with()
+            .set_blue_hyd_pressure()
+            .set_green_hyd_pressure()
+            .run_one_tick()
+            .move_slats_uncommanded(5.)
+            .run_one_tick();
+        assert!(test_bed.slats_wing_tip_brakes_engaged());
+
+        test_bed = test_bed.set_wing_tip_brakes_reset(true).run_one_tick();
+
+        assert!(test_bed.slats_wing_tip_brakes_engaged());
+    }
+
+    #[test]
+    fn wing_tip_brakes_reset_on_ground_unlocks_surfaces() {
+        let mut test_bed = test_bed_with()
+            .set_blue_hyd_pressure()
+            .set_green_hyd_pressure()
+            .set_on_ground(true)
+            .set_indicated_airspeed(0.)
+            .run_one_tick()
+            .move_slats_uncommanded(5.)
+            .run_one_tick();
+        assert!(test_bed.slats_wing_tip_brakes_engaged());
+
+        test_bed = test_bed
+            .set_wing_tip_brakes_reset(true)
+            .run_one_tick()
+            .set_wing_tip_brakes_reset(false)
+            .run_one_tick();
+        assert!(!test_bed.slats_wing_tip_brakes_engaged());
+
+        test_bed = test_bed.set_flaps_handle_position(0);
+        for _ in 0..300 {
+            test_bed = test_bed.run_one_tick();
+        }
+        assert!(test_bed.get_slats_angle() < 0.1);
+        assert!(!test_bed.slats_wing_tip_brakes_engaged());
+    }
+
+    #[test]
+    fn wing_tip_brakes_relock_after_reset_if_asymmetry_remains() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_yellow_hyd_pressure()
+            .set_on_ground(true)
+            .set_indicated_airspeed(0.)
+            .fail_left_flaps_transmission()
+            .run_one_tick();
+
+        test_bed = test_bed.set_flaps_handle_position(2);
+        for _ in 0..300 {
+            test_bed = test_bed.run_one_tick();
+        }
+        assert!(test_bed.flaps_wing_tip_brakes_engaged());
+
+        test_bed = test_bed
+            .set_wing_tip_brakes_reset(true)
+            .run_one_tick()
+            .set_wing_tip_brakes_reset(false)
+            .run_one_tick();
+
+        assert!(test_bed.flaps_wing_tip_brakes_engaged());
+    }
+
+    #[test]
+    fn flap_load_relief_retracts_flaps_above_vfe_in_conf_full() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_indicated_airspeed(150.)
+            .set_flaps_handle_position(4)
+            .run_one_tick();
+
+        assert!(!test_bed.is_flap_load_relief_active());
+        assert_about_eq!(test_bed.get_flaps_demanded_angle(), 40.);
+
+        test_bed = test_bed.set_indicated_airspeed(180.).run_one_tick();
+
+        assert!(test_bed.is_flap_load_relief_active());
+        assert_eq!(test_bed.get_flaps_conf(), FlapsConf::ConfFull);
+        assert_about_eq!(test_bed.get_flaps_demanded_angle(), 20.);
+        assert_about_eq!(test_bed.get_slats_demanded_angle(), 27.);
+    }
+
+    #[test]
+    fn flap_load_relief_extends_flaps_back_when_speed_decreases() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_indicated_airspeed(150.)
+            .set_flaps_handle_position(4)
+            .run_one_tick()
+            .set_indicated_airspeed(180.)
+            .run_one_tick();
+
+        assert!(test_bed.is_flap_load_relief_active());
+
+        test_bed = test_bed.set_indicated_airspeed(176.).run_one_tick();
+        assert!(test_bed.is_flap_load_relief_active());
+        assert_about_eq!(test_bed.get_flaps_demanded_angle(), 20.);
+
+        test_bed = test_bed.set_indicated_airspeed(173.).run_one_tick();
+        assert!(!test_bed.is_flap_load_relief_active());
+        assert_about_eq!(test_bed.get_flaps_demanded_angle(), 40.);
+    }
+
+    #[test]
+    fn flap_load_relief_retracts_flaps_above_vfe_in_conf_3() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_indicated_airspeed(150.)
+            .set_flaps_handle_position(3)
+            .run_one_tick();
+
+        assert_about_eq!(test_bed.get_flaps_demanded_angle(), 20.);
+
+        test_bed = test_bed.set_indicated_airspeed(188.).run_one_tick();
+
+        assert!(test_bed.is_flap_load_relief_active());
+        assert_eq!(test_bed.get_flaps_conf(), FlapsConf::Conf3);
+        assert_about_eq!(test_bed.get_flaps_demanded_angle(), 15.);
+    }
+
+    #[test]
+    fn flap_load_relief_is_not_active_in_conf_2() {
+        let test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_indicated_airspeed(150.)
+            .set_flaps_handle_position(2)
+            .run_one_tick()
+            .set_indicated_airspeed(230.)
+            .run_one_tick();
+
+        assert!(!test_bed.query(|a| a.slat_flap_complex.sfcc.flap_load_relief_active));
+        assert_about_eq!(test_bed.get_flaps_demanded_angle(), 15.);
+    }
+
+    #[test]
+    fn flap_load_relief_is_reevaluated_on_configuration_change() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_indicated_airspeed(150.)
+            .set_flaps_handle_position(4)
+            .run_one_tick()
+            .set_indicated_airspeed(183.)
+            .run_one_tick();
+
+        assert!(test_bed.is_flap_load_relief_active());
+
+        test_bed = test_bed.set_flaps_handle_position(3).run_one_tick();
+
+        assert!(!test_bed.is_flap_load_relief_active());
+        assert_about_eq!(test_bed.get_flaps_demanded_angle(), 20.);
+    }
+
+    #[test]
+    fn alpha_lock_inhibits_slats_retraction_at_low_speed() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_indicated_airspeed(140.)
+            .set_flaps_handle_position(1)
+            .run_one_tick();
+
+        test_bed = test_bed.set_flaps_handle_position(0).run_one_tick();
+
+        assert!(test_bed.is_alpha_lock_engaged());
+        assert_eq!(test_bed.get_flaps_conf(), FlapsConf::Conf0);
+        assert_about_eq!(test_bed.get_flaps_demanded_angle(), 0.);
+        assert_about_eq!(test_bed.get_slats_demanded_angle(), 18.);
+
+        test_bed = test_bed.set_indicated_airspeed(150.).run_one_tick();
+        assert!(test_bed.is_alpha_lock_engaged());
+
+        test_bed = test_bed.set_indicated_airspeed(160.).run_one_tick();
+        assert!(!test_bed.is_alpha_lock_engaged());
+        assert_about_eq!(test_bed.get_slats_demanded_angle(), 0.);
+    }
+
+    #[test]
+    fn alpha_lock_inhibits_slats_retraction_at_high_angle_of_attack() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_indicated_airspeed(200.)
+            .set_angle_of_attack(9.)
+            .set_flaps_handle_position(1)
+            .run_one_tick();
+
+        test_bed = test_bed.set_flaps_handle_position(0).run_one_tick();
+
+        assert!(test_bed.is_alpha_lock_engaged());
+        assert_about_eq!(test_bed.get_slats_demanded_angle(), 18.);
+
+        test_bed = test_bed.set_angle_of_attack(8.).run_one_tick();
+        assert!(test_bed.is_alpha_lock_engaged());
+
+        test_bed = test_bed.set_angle_of_attack(7.).run_one_tick();
+        assert!(!test_bed.is_alpha_lock_engaged());
+        assert_about_eq!(test_bed.get_slats_demanded_angle(), 0.);
+    }
+
+    #[test]
+    fn alpha_lock_does_not_engage_once_slats_retraction_started() {
+        let mut test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_indicated_airspeed(200.)
+            .set_flaps_handle_position(1)
+            .run_one_tick()
+            .set_flaps_handle_position(0)
+            .run_one_tick();
+
+        assert!(!test_bed.is_alpha_lock_engaged());
+
+        test_bed = test_bed.set_angle_of_attack(10.).run_one_tick();
+
+        assert!(!test_bed.is_alpha_lock_engaged());
+        assert_about_eq!(test_bed.get_slats_demanded_angle(), 0.);
+    }
+
+    #[test]
+    fn alpha_lock_is_inhibited_during_ground_operations() {
+        let test_bed = test_bed_with()
+            .set_green_hyd_pressure()
+            .set_indicated_airspeed(30.)
+            .set_angle_of_attack(10.)
+            .set_flaps_handle_position(1)
+            .run_one_tick()
+            .set_flaps_handle_position(0)
+            .run_one_tick();
+
+        assert!(!test_bed.query(|a| a.slat_flap_complex.sfcc.alpha_lock_engaged));
+        assert_about_eq!(test_bed.get_slats_demanded_angle(), 0.);
+    }
 }
@@ -0,0 +1,611 @@
+use nalgebra::Vector3;
+
+use std::time::Duration;
+use uom::si::{
+    angle::degree, f64::*, length::meter, mass::kilogram, pressure::psi, ratio::ratio,
+    volume_rate::gallon_per_second,
+};
+
+use systems::{
+    hydraulic::linear_actuator::{
+        Actuator, BoundedLinearLength, HydraulicAssemblyController,
+        HydraulicLinearActuatorAssembly, LinearActuatedRigidBodyOnHingeAxis, LinearActuator,
+        LinearActuatorMode,
+    },
+    landing_gear::{GearSystemSensors, GearWheel},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
+    },
+};
+
+struct A320GearSystemFactory {}
+impl A320GearSystemFactory {
+    const FLOW_CONTROL_PROPORTIONAL_GAIN: f64 = 0.05;
+    const FLOW_CONTROL_INTEGRAL_GAIN: f64 = 5.;
+    const FLOW_CONTROL_FORCE_GAIN: f64 = 200000.;
+
+    fn a320_main_gear_actuator(bounded_linear_length: &impl BoundedLinearLength) -> LinearActuator {
+        LinearActuator::new(
+            bounded_linear_length,
+            1,
+            Length::new::<meter>(0.145),
+            Length::new::<meter>(0.105),
+            VolumeRate::new::<gallon_per_second>(0.17),
+            800000.,
+            15000.,
+            50000.,
+            1200000.,
+            Duration::from_millis(100),
+            [1., 1., 1., 1., 1., 1.],
+            [0., 0.2, 0.21, 0.79, 0.8, 1.],
+            Self::FLOW_CONTROL_PROPORTIONAL_GAIN,
+            Self::FLOW_CONTROL_INTEGRAL_GAIN,
+            Self::FLOW_CONTROL_FORCE_GAIN,
+        )
+    }
+
+    fn a320_nose_gear_actuator(bounded_linear_length: &impl BoundedLinearLength) -> LinearActuator {
+        LinearActuator::new(
+            bounded_linear_length,
+            1,
+            Length::new::<meter>(0.1),
+            Length::new::<meter>(0.07),
+            VolumeRate::new::<gallon_per_second>(0.1),
+            400000.,
+            10000.,
+            20000.,
+            400000.,
+            Duration::from_millis(100),
+            [1., 1., 1., 1., 1., 1.],
+            [0., 0.2, 0.21, 0.79, 0.8, 1.],
+            Self::FLOW_CONTROL_PROPORTIONAL_GAIN,
+            Self::FLOW_CONTROL_INTEGRAL_GAIN,
+            Self::FLOW_CONTROL_FORCE_GAIN,
+        )
+    }
+
+    fn a320_gear_door_actuator(bounded_linear_length: &impl BoundedLinearLength) -> LinearActuator {
+        LinearActuator::new(
+            bounded_linear_length,
+            1,
+            Length::new::<meter>(0.055),
+            Length::new::<meter>(0.03),
+            VolumeRate::new::<gallon_per_second>(0.06),
+            20000.,
+            5000.,
+            2000.,
+            28000.,
+            Duration::from_millis(100),
+            [0.5, 1., 1., 1., 1., 0.5],
+            [0., 0.2, 0.21, 0.79, 0.8, 1.],
+            Self::FLOW_CONTROL_PROPORTIONAL_GAIN,
+            Self::FLOW_CONTROL_INTEGRAL_GAIN,
+            Self::FLOW_CONTROL_FORCE_GAIN,
+        )
+    }
+
+    /// Builds a main gear body. Position 0 is gear down, position 1 is gear up.
+    /// The centre of gravity is slightly offset inboard so the gear stays pushed against
+    /// its down stop, as does the real gear with its over centre side brace.
+    fn a320_main_gear_body(
+        wheel_id: GearWheel,
+        init_up: bool,
+    ) -> LinearActuatedRigidBodyOnHingeAxis {
+        let side = if wheel_id == GearWheel::LEFT { -1. } else { 1. };
+
+        let size = Vector3::new(0.3, 3.453, 0.3);
+        let cg_offset = Vector3::new(-side * 0.2, -3. / 4. * size[1], 0.);
+
+        let control_arm = Vector3::new(-side * 0.1815, 0.15, 0.);
+        let anchor = Vector3::new(-side * 0.26, 0.15, 0.);
+
+        let (min_angle, init_angle) = if wheel_id == GearWheel::LEFT {
+            (0., if init_up { 80. } else { 0. })
+        } else {
+            (-80., if init_up { -80. } else { 0. })
+        };
+
+        LinearActuatedRigidBodyOnHingeAxis::new(
+            Mass::new::<kilogram>(700.),
+            size,
+            cg_offset,
+            control_arm,
+            anchor,
+            Angle::new::<degree>(min_angle),
+            Angle::new::<degree>(80.),
+            Angle::new::<degree>(init_angle),
+            150.,
+            true,
+            Vector3::new(0., 0., 1.),
+        )
+    }
+
+    /// Builds the nose gear body, retracting forward. Position 0 is gear down, position 1 is gear up.
+    fn a320_nose_gear_body(init_up: bool) -> LinearActuatedRigidBodyOnHingeAxis {
+        let size = Vector3::new(0.3, 2., 0.3);
+        let cg_offset = Vector3::new(0., -size[1] / 2., 0.1);
+
+        let control_arm = Vector3::new(0., 0.2, 0.);
+        let anchor = Vector3::new(0., 0.25, 0.15);
+
+        LinearActuatedRigidBodyOnHingeAxis::new(
+            Mass::new::<kilogram>(250.),
+            size,
+            cg_offset,
+            control_arm,
+            anchor,
+            Angle::new::<degree>(-90.),
+            Angle::new::<degree>(90.),
+            Angle::new::<degree>(if init_up { -90. } else { 0. }),
+            150.,
+            true,
+            Vector3::new(1., 0., 0.),
+        )
+    }
+
+    /// Builds a gear door body. Position 0 is door closed, position 1 is door open.
+    fn a320_gear_door_body(wheel_id: GearWheel) -> LinearActuatedRigidBodyOnHingeAxis {
+        let side = if wheel_id == GearWheel::LEFT { -1. } else { 1. };
+
+        let (mass, size, control_arm, anchor) = if wheel_id == GearWheel::CENTER {
+            (
+                Mass::new::<kilogram>(40.),
+                Vector3::new(1.1, 0.02, 1.9),
+                Vector3::new(0.5, 0., 0.),
+                Vector3::new(0.12, 0.15, 0.),
+            )
+        } else {
+            (
+                Mass::new::<kilogram>(50.),
+                Vector3::new(side * 1.73, 0.02, 1.7),
+                Vector3::new(side * 0.76, 0., 0.),
+                Vector3::new(side * 0.19, 0.23, 0.),
+            )
+        };
+        let cg_offset = Vector3::new(2. / 3. * size[0], 0.1, 0.);
+
+        let min_angle = if wheel_id == GearWheel::LEFT {
+            0.
+        } else {
+            -85.
+        };
+
+        LinearActuatedRigidBodyOnHingeAxis::new(
+            mass,
+            size,
+            cg_offset,
+            control_arm,
+            anchor,
+            Angle::new::<degree>(min_angle),
+            Angle::new::<degree>(85.),
+            Angle::new::<degree>(0.),
+            150.,
+            true,
+            Vector3::new(0., 0., 1.),
+        )
+    }
+
+    fn a320_gear_assembly(
+        wheel_id: GearWheel,
+        init_up: bool,
+    ) -> HydraulicLinearActuatorAssembly<1> {
+        let gear_body = if wheel_id == GearWheel::CENTER {
+            A320GearSystemFactory::a320_nose_gear_body(init_up)
+        } else {
+            A320GearSystemFactory::a320_main_gear_body(wheel_id, init_up)
+        };
+
+        let gear_actuator = if wheel_id == GearWheel::CENTER {
+            A320GearSystemFactory::a320_nose_gear_actuator(&gear_body)
+        } else {
+            A320GearSystemFactory::a320_main_gear_actuator(&gear_body)
+        };
+
+        HydraulicLinearActuatorAssembly::new([gear_actuator], gear_body)
+    }
+
+    fn a320_gear_door_assembly(wheel_id: GearWheel) -> HydraulicLinearActuatorAssembly<1> {
+        let door_body = A320GearSystemFactory::a320_gear_door_body(wheel_id);
+        let door_actuator = A320GearSystemFactory::a320_gear_door_actuator(&door_body);
+
+        HydraulicLinearActuatorAssembly::new([door_actuator], door_body)
+    }
+}
+
+/// A gear or a gear door: a rigid body moved by its hydraulic actuator
+/// and held in position by its lock mechanism.
+struct GearSystemComponent {
+    hydraulic_assembly: HydraulicLinearActuatorAssembly<1>,
+
+    position: Ratio,
+    is_locked: bool,
+}
+impl GearSystemComponent {
+    const LOCKED_POSITION_TOLERANCE: f64 = 0.01;
+
+    fn new(hydraulic_assembly: HydraulicLinearActuatorAssembly<1>) -> Self {
+        Self {
+            position: hydraulic_assembly.position_normalized(),
+            is_locked: hydraulic_assembly.is_locked(),
+            hydraulic_assembly,
+        }
+    }
+
+    fn position(&self) -> Ratio {
+        self.position
+    }
+
+    fn is_locked(&self) -> bool {
+        self.is_locked
+    }
+
+    fn is_locked_at(&self, position: Ratio) -> bool {
+        self.is_locked
+            && (self.position - position).abs()
+                < Ratio::new::<ratio>(Self::LOCKED_POSITION_TOLERANCE)
+    }
+
+    fn actuator(&mut self) -> &mut impl Actuator {
+        self.hydraulic_assembly.actuator(0)
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        controller: &impl HydraulicAssemblyController,
+        current_pressure: Pressure,
+    ) {
+        self.hydraulic_assembly.update(
+            context,
+            std::slice::from_ref(controller),
+            [current_pressure],
+        );
+        self.is_locked = self.hydraulic_assembly.is_locked();
+        self.position = self.hydraulic_assembly.position_normalized();
+    }
+}
+
+struct GearSystemComponentController {
+    requested_mode: LinearActuatorMode,
+    requested_position: Ratio,
+    should_lock: bool,
+    requested_lock_position: Ratio,
+}
+impl GearSystemComponentController {
+    fn new() -> Self {
+        Self {
+            requested_mode: LinearActuatorMode::ClosedValves,
+            requested_position: Ratio::new::<ratio>(0.),
+            should_lock: true,
+            requested_lock_position: Ratio::new::<ratio>(0.),
+        }
+    }
+
+    fn control_position(&mut self, position: Ratio) {
+        self.requested_mode = LinearActuatorMode::PositionControl;
+        self.requested_position = position;
+    }
+
+    fn close_valves(&mut self) {
+        self.requested_mode = LinearActuatorMode::ClosedValves;
+    }
+
+    fn damp(&mut self) {
+        self.requested_mode = LinearActuatorMode::ClosedCircuitDamping;
+    }
+
+    fn lock_at(&mut self, position: Ratio) {
+        self.should_lock = true;
+        self.requested_lock_position = position;
+    }
+
+    fn release_lock(&mut self) {
+        self.should_lock = false;
+    }
+}
+impl HydraulicAssemblyController for GearSystemComponentController {
+    fn requested_mode(&self) -> LinearActuatorMode {
+        self.requested_mode
+    }
+
+    fn requested_position(&self) -> Ratio {
+        self.requested_position
+    }
+
+    fn should_lock(&self) -> bool {
+        self.should_lock
+    }
+
+    fn requested_lock_position(&self) -> Ratio {
+        self.requested_lock_position
+    }
+}
+
+/// Sequences the gear doors and gears from the gear lever position, and handles
+/// the gravity extension crank. The crank isolates the gear system from green hydraulic
+/// supply on its first turn, releases the door uplocks on its second turn and
+/// the gear uplocks on its third turn, letting the gears free fall into their downlocks.
+pub(super) struct A320GearSystemController {
+    gear_handle_position_id: VariableIdentifier,
+    gravity_extension_handle_position_id: VariableIdentifier,
+
+    is_gear_lever_down: bool,
+    gravity_extension_handle_turns: Ratio,
+
+    gear_controllers: [GearSystemComponentController; 3],
+    door_controllers: [GearSystemComponentController; 3],
+}
+impl A320GearSystemController {
+    const GEAR_DOWN_POSITION: f64 = 0.;
+    const GEAR_UP_POSITION: f64 = 1.;
+    const DOOR_CLOSED_POSITION: f64 = 0.;
+    const DOOR_OPEN_POSITION_THRESHOLD: f64 = 0.9;
+
+    const RETRACTION_POSITION_REQUEST: f64 = 1.5;
+    const EXTENSION_POSITION_REQUEST: f64 = -0.5;
+
+    const MIN_PRESSURE_TO_RELEASE_LOCKS_PSI: f64 = 1500.;
+
+    const GRAVITY_EXTENSION_TURNS_TO_ISOLATE_HYDRAULICS: f64 = 1.;
+    const GRAVITY_EXTENSION_TURNS_TO_RELEASE_DOORS: f64 = 2.;
+    const GRAVITY_EXTENSION_TURNS_TO_RELEASE_GEARS: f64 = 3.;
+
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        Self {
+            gear_handle_position_id: context.get_identifier("GEAR HANDLE POSITION".to_owned()),
+            gravity_extension_handle_position_id: context
+                .get_identifier("GRAVITYGEAR_ROTATE_PCT".to_owned()),
+
+            is_gear_lever_down: true,
+            gravity_extension_handle_turns: Ratio::new::<ratio>(0.),
+
+            gear_controllers: [
+                GearSystemComponentController::new(),
+                GearSystemComponentController::new(),
+                GearSystemComponentController::new(),
+            ],
+            door_controllers: [
+                GearSystemComponentController::new(),
+                GearSystemComponentController::new(),
+                GearSystemComponentController::new(),
+            ],
+        }
+    }
+
+    pub(super) fn update(&mut self, gear_system: &A320GearSystem, green_pressure: Pressure) {
+        if self.is_hydraulic_supply_isolated() {
+            self.update_gravity_extension(gear_system);
+        } else {
+            self.update_normal_operation(gear_system, green_pressure);
+        }
+    }
+
+    fn update_normal_operation(&mut self, gear_system: &A320GearSystem, green_pressure: Pressure) {
+        let (gear_lock_position, gear_position_request) = if self.is_gear_lever_down {
+            (
+                Ratio::new::<ratio>(Self::GEAR_DOWN_POSITION),
+                Ratio::new::<ratio>(Self::EXTENSION_POSITION_REQUEST),
+            )
+        } else {
+            (
+                Ratio::new::<ratio>(Self::GEAR_UP_POSITION),
+                Ratio::new::<ratio>(Self::RETRACTION_POSITION_REQUEST),
+            )
+        };
+
+        let can_release_locks =
+            green_pressure > Pressure::new::<psi>(Self::MIN_PRESSURE_TO_RELEASE_LOCKS_PSI);
+        let gears_locked_in_requested_position = gear_system
+            .gears
+            .iter()
+            .all(|gear| gear.is_locked_at(gear_lock_position));
+        let doors_are_open = gear_system
+            .doors
+            .iter()
+            .all(|door| door.position() > Ratio::new::<ratio>(Self::DOOR_OPEN_POSITION_THRESHOLD));
+
+        for (controller, door) in self.door_controllers.iter_mut().zip(&gear_system.doors) {
+            if gears_locked_in_requested_position || (door.is_locked() && !can_release_locks) {
+                controller.lock_at(Ratio::new::<ratio>(Self::DOOR_CLOSED_POSITION));
+
+                if door.is_locked() {
+                    controller.close_valves();
+                } else {
+                    controller
+                        .control_position(Ratio::new::<ratio>(Self::EXTENSION_POSITION_REQUEST));
+                }
+            } else {
+                controller.release_lock();
+                controller.control_position(Ratio::new::<ratio>(Self::RETRACTION_POSITION_REQUEST));
+            }
+        }
+
+        for (controller, gear) in self.gear_controllers.iter_mut().zip(&gear_system.gears) {
+            if doors_are_open && !gear.is_locked_at(gear_lock_position) {
+                if gear.is_locked() && can_release_locks {
+                    controller.release_lock();
+                } else {
+                    controller.lock_at(gear_lock_position);
+                }
+
+                controller.control_position(gear_position_request);
+            } else {
+                controller.lock_at(gear_lock_position);
+                controller.close_valves();
+            }
+        }
+    }
+
+    fn update_gravity_extension(&mut self, gear_system: &A320GearSystem) {
+        let gear_down_position = Ratio::new::<ratio>(Self::GEAR_DOWN_POSITION);
+
+        for controller in self.door_controllers.iter_mut() {
+            controller.damp();
+
+            if self.gravity_extension_handle_turns
+                >= Ratio::new::<ratio>(Self::GRAVITY_EXTENSION_TURNS_TO_RELEASE_DOORS)
+            {
+                controller.release_lock();
+            } else {
+                controller.lock_at(Ratio::new::<ratio>(Self::DOOR_CLOSED_POSITION));
+            }
+        }
+
+        for (controller, gear) in self.gear_controllers.iter_mut().zip(&gear_system.gears) {
+            controller.damp();
+
+            if self.gravity_extension_handle_turns
+                >= Ratio::new::<ratio>(Self::GRAVITY_EXTENSION_TURNS_TO_RELEASE_GEARS)
+                && gear.is_locked()
+                && !gear.is_locked_at(gear_down_position)
+            {
+                controller.release_lock();
+            } else {
+                controller.lock_at(gear_down_position);
+            }
+        }
+    }
+
+    fn is_gear_lever_down(&self) -> bool {
+        self.is_gear_lever_down
+    }
+
+    fn is_hydraulic_supply_isolated(&self) -> bool {
+        self.gravity_extension_handle_turns
+            >= Ratio::new::<ratio>(Self::GRAVITY_EXTENSION_TURNS_TO_ISOLATE_HYDRAULICS)
+    }
+
+    fn gear_controller(&self, wheel_id: GearWheel) -> &GearSystemComponentController {
+        &self.gear_controllers[wheel_id as usize]
+    }
+
+    fn door_controller(&self, wheel_id: GearWheel) -> &GearSystemComponentController {
+        &self.door_controllers[wheel_id as usize]
+    }
+}
+impl SimulationElement for A320GearSystemController {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_gear_lever_down = reader.read(&self.gear_handle_position_id);
+        self.gravity_extension_handle_turns =
+            reader.read(&self.gravity_extension_handle_position_id);
+    }
+}
+
+/// The A320 nose and main gears with their doors, each moved by a green hydraulic actuator.
+/// Gears and doors arrays are indexed by their `GearWheel` identifier.
+pub(super) struct A320GearSystem {
+    gear_position_ids: [VariableIdentifier; 3],
+    door_position_ids: [VariableIdentifier; 3],
+
+    gears: [GearSystemComponent; 3],
+    doors: [GearSystemComponent; 3],
+
+    is_initialised: bool,
+}
+impl A320GearSystem {
+    const WHEEL_IDS: [GearWheel; 3] = [GearWheel::CENTER, GearWheel::LEFT, GearWheel::RIGHT];
+    const WHEEL_NAMES: [&'static str; 3] = ["CENTER", "LEFT", "RIGHT"];
+
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        Self {
+            gear_position_ids: Self::WHEEL_NAMES
+                .map(|name| context.get_identifier(format!("GEAR_{}_POSITION", name))),
+            door_position_ids: Self::WHEEL_NAMES
+                .map(|name| context.get_identifier(format!("GEAR_DOOR_{}_POSITION", name))),
+
+            gears: Self::WHEEL_IDS.map(|wheel_id| {
+                GearSystemComponent::new(A320GearSystemFactory::a320_gear_assembly(wheel_id, false))
+            }),
+            doors: Self::WHEEL_IDS.map(|wheel_id| {
+                GearSystemComponent::new(A320GearSystemFactory::a320_gear_door_assembly(wheel_id))
+            }),
+
+            is_initialised: false,
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        controller: &A320GearSystemController,
+        green_pressure: Pressure,
+    ) {
+        if !self.is_initialised {
+            // When the simulation starts in flight with the gear lever up, the gears start retracted.
+            if context.is_in_flight() && !controller.is_gear_lever_down() {
+                self.set_gears_up_and_locked();
+            }
+            self.is_initialised = true;
+        }
+
+        let supplied_pressure = if controller.is_hydraulic_supply_isolated() {
+            Pressure::new::<psi>(0.)
+        } else {
+            green_pressure
+        };
+
+        for wheel_id in Self::WHEEL_IDS {
+            self.doors[wheel_id as usize].update(
+                context,
+                controller.door_controller(wheel_id),
+                supplied_pressure,
+            );
+            self.gears[wheel_id as usize].update(
+                context,
+                controller.gear_controller(wheel_id),
+                supplied_pressure,
+            );
+        }
+    }
+
+    pub(super) fn gear_actuator(&mut self, wheel_id: GearWheel) -> &mut impl Actuator {
+        self.gears[wheel_id as usize].actuator()
+    }
+
+    pub(super) fn door_actuator(&mut self, wheel_id: GearWheel) -> &mut impl Actuator {
+        self.doors[wheel_id as usize].actuator()
+    }
+
+    /// Extension of the gear as expected by the simulator: 0 when up, 1 when down.
+    fn gear_extension(&self, wheel_id: GearWheel) -> Ratio {
+        Ratio::new::<ratio>(1.) - self.gears[wheel_id as usize].position()
+    }
+
+    fn door_position(&self, wheel_id: GearWheel) -> Ratio {
+        self.doors[wheel_id as usize].position()
+    }
+
+    fn set_gears_up_and_locked(&mut self) {
+        self.gears = Self::WHEEL_IDS.map(|wheel_id| {
+            GearSystemComponent::new(A320GearSystemFactory::a320_gear_assembly(wheel_id, true))
+        });
+    }
+}
+impl GearSystemSensors for A320GearSystem {
+    fn is_wheel_id_up_and_locked(&self, wheel_id: GearWheel) -> bool {
+        self.gears[wheel_id as usize].is_locked_at(Ratio::new::<ratio>(
+            A320GearSystemController::GEAR_UP_POSITION,
+        ))
+    }
+
+    fn is_wheel_id_down_and_locked(&self, wheel_id: GearWheel) -> bool {
+        self.gears[wheel_id as usize].is_locked_at(Ratio::new::<ratio>(
+            A320GearSystemController::GEAR_DOWN_POSITION,
+        ))
+    }
+}
+impl SimulationElement for A320GearSystem {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        for wheel_id in Self::WHEEL_IDS {
+            writer.write(
+                &self.gear_position_ids[wheel_id as usize],
+                self.gear_extension(wheel_id),
+            );
+            writer.write(
+                &self.door_position_ids[wheel_id as usize],
+                self.door_position(wheel_id),
+            );
+        }
+    }
+}
@@ -7,22 +7,30 @@ use uom::si::{
     angular_velocity::{radian_per_second, revolution_per_minute},
     electric_current::ampere,
     f64::*,
+    force::newton,
     length::meter,
     mass::kilogram,
+    power::watt,
     pressure::psi,
     ratio::{percent, ratio},
-    velocity::knot,
-    volume::{cubic_inch, gallon, liter},
+    thermodynamic_temperature::degree_celsius,
+    torque::newton_meter,
+    velocity::{knot, meter_per_second},
+    volume::{cubic_inch, gallon},
     volume_rate::gallon_per_second,
 };
 
 use systems::{
+    electrical::ComputerPowerSupply,
     engine::Engine,
+    failures::FailureType,
     hydraulic::{
+        anti_skid::{AntiSkidController, BrakedWheel, RunwayCondition},
         brake_circuit::{
             AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel, BrakeCircuit,
             BrakeCircuitController,
         },
+        brake_temperature::WheelBrakeTemperature,
         electrical_generator::{GeneratorControlUnit, HydraulicGeneratorMotor},
         flap_slat::FlapSlatAssembly,
         linear_actuator::{
@@ -34,19 +42,20 @@ use systems::{
             Pushback, SteeringActuator, SteeringAngleLimiter, SteeringController,
             SteeringRatioToAngle,
         },
-        ElectricPump, EngineDrivenPump, HydraulicCircuit, HydraulicCircuitController,
-        PowerTransferUnit, PowerTransferUnitController, PressureSwitch, PressureSwitchType,
-        PumpController, RamAirTurbine, RamAirTurbineController, Reservoir, SectionPressure,
+        ElectricPump, EngineDrivenPump, HandPump, HandPumpBranch, HydraulicCircuit,
+        HydraulicCircuitController, PowerTransferUnit, PowerTransferUnitController, PumpController,
+        RamAirTurbine, RamAirTurbineController, Reservoir, SectionPressure,
     },
+    landing_gear::{GearSystemSensors, GearWheel},
     overhead::{
         AutoOffFaultPushButton, AutoOnFaultPushButton, MomentaryOnPushButton, MomentaryPushButton,
     },
     shared::{
         interpolation, random_from_range,
         update_iterator::{FixedStepLoop, MaxStepLoop},
-        DelayedFalseLogicGate, DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType,
-        ElectricalBuses, EmergencyElectricalRatPushButton, EmergencyElectricalState,
-        EmergencyGeneratorPower, EngineFirePushButtons, HydraulicColor,
+        CargoDoorLocked, DelayedFalseLogicGate, DelayedPulseTrueLogicGate, DelayedTrueLogicGate,
+        ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
+        EmergencyElectricalState, EmergencyGeneratorPower, EngineFirePushButtons, HydraulicColor,
         HydraulicGeneratorControlUnit, LgciuSensors, ReservoirAirPressure,
     },
     simulation::{
@@ -58,131 +67,23 @@ use systems::{
 #[cfg(test)]
 use systems::hydraulic::PressureSwitchState;
 
+mod config;
+pub use config::A320HydraulicConfig;
+
 mod flaps_computer;
 use flaps_computer::SlatFlapComplex;
 
-struct A320HydraulicReservoirFactory {}
-impl A320HydraulicReservoirFactory {
-    fn new_green_reservoir(context: &mut InitContext) -> Reservoir {
-        Reservoir::new(
-            context,
-            HydraulicColor::Green,
-            Volume::new::<liter>(23.),
-            Volume::new::<liter>(18.),
-            Volume::new::<gallon>(3.6),
-            vec![PressureSwitch::new(
-                Pressure::new::<psi>(25.),
-                Pressure::new::<psi>(22.),
-                PressureSwitchType::Relative,
-            )],
-            Volume::new::<liter>(3.),
-        )
-    }
-
-    fn new_blue_reservoir(context: &mut InitContext) -> Reservoir {
-        Reservoir::new(
-            context,
-            HydraulicColor::Blue,
-            Volume::new::<liter>(10.),
-            Volume::new::<liter>(8.),
-            Volume::new::<gallon>(1.56),
-            vec![
-                PressureSwitch::new(
-                    Pressure::new::<psi>(25.),
-                    Pressure::new::<psi>(22.),
-                    PressureSwitchType::Relative,
-                ),
-                PressureSwitch::new(
-                    Pressure::new::<psi>(48.),
-                    Pressure::new::<psi>(45.),
-                    PressureSwitchType::Absolute,
-                ),
-            ],
-            Volume::new::<liter>(2.),
-        )
-    }
+mod gear_system;
+use gear_system::{A320GearSystem, A320GearSystemController};
 
-    fn new_yellow_reservoir(context: &mut InitContext) -> Reservoir {
-        Reservoir::new(
-            context,
-            HydraulicColor::Yellow,
-            Volume::new::<liter>(20.),
-            Volume::new::<liter>(18.),
-            Volume::new::<gallon>(3.6),
-            vec![PressureSwitch::new(
-                Pressure::new::<psi>(25.),
-                Pressure::new::<psi>(22.),
-                PressureSwitchType::Relative,
-            )],
-            Volume::new::<liter>(3.),
-        )
-    }
-}
+mod spoilers;
+use spoilers::{A320SpoilerController, A320SpoilerSystem, SpoilerSide};
 
 struct A320HydraulicCircuitFactory {}
 impl A320HydraulicCircuitFactory {
-    const MIN_PRESS_EDP_SECTION_LO_HYST: f64 = 1740.0;
-    const MIN_PRESS_EDP_SECTION_HI_HYST: f64 = 2200.0;
-    const MIN_PRESS_PRESSURISED_LO_HYST: f64 = 1450.0;
-    const MIN_PRESS_PRESSURISED_HI_HYST: f64 = 1750.0;
-
     const GREEN_ENGINE_PUMP_INDEX: usize = 0;
     const YELLOW_ENGINE_PUMP_INDEX: usize = 0;
     const BLUE_ELECTRIC_PUMP_INDEX: usize = 0;
-
-    fn new_green_circuit(context: &mut InitContext) -> HydraulicCircuit {
-        let reservoir = A320HydraulicReservoirFactory::new_green_reservoir(context);
-        HydraulicCircuit::new(
-            context,
-            HydraulicColor::Green,
-            1,
-            Ratio::new::<percent>(100.),
-            Volume::new::<gallon>(10.),
-            reservoir,
-            Pressure::new::<psi>(Self::MIN_PRESS_PRESSURISED_LO_HYST),
-            Pressure::new::<psi>(Self::MIN_PRESS_PRESSURISED_HI_HYST),
-            Pressure::new::<psi>(Self::MIN_PRESS_EDP_SECTION_LO_HYST),
-            Pressure::new::<psi>(Self::MIN_PRESS_EDP_SECTION_HI_HYST),
-            true,
-            false,
-        )
-    }
-
-    fn new_blue_circuit(context: &mut InitContext) -> HydraulicCircuit {
-        let reservoir = A320HydraulicReservoirFactory::new_blue_reservoir(context);
-        HydraulicCircuit::new(
-            context,
-            HydraulicColor::Blue,
-            1,
-            Ratio::new::<percent>(100.),
-            Volume::new::<gallon>(8.),
-            reservoir,
-            Pressure::new::<psi>(Self::MIN_PRESS_PRESSURISED_LO_HYST),
-            Pressure::new::<psi>(Self::MIN_PRESS_PRESSURISED_HI_HYST),
-            Pressure::new::<psi>(Self::MIN_PRESS_EDP_SECTION_LO_HYST),
-            Pressure::new::<psi>(Self::MIN_PRESS_EDP_SECTION_HI_HYST),
-            false,
-            false,
-        )
-    }
-
-    fn new_yellow_circuit(context: &mut InitContext) -> HydraulicCircuit {
-        let reservoir = A320HydraulicReservoirFactory::new_yellow_reservoir(context);
-        HydraulicCircuit::new(
-            context,
-            HydraulicColor::Yellow,
-            1,
-            Ratio::new::<percent>(100.),
-            Volume::new::<gallon>(10.),
-            reservoir,
-            Pressure::new::<psi>(Self::MIN_PRESS_PRESSURISED_LO_HYST),
-            Pressure::new::<psi>(Self::MIN_PRESS_PRESSURISED_HI_HYST),
-            Pressure::new::<psi>(Self::MIN_PRESS_EDP_SECTION_LO_HYST),
-            Pressure::new::<psi>(Self::MIN_PRESS_EDP_SECTION_HI_HYST),
-            false,
-            true,
-        )
-    }
 }
 
 struct A320CargoDoorFactory {}
@@ -326,6 +227,168 @@ impl A320AileronFactory {
     }
 }
 
+struct A320ElevatorFactory {}
+impl A320ElevatorFactory {
+    const FLOW_CONTROL_PROPORTIONAL_GAIN: f64 = 1.5;
+    const FLOW_CONTROL_INTEGRAL_GAIN: f64 = 3.;
+    const FLOW_CONTROL_FORCE_GAIN: f64 = 200000.;
+
+    const MAX_DAMPING_CONSTANT_FOR_SLOW_DAMPING: f64 = 1000000.;
+
+    fn a320_elevator_actuator(bounded_linear_length: &impl BoundedLinearLength) -> LinearActuator {
+        let randomized_damping = random_from_range(
+            Self::MAX_DAMPING_CONSTANT_FOR_SLOW_DAMPING / 10.,
+            Self::MAX_DAMPING_CONSTANT_FOR_SLOW_DAMPING,
+        );
+
+        LinearActuator::new(
+            bounded_linear_length,
+            1,
+            Length::new::<meter>(0.0407),
+            Length::new::<meter>(0.),
+            VolumeRate::new::<gallon_per_second>(0.029),
+            80000.,
+            1500.,
+            5000.,
+            randomized_damping,
+            Duration::from_millis(300),
+            [1., 1., 1., 1., 1., 1.],
+            [0., 0.2, 0.21, 0.79, 0.8, 1.],
+            Self::FLOW_CONTROL_PROPORTIONAL_GAIN,
+            Self::FLOW_CONTROL_INTEGRAL_GAIN,
+            Self::FLOW_CONTROL_FORCE_GAIN,
+        )
+    }
+
+    /// Builds an elevator control surface body for A320 Neo
+    /// Travel is 17° down and 30° up, 0 position being full down
+    fn a320_elevator_body() -> LinearActuatedRigidBodyOnHingeAxis {
+        let size = Vector3::new(6., 0.405, 1.125);
+        let cg_offset = Vector3::new(0., 0., -0.5 * size[2]);
+
+        let control_arm = Vector3::new(0., -0.091, 0.);
+        let anchor = Vector3::new(0., -0.091, 0.41);
+
+        LinearActuatedRigidBodyOnHingeAxis::new(
+            Mass::new::<kilogram>(58.6),
+            size,
+            cg_offset,
+            control_arm,
+            anchor,
+            Angle::new::<degree>(-17.),
+            Angle::new::<degree>(47.),
+            Angle::new::<degree>(-17.),
+            1.,
+            false,
+            Vector3::new(1., 0., 0.),
+        )
+    }
+
+    /// Builds an elevator assembly consisting of the elevator physical rigid body and two hydraulic actuators connected
+    /// to it
+    fn a320_elevator_assembly() -> HydraulicLinearActuatorAssembly<2> {
+        let elevator_body = A320ElevatorFactory::a320_elevator_body();
+
+        let elevator_actuator_outboard =
+            A320ElevatorFactory::a320_elevator_actuator(&elevator_body);
+        let elevator_actuator_inboard = A320ElevatorFactory::a320_elevator_actuator(&elevator_body);
+
+        HydraulicLinearActuatorAssembly::new(
+            [elevator_actuator_outboard, elevator_actuator_inboard],
+            elevator_body,
+        )
+    }
+
+    fn new_elevator(context: &mut InitContext, id: ElevatorSide) -> ElevatorAssembly {
+        let assembly = A320ElevatorFactory::a320_elevator_assembly();
+        ElevatorAssembly::new(context, id, assembly)
+    }
+}
+
+struct A320RudderFactory {}
+impl A320RudderFactory {
+    const FLOW_CONTROL_PROPORTIONAL_GAIN: f64 = 1.5;
+    const FLOW_CONTROL_INTEGRAL_GAIN: f64 = 2.;
+    // Three actuators are controlling the rudder in parallel, each one of them only provides a third
+    // of the required force
+    const FLOW_CONTROL_FORCE_GAIN: f64 = 120000.;
+
+    const MAX_DAMPING_CONSTANT_FOR_SLOW_DAMPING: f64 = 300000.;
+
+    fn a320_rudder_actuator(bounded_linear_length: &impl BoundedLinearLength) -> LinearActuator {
+        let randomized_damping = random_from_range(
+            Self::MAX_DAMPING_CONSTANT_FOR_SLOW_DAMPING / 5.,
+            Self::MAX_DAMPING_CONSTANT_FOR_SLOW_DAMPING,
+        );
+
+        LinearActuator::new(
+            bounded_linear_length,
+            1,
+            Length::new::<meter>(0.06),
+            Length::new::<meter>(0.),
+            VolumeRate::new::<gallon_per_second>(0.0495),
+            80000.,
+            1500.,
+            5000.,
+            randomized_damping,
+            Duration::from_millis(300),
+            [1., 1., 1., 1., 1., 1.],
+            [0., 0.2, 0.21, 0.79, 0.8, 1.],
+            Self::FLOW_CONTROL_PROPORTIONAL_GAIN,
+            Self::FLOW_CONTROL_INTEGRAL_GAIN,
+            Self::FLOW_CONTROL_FORCE_GAIN,
+        )
+    }
+
+    /// Builds a rudder control surface body for A320 Neo
+    /// Rudder hinge axis is vertical, so gravity has no effect on its position on a level aircraft
+    fn a320_rudder_body() -> LinearActuatedRigidBodyOnHingeAxis {
+        let size = Vector3::new(0.35, 5.5, 1.8);
+        let cg_offset = Vector3::new(0., 0., -0.5 * size[2]);
+
+        let control_arm = Vector3::new(-0.144, 0., 0.);
+        let anchor = Vector3::new(-0.144, 0., 0.5);
+
+        LinearActuatedRigidBodyOnHingeAxis::new(
+            Mass::new::<kilogram>(95.),
+            size,
+            cg_offset,
+            control_arm,
+            anchor,
+            Angle::new::<degree>(-25.),
+            Angle::new::<degree>(50.),
+            Angle::new::<degree>(0.),
+            100.,
+            false,
+            Vector3::new(0., 1., 0.),
+        )
+    }
+
+    /// Builds a rudder assembly consisting of the rudder physical rigid body and three hydraulic actuators connected
+    /// to it
+    fn a320_rudder_assembly() -> HydraulicLinearActuatorAssembly<3> {
+        let rudder_body = A320RudderFactory::a320_rudder_body();
+
+        let rudder_actuator_green = A320RudderFactory::a320_rudder_actuator(&rudder_body);
+        let rudder_actuator_blue = A320RudderFactory::a320_rudder_actuator(&rudder_body);
+        let rudder_actuator_yellow = A320RudderFactory::a320_rudder_actuator(&rudder_body);
+
+        HydraulicLinearActuatorAssembly::new(
+            [
+                rudder_actuator_green,
+                rudder_actuator_blue,
+                rudder_actuator_yellow,
+            ],
+            rudder_body,
+        )
+    }
+
+    fn new_rudder(context: &mut InitContext) -> RudderAssembly {
+        let assembly = A320RudderFactory::a320_rudder_assembly();
+        RudderAssembly::new(context, assembly)
+    }
+}
+
 pub(super) struct A320Hydraulic {
     hyd_ptu_ecam_memo_id: VariableIdentifier,
     ptu_high_pitch_sound_id: VariableIdentifier,
@@ -357,6 +420,9 @@ pub(super) struct A320Hydraulic {
     yellow_electric_pump: ElectricPump,
     yellow_electric_pump_controller: A320YellowElectricPumpController,
 
+    yellow_hand_pump: HandPump,
+    yellow_hand_pump_branch: HandPumpBranch,
+
     pushback_tug: PushbackTug,
 
     ram_air_turbine: RamAirTurbine,
@@ -368,6 +434,7 @@ pub(super) struct A320Hydraulic {
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
     braking_force: A320BrakingForce,
+    brake_temperatures: A320BrakeTemperatures,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -380,10 +447,22 @@ pub(super) struct A320Hydraulic {
     forward_cargo_door_controller: A320DoorController,
     aft_cargo_door: CargoDoor,
     aft_cargo_door_controller: A320DoorController,
+    bulk_cargo_door: BulkCargoDoor,
+
+    gear_system: A320GearSystem,
+    gear_system_controller: A320GearSystemController,
+
+    spoiler_system: A320SpoilerSystem,
+    spoiler_controller: A320SpoilerController,
 
     elac_computer: ElacComputer,
     left_aileron: AileronAssembly,
     right_aileron: AileronAssembly,
+    left_elevator: ElevatorAssembly,
+    right_elevator: ElevatorAssembly,
+
+    rudder_control: RudderMechanicalControl,
+    rudder: RudderAssembly,
 }
 impl A320Hydraulic {
     const FLAP_FFPU_TO_SURFACE_ANGLE_BREAKPTS: [f64; 12] = [
@@ -404,6 +483,8 @@ impl A320Hydraulic {
     const AFT_CARGO_DOOR_ID: &'static str = "AFT";
 
     const ELECTRIC_PUMP_MAX_CURRENT_AMPERE: f64 = 45.;
+    const YELLOW_HAND_PUMP_DISPLACEMENT_PER_STROKE_CUBIC_INCH: f64 = 2.;
+    const YELLOW_HAND_PUMP_BRANCH_VOLUME_GALLON: f64 = 0.5;
     const BLUE_ELEC_PUMP_CONTROL_POWER_BUS: ElectricalBusType =
         ElectricalBusType::DirectCurrentEssential;
     const BLUE_ELEC_PUMP_SUPPLY_POWER_BUS: ElectricalBusType =
@@ -438,7 +519,7 @@ impl A320Hydraulic {
     const HYDRAULIC_SIM_FLIGHT_CONTROLS_MAX_TIME_STEP_MILLISECONDS: Duration =
         Duration::from_millis(10);
 
-    pub(super) fn new(context: &mut InitContext) -> A320Hydraulic {
+    pub(super) fn new(context: &mut InitContext, config: &A320HydraulicConfig) -> A320Hydraulic {
         A320Hydraulic {
             hyd_ptu_ecam_memo_id: context.get_identifier("HYD_PTU_ON_ECAM_MEMO".to_owned()),
             ptu_high_pitch_sound_id: context.get_identifier("HYD_PTU_HIGH_PITCH_SOUND".to_owned()),
@@ -449,6 +530,8 @@ impl A320Hydraulic {
                 AngularVelocity::new::<radian_per_second>(0.35),
                 Length::new::<meter>(0.075),
                 Ratio::new::<ratio>(0.18),
+                Length::new::<meter>(0.1),
+                AngularVelocity::new::<radian_per_second>(0.3),
             ),
 
             core_hydraulic_updater: FixedStepLoop::new(Self::HYDRAULIC_SIM_TIME_STEP),
@@ -459,11 +542,23 @@ impl A320Hydraulic {
 
             brake_steer_computer: A320HydraulicBrakeSteerComputerUnit::new(context),
 
-            blue_circuit: A320HydraulicCircuitFactory::new_blue_circuit(context),
+            blue_circuit: HydraulicCircuit::from_config(
+                context,
+                HydraulicColor::Blue,
+                &config.blue,
+            ),
             blue_circuit_controller: A320HydraulicCircuitController::new(None),
-            green_circuit: A320HydraulicCircuitFactory::new_green_circuit(context),
+            green_circuit: HydraulicCircuit::from_config(
+                context,
+                HydraulicColor::Green,
+                &config.green,
+            ),
             green_circuit_controller: A320HydraulicCircuitController::new(Some(1)),
-            yellow_circuit: A320HydraulicCircuitFactory::new_yellow_circuit(context),
+            yellow_circuit: HydraulicCircuit::from_config(
+                context,
+                HydraulicColor::Yellow,
+                &config.yellow,
+            ),
             yellow_circuit_controller: A320HydraulicCircuitController::new(Some(2)),
 
             engine_driven_pump_1: EngineDrivenPump::new(context, "GREEN"),
@@ -506,6 +601,17 @@ impl A320Hydraulic {
                 Self::YELLOW_ELEC_PUMP_CONTROL_FROM_CARGO_DOOR_OPERATION_POWER_BUS,
             ),
 
+            yellow_hand_pump: HandPump::new(
+                context,
+                "YELLOW",
+                Volume::new::<cubic_inch>(
+                    Self::YELLOW_HAND_PUMP_DISPLACEMENT_PER_STROKE_CUBIC_INCH,
+                ),
+            ),
+            yellow_hand_pump_branch: HandPumpBranch::new(Volume::new::<gallon>(
+                Self::YELLOW_HAND_PUMP_BRANCH_VOLUME_GALLON,
+            )),
+
             pushback_tug: PushbackTug::new(context),
 
             ram_air_turbine: RamAirTurbine::new(context),
@@ -540,10 +646,13 @@ impl A320Hydraulic {
             ),
 
             braking_force: A320BrakingForce::new(context),
+            brake_temperatures: A320BrakeTemperatures::new(context),
 
             flap_system: FlapSlatAssembly::new(
                 context,
                 "FLAPS",
+                FailureType::FlapTransmission(1),
+                FailureType::FlapTransmission(2),
                 Volume::new::<cubic_inch>(0.32),
                 AngularVelocity::new::<radian_per_second>(0.13),
                 Angle::new::<degree>(251.97),
@@ -556,6 +665,8 @@ impl A320Hydraulic {
             slat_system: FlapSlatAssembly::new(
                 context,
                 "SLATS",
+                FailureType::SlatTransmission(1),
+                FailureType::SlatTransmission(2),
                 Volume::new::<cubic_inch>(0.32),
                 AngularVelocity::new::<radian_per_second>(0.09),
                 Angle::new::<degree>(170.07975),
@@ -590,10 +701,22 @@ impl A320Hydraulic {
                 Self::AFT_CARGO_DOOR_ID,
             ),
             aft_cargo_door_controller: A320DoorController::new(context, Self::AFT_CARGO_DOOR_ID),
+            bulk_cargo_door: BulkCargoDoor::new(context),
+
+            gear_system: A320GearSystem::new(context),
+            gear_system_controller: A320GearSystemController::new(context),
+
+            spoiler_system: A320SpoilerSystem::new(context),
+            spoiler_controller: A320SpoilerController::new(context),
 
             elac_computer: ElacComputer::new(context),
             left_aileron: A320AileronFactory::new_aileron(context, AileronSide::Left),
             right_aileron: A320AileronFactory::new_aileron(context, AileronSide::Right),
+            left_elevator: A320ElevatorFactory::new_elevator(context, ElevatorSide::Left),
+            right_elevator: A320ElevatorFactory::new_elevator(context, ElevatorSide::Right),
+
+            rudder_control: RudderMechanicalControl::new(context),
+            rudder: A320RudderFactory::new_rudder(context),
         }
     }
 
@@ -658,6 +781,7 @@ impl A320Hydraulic {
         self.power_transfer_unit_controller
             .has_air_pressure_low_fault()
             || self.power_transfer_unit_controller.has_low_level_fault()
+            || self.power_transfer_unit_controller.has_overheat_fault()
     }
 
     fn green_edp_has_fault(&self) -> bool {
@@ -667,6 +791,7 @@ impl A320Hydraulic {
                 .engine_driven_pump_1_controller
                 .has_air_pressure_low_fault()
             || self.engine_driven_pump_1_controller.has_low_level_fault()
+            || self.engine_driven_pump_1_controller.has_overheat_fault()
     }
 
     fn yellow_epump_has_fault(&self) -> bool {
@@ -676,6 +801,7 @@ impl A320Hydraulic {
                 .yellow_electric_pump_controller
                 .has_air_pressure_low_fault()
             || self.yellow_electric_pump_controller.has_low_level_fault()
+            || self.yellow_electric_pump_controller.has_overheat_fault()
     }
 
     fn yellow_edp_has_fault(&self) -> bool {
@@ -685,6 +811,7 @@ impl A320Hydraulic {
                 .engine_driven_pump_2_controller
                 .has_air_pressure_low_fault()
             || self.engine_driven_pump_2_controller.has_low_level_fault()
+            || self.engine_driven_pump_2_controller.has_overheat_fault()
     }
 
     fn blue_epump_has_fault(&self) -> bool {
@@ -693,6 +820,7 @@ impl A320Hydraulic {
                 .blue_electric_pump_controller
                 .has_air_pressure_low_fault()
             || self.blue_electric_pump_controller.has_low_level_fault()
+            || self.blue_electric_pump_controller.has_overheat_fault()
     }
 
     pub fn green_reservoir(&self) -> &Reservoir {
@@ -736,16 +864,46 @@ impl A320Hydraulic {
     fn update_flight_controls_physics(&mut self, context: &UpdateContext) {
         self.left_aileron.update(
             context,
-            self.elac_computer.left_controllers(),
+            self.elac_computer.left_aileron_controllers(),
             self.blue_circuit.system_pressure(),
             self.green_circuit.system_pressure(),
         );
 
         self.right_aileron.update(
             context,
-            self.elac_computer.right_controllers(),
+            self.elac_computer.right_aileron_controllers(),
+            self.blue_circuit.system_pressure(),
+            self.green_circuit.system_pressure(),
+        );
+
+        self.left_elevator.update(
+            context,
+            self.elac_computer.left_elevator_controllers(),
+            self.blue_circuit.system_pressure(),
+            self.green_circuit.system_pressure(),
+        );
+
+        self.right_elevator.update(
+            context,
+            self.elac_computer.right_elevator_controllers(),
+            self.blue_circuit.system_pressure(),
+            self.yellow_circuit.system_pressure(),
+        );
+
+        self.rudder.update(
+            context,
+            self.rudder_control.controllers(),
+            self.green_circuit.system_pressure(),
             self.blue_circuit.system_pressure(),
+            self.yellow_circuit.system_pressure(),
+        );
+
+        self.spoiler_system.update(
+            context,
+            &self.spoiler_controller,
             self.green_circuit.system_pressure(),
+            self.yellow_circuit.system_pressure(),
+            self.blue_circuit.system_pressure(),
         );
     }
 
@@ -760,13 +918,19 @@ impl A320Hydraulic {
         self.forward_cargo_door.update(
             context,
             &self.forward_cargo_door_controller,
-            self.yellow_circuit.system_pressure(),
+            self.yellow_hand_pump_branch.pressure(),
         );
 
         self.aft_cargo_door.update(
             context,
             &self.aft_cargo_door_controller,
-            self.yellow_circuit.system_pressure(),
+            self.yellow_hand_pump_branch.pressure(),
+        );
+
+        self.gear_system.update(
+            context,
+            &self.gear_system_controller,
+            self.green_circuit.system_pressure(),
         );
 
         self.ram_air_turbine.update_physics(
@@ -804,6 +968,8 @@ impl A320Hydraulic {
         engine1: &impl Engine,
         engine2: &impl Engine,
     ) {
+        self.bulk_cargo_door.update(context);
+
         self.nose_steering.update(
             context,
             self.yellow_circuit.system_pressure(),
@@ -811,6 +977,15 @@ impl A320Hydraulic {
             &self.pushback_tug,
         );
 
+        self.spoiler_controller.update(
+            context,
+            lgciu1,
+            lgciu2,
+            self.green_circuit.system_pressure(),
+            self.yellow_circuit.system_pressure(),
+            self.blue_circuit.system_pressure(),
+        );
+
         // Process brake logic (which circuit brakes) and send brake demands (how much)
         self.brake_steer_computer.update(
             context,
@@ -819,6 +994,10 @@ impl A320Hydraulic {
             lgciu1,
             lgciu2,
             autobrake_panel,
+            self.spoiler_system
+                .ground_spoilers_are_deployed(&self.spoiler_controller),
+            self.braking_force.left_wheels_slip_ratio(),
+            self.braking_force.right_wheels_slip_ratio(),
             engine1,
             engine2,
         );
@@ -839,8 +1018,14 @@ impl A320Hydraulic {
             context,
             &self.braking_circuit_norm,
             &self.braking_circuit_altn,
+            &self.brake_temperatures,
+            lgciu1,
+            lgciu2,
         );
 
+        self.brake_temperatures
+            .update(context, &self.braking_force, lgciu1, &self.gear_system);
+
         self.slats_flaps_complex
             .update(context, &self.flap_system, &self.slat_system);
 
@@ -848,6 +1033,7 @@ impl A320Hydraulic {
             context,
             self.slats_flaps_complex.flap_demand(),
             self.slats_flaps_complex.flap_demand(),
+            self.slats_flaps_complex.flap_wing_tip_brakes_engaged(),
             self.green_circuit.system_pressure(),
             self.yellow_circuit.system_pressure(),
         );
@@ -856,6 +1042,7 @@ impl A320Hydraulic {
             context,
             self.slats_flaps_complex.slat_demand(),
             self.slats_flaps_complex.slat_demand(),
+            self.slats_flaps_complex.slat_wing_tip_brakes_engaged(),
             self.blue_circuit.system_pressure(),
             self.green_circuit.system_pressure(),
         );
@@ -863,18 +1050,29 @@ impl A320Hydraulic {
         self.forward_cargo_door_controller.update(
             context,
             &self.forward_cargo_door,
-            self.yellow_circuit.system_pressure(),
+            self.yellow_hand_pump_branch.pressure(),
         );
 
         self.aft_cargo_door_controller.update(
             context,
             &self.aft_cargo_door,
-            self.yellow_circuit.system_pressure(),
+            self.yellow_hand_pump_branch.pressure(),
         );
 
+        self.gear_system_controller
+            .update(&self.gear_system, self.green_circuit.system_pressure());
+
         self.elac_computer.update(
+            context,
+            self.green_circuit.system_pressure(),
             self.blue_circuit.system_pressure(),
+            self.yellow_circuit.system_pressure(),
+        );
+
+        self.rudder_control.update(
             self.green_circuit.system_pressure(),
+            self.blue_circuit.system_pressure(),
+            self.yellow_circuit.system_pressure(),
         );
         self.slats_flaps_complex
             .update(context, &self.flap_system, &self.slat_system);
@@ -896,27 +1094,62 @@ impl A320Hydraulic {
         self.green_circuit
             .update_actuator_volumes(self.right_aileron.actuator(AileronActuatorCircuit::Green));
 
+        self.green_circuit.update_actuator_volumes(
+            self.left_elevator
+                .actuator(ElevatorActuatorCircuit::GreenOrYellow),
+        );
+        self.green_circuit
+            .update_actuator_volumes(self.rudder.actuator(RudderActuatorCircuit::Green));
+
         self.green_circuit
             .update_actuator_volumes(self.flap_system.left_motor());
         self.green_circuit
             .update_actuator_volumes(self.slat_system.right_motor());
+
+        for wheel_id in [GearWheel::CENTER, GearWheel::LEFT, GearWheel::RIGHT] {
+            self.green_circuit
+                .update_actuator_volumes(self.gear_system.gear_actuator(wheel_id));
+            self.green_circuit
+                .update_actuator_volumes(self.gear_system.door_actuator(wheel_id));
+        }
+
+        for index in A320SpoilerSystem::panels_on_circuit(HydraulicColor::Green) {
+            self.green_circuit
+                .update_actuator_volumes(self.spoiler_system.actuator(SpoilerSide::Left, index));
+            self.green_circuit
+                .update_actuator_volumes(self.spoiler_system.actuator(SpoilerSide::Right, index));
+        }
     }
 
     fn update_yellow_actuators_volume(&mut self) {
-        self.yellow_circuit
+        self.yellow_hand_pump_branch
             .update_actuator_volumes(&mut self.braking_circuit_altn);
 
         self.yellow_circuit
             .update_actuator_volumes(self.flap_system.right_motor());
 
-        self.yellow_circuit
+        self.yellow_hand_pump_branch
             .update_actuator_volumes(self.forward_cargo_door.actuator());
 
-        self.yellow_circuit
+        self.yellow_hand_pump_branch
             .update_actuator_volumes(self.aft_cargo_door.actuator());
 
         self.yellow_circuit
             .update_actuator_volumes(&mut self.nose_steering);
+
+        self.yellow_circuit.update_actuator_volumes(
+            self.right_elevator
+                .actuator(ElevatorActuatorCircuit::GreenOrYellow),
+        );
+        self.yellow_circuit
+            .update_actuator_volumes(self.rudder.actuator(RudderActuatorCircuit::Yellow));
+
+        for index in A320SpoilerSystem::panels_on_circuit(HydraulicColor::Yellow) {
+            self.yellow_circuit
+                .update_actuator_volumes(self.spoiler_system.actuator(SpoilerSide::Left, index));
+            self.yellow_circuit
+                .update_actuator_volumes(self.spoiler_system.actuator(SpoilerSide::Right, index));
+        }
     }
 
     fn update_blue_actuators_volume(&mut self) {
@@ -929,6 +1162,20 @@ impl A320Hydraulic {
             .update_actuator_volumes(self.left_aileron.actuator(AileronActuatorCircuit::Blue));
         self.blue_circuit
             .update_actuator_volumes(self.right_aileron.actuator(AileronActuatorCircuit::Blue));
+
+        self.blue_circuit
+            .update_actuator_volumes(self.left_elevator.actuator(ElevatorActuatorCircuit::Blue));
+        self.blue_circuit
+            .update_actuator_volumes(self.right_elevator.actuator(ElevatorActuatorCircuit::Blue));
+        self.blue_circuit
+            .update_actuator_volumes(self.rudder.actuator(RudderActuatorCircuit::Blue));
+
+        for index in A320SpoilerSystem::panels_on_circuit(HydraulicColor::Blue) {
+            self.blue_circuit
+                .update_actuator_volumes(self.spoiler_system.actuator(SpoilerSide::Left, index));
+            self.blue_circuit
+                .update_actuator_volumes(self.spoiler_system.actuator(SpoilerSide::Right, index));
+        }
     }
 
     // All the core hydraulics updates that needs to be done at the slowest fixed step rate
@@ -1034,6 +1281,11 @@ impl A320Hydraulic {
             self.yellow_circuit.reservoir(),
             &self.yellow_electric_pump_controller,
         );
+        self.yellow_hand_pump.update(
+            context,
+            &self.yellow_hand_pump_branch,
+            self.yellow_circuit.reservoir(),
+        );
 
         self.ram_air_turbine.update(
             context,
@@ -1063,6 +1315,11 @@ impl A320Hydraulic {
             &self.yellow_circuit_controller,
             reservoir_pneumatics.yellow_reservoir_pressure(),
         );
+        self.yellow_circuit.update_hand_pump_branch(
+            context,
+            &mut self.yellow_hand_pump_branch,
+            &mut self.yellow_hand_pump,
+        );
 
         self.blue_circuit_controller
             .update(engine_fire_push_buttons);
@@ -1082,7 +1339,7 @@ impl A320Hydraulic {
         );
         self.braking_circuit_altn.update(
             context,
-            self.yellow_circuit.system_section(),
+            &self.yellow_hand_pump_branch,
             self.brake_steer_computer.alternate_controller(),
         );
     }
@@ -1153,12 +1410,17 @@ impl SimulationElement for A320Hydraulic {
 
         self.yellow_electric_pump.accept(visitor);
         self.yellow_electric_pump_controller.accept(visitor);
+        self.yellow_hand_pump.accept(visitor);
 
         self.forward_cargo_door_controller.accept(visitor);
         self.forward_cargo_door.accept(visitor);
 
         self.aft_cargo_door_controller.accept(visitor);
         self.aft_cargo_door.accept(visitor);
+        self.bulk_cargo_door.accept(visitor);
+
+        self.gear_system_controller.accept(visitor);
+        self.gear_system.accept(visitor);
 
         self.pushback_tug.accept(visitor);
 
@@ -1177,6 +1439,7 @@ impl SimulationElement for A320Hydraulic {
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
+        self.brake_temperatures.accept(visitor);
 
         self.emergency_gen.accept(visitor);
         self.nose_steering.accept(visitor);
@@ -1187,6 +1450,14 @@ impl SimulationElement for A320Hydraulic {
         self.elac_computer.accept(visitor);
         self.left_aileron.accept(visitor);
         self.right_aileron.accept(visitor);
+        self.left_elevator.accept(visitor);
+        self.right_elevator.accept(visitor);
+
+        self.rudder_control.accept(visitor);
+        self.rudder.accept(visitor);
+
+        self.spoiler_controller.accept(visitor);
+        self.spoiler_system.accept(visitor);
 
         visitor.visit(self);
     }
@@ -1203,6 +1474,22 @@ impl SimulationElement for A320Hydraulic {
         );
     }
 }
+impl GearSystemSensors for A320Hydraulic {
+    fn is_wheel_id_up_and_locked(&self, wheel_id: GearWheel) -> bool {
+        self.gear_system.is_wheel_id_up_and_locked(wheel_id)
+    }
+
+    fn is_wheel_id_down_and_locked(&self, wheel_id: GearWheel) -> bool {
+        self.gear_system.is_wheel_id_down_and_locked(wheel_id)
+    }
+}
+impl CargoDoorLocked for A320Hydraulic {
+    fn all_cargo_doors_locked(&self) -> bool {
+        self.forward_cargo_door.is_locked()
+            && self.aft_cargo_door.is_locked()
+            && self.bulk_cargo_door.is_locked()
+    }
+}
 impl HydraulicGeneratorControlUnit for A320Hydraulic {
     fn max_allowed_power(&self) -> Power {
         self.gcu.max_allowed_power()
@@ -1249,6 +1536,7 @@ struct A320EngineDrivenPumpController {
     has_pressure_low_fault: bool,
     has_air_pressure_low_fault: bool,
     has_low_level_fault: bool,
+    has_overheat_fault: bool,
     is_pressure_low: bool,
 }
 impl A320EngineDrivenPumpController {
@@ -1271,6 +1559,7 @@ impl A320EngineDrivenPumpController {
             has_pressure_low_fault: false,
             has_air_pressure_low_fault: false,
             has_low_level_fault: false,
+            has_overheat_fault: false,
 
             is_pressure_low: true,
         }
@@ -1309,6 +1598,15 @@ impl A320EngineDrivenPumpController {
             reservoir.is_low_level() && overhead_panel.edp_push_button_is_auto(self.engine_number);
     }
 
+    fn update_overheat(
+        &mut self,
+        reservoir: &Reservoir,
+        overhead_panel: &A320HydraulicOverheadPanel,
+    ) {
+        self.has_overheat_fault =
+            reservoir.is_overheat() && overhead_panel.edp_push_button_is_auto(self.engine_number);
+    }
+
     fn update<T: EngineFirePushButtons>(
         &mut self,
         overhead_panel: &A320HydraulicOverheadPanel,
@@ -1337,6 +1635,8 @@ impl A320EngineDrivenPumpController {
         self.update_low_air_pressure(reservoir, overhead_panel);
 
         self.update_low_level(reservoir, overhead_panel);
+
+        self.update_overheat(reservoir, overhead_panel);
     }
 
     fn has_pressure_low_fault(&self) -> bool {
@@ -1350,7 +1650,11 @@ impl A320EngineDrivenPumpController {
     fn has_low_level_fault(&self) -> bool {
         self.has_low_level_fault
     }
-}
+
+    fn has_overheat_fault(&self) -> bool {
+        self.has_overheat_fault
+    }
+}
 impl PumpController for A320EngineDrivenPumpController {
     fn should_pressurise(&self) -> bool {
         self.should_pressurise
@@ -1381,6 +1685,7 @@ struct A320BlueElectricPumpController {
     has_pressure_low_fault: bool,
     has_air_pressure_low_fault: bool,
     has_low_level_fault: bool,
+    has_overheat_fault: bool,
     is_pressure_low: bool,
 }
 impl A320BlueElectricPumpController {
@@ -1395,6 +1700,7 @@ impl A320BlueElectricPumpController {
             has_pressure_low_fault: false,
             has_air_pressure_low_fault: false,
             has_low_level_fault: false,
+            has_overheat_fault: false,
 
             is_pressure_low: true,
         }
@@ -1432,6 +1738,8 @@ impl A320BlueElectricPumpController {
         self.update_low_air_pressure(reservoir, overhead_panel);
 
         self.update_low_level(reservoir, overhead_panel);
+
+        self.update_overheat(reservoir, overhead_panel);
     }
 
     fn update_low_pressure(
@@ -1475,6 +1783,15 @@ impl A320BlueElectricPumpController {
             reservoir.is_low_level() && !overhead_panel.blue_epump_push_button_is_off();
     }
 
+    fn update_overheat(
+        &mut self,
+        reservoir: &Reservoir,
+        overhead_panel: &A320HydraulicOverheadPanel,
+    ) {
+        self.has_overheat_fault =
+            reservoir.is_overheat() && !overhead_panel.blue_epump_push_button_is_off();
+    }
+
     fn has_pressure_low_fault(&self) -> bool {
         self.has_pressure_low_fault
     }
@@ -1486,6 +1803,10 @@ impl A320BlueElectricPumpController {
     fn has_low_level_fault(&self) -> bool {
         self.has_low_level_fault
     }
+
+    fn has_overheat_fault(&self) -> bool {
+        self.has_overheat_fault
+    }
 }
 
 impl PumpController for A320BlueElectricPumpController {
@@ -1514,6 +1835,7 @@ struct A320YellowElectricPumpController {
     has_pressure_low_fault: bool,
     has_air_pressure_low_fault: bool,
     has_low_level_fault: bool,
+    has_overheat_fault: bool,
     is_pressure_low: bool,
     should_activate_yellow_pump_for_cargo_door_operation: DelayedFalseLogicGate,
 }
@@ -1537,6 +1859,7 @@ impl A320YellowElectricPumpController {
             has_pressure_low_fault: false,
             has_air_pressure_low_fault: false,
             has_low_level_fault: false,
+            has_overheat_fault: false,
 
             is_pressure_low: true,
             should_activate_yellow_pump_for_cargo_door_operation: DelayedFalseLogicGate::new(
@@ -1572,6 +1895,8 @@ impl A320YellowElectricPumpController {
         self.update_low_air_pressure(reservoir, overhead_panel);
 
         self.update_low_level(reservoir, overhead_panel);
+
+        self.update_overheat(reservoir, overhead_panel);
     }
 
     fn update_low_pressure(&mut self, section: &impl SectionPressure) {
@@ -1599,6 +1924,15 @@ impl A320YellowElectricPumpController {
             reservoir.is_low_level() && !overhead_panel.yellow_epump_push_button_is_auto();
     }
 
+    fn update_overheat(
+        &mut self,
+        reservoir: &Reservoir,
+        overhead_panel: &A320HydraulicOverheadPanel,
+    ) {
+        self.has_overheat_fault =
+            reservoir.is_overheat() && !overhead_panel.yellow_epump_push_button_is_auto();
+    }
+
     fn has_pressure_low_fault(&self) -> bool {
         self.has_pressure_low_fault
     }
@@ -1611,6 +1945,10 @@ impl A320YellowElectricPumpController {
         self.has_low_level_fault
     }
 
+    fn has_overheat_fault(&self) -> bool {
+        self.has_overheat_fault
+    }
+
     #[cfg(test)]
     fn should_pressurise_for_cargo_door_operation(&self) -> bool {
         self.should_activate_yellow_pump_for_cargo_door_operation
@@ -1653,6 +1991,7 @@ struct A320PowerTransferUnitController {
 
     has_air_pressure_low_fault: bool,
     has_low_level_fault: bool,
+    has_overheat_fault: bool,
 }
 impl A320PowerTransferUnitController {
     const DURATION_OF_PTU_INHIBIT_AFTER_CARGO_DOOR_OPERATION: Duration = Duration::from_secs(40);
@@ -1678,6 +2017,7 @@ impl A320PowerTransferUnitController {
 
             has_air_pressure_low_fault: false,
             has_low_level_fault: false,
+            has_overheat_fault: false,
         }
     }
 
@@ -1715,6 +2055,8 @@ impl A320PowerTransferUnitController {
         self.update_low_air_pressure(reservoir_left_side, reservoir_right_side, overhead_panel);
 
         self.update_low_level(reservoir_left_side, reservoir_right_side, overhead_panel);
+
+        self.update_overheat(reservoir_left_side, reservoir_right_side, overhead_panel);
     }
 
     fn update_low_air_pressure(
@@ -1739,6 +2081,17 @@ impl A320PowerTransferUnitController {
             && overhead_panel.ptu_push_button_is_auto();
     }
 
+    fn update_overheat(
+        &mut self,
+        reservoir_left_side: &Reservoir,
+        reservoir_right_side: &Reservoir,
+        overhead_panel: &A320HydraulicOverheadPanel,
+    ) {
+        self.has_overheat_fault = (reservoir_left_side.is_overheat()
+            || reservoir_right_side.is_overheat())
+            && overhead_panel.ptu_push_button_is_auto();
+    }
+
     fn has_air_pressure_low_fault(&self) -> bool {
         self.has_air_pressure_low_fault
     }
@@ -1746,6 +2099,10 @@ impl A320PowerTransferUnitController {
     fn has_low_level_fault(&self) -> bool {
         self.has_low_level_fault
     }
+
+    fn has_overheat_fault(&self) -> bool {
+        self.has_overheat_fault
+    }
 }
 impl PowerTransferUnitController for A320PowerTransferUnitController {
     fn should_enable(&self) -> bool {
@@ -1901,6 +2258,11 @@ struct A320HydraulicBrakeSteerComputerUnit {
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
 
+    norm_left_anti_skid: AntiSkidController,
+    norm_right_anti_skid: AntiSkidController,
+    alternate_left_anti_skid: AntiSkidController,
+    alternate_right_anti_skid: AntiSkidController,
+
     tiller_pedal_disconnect: bool,
     tiller_handle_position: Ratio,
     rudder_pedal_position: Ratio,
@@ -1947,6 +2309,15 @@ impl A320HydraulicBrakeSteerComputerUnit {
 
     const PILOT_INPUT_DETECTION_TRESHOLD: f64 = 0.2;
 
+    // Normal brakes servo valves modulate each wheel quickly around optimal slip. Alternate ones are
+    // slower, and regulate each pair of wheels with a safer margin to the friction peak
+    const NORM_ANTI_SKID_TARGET_SLIP: f64 = 0.12;
+    const NORM_ANTI_SKID_P_GAIN: f64 = 2.;
+    const NORM_ANTI_SKID_I_GAIN: f64 = 10.;
+    const ALTERNATE_ANTI_SKID_TARGET_SLIP: f64 = 0.08;
+    const ALTERNATE_ANTI_SKID_P_GAIN: f64 = 1.;
+    const ALTERNATE_ANTI_SKID_I_GAIN: f64 = 4.;
+
     fn new(context: &mut InitContext) -> Self {
         Self {
             park_brake_lever_pos_id: context.get_identifier("PARK_BRAKE_LEVER_POS".to_owned()),
@@ -1979,6 +2350,11 @@ impl A320HydraulicBrakeSteerComputerUnit {
             ),
             anti_skid_activated: true,
 
+            norm_left_anti_skid: Self::new_norm_anti_skid(),
+            norm_right_anti_skid: Self::new_norm_anti_skid(),
+            alternate_left_anti_skid: Self::new_alternate_anti_skid(),
+            alternate_right_anti_skid: Self::new_alternate_anti_skid(),
+
             tiller_pedal_disconnect: false,
             tiller_handle_position: Ratio::new::<ratio>(0.),
             rudder_pedal_position: Ratio::new::<ratio>(0.),
@@ -2008,6 +2384,22 @@ impl A320HydraulicBrakeSteerComputerUnit {
         }
     }
 
+    fn new_norm_anti_skid() -> AntiSkidController {
+        AntiSkidController::new(
+            Ratio::new::<ratio>(Self::NORM_ANTI_SKID_TARGET_SLIP),
+            Self::NORM_ANTI_SKID_P_GAIN,
+            Self::NORM_ANTI_SKID_I_GAIN,
+        )
+    }
+
+    fn new_alternate_anti_skid() -> AntiSkidController {
+        AntiSkidController::new(
+            Ratio::new::<ratio>(Self::ALTERNATE_ANTI_SKID_TARGET_SLIP),
+            Self::ALTERNATE_ANTI_SKID_P_GAIN,
+            Self::ALTERNATE_ANTI_SKID_I_GAIN,
+        )
+    }
+
     fn allow_autobrake_arming(&self) -> bool {
         self.anti_skid_activated && self.normal_brakes_available
     }
@@ -2064,6 +2456,9 @@ impl A320HydraulicBrakeSteerComputerUnit {
         lgciu1: &impl LgciuSensors,
         lgciu2: &impl LgciuSensors,
         autobrake_panel: &AutobrakePanel,
+        ground_spoilers_are_deployed: bool,
+        left_wheels_slip_ratio: Ratio,
+        right_wheels_slip_ratio: Ratio,
         engine1: &impl Engine,
         engine2: &impl Engine,
     ) {
@@ -2078,6 +2473,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
             self.allow_autobrake_arming(),
             self.left_brake_pilot_input,
             self.right_brake_pilot_input,
+            ground_spoilers_are_deployed,
             lgciu1,
             lgciu2,
         );
@@ -2091,6 +2487,20 @@ impl A320HydraulicBrakeSteerComputerUnit {
             !lgciu1.all_down_and_locked() && !self.is_gear_lever_down,
         );
 
+        let green_used_for_brakes =
+            self.normal_brakes_available && self.anti_skid_activated && !self.parking_brake_demand;
+        let alternate_anti_skid_enabled = !is_in_flight_gear_lever_up
+            && !green_used_for_brakes
+            && self.anti_skid_activated
+            && !self.parking_brake_demand;
+        self.update_anti_skid(
+            context,
+            !is_in_flight_gear_lever_up && green_used_for_brakes,
+            alternate_anti_skid_enabled,
+            left_wheels_slip_ratio,
+            right_wheels_slip_ratio,
+        );
+
         if is_in_flight_gear_lever_up {
             if self.should_disable_auto_brake_when_retracting.output() {
                 self.norm_brake_outputs.set_no_demands();
@@ -2101,50 +2511,75 @@ impl A320HydraulicBrakeSteerComputerUnit {
             }
 
             self.alternate_brake_outputs.set_no_demands();
-        } else {
-            let green_used_for_brakes = self.normal_brakes_available
-                && self.anti_skid_activated
-                && !self.parking_brake_demand;
-
-            if green_used_for_brakes {
-                // Final output on normal brakes is max(pilot demand , autobrake demand) to allow pilot override autobrake demand
-                self.norm_brake_outputs.set_brake_demands(
+        } else if green_used_for_brakes {
+            // Final output on normal brakes is max(pilot demand , autobrake demand) to allow pilot override autobrake demand
+            // Anti-skid then limits it to avoid wheels locking
+            self.norm_brake_outputs.set_brake_demands(
+                self.norm_left_anti_skid.limited_demand(
                     self.left_brake_pilot_input
                         .max(self.autobrake_controller.brake_output()),
+                ),
+                self.norm_right_anti_skid.limited_demand(
                     self.right_brake_pilot_input
                         .max(self.autobrake_controller.brake_output()),
-                );
+                ),
+            );
 
-                self.alternate_brake_outputs.set_no_demands();
+            self.alternate_brake_outputs.set_no_demands();
+        } else {
+            self.norm_brake_outputs.set_no_demands();
+
+            if !self.parking_brake_demand {
+                // Normal braking but using alternate circuit
+                self.alternate_brake_outputs.set_brake_demands(
+                    self.alternate_left_anti_skid
+                        .limited_demand(self.left_brake_pilot_input),
+                    self.alternate_right_anti_skid
+                        .limited_demand(self.right_brake_pilot_input),
+                );
             } else {
-                self.norm_brake_outputs.set_no_demands();
+                // Else we just use parking brake
+                self.alternate_brake_outputs.set_max_demands();
 
-                if !self.parking_brake_demand {
-                    // Normal braking but using alternate circuit
-                    self.alternate_brake_outputs.set_brake_demands(
+                // Special case: parking brake on but yellow can't provide enough brakes: green are allowed to brake for emergency
+                if alternate_circuit.left_brake_pressure().get::<psi>()
+                    < Self::MIN_PRESSURE_PARK_BRAKE_EMERGENCY
+                    || alternate_circuit.right_brake_pressure().get::<psi>()
+                        < Self::MIN_PRESSURE_PARK_BRAKE_EMERGENCY
+                {
+                    self.norm_brake_outputs.set_brake_demands(
                         self.left_brake_pilot_input,
                         self.right_brake_pilot_input,
                     );
-                } else {
-                    // Else we just use parking brake
-                    self.alternate_brake_outputs.set_max_demands();
-
-                    // Special case: parking brake on but yellow can't provide enough brakes: green are allowed to brake for emergency
-                    if alternate_circuit.left_brake_pressure().get::<psi>()
-                        < Self::MIN_PRESSURE_PARK_BRAKE_EMERGENCY
-                        || alternate_circuit.right_brake_pressure().get::<psi>()
-                            < Self::MIN_PRESSURE_PARK_BRAKE_EMERGENCY
-                    {
-                        self.norm_brake_outputs.set_brake_demands(
-                            self.left_brake_pilot_input,
-                            self.right_brake_pilot_input,
-                        );
-                    }
                 }
             }
         }
     }
 
+    fn update_anti_skid(
+        &mut self,
+        context: &UpdateContext,
+        norm_anti_skid_enabled: bool,
+        alternate_anti_skid_enabled: bool,
+        left_wheels_slip_ratio: Ratio,
+        right_wheels_slip_ratio: Ratio,
+    ) {
+        self.norm_left_anti_skid
+            .update(context, norm_anti_skid_enabled, left_wheels_slip_ratio);
+        self.norm_right_anti_skid
+            .update(context, norm_anti_skid_enabled, right_wheels_slip_ratio);
+        self.alternate_left_anti_skid.update(
+            context,
+            alternate_anti_skid_enabled,
+            left_wheels_slip_ratio,
+        );
+        self.alternate_right_anti_skid.update(
+            context,
+            alternate_anti_skid_enabled,
+            right_wheels_slip_ratio,
+        );
+    }
+
     fn update_steering_demands(
         &mut self,
         lgciu1: &impl LgciuSensors,
@@ -2243,11 +2678,16 @@ struct A320BrakingForce {
     brake_right_force_factor_id: VariableIdentifier,
     trailing_edge_flaps_left_percent_id: VariableIdentifier,
     trailing_edge_flaps_right_percent_id: VariableIdentifier,
+    runway_condition_id: VariableIdentifier,
 
     left_braking_force: f64,
     right_braking_force: f64,
 
     flap_position: f64,
+    runway_condition: RunwayCondition,
+
+    wheels: [BrakedWheel; 4],
+    brake_torques: [Torque; 4],
 }
 impl A320BrakingForce {
     const REFERENCE_PRESSURE_FOR_MAX_FORCE: f64 = 2538.;
@@ -2255,6 +2695,21 @@ impl A320BrakingForce {
     const FLAPS_BREAKPOINTS: [f64; 3] = [0., 50., 100.];
     const FLAPS_PENALTY_PERCENT: [f64; 3] = [5., 5., 0.];
 
+    const LEFT_WHEELS: [usize; 2] = [0, 1];
+    const RIGHT_WHEELS: [usize; 2] = [2, 3];
+
+    // Force of one brake when the sim applies a braking force factor of 1
+    const MAX_BRAKING_FORCE_PER_WHEEL_NEWTON: f64 = 85000.;
+
+    const WHEEL_RADIUS_METER: f64 = 0.585;
+    const WHEEL_MOMENT_OF_INERTIA_KG_M2: f64 = 30.;
+
+    // Share of a typical landing weight supported by each main wheel
+    const NORMAL_LOAD_PER_WHEEL_NEWTON: f64 = 150000.;
+
+    // A deflated tyre has lost most of its grip
+    const DEFLATED_TYRE_FRICTION_RATIO: f64 = 0.5;
+
     pub fn new(context: &mut InitContext) -> Self {
         A320BrakingForce {
             brake_left_force_factor_id: context
@@ -2265,19 +2720,39 @@ impl A320BrakingForce {
                 .get_identifier("LEFT_FLAPS_POSITION_PERCENT".to_owned()),
             trailing_edge_flaps_right_percent_id: context
                 .get_identifier("RIGHT_FLAPS_POSITION_PERCENT".to_owned()),
+            runway_condition_id: context.get_identifier("SURFACE CONDITION".to_owned()),
 
             left_braking_force: 0.,
             right_braking_force: 0.,
 
             flap_position: 0.,
+            runway_condition: RunwayCondition::Dry,
+
+            wheels: [
+                Self::new_wheel(),
+                Self::new_wheel(),
+                Self::new_wheel(),
+                Self::new_wheel(),
+            ],
+            brake_torques: [Torque::new::<newton_meter>(0.); 4],
         }
     }
 
+    fn new_wheel() -> BrakedWheel {
+        BrakedWheel::new(
+            Length::new::<meter>(Self::WHEEL_RADIUS_METER),
+            Self::WHEEL_MOMENT_OF_INERTIA_KG_M2,
+        )
+    }
+
     pub fn update_forces(
         &mut self,
         context: &UpdateContext,
         norm_brakes: &BrakeCircuit,
         altn_brakes: &BrakeCircuit,
+        brake_temperatures: &A320BrakeTemperatures,
+        lgciu1: &impl LgciuSensors,
+        lgciu2: &impl LgciuSensors,
     ) {
         // Base formula for output force is output_force[0:1] = 50 * sqrt(current_pressure) / Max_brake_pressure
         // This formula gives a bit more punch for lower brake pressures (like 1000 psi alternate braking), as linear formula
@@ -2287,20 +2762,111 @@ impl A320BrakingForce {
             / Self::REFERENCE_PRESSURE_FOR_MAX_FORCE;
         let left_force_altn = 50. * altn_brakes.left_brake_pressure().get::<psi>().sqrt()
             / Self::REFERENCE_PRESSURE_FOR_MAX_FORCE;
-        self.left_braking_force = left_force_norm + left_force_altn;
-        self.left_braking_force = self.left_braking_force.max(0.).min(1.);
+        let left_brake_demand = (left_force_norm + left_force_altn).max(0.).min(1.);
 
         let right_force_norm = 50. * norm_brakes.right_brake_pressure().get::<psi>().sqrt()
             / Self::REFERENCE_PRESSURE_FOR_MAX_FORCE;
         let right_force_altn = 50. * altn_brakes.right_brake_pressure().get::<psi>().sqrt()
             / Self::REFERENCE_PRESSURE_FOR_MAX_FORCE;
-        self.right_braking_force = right_force_norm + right_force_altn;
-        self.right_braking_force = self.right_braking_force.max(0.).min(1.);
+        let right_brake_demand = (right_force_norm + right_force_altn).max(0.).min(1.);
+
+        let flaps_correction = self.flaps_correction(context);
+
+        for (wheels, brake_demand, wheels_on_ground) in [
+            (
+                Self::LEFT_WHEELS,
+                left_brake_demand,
+                lgciu1.left_gear_compressed(false),
+            ),
+            (
+                Self::RIGHT_WHEELS,
+                right_brake_demand,
+                lgciu2.right_gear_compressed(false),
+            ),
+        ] {
+            for wheel in wheels {
+                self.update_wheel(
+                    context,
+                    wheel,
+                    brake_demand * flaps_correction,
+                    wheels_on_ground,
+                    brake_temperatures.is_tyre_deflated(wheel),
+                );
+            }
+        }
+
+        // Force sent to the sim is the force tyres actually get from the runway
+        self.left_braking_force = self.wheels_braking_force(Self::LEFT_WHEELS);
+        self.right_braking_force = self.wheels_braking_force(Self::RIGHT_WHEELS);
+    }
+
+    fn update_wheel(
+        &mut self,
+        context: &UpdateContext,
+        wheel: usize,
+        brake_demand: f64,
+        is_on_ground: bool,
+        tyre_is_deflated: bool,
+    ) {
+        self.brake_torques[wheel] = Torque::new::<newton_meter>(
+            brake_demand * Self::MAX_BRAKING_FORCE_PER_WHEEL_NEWTON * Self::WHEEL_RADIUS_METER,
+        );
+
+        let normal_load = Force::new::<newton>(if is_on_ground {
+            Self::NORMAL_LOAD_PER_WHEEL_NEWTON
+        } else {
+            0.
+        });
+
+        let mut runway_friction = self.runway_condition.friction_coefficient();
+        if tyre_is_deflated {
+            runway_friction *= Self::DEFLATED_TYRE_FRICTION_RATIO;
+        }
+
+        self.wheels[wheel].update(
+            context,
+            self.brake_torques[wheel],
+            normal_load,
+            runway_friction,
+        );
+    }
+
+    fn wheels_braking_force(&self, wheels: [usize; 2]) -> f64 {
+        let tyre_force: f64 = wheels
+            .iter()
+            .map(|&wheel| self.wheels[wheel].tyre_force().get::<newton>())
+            .sum();
+
+        (tyre_force / (wheels.len() as f64 * Self::MAX_BRAKING_FORCE_PER_WHEEL_NEWTON))
+            .clamp(0., 1.)
+    }
+
+    fn wheels_max_slip_ratio(&self, wheels: [usize; 2]) -> Ratio {
+        wheels
+            .iter()
+            .map(|&wheel| self.wheels[wheel].slip_ratio())
+            .fold(Ratio::new::<ratio>(0.), Ratio::max)
+    }
+
+    fn left_wheels_slip_ratio(&self) -> Ratio {
+        self.wheels_max_slip_ratio(Self::LEFT_WHEELS)
+    }
+
+    fn right_wheels_slip_ratio(&self) -> Ratio {
+        self.wheels_max_slip_ratio(Self::RIGHT_WHEELS)
+    }
 
-        self.correct_with_flaps_state(context);
+    /// Power dissipated by the brake of a wheel
+    fn braking_power(&self, wheel: usize) -> Power {
+        Power::new::<watt>(
+            self.brake_torques[wheel].get::<newton_meter>()
+                * self.wheels[wheel]
+                    .angular_speed()
+                    .get::<radian_per_second>(),
+        )
     }
 
-    fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
+    fn flaps_correction(&self, context: &UpdateContext) -> f64 {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
             &Self::FLAPS_PENALTY_PERCENT,
@@ -2315,11 +2881,7 @@ impl A320BrakingForce {
 
         let final_flaps_correction_with_speed = flap_correction * airspeed_corrective_factor;
 
-        self.left_braking_force = self.left_braking_force
-            - (self.left_braking_force * final_flaps_correction_with_speed.get::<ratio>());
-
-        self.right_braking_force = self.right_braking_force
-            - (self.right_braking_force * final_flaps_correction_with_speed.get::<ratio>());
+        1. - final_flaps_correction_with_speed.get::<ratio>()
     }
 }
 
@@ -2334,6 +2896,111 @@ impl SimulationElement for A320BrakingForce {
         let left_flap: f64 = reader.read(&self.trailing_edge_flaps_left_percent_id);
         let right_flap: f64 = reader.read(&self.trailing_edge_flaps_right_percent_id);
         self.flap_position = (left_flap + right_flap) / 2.;
+
+        let runway_condition: f64 = reader.read(&self.runway_condition_id);
+        self.runway_condition = runway_condition.into();
+    }
+}
+
+/// Temperatures of the four main wheel brakes, numbered 1 to 4 from left to right.
+/// Also handles the brake fans and the hot brakes detection.
+struct A320BrakeTemperatures {
+    brake_fan_pb_pressed_id: VariableIdentifier,
+    brake_fan_id: VariableIdentifier,
+    brakes_hot_id: VariableIdentifier,
+
+    brake_fan_pb_pressed: bool,
+    brake_fan_is_powered: bool,
+    brake_fan_is_on: bool,
+
+    brakes: [WheelBrakeTemperature; 4],
+}
+impl A320BrakeTemperatures {
+    const HOT_BRAKES_THRESHOLD_DEGREE_CELSIUS: f64 = 300.;
+
+    const BRAKE_FAN_POWER_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrent(2);
+
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            brake_fan_pb_pressed_id: context.get_identifier("BRAKE_FAN_BTN_PRESSED".to_owned()),
+            brake_fan_id: context.get_identifier("BRAKE_FAN".to_owned()),
+            brakes_hot_id: context.get_identifier("BRAKES_HOT".to_owned()),
+
+            brake_fan_pb_pressed: false,
+            brake_fan_is_powered: false,
+            brake_fan_is_on: false,
+
+            brakes: [
+                WheelBrakeTemperature::new(context, 1),
+                WheelBrakeTemperature::new(context, 2),
+                WheelBrakeTemperature::new(context, 3),
+                WheelBrakeTemperature::new(context, 4),
+            ],
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        braking_force: &A320BrakingForce,
+        lgciu1: &impl LgciuSensors,
+        gear_system: &impl GearSystemSensors,
+    ) {
+        let gear_is_down_and_locked = lgciu1.all_down_and_locked();
+
+        // Brake fans only run when the left main landing gear is down and locked
+        self.brake_fan_is_on = self.brake_fan_pb_pressed
+            && self.brake_fan_is_powered
+            && gear_system.is_wheel_id_down_and_locked(GearWheel::LEFT);
+
+        // Wheels are only cooled by the airflow when out of their bay
+        let airflow_speed = if gear_is_down_and_locked {
+            context.true_airspeed()
+        } else {
+            Velocity::new::<knot>(0.)
+        };
+
+        for (wheel, brake) in self.brakes.iter_mut().enumerate() {
+            brake.update(
+                context,
+                braking_force.braking_power(wheel),
+                airflow_speed,
+                self.brake_fan_is_on,
+            );
+        }
+    }
+
+    fn is_tyre_deflated(&self, wheel: usize) -> bool {
+        self.brakes[wheel].is_fuse_plug_melted()
+    }
+
+    fn brakes_are_hot(&self) -> bool {
+        self.brakes.iter().any(|brake| {
+            brake.reported_temperature().get::<degree_celsius>()
+                > Self::HOT_BRAKES_THRESHOLD_DEGREE_CELSIUS
+        })
+    }
+}
+impl SimulationElement for A320BrakeTemperatures {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for brake in &mut self.brakes {
+            brake.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.brake_fan_pb_pressed = reader.read(&self.brake_fan_pb_pressed_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.brake_fan_id, self.brake_fan_is_on);
+        writer.write(&self.brakes_hot_id, self.brakes_are_hot());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.brake_fan_is_powered = buses.is_powered(Self::BRAKE_FAN_POWER_BUS);
     }
 }
 
@@ -2553,6 +3220,69 @@ impl SimulationElement for CargoDoor {
     }
 }
 
+/// Bulk cargo door has no hydraulic actuation: ground crew swings it open and closed by hand.
+/// Once closed, its latch is engaged and detected by a proximity sensor.
+struct BulkCargoDoor {
+    open_request_id: VariableIdentifier,
+    position_id: VariableIdentifier,
+    locked_id: VariableIdentifier,
+
+    open_requested: bool,
+    position: Ratio,
+    is_latched: bool,
+}
+impl BulkCargoDoor {
+    // Time for the ground crew to fully swing the door open or closed
+    const MANUAL_OPERATION_DURATION: Duration = Duration::from_secs(6);
+
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            open_request_id: context.get_identifier("BULK_DOOR_CARGO_OPEN_REQ".to_owned()),
+            position_id: context.get_identifier("BULK_DOOR_CARGO_POSITION".to_owned()),
+            locked_id: context.get_identifier("BULK_DOOR_CARGO_LOCKED".to_owned()),
+
+            open_requested: false,
+            position: Ratio::new::<ratio>(0.),
+            is_latched: true,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        if self.open_requested {
+            self.is_latched = false;
+        }
+
+        if !self.is_latched {
+            let travel = context.delta_as_secs_f64()
+                / Self::MANUAL_OPERATION_DURATION.as_secs_f64()
+                * if self.open_requested { 1. } else { -1. };
+
+            self.position =
+                Ratio::new::<ratio>((self.position.get::<ratio>() + travel).clamp(0., 1.));
+
+            self.is_latched = !self.open_requested && self.position <= Ratio::new::<ratio>(0.);
+        }
+    }
+
+    fn position(&self) -> Ratio {
+        self.position
+    }
+
+    fn is_locked(&self) -> bool {
+        self.is_latched
+    }
+}
+impl SimulationElement for BulkCargoDoor {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.open_requested = reader.read(&self.open_request_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.position_id, self.position());
+        writer.write(&self.locked_id, self.is_locked());
+    }
+}
+
 struct PushbackTug {
     nw_strg_disc_memo_id: VariableIdentifier,
     state_id: VariableIdentifier,
@@ -2628,8 +3358,11 @@ impl SimulationElement for PushbackTug {
 pub struct A320AutobrakeController {
     armed_mode_id: VariableIdentifier,
     decel_light_id: VariableIdentifier,
-    spoilers_ground_spoilers_active_id: VariableIdentifier,
     external_disarm_event_id: VariableIdentifier,
+    btv_selected_exit_distance_id: VariableIdentifier,
+    btv_exit_distance_id: VariableIdentifier,
+    btv_dry_stopping_distance_id: VariableIdentifier,
+    btv_wet_stopping_distance_id: VariableIdentifier,
 
     deceleration_governor: AutobrakeDecelerationGovernor,
 
@@ -2647,6 +3380,12 @@ pub struct A320AutobrakeController {
     should_reject_max_mode_after_time_in_flight: DelayedTrueLogicGate,
 
     external_disarm_event: bool,
+
+    is_on_ground: bool,
+    ground_speed: Velocity,
+    btv_selected_exit_distance: Length,
+    last_btv_selected_exit_distance: Length,
+    btv_exit_distance: Length,
 }
 impl A320AutobrakeController {
     const DURATION_OF_FLIGHT_TO_DISARM_AUTOBRAKE_SECS: f64 = 10.;
@@ -2661,6 +3400,18 @@ impl A320AutobrakeController {
     const MAX_MODE_DECEL_TARGET_MS2: f64 = -6.;
     const OFF_MODE_DECEL_TARGET_MS2: f64 = 5.;
 
+    // BTV starts like LOW mode, then ramps at LOW mode rate towards the deceleration needed to
+    // reach the exit at exit speed
+    const BTV_MODE_DECEL_LIMIT_PROFILE_ACCEL_MS2: [f64; 4] = [4., 4., 0., -3.];
+    const BTV_MODE_DECEL_LIMIT_PROFILE_TIME_S: [f64; 4] = [0., 1.99, 2., 5.75];
+    const BTV_MODE_MIN_DECEL_TARGET_MS2: f64 = -1.;
+    const BTV_MODE_MAX_DECEL_TARGET_MS2: f64 = -3.;
+    const BTV_EXIT_SPEED_KNOT: f64 = 10.;
+
+    // Max braking deceleration used to predict stopping distances on dry and wet runways
+    const PREDICTED_DRY_STOPPING_DECEL_MS2: f64 = 5.;
+    const PREDICTED_WET_STOPPING_DECEL_MS2: f64 = 3.;
+
     const MARGIN_PERCENT_TO_TARGET_TO_SHOW_DECEL_IN_LO_MED: f64 = 80.;
     const TARGET_TO_SHOW_DECEL_IN_MAX_MS2: f64 = -2.7;
 
@@ -2668,9 +3419,14 @@ impl A320AutobrakeController {
         A320AutobrakeController {
             armed_mode_id: context.get_identifier("AUTOBRAKES_ARMED_MODE".to_owned()),
             decel_light_id: context.get_identifier("AUTOBRAKES_DECEL_LIGHT".to_owned()),
-            spoilers_ground_spoilers_active_id: context
-                .get_identifier("SPOILERS_GROUND_SPOILERS_ACTIVE".to_owned()),
             external_disarm_event_id: context.get_identifier("AUTOBRAKE_DISARM".to_owned()),
+            btv_selected_exit_distance_id: context
+                .get_identifier("BTV_SELECTED_EXIT_DISTANCE".to_owned()),
+            btv_exit_distance_id: context.get_identifier("BTV_EXIT_DISTANCE".to_owned()),
+            btv_dry_stopping_distance_id: context
+                .get_identifier("BTV_DRY_STOPPING_DISTANCE".to_owned()),
+            btv_wet_stopping_distance_id: context
+                .get_identifier("BTV_WET_STOPPING_DISTANCE".to_owned()),
 
             deceleration_governor: AutobrakeDecelerationGovernor::new(),
             target: Acceleration::new::<meter_per_second_squared>(0.),
@@ -2687,6 +3443,12 @@ impl A320AutobrakeController {
                 Duration::from_secs_f64(Self::DURATION_OF_FLIGHT_TO_DISARM_AUTOBRAKE_SECS),
             ),
             external_disarm_event: false,
+
+            is_on_ground: false,
+            ground_speed: Velocity::new::<knot>(0.),
+            btv_selected_exit_distance: Length::new::<meter>(0.),
+            last_btv_selected_exit_distance: Length::new::<meter>(0.),
+            btv_exit_distance: Length::new::<meter>(0.),
         }
     }
 
@@ -2726,7 +3488,7 @@ impl A320AutobrakeController {
     fn is_decelerating(&self) -> bool {
         match self.mode {
             AutobrakeMode::NONE => false,
-            AutobrakeMode::LOW | AutobrakeMode::MED => {
+            AutobrakeMode::LOW | AutobrakeMode::MED | AutobrakeMode::BTV => {
                 self.deceleration_demanded()
                     && self
                         .deceleration_governor
@@ -2753,7 +3515,7 @@ impl A320AutobrakeController {
     fn should_disarm_due_to_pedal_input(&self) -> bool {
         match self.mode {
             AutobrakeMode::NONE => false,
-            AutobrakeMode::LOW | AutobrakeMode::MED => {
+            AutobrakeMode::LOW | AutobrakeMode::MED | AutobrakeMode::BTV => {
                 self.left_brake_pedal_input > Ratio::new::<percent>(53.)
                     || self.right_brake_pedal_input > Ratio::new::<percent>(53.)
                     || (self.left_brake_pedal_input > Ratio::new::<percent>(11.)
@@ -2775,6 +3537,79 @@ impl A320AutobrakeController {
             || self.spoilers_retracted_during_this_update()
             || self.should_disarm_after_time_in_flight.output()
             || self.external_disarm_event
+            || self.should_disarm_btv_without_exit()
+    }
+
+    /// BTV can only be armed with a selected runway exit. Once braking, it keeps braking even if
+    /// the exit is passed.
+    fn should_disarm_btv_without_exit(&self) -> bool {
+        self.mode == AutobrakeMode::BTV
+            && !self.deceleration_governor.is_engaged()
+            && self.btv_exit_distance <= Length::new::<meter>(0.)
+    }
+
+    fn btv_target(&self) -> f64 {
+        let decel_limit = interpolation(
+            &Self::BTV_MODE_DECEL_LIMIT_PROFILE_TIME_S,
+            &Self::BTV_MODE_DECEL_LIMIT_PROFILE_ACCEL_MS2,
+            self.deceleration_governor.time_engaged().as_secs_f64(),
+        );
+
+        self.btv_required_decel().max(decel_limit)
+    }
+
+    fn btv_required_decel(&self) -> f64 {
+        let ground_speed = self.ground_speed.get::<meter_per_second>().abs();
+        let exit_speed = Velocity::new::<knot>(Self::BTV_EXIT_SPEED_KNOT).get::<meter_per_second>();
+
+        if ground_speed <= exit_speed {
+            Self::OFF_MODE_DECEL_TARGET_MS2
+        } else if self.btv_exit_distance <= Length::new::<meter>(0.) {
+            Self::BTV_MODE_MAX_DECEL_TARGET_MS2
+        } else {
+            let required_decel = -(ground_speed.powi(2) - exit_speed.powi(2))
+                / (2. * self.btv_exit_distance.get::<meter>());
+
+            required_decel.clamp(
+                Self::BTV_MODE_MAX_DECEL_TARGET_MS2,
+                Self::BTV_MODE_MIN_DECEL_TARGET_MS2,
+            )
+        }
+    }
+
+    /// Remaining distance to the selected exit is latched from the selection, then reduced by the
+    /// distance rolled on ground until a new exit is selected.
+    fn update_btv_exit_distance(&mut self, context: &UpdateContext) {
+        if !self.is_on_ground
+            || self.btv_selected_exit_distance != self.last_btv_selected_exit_distance
+        {
+            self.btv_exit_distance = self.btv_selected_exit_distance;
+        } else {
+            let rolled_distance = Length::new::<meter>(
+                self.ground_speed.get::<meter_per_second>().abs() * context.delta_as_secs_f64(),
+            );
+
+            self.btv_exit_distance =
+                (self.btv_exit_distance - rolled_distance).max(Length::new::<meter>(0.));
+        }
+
+        self.last_btv_selected_exit_distance = self.btv_selected_exit_distance;
+    }
+
+    /// Stopping distances are only predicted on ground, or in flight when BTV is armed for landing
+    fn should_predict_stopping_distances(&self) -> bool {
+        self.is_on_ground || self.mode == AutobrakeMode::BTV
+    }
+
+    /// Stopping distances which aren't predicted are zero
+    fn predicted_stopping_distance(&self, deceleration_ms2: f64) -> Length {
+        if self.should_predict_stopping_distances() {
+            Length::new::<meter>(
+                self.ground_speed.get::<meter_per_second>().powi(2) / (2. * deceleration_ms2),
+            )
+        } else {
+            Length::new::<meter>(0.)
+        }
     }
 
     fn calculate_target(&mut self) -> Acceleration {
@@ -2791,6 +3626,7 @@ impl A320AutobrakeController {
                 self.deceleration_governor.time_engaged().as_secs_f64(),
             ),
             AutobrakeMode::MAX => Self::MAX_MODE_DECEL_TARGET_MS2,
+            AutobrakeMode::BTV => self.btv_target(),
             _ => Self::OFF_MODE_DECEL_TARGET_MS2,
         })
     }
@@ -2801,9 +3637,13 @@ impl A320AutobrakeController {
         allow_arming: bool,
         pedal_input_left: Ratio,
         pedal_input_right: Ratio,
+        ground_spoilers_are_deployed: bool,
         lgciu1: &impl LgciuSensors,
         lgciu2: &impl LgciuSensors,
     ) {
+        self.last_ground_spoilers_are_deployed = self.ground_spoilers_are_deployed;
+        self.ground_spoilers_are_deployed = ground_spoilers_are_deployed;
+
         let in_flight_lgciu1 =
             !lgciu1.right_gear_compressed(false) && !lgciu1.left_gear_compressed(false);
         let in_flight_lgciu2 =
@@ -2817,6 +3657,10 @@ impl A320AutobrakeController {
         self.arming_is_allowed_by_bcu = allow_arming;
         self.left_brake_pedal_input = pedal_input_left;
         self.right_brake_pedal_input = pedal_input_right;
+        self.is_on_ground = !(in_flight_lgciu1 && in_flight_lgciu2);
+        self.ground_speed = context.ground_speed();
+
+        self.update_btv_exit_distance(context);
     }
 
     fn update(
@@ -2826,6 +3670,7 @@ impl A320AutobrakeController {
         allow_arming: bool,
         pedal_input_left: Ratio,
         pedal_input_right: Ratio,
+        ground_spoilers_are_deployed: bool,
         lgciu1: &impl LgciuSensors,
         lgciu2: &impl LgciuSensors,
     ) {
@@ -2834,6 +3679,7 @@ impl A320AutobrakeController {
             allow_arming,
             pedal_input_left,
             pedal_input_right,
+            ground_spoilers_are_deployed,
             lgciu1,
             lgciu2,
         );
@@ -2850,12 +3696,27 @@ impl SimulationElement for A320AutobrakeController {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.armed_mode_id, self.mode as u8 as f64);
         writer.write(&self.decel_light_id, self.is_decelerating());
+        writer.write(
+            &self.btv_exit_distance_id,
+            self.btv_exit_distance.get::<meter>(),
+        );
+
+        writer.write(
+            &self.btv_dry_stopping_distance_id,
+            self.predicted_stopping_distance(Self::PREDICTED_DRY_STOPPING_DECEL_MS2)
+                .get::<meter>(),
+        );
+        writer.write(
+            &self.btv_wet_stopping_distance_id,
+            self.predicted_stopping_distance(Self::PREDICTED_WET_STOPPING_DECEL_MS2)
+                .get::<meter>(),
+        );
     }
 
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.last_ground_spoilers_are_deployed = self.ground_spoilers_are_deployed;
-        self.ground_spoilers_are_deployed = reader.read(&self.spoilers_ground_spoilers_active_id);
         self.external_disarm_event = reader.read(&self.external_disarm_event_id);
+        self.btv_selected_exit_distance =
+            Length::new::<meter>(reader.read(&self.btv_selected_exit_distance_id));
 
         // Reading current mode in sim to initialize correct mode if sim changes it (from .FLT files for example)
         self.mode = reader.read_f64(&self.armed_mode_id).into();
@@ -2960,11 +3821,11 @@ impl SimulationElement for A320HydraulicOverheadPanel {
     }
 }
 
-struct AileronController {
+struct PrimarySurfaceController {
     mode: LinearActuatorMode,
     requested_position: Ratio,
 }
-impl AileronController {
+impl PrimarySurfaceController {
     fn new() -> Self {
         Self {
             mode: LinearActuatorMode::ClosedCircuitDamping,
@@ -2984,7 +3845,7 @@ impl AileronController {
             .max(Ratio::new::<ratio>(0.));
     }
 }
-impl HydraulicAssemblyController for AileronController {
+impl HydraulicAssemblyController for PrimarySurfaceController {
     fn requested_mode(&self) -> LinearActuatorMode {
         self.mode
     }
@@ -3025,20 +3886,48 @@ impl AileronHydConfiguration {
     }
 }
 
+enum ElevatorHydConfiguration {
+    BothCircuits,
+    GreenOrYellowOnly,
+    BlueOnly,
+    NoHyd,
+}
+impl ElevatorHydConfiguration {
+    fn from_hyd_state(
+        green_or_yellow_circuit_available: bool,
+        blue_circuit_available: bool,
+    ) -> ElevatorHydConfiguration {
+        if green_or_yellow_circuit_available && blue_circuit_available {
+            ElevatorHydConfiguration::BothCircuits
+        } else if green_or_yellow_circuit_available {
+            ElevatorHydConfiguration::GreenOrYellowOnly
+        } else if blue_circuit_available {
+            ElevatorHydConfiguration::BlueOnly
+        } else {
+            ElevatorHydConfiguration::NoHyd
+        }
+    }
+}
+
 /// Implements a placeholder elac computer logic commanding correct hydraulic modes depending
 /// on pressure state.
 /// TODO: Receive each actuator mode and commands directly from a FBW Elac implementation
 struct ElacComputer {
     requested_position_left_id: VariableIdentifier,
     requested_position_right_id: VariableIdentifier,
+    requested_elevator_position_id: VariableIdentifier,
 
     left_position_requested: Ratio,
     right_position_requested: Ratio,
+    elevator_position_requested: Ratio,
 
-    left_controllers: [AileronController; 2],
-    right_controllers: [AileronController; 2],
+    left_controllers: [PrimarySurfaceController; 2],
+    right_controllers: [PrimarySurfaceController; 2],
 
-    is_powered: bool,
+    left_elevator_controllers: [PrimarySurfaceController; 2],
+    right_elevator_controllers: [PrimarySurfaceController; 2],
+
+    power_supply: ComputerPowerSupply,
 }
 impl ElacComputer {
     //TODO hot busses are in reality sub busses 703pp and 704pp
@@ -3048,6 +3937,8 @@ impl ElacComputer {
         ElectricalBusType::DirectCurrentHot(1),
         ElectricalBusType::DirectCurrentHot(2),
     ];
+    const POWER_HOLD_UP_TIME: Duration = Duration::from_millis(100);
+    const START_UP_TIME: Duration = Duration::from_secs(2);
 
     fn new(context: &mut InitContext) -> Self {
         Self {
@@ -3055,15 +3946,39 @@ impl ElacComputer {
                 .get_identifier("HYD_AILERON_LEFT_DEMAND".to_owned()),
             requested_position_right_id: context
                 .get_identifier("HYD_AILERON_RIGHT_DEMAND".to_owned()),
+            requested_elevator_position_id: context
+                .get_identifier("HYD_ELEVATOR_DEMAND".to_owned()),
 
             left_position_requested: Ratio::default(),
             right_position_requested: Ratio::default(),
+            elevator_position_requested: Ratio::default(),
 
             // Controllers are in outward->inward order, so for aileron [Blue circuit, Green circuit]
-            left_controllers: [AileronController::new(), AileronController::new()],
-            right_controllers: [AileronController::new(), AileronController::new()],
+            left_controllers: [
+                PrimarySurfaceController::new(),
+                PrimarySurfaceController::new(),
+            ],
+            right_controllers: [
+                PrimarySurfaceController::new(),
+                PrimarySurfaceController::new(),
+            ],
 
-            is_powered: false,
+            // Controllers are in [Blue circuit, Green circuit] order for left elevator
+            // and [Blue circuit, Yellow circuit] order for right elevator
+            left_elevator_controllers: [
+                PrimarySurfaceController::new(),
+                PrimarySurfaceController::new(),
+            ],
+            right_elevator_controllers: [
+                PrimarySurfaceController::new(),
+                PrimarySurfaceController::new(),
+            ],
+
+            power_supply: ComputerPowerSupply::new(
+                Self::ALL_POWER_BUSES.to_vec(),
+                Self::POWER_HOLD_UP_TIME,
+                Self::START_UP_TIME,
+            ),
         }
     }
 
@@ -3142,7 +4057,7 @@ impl ElacComputer {
     }
 
     fn update_aileron(&mut self, green_circuit_available: bool, blue_circuit_available: bool) {
-        if self.is_powered {
+        if self.power_supply.is_operational() {
             self.set_right_aileron_position_control(AileronHydConfiguration::from_hyd_state(
                 green_circuit_available,
                 blue_circuit_available,
@@ -3156,33 +4071,129 @@ impl ElacComputer {
         }
     }
 
-    fn update(&mut self, blue_pressure: Pressure, green_pressure: Pressure) {
+    fn update_elevator_requested_position(&mut self) {
+        for controller in self
+            .left_elevator_controllers
+            .iter_mut()
+            .chain(self.right_elevator_controllers.iter_mut())
+        {
+            controller.set_requested_position(self.elevator_position_requested);
+        }
+    }
+
+    /// In normal operation the green (left) or yellow (right) actuator is active, while the blue
+    /// one is in active damping mode. Blue actuator takes over if the other circuit is lost.
+    fn set_elevator_position_control(
+        controllers: &mut [PrimarySurfaceController; 2],
+        hydraulic_configuration: ElevatorHydConfiguration,
+    ) {
+        let (blue_mode, green_or_yellow_mode) = match hydraulic_configuration {
+            ElevatorHydConfiguration::BothCircuits
+            | ElevatorHydConfiguration::GreenOrYellowOnly => (
+                LinearActuatorMode::ActiveDamping,
+                LinearActuatorMode::PositionControl,
+            ),
+            ElevatorHydConfiguration::BlueOnly => (
+                LinearActuatorMode::PositionControl,
+                LinearActuatorMode::ActiveDamping,
+            ),
+            ElevatorHydConfiguration::NoHyd => (
+                LinearActuatorMode::ClosedCircuitDamping,
+                LinearActuatorMode::ClosedCircuitDamping,
+            ),
+        };
+
+        controllers[ElevatorActuatorCircuit::Blue as usize].set_mode(blue_mode);
+        controllers[ElevatorActuatorCircuit::GreenOrYellow as usize].set_mode(green_or_yellow_mode);
+    }
+
+    fn update_elevator(
+        &mut self,
+        green_circuit_available: bool,
+        blue_circuit_available: bool,
+        yellow_circuit_available: bool,
+    ) {
+        let (left_configuration, right_configuration) = if self.power_supply.is_operational() {
+            (
+                ElevatorHydConfiguration::from_hyd_state(
+                    green_circuit_available,
+                    blue_circuit_available,
+                ),
+                ElevatorHydConfiguration::from_hyd_state(
+                    yellow_circuit_available,
+                    blue_circuit_available,
+                ),
+            )
+        } else {
+            (
+                ElevatorHydConfiguration::NoHyd,
+                ElevatorHydConfiguration::NoHyd,
+            )
+        };
+
+        Self::set_elevator_position_control(
+            &mut self.left_elevator_controllers,
+            left_configuration,
+        );
+        Self::set_elevator_position_control(
+            &mut self.right_elevator_controllers,
+            right_configuration,
+        );
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        green_pressure: Pressure,
+        blue_pressure: Pressure,
+        yellow_pressure: Pressure,
+    ) {
+        self.power_supply.update(context);
         self.update_aileron_requested_position();
+        self.update_elevator_requested_position();
 
         let blue_circuit_available = blue_pressure.get::<psi>() > 1500.;
         let green_circuit_available = green_pressure.get::<psi>() > 1500.;
+        let yellow_circuit_available = yellow_pressure.get::<psi>() > 1500.;
 
         self.update_aileron(green_circuit_available, blue_circuit_available);
+        self.update_elevator(
+            green_circuit_available,
+            blue_circuit_available,
+            yellow_circuit_available,
+        );
     }
 
-    fn left_controllers(&self) -> &[impl HydraulicAssemblyController] {
+    fn left_aileron_controllers(&self) -> &[impl HydraulicAssemblyController] {
         &self.left_controllers[..]
     }
 
-    fn right_controllers(&self) -> &[impl HydraulicAssemblyController] {
+    fn right_aileron_controllers(&self) -> &[impl HydraulicAssemblyController] {
         &self.right_controllers[..]
     }
+
+    fn left_elevator_controllers(&self) -> &[impl HydraulicAssemblyController] {
+        &self.left_elevator_controllers[..]
+    }
+
+    fn right_elevator_controllers(&self) -> &[impl HydraulicAssemblyController] {
+        &self.right_elevator_controllers[..]
+    }
 }
 impl SimulationElement for ElacComputer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.power_supply.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.left_position_requested =
             Ratio::new::<ratio>(reader.read(&self.requested_position_left_id));
         self.right_position_requested =
             Ratio::new::<ratio>(reader.read(&self.requested_position_right_id));
-    }
-
-    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        self.is_powered = buses.any_is_powered(&Self::ALL_POWER_BUSES);
+        self.elevator_position_requested =
+            Ratio::new::<ratio>(reader.read(&self.requested_elevator_position_id));
     }
 }
 
@@ -3247,6 +4258,187 @@ impl SimulationElement for AileronAssembly {
     }
 }
 
+#[derive(PartialEq, Clone, Copy)]
+enum ElevatorSide {
+    Left,
+    Right,
+}
+
+/// Each elevator has one actuator on blue circuit, the other one is on green circuit for left elevator
+/// and on yellow circuit for right elevator
+#[derive(PartialEq, Clone, Copy)]
+enum ElevatorActuatorCircuit {
+    Blue = 0,
+    GreenOrYellow = 1,
+}
+
+struct ElevatorAssembly {
+    hydraulic_assembly: HydraulicLinearActuatorAssembly<2>,
+
+    position_id: VariableIdentifier,
+
+    position: Ratio,
+}
+impl ElevatorAssembly {
+    fn new(
+        context: &mut InitContext,
+        id: ElevatorSide,
+        hydraulic_assembly: HydraulicLinearActuatorAssembly<2>,
+    ) -> Self {
+        Self {
+            hydraulic_assembly,
+            position_id: match id {
+                ElevatorSide::Left => context.get_identifier("HYD_ELEV_LEFT_DEFLECTION".to_owned()),
+                ElevatorSide::Right => {
+                    context.get_identifier("HYD_ELEV_RIGHT_DEFLECTION".to_owned())
+                }
+            },
+            position: Ratio::new::<ratio>(0.),
+        }
+    }
+
+    fn actuator(&mut self, circuit: ElevatorActuatorCircuit) -> &mut impl Actuator {
+        self.hydraulic_assembly.actuator(circuit as usize)
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        elevator_controllers: &[impl HydraulicAssemblyController],
+        current_pressure_blue: Pressure,
+        current_pressure_green_or_yellow: Pressure,
+    ) {
+        self.hydraulic_assembly.update(
+            context,
+            elevator_controllers,
+            [current_pressure_blue, current_pressure_green_or_yellow],
+        );
+
+        self.position = self.hydraulic_assembly.position_normalized();
+    }
+}
+impl SimulationElement for ElevatorAssembly {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.position_id, self.position.get::<ratio>());
+    }
+}
+
+/// Rudder actuators are mechanically controlled from pedals, trim and yaw damper inputs.
+/// All three actuators are active at the same time as long as their circuit is pressurised.
+struct RudderMechanicalControl {
+    requested_position_id: VariableIdentifier,
+
+    position_requested: Ratio,
+
+    controllers: [PrimarySurfaceController; 3],
+}
+impl RudderMechanicalControl {
+    fn new(context: &mut InitContext) -> Self {
+        let mut controllers = [
+            PrimarySurfaceController::new(),
+            PrimarySurfaceController::new(),
+            PrimarySurfaceController::new(),
+        ];
+        for controller in &mut controllers {
+            controller.set_requested_position(Ratio::new::<ratio>(0.5));
+        }
+
+        Self {
+            requested_position_id: context.get_identifier("HYD_RUDDER_DEMAND".to_owned()),
+            position_requested: Ratio::new::<ratio>(0.5),
+            controllers,
+        }
+    }
+
+    fn update(
+        &mut self,
+        green_pressure: Pressure,
+        blue_pressure: Pressure,
+        yellow_pressure: Pressure,
+    ) {
+        for (circuit, pressure) in [
+            (RudderActuatorCircuit::Green, green_pressure),
+            (RudderActuatorCircuit::Blue, blue_pressure),
+            (RudderActuatorCircuit::Yellow, yellow_pressure),
+        ] {
+            let controller = &mut self.controllers[circuit as usize];
+
+            controller.set_requested_position(self.position_requested);
+            controller.set_mode(if pressure.get::<psi>() > 1500. {
+                LinearActuatorMode::PositionControl
+            } else {
+                LinearActuatorMode::ClosedCircuitDamping
+            });
+        }
+    }
+
+    fn controllers(&self) -> &[impl HydraulicAssemblyController] {
+        &self.controllers[..]
+    }
+}
+impl SimulationElement for RudderMechanicalControl {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.position_requested = Ratio::new::<ratio>(reader.read(&self.requested_position_id));
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum RudderActuatorCircuit {
+    Green = 0,
+    Blue = 1,
+    Yellow = 2,
+}
+
+struct RudderAssembly {
+    hydraulic_assembly: HydraulicLinearActuatorAssembly<3>,
+
+    position_id: VariableIdentifier,
+
+    position: Ratio,
+}
+impl RudderAssembly {
+    fn new(
+        context: &mut InitContext,
+        hydraulic_assembly: HydraulicLinearActuatorAssembly<3>,
+    ) -> Self {
+        Self {
+            hydraulic_assembly,
+            position_id: context.get_identifier("HYD_RUD_DEFLECTION".to_owned()),
+            position: Ratio::new::<ratio>(0.5),
+        }
+    }
+
+    fn actuator(&mut self, circuit: RudderActuatorCircuit) -> &mut impl Actuator {
+        self.hydraulic_assembly.actuator(circuit as usize)
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        rudder_controllers: &[impl HydraulicAssemblyController],
+        current_pressure_green: Pressure,
+        current_pressure_blue: Pressure,
+        current_pressure_yellow: Pressure,
+    ) {
+        self.hydraulic_assembly.update(
+            context,
+            rudder_controllers,
+            [
+                current_pressure_green,
+                current_pressure_blue,
+                current_pressure_yellow,
+            ],
+        );
+
+        self.position = self.hydraulic_assembly.position_normalized();
+    }
+}
+impl SimulationElement for RudderAssembly {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.position_id, self.position.get::<ratio>());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3261,7 +4453,10 @@ mod tests {
             engine::{leap_engine::LeapEngine, EngineFireOverheadPanel},
             hydraulic::electrical_generator::TestGenerator,
             landing_gear::{LandingGear, LandingGearControlInterfaceUnit},
-            shared::{EmergencyElectricalState, HydraulicGeneratorControlUnit, PotentialOrigin},
+            shared::{
+                EmergencyElectricalState, HydraulicGeneratorControlUnit, LandingGearRealPosition,
+                PotentialOrigin,
+            },
             simulation::{
                 test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
                 Aircraft, InitContext,
@@ -3273,7 +4468,9 @@ mod tests {
             electric_potential::volt,
             length::foot,
             ratio::{percent, ratio},
+            thermodynamic_temperature::degree_celsius,
             volume::liter,
+            volume_rate::gallon_per_minute,
         };
 
         struct A320TestEmergencyElectricalOverheadPanel {
@@ -3412,12 +4609,12 @@ mod tests {
             is_dc_hot_2_powered: bool,
         }
         impl A320HydraulicsTestAircraft {
-            fn new(context: &mut InitContext) -> Self {
+            fn new(context: &mut InitContext, config: &A320HydraulicConfig) -> Self {
                 Self {
                     pneumatics: A320TestPneumatics::new(),
                     engine_1: LeapEngine::new(context, 1),
                     engine_2: LeapEngine::new(context, 2),
-                    hydraulics: A320Hydraulic::new(context),
+                    hydraulics: A320Hydraulic::new(context, config),
                     overhead: A320HydraulicOverheadPanel::new(context),
                     autobrake_panel: AutobrakePanel::new(context),
                     emergency_electrical_overhead: A320TestEmergencyElectricalOverheadPanel::new(
@@ -3503,6 +4700,12 @@ mod tests {
                     .accumulator_fluid_volume()
             }
 
+            fn is_yellow_hand_pump_branch_pressurised(&self) -> bool {
+                self.hydraulics
+                    .yellow_hand_pump_branch
+                    .is_pressure_switch_pressurised()
+            }
+
             fn is_nws_pin_inserted(&self) -> bool {
                 self.hydraulics.nose_wheel_steering_pin_is_inserted()
             }
@@ -3546,6 +4749,14 @@ mod tests {
                 self.hydraulics.power_transfer_unit.is_enabled()
             }
 
+            fn ptu_flow(&self) -> VolumeRate {
+                self.hydraulics.power_transfer_unit.flow()
+            }
+
+            fn ptu_heat_generated(&self) -> Power {
+                self.hydraulics.power_transfer_unit.heat_generated()
+            }
+
             fn is_blue_pressurised(&self) -> bool {
                 self.hydraulics.is_blue_pressurised()
             }
@@ -3589,6 +4800,22 @@ mod tests {
             fn set_dc_ess_is_powered(&mut self, bus_is_alive: bool) {
                 self.is_dc_ess_powered = bus_is_alive;
             }
+
+            fn set_dc_hot_buses_are_powered(&mut self, buses_are_alive: bool) {
+                self.is_dc_hot_1_powered = buses_are_alive;
+                self.is_dc_hot_2_powered = buses_are_alive;
+            }
+
+            fn set_all_buses_unpowered(&mut self) {
+                self.is_ac_ground_service_powered = false;
+                self.is_dc_ground_service_powered = false;
+                self.is_ac_1_powered = false;
+                self.is_ac_2_powered = false;
+                self.is_dc_1_powered = false;
+                self.is_dc_2_powered = false;
+                self.is_dc_ess_powered = false;
+                self.set_dc_hot_buses_are_powered(false);
+            }
         }
 
         impl Aircraft for A320HydraulicsTestAircraft {
@@ -3641,6 +4868,7 @@ mod tests {
             fn update_after_power_distribution(&mut self, context: &UpdateContext) {
                 self.electrical.update(&self.hydraulics.gcu, context);
 
+                self.landing_gear.update(&self.hydraulics);
                 self.lgciu1.update(
                     &self.landing_gear,
                     self.ext_pwr.output_potential().is_powered(),
@@ -3691,8 +4919,14 @@ mod tests {
         }
         impl A320HydraulicsTestBed {
             fn new() -> Self {
+                Self::new_with_config(&A320HydraulicConfig::default())
+            }
+
+            fn new_with_config(config: &A320HydraulicConfig) -> Self {
                 Self {
-                    test_bed: SimulationTestBed::new(A320HydraulicsTestAircraft::new),
+                    test_bed: SimulationTestBed::new(|context| {
+                        A320HydraulicsTestAircraft::new(context, config)
+                    }),
                 }
             }
 
@@ -3718,6 +4952,14 @@ mod tests {
                 self.query(|a| a.is_ptu_enabled())
             }
 
+            fn ptu_flow(&self) -> VolumeRate {
+                self.query(|a| a.ptu_flow())
+            }
+
+            fn ptu_heat_generated(&self) -> Power {
+                self.query(|a| a.ptu_heat_generated())
+            }
+
             fn is_blue_pressurised(&self) -> bool {
                 self.query(|a| a.is_blue_pressurised())
             }
@@ -3730,6 +4972,10 @@ mod tests {
                 self.query(|a| a.is_yellow_pressurised())
             }
 
+            fn is_yellow_hand_pump_branch_pressurised(&self) -> bool {
+                self.query(|a| a.is_yellow_hand_pump_branch_pressurised())
+            }
+
             fn is_flaps_moving(&mut self) -> bool {
                 self.read_by_name("IS_FLAPS_MOVING")
             }
@@ -3738,6 +4984,15 @@ mod tests {
                 self.read_by_name("IS_SLATS_MOVING")
             }
 
+            fn is_flaps_wing_tip_brakes_engaged(&mut self) -> bool {
+                self.read_by_name("FLAPS_WING_TIP_BRAKES_ENGAGED")
+            }
+
+            fn fail_left_flap_transmission(mut self) -> Self {
+                self.fail(FailureType::FlapTransmission(1));
+                self
+            }
+
             fn nose_steering_position(&self) -> Angle {
                 self.query(|a| a.nose_steering_position())
             }
@@ -3758,6 +5013,22 @@ mod tests {
                 self.read_by_name("AFT_DOOR_CARGO_POSITION")
             }
 
+            fn is_cargo_aft_door_locked_down(&mut self) -> bool {
+                self.read_by_name("AFT_DOOR_CARGO_LOCKED")
+            }
+
+            fn bulk_cargo_door_position(&mut self) -> f64 {
+                self.read_by_name("BULK_DOOR_CARGO_POSITION")
+            }
+
+            fn is_bulk_cargo_door_locked(&mut self) -> bool {
+                self.read_by_name("BULK_DOOR_CARGO_LOCKED")
+            }
+
+            fn all_cargo_doors_locked(&self) -> bool {
+                self.query(|a| a.hydraulics.all_cargo_doors_locked())
+            }
+
             fn green_pressure(&mut self) -> Pressure {
                 self.read_by_name("HYD_GREEN_SYSTEM_1_SECTION_PRESSURE")
             }
@@ -3778,6 +5049,14 @@ mod tests {
                 self.read_by_name("HYD_GREEN_EDPUMP_LOW_PRESS")
             }
 
+            fn get_green_fluid_temperature(&mut self) -> ThermodynamicTemperature {
+                self.read_by_name("HYD_GREEN_FLUID_TEMPERATURE")
+            }
+
+            fn is_green_reservoir_overheat(&mut self) -> bool {
+                self.read_by_name("HYD_GREEN_RESERVOIR_OVHT")
+            }
+
             fn green_edp_has_fault(&mut self) -> bool {
                 self.read_by_name("OVHD_HYD_ENG_1_PUMP_PB_HAS_FAULT")
             }
@@ -3870,6 +5149,18 @@ mod tests {
                 Ratio::new::<ratio>(self.read_by_name("HYD_AIL_RIGHT_DEFLECTION"))
             }
 
+            fn get_left_elevator_position(&mut self) -> Ratio {
+                Ratio::new::<ratio>(self.read_by_name("HYD_ELEV_LEFT_DEFLECTION"))
+            }
+
+            fn get_right_elevator_position(&mut self) -> Ratio {
+                Ratio::new::<ratio>(self.read_by_name("HYD_ELEV_RIGHT_DEFLECTION"))
+            }
+
+            fn get_rudder_position(&mut self) -> Ratio {
+                Ratio::new::<ratio>(self.read_by_name("HYD_RUD_DEFLECTION"))
+            }
+
             fn rat_deploy_commanded(&self) -> bool {
                 self.query(|a| a.is_rat_commanded_to_deploy())
             }
@@ -3987,6 +5278,26 @@ mod tests {
                 self
             }
 
+            fn open_aft_cargo_door(mut self) -> Self {
+                self.write_by_name("AFT_DOOR_CARGO_OPEN_REQ", 1.);
+                self
+            }
+
+            fn close_aft_cargo_door(mut self) -> Self {
+                self.write_by_name("AFT_DOOR_CARGO_OPEN_REQ", 0.);
+                self
+            }
+
+            fn open_bulk_cargo_door(mut self) -> Self {
+                self.write_by_name("BULK_DOOR_CARGO_OPEN_REQ", true);
+                self
+            }
+
+            fn close_bulk_cargo_door(mut self) -> Self {
+                self.write_by_name("BULK_DOOR_CARGO_OPEN_REQ", false);
+                self
+            }
+
             fn set_pushback_state(mut self, is_pushed_back: bool) -> Self {
                 if is_pushed_back {
                     self.write_by_name("PUSHBACK STATE", 0.);
@@ -4048,23 +5359,35 @@ mod tests {
             }
 
             fn set_gear_up(mut self) -> Self {
-                self.write_by_name("GEAR CENTER POSITION", 0.);
-                self.write_by_name("GEAR LEFT POSITION", 0.);
-                self.write_by_name("GEAR RIGHT POSITION", 0.);
                 self.write_by_name("GEAR HANDLE POSITION", false);
 
                 self
             }
 
             fn set_gear_down(mut self) -> Self {
-                self.write_by_name("GEAR CENTER POSITION", 100.);
-                self.write_by_name("GEAR LEFT POSITION", 100.);
-                self.write_by_name("GEAR RIGHT POSITION", 100.);
                 self.write_by_name("GEAR HANDLE POSITION", true);
 
                 self
             }
 
+            fn set_gravity_extension_handle_turns(mut self, turns: f64) -> Self {
+                self.write_by_name("GRAVITYGEAR_ROTATE_PCT", turns * 100.);
+
+                self
+            }
+
+            fn is_gear_down_and_locked(&self) -> bool {
+                self.query(|a| a.landing_gear.is_down_and_locked())
+            }
+
+            fn is_gear_up_and_locked(&self) -> bool {
+                self.query(|a| a.landing_gear.is_up_and_locked())
+            }
+
+            fn gear_door_position(&mut self, wheel_id: &str) -> Ratio {
+                self.read_by_name(&format!("GEAR_DOOR_{}_POSITION", wheel_id))
+            }
+
             fn set_anti_skid(mut self, is_set: bool) -> Self {
                 self.write_by_name("ANTISKID BRAKES ACTIVE", is_set);
                 self
@@ -4075,6 +5398,11 @@ mod tests {
                 self
             }
 
+            fn set_yellow_hand_pump_stroke_rate(mut self, strokes_per_second: f64) -> Self {
+                self.write_by_name("HYD_YELLOW_HAND_PUMP_STROKE_RATE", strokes_per_second);
+                self
+            }
+
             fn set_blue_e_pump(mut self, is_auto: bool) -> Self {
                 self.write_by_name("OVHD_HYD_EPUMPB_PB_IS_AUTO", is_auto);
                 self
@@ -4131,6 +5459,11 @@ mod tests {
                 self
             }
 
+            fn all_electrical_power_lost(mut self) -> Self {
+                self.command(|a| a.set_all_buses_unpowered());
+                self
+            }
+
             fn dc_ground_service_lost(mut self) -> Self {
                 self.command(|a| a.set_dc_ground_service_is_powered(false));
                 self
@@ -4160,6 +5493,24 @@ mod tests {
                 self
             }
 
+            fn all_elac_power_lost(mut self) -> Self {
+                self.command(|a| {
+                    a.set_dc_ess_is_powered(false);
+                    a.set_dc_bus_2_is_powered(false);
+                    a.set_dc_hot_buses_are_powered(false);
+                });
+                self
+            }
+
+            fn all_elac_power_restored(mut self) -> Self {
+                self.command(|a| {
+                    a.set_dc_ess_is_powered(true);
+                    a.set_dc_bus_2_is_powered(true);
+                    a.set_dc_hot_buses_are_powered(true);
+                });
+                self
+            }
+
             fn set_cold_dark_inputs(self) -> Self {
                 self.set_eng1_fire_button(false)
                     .set_eng2_fire_button(false)
@@ -4176,6 +5527,8 @@ mod tests {
                     .set_pushback_state(false)
                     .air_press_nominal()
                     .set_ailerons_neutral()
+                    .set_elevator_neutral()
+                    .set_rudder_neutral()
             }
 
             fn set_left_brake(mut self, position: Ratio) -> Self {
@@ -4209,40 +5562,173 @@ mod tests {
                 self
             }
 
-            fn set_deploy_spoilers(mut self) -> Self {
-                self.write_by_name("SPOILERS_GROUND_SPOILERS_ACTIVE", true);
+            fn set_autobrake_btv(mut self, exit_distance: Length) -> Self {
+                self = self.set_btv_exit_distance(exit_distance);
+                self.write_by_name("AUTOBRAKES_ARMED_MODE", AutobrakeMode::BTV as u8 as f64);
                 self
             }
 
-            fn set_retract_spoilers(mut self) -> Self {
-                self.write_by_name("SPOILERS_GROUND_SPOILERS_ACTIVE", false);
+            fn set_btv_exit_distance(mut self, exit_distance: Length) -> Self {
+                self.write_by_name("BTV_SELECTED_EXIT_DISTANCE", exit_distance.get::<meter>());
                 self
             }
 
-            fn set_ailerons_neutral(mut self) -> Self {
-                self.write_by_name("HYD_AILERON_LEFT_DEMAND", 0.5);
-                self.write_by_name("HYD_AILERON_RIGHT_DEMAND", 0.5);
-                self
+            fn get_btv_exit_distance(&mut self) -> Length {
+                Length::new::<meter>(self.read_by_name("BTV_EXIT_DISTANCE"))
             }
 
-            fn set_ailerons_left_turn(mut self) -> Self {
-                self.write_by_name("HYD_AILERON_LEFT_DEMAND", 1.);
-                self.write_by_name("HYD_AILERON_RIGHT_DEMAND", 0.);
-                self
+            fn get_autobrake_target(&self) -> Acceleration {
+                self.query(|a| {
+                    a.hydraulics
+                        .brake_steer_computer
+                        .autobrake_controller
+                        .target
+                })
             }
 
-            fn set_ailerons_right_turn(mut self) -> Self {
-                self.write_by_name("HYD_AILERON_LEFT_DEMAND", 0.);
-                self.write_by_name("HYD_AILERON_RIGHT_DEMAND", 1.);
-                self
+            fn get_btv_dry_stopping_distance(&mut self) -> Length {
+                Length::new::<meter>(self.read_by_name("BTV_DRY_STOPPING_DISTANCE"))
             }
 
-            fn empty_brake_accumulator_using_park_brake(mut self) -> Self {
-                self = self
-                    .set_park_brake(true)
-                    .run_waiting_for(Duration::from_secs(1));
+            fn get_btv_wet_stopping_distance(&mut self) -> Length {
+                Length::new::<meter>(self.read_by_name("BTV_WET_STOPPING_DISTANCE"))
+            }
 
-                let mut number_of_loops = 0;
+            /// Ground spoilers deploy as in a rejected take off: armed, idle throttles, and high ground speed
+            fn set_deploy_spoilers(self) -> Self {
+                self.arm_ground_spoilers(true)
+                    .set_throttles_angle(Angle::new::<degree>(0.))
+                    .set_ground_speed(Velocity::new::<knot>(100.))
+            }
+
+            fn set_retract_spoilers(self) -> Self {
+                self.arm_ground_spoilers(false)
+            }
+
+            fn arm_ground_spoilers(mut self, is_armed: bool) -> Self {
+                self.write_by_name("SPOILERS_ARMED", is_armed);
+                self
+            }
+
+            fn set_speedbrake_handle(mut self, position: Ratio) -> Self {
+                self.write_by_name("SPOILERS_HANDLE_POSITION", position.get::<ratio>());
+                self
+            }
+
+            fn set_throttles_angle(mut self, angle: Angle) -> Self {
+                self.write_by_name("AUTOTHRUST_TLA:1", angle.get::<degree>());
+                self.write_by_name("AUTOTHRUST_TLA:2", angle.get::<degree>());
+                self
+            }
+
+            fn set_ground_speed(mut self, ground_speed: Velocity) -> Self {
+                self.write_by_name("GPS GROUND SPEED", ground_speed);
+                self
+            }
+
+            fn get_spoiler_position(&mut self, side: &str, number: usize) -> Ratio {
+                Ratio::new::<ratio>(
+                    self.read_by_name(&format!("HYD_SPOILER_{}_{}_DEFLECTION", side, number)),
+                )
+            }
+
+            fn touches_down(self) -> Self {
+                self.on_the_ground()
+                    .set_ground_speed(Velocity::new::<knot>(130.))
+            }
+
+            fn set_runway_condition(mut self, runway_condition: RunwayCondition) -> Self {
+                self.write_by_name("SURFACE CONDITION", runway_condition as u8 as f64);
+                self
+            }
+
+            fn get_left_braking_force(&mut self) -> f64 {
+                self.read_by_name("BRAKE LEFT FORCE FACTOR")
+            }
+
+            fn set_brake_fan(mut self, is_pressed: bool) -> Self {
+                self.write_by_name("BRAKE_FAN_BTN_PRESSED", is_pressed);
+                self
+            }
+
+            fn get_brake_temperature(&mut self, wheel_number: usize) -> ThermodynamicTemperature {
+                self.read_by_name(&format!("BRAKE_TEMPERATURE_{}", wheel_number))
+            }
+
+            fn get_reported_brake_temperature(
+                &mut self,
+                wheel_number: usize,
+            ) -> ThermodynamicTemperature {
+                self.read_by_name(&format!("REPORTED_BRAKE_TEMPERATURE_{}", wheel_number))
+            }
+
+            fn is_brake_fuse_plug_melted(&mut self, wheel_number: usize) -> bool {
+                self.read_by_name(&format!("BRAKE_FUSE_PLUG_MELTED_{}", wheel_number))
+            }
+
+            fn is_brake_fan_on(&mut self) -> bool {
+                self.read_by_name("BRAKE_FAN")
+            }
+
+            fn are_brakes_hot(&mut self) -> bool {
+                self.read_by_name("BRAKES_HOT")
+            }
+
+            fn set_ailerons_neutral(mut self) -> Self {
+                self.write_by_name("HYD_AILERON_LEFT_DEMAND", 0.5);
+                self.write_by_name("HYD_AILERON_RIGHT_DEMAND", 0.5);
+                self
+            }
+
+            fn set_ailerons_left_turn(mut self) -> Self {
+                self.write_by_name("HYD_AILERON_LEFT_DEMAND", 1.);
+                self.write_by_name("HYD_AILERON_RIGHT_DEMAND", 0.);
+                self
+            }
+
+            fn set_ailerons_right_turn(mut self) -> Self {
+                self.write_by_name("HYD_AILERON_LEFT_DEMAND", 0.);
+                self.write_by_name("HYD_AILERON_RIGHT_DEMAND", 1.);
+                self
+            }
+
+            fn set_elevator_neutral(mut self) -> Self {
+                // Elevator travel is 17° down to 30° up, neutral is at 17/47
+                self.write_by_name("HYD_ELEVATOR_DEMAND", 17. / 47.);
+                self
+            }
+
+            fn set_elevator_full_up(mut self) -> Self {
+                self.write_by_name("HYD_ELEVATOR_DEMAND", 1.);
+                self
+            }
+
+            fn set_elevator_full_down(mut self) -> Self {
+                self.write_by_name("HYD_ELEVATOR_DEMAND", 0.);
+                self
+            }
+
+            fn set_rudder_neutral(mut self) -> Self {
+                self.write_by_name("HYD_RUDDER_DEMAND", 0.5);
+                self
+            }
+
+            fn set_rudder_full_left(mut self) -> Self {
+                self.write_by_name("HYD_RUDDER_DEMAND", 0.);
+                self
+            }
+
+            fn set_rudder_full_right(mut self) -> Self {
+                self.write_by_name("HYD_RUDDER_DEMAND", 1.);
+                self
+            }
+
+            fn empty_brake_accumulator_using_park_brake(mut self) -> Self {
+                self = self
+                    .set_park_brake(true)
+                    .run_waiting_for(Duration::from_secs(1));
+
+                let mut number_of_loops = 0;
                 while self
                     .get_brake_yellow_accumulator_fluid_volume()
                     .get::<gallon>()
@@ -4322,6 +5808,36 @@ mod tests {
             test_bed()
         }
 
+        #[test]
+        fn reservoirs_and_circuits_follow_loaded_config() {
+            let mut config = A320HydraulicConfig::default();
+            config.yellow.reservoir.level_gallon = 2.;
+            config.blue.accumulator.fluid_volume_gallon = 0.1;
+
+            let mut test_bed = A320HydraulicsTestBed::new_with_config(&config)
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            assert!((test_bed.get_yellow_reservoir_volume().get::<gallon>() - 2.).abs() < 0.01);
+            assert!((test_bed.get_green_reservoir_volume().get::<gallon>() - 3.6).abs() < 0.01);
+            assert!(
+                (test_bed
+                    .query(|a| a.hydraulics.blue_circuit.system_accumulator_fluid_volume())
+                    .get::<gallon>()
+                    - 0.1)
+                    .abs()
+                    < 0.01
+            );
+            assert!(
+                test_bed
+                    .query(|a| a.hydraulics.green_circuit.system_accumulator_fluid_volume())
+                    .get::<gallon>()
+                    < 0.01
+            );
+        }
+
         #[test]
         fn pressure_state_at_init_one_simulation_step() {
             let mut test_bed = test_bed_with()
@@ -4358,6 +5874,99 @@ mod tests {
             assert!(test_bed.yellow_pressure() < Pressure::new::<psi>(50.));
         }
 
+        #[test]
+        fn green_fluid_heats_up_when_engine_driven_pump_pressurises() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            let cold_temperature = test_bed.get_green_fluid_temperature();
+
+            test_bed = test_bed
+                .start_eng1(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(60));
+
+            assert!(test_bed.is_green_pressurised());
+            assert!(
+                test_bed
+                    .get_green_fluid_temperature()
+                    .get::<degree_celsius>()
+                    > cold_temperature.get::<degree_celsius>() + 0.5
+            );
+        }
+
+        #[test]
+        fn ptu_losses_heat_green_fluid_while_transferring() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            let cold_temperature = test_bed.get_green_fluid_temperature();
+
+            test_bed = test_bed
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            let mut transferring_ticks = 0;
+            for _ in 0..20 {
+                test_bed = test_bed.run_one_tick();
+
+                if test_bed.ptu_flow() > VolumeRate::new::<gallon_per_minute>(0.) {
+                    transferring_ticks += 1;
+                    assert!(test_bed.ptu_heat_generated() > Power::new::<watt>(0.));
+                }
+            }
+            assert!(transferring_ticks > 0);
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(60));
+
+            assert!(test_bed.is_green_pressurised());
+            assert!(
+                test_bed
+                    .get_green_fluid_temperature()
+                    .get::<degree_celsius>()
+                    > cold_temperature.get::<degree_celsius>() + 0.5
+            );
+        }
+
+        #[test]
+        fn green_fluid_stays_below_overheat_at_max_flow_on_a_hot_day() {
+            let mut test_bed = test_bed_with().in_flight();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(50.));
+
+            test_bed = test_bed
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_one_tick();
+
+            // Flaps and slats kept moving back and forth until fluid temperature settles
+            let mut previous_temperature = test_bed.get_green_fluid_temperature();
+            for cycle in 0..40 {
+                if cycle == 35 {
+                    previous_temperature = test_bed.get_green_fluid_temperature();
+                }
+
+                test_bed = test_bed
+                    .set_flaps_handle_position(if cycle % 2 == 0 { 4 } else { 0 })
+                    .run_waiting_for(Duration::from_secs(60));
+            }
+
+            let temperature = test_bed.get_green_fluid_temperature();
+            assert!(test_bed.is_green_pressurised());
+            assert!(
+                (temperature.get::<degree_celsius>()
+                    - previous_temperature.get::<degree_celsius>())
+                .abs()
+                    < 1.
+            );
+            assert!(temperature.get::<degree_celsius>() < 95.);
+            assert!(!test_bed.is_green_reservoir_overheat());
+        }
+
         #[test]
         fn ptu_inhibited_by_overhead_off_push_button() {
             let mut test_bed = test_bed_with()
@@ -5846,8 +7455,8 @@ mod tests {
             assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(50.));
             assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
 
-            // Check no more autobrakes after 3s
-            test_bed = test_bed.run_waiting_for(Duration::from_secs(3));
+            // Check no more autobrakes once the gears have left their downlocks
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(10));
 
             assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
             assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
@@ -6161,6 +7770,42 @@ mod tests {
             assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
         }
 
+        #[test]
+        fn autobrakes_do_not_activate_when_ground_spoilers_cannot_extend() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_yellow_ed_pump(false)
+                .set_blue_e_pump(false)
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs(30));
+
+            assert!(test_bed.green_pressure() > Pressure::new::<psi>(2500.));
+            assert!(test_bed.yellow_pressure() < Pressure::new::<psi>(500.));
+            assert!(test_bed.blue_pressure() < Pressure::new::<psi>(500.));
+
+            test_bed = test_bed
+                .set_autobrake_max()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::MAX);
+
+            test_bed = test_bed
+                .set_deploy_spoilers()
+                .run_waiting_for(Duration::from_secs(6));
+
+            // Only the green panels 1 and 5 extend
+            assert!(test_bed.get_spoiler_position("LEFT", 1) > Ratio::new::<ratio>(0.9));
+            assert!(test_bed.get_spoiler_position("LEFT", 3) < Ratio::new::<ratio>(0.1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::MAX);
+            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
+        }
+
         #[test]
         fn autobrakes_disengage_on_spoiler_retract() {
             let mut test_bed = test_bed_with()
@@ -6831,6 +8476,38 @@ mod tests {
             assert!(test_bed.is_slats_moving());
         }
 
+        #[test]
+        fn flap_transmission_failure_locks_flaps_asymmetric() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .fail_left_flap_transmission()
+                .set_yellow_e_pump(false)
+                .set_flaps_handle_position(4)
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.is_flaps_wing_tip_brakes_engaged());
+            assert!(!test_bed.is_flaps_moving());
+            assert!(test_bed.get_flaps_left_position_percent() < 0.1);
+            assert!(test_bed.get_flaps_right_position_percent() > 1.);
+            assert!(test_bed.get_flaps_right_position_percent() < 5.);
+
+            let locked_right_flaps_position = test_bed.get_flaps_right_position_percent();
+            test_bed = test_bed
+                .set_flaps_handle_position(0)
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.is_flaps_wing_tip_brakes_engaged());
+            assert!(
+                (test_bed.get_flaps_right_position_percent() - locked_right_flaps_position).abs()
+                    < 0.01
+            );
+        }
+
         #[test]
         fn yellow_epump_can_deploy_flaps_and_slats() {
             let mut test_bed = test_bed_with()
@@ -7089,78 +8766,172 @@ mod tests {
         }
 
         #[test]
-        fn cargo_door_opened_uses_correct_reservoir_amount() {
+        fn aft_cargo_door_controller_opens_and_closes_aft_door_only() {
             let mut test_bed = test_bed_with()
                 .engines_off()
                 .on_the_ground()
                 .set_cold_dark_inputs()
-                .set_yellow_e_pump(false)
-                .set_ptu_state(false)
-                .run_waiting_for(Duration::from_secs_f64(20.));
-
-            assert!(test_bed.is_cargo_fwd_door_locked_down());
-            assert!(test_bed.is_yellow_pressurised());
+                .run_one_tick();
 
-            let pressurised_yellow_level_door_closed = test_bed.get_yellow_reservoir_volume();
+            assert!(test_bed.is_cargo_aft_door_locked_down());
 
             test_bed = test_bed
-                .open_fwd_cargo_door()
-                .run_waiting_for(Duration::from_secs_f64(40.));
-
-            assert!(!test_bed.is_cargo_fwd_door_locked_down());
-            assert!(test_bed.cargo_fwd_door_position() > 0.85);
+                .open_aft_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(30.));
 
-            let pressurised_yellow_level_door_opened = test_bed.get_yellow_reservoir_volume();
+            assert!(!test_bed.is_cargo_aft_door_locked_down());
+            assert!(test_bed.cargo_aft_door_position() > 85.);
+            assert!(test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.cargo_fwd_door_position() == 0.);
 
-            let volume_used_liter = (pressurised_yellow_level_door_closed
-                - pressurised_yellow_level_door_opened)
-                .get::<liter>();
+            test_bed = test_bed
+                .close_aft_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(60.));
 
-            // For one cargo door we expect losing between 0.6 to 0.8 liter of fluid into the two actuators
-            assert!((0.6..=0.8).contains(&volume_used_liter));
+            assert!(test_bed.is_cargo_aft_door_locked_down());
+            assert!(test_bed.cargo_aft_door_position() <= 0.);
         }
 
         #[test]
-        fn cargo_door_controller_closes_the_door() {
+        fn bulk_cargo_door_is_operated_by_hand() {
             let mut test_bed = test_bed_with()
                 .engines_off()
                 .on_the_ground()
                 .set_cold_dark_inputs()
                 .run_one_tick();
 
+            assert!(test_bed.is_bulk_cargo_door_locked());
+            assert!(test_bed.bulk_cargo_door_position() == 0.);
+
             test_bed = test_bed
-                .open_fwd_cargo_door()
-                .run_waiting_for(Duration::from_secs_f64(30.));
+                .open_bulk_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(1.));
 
-            assert!(!test_bed.is_cargo_fwd_door_locked_down());
-            assert!(test_bed.cargo_fwd_door_position() > 0.85);
+            assert!(!test_bed.is_bulk_cargo_door_locked());
+            assert!(test_bed.bulk_cargo_door_position() > 0.);
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.bulk_cargo_door_position() >= 100.);
+            assert!(!test_bed.is_yellow_pressurised());
 
             test_bed = test_bed
-                .close_fwd_cargo_door()
-                .run_waiting_for(Duration::from_secs_f64(60.));
+                .close_bulk_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(3.));
 
-            assert!(test_bed.is_cargo_fwd_door_locked_down());
-            assert!(test_bed.cargo_fwd_door_position() <= 0.);
+            assert!(!test_bed.is_bulk_cargo_door_locked());
+            assert!(test_bed.bulk_cargo_door_position() > 0.);
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.is_bulk_cargo_door_locked());
+            assert!(test_bed.bulk_cargo_door_position() == 0.);
         }
 
         #[test]
-        fn cargo_door_controller_closes_the_door_after_yellow_pump_auto_shutdown() {
+        fn cargo_doors_are_reported_unlocked_if_any_door_is_not_locked() {
             let mut test_bed = test_bed_with()
                 .engines_off()
                 .on_the_ground()
                 .set_cold_dark_inputs()
                 .run_one_tick();
 
+            assert!(test_bed.all_cargo_doors_locked());
+
             test_bed = test_bed
-                .open_fwd_cargo_door()
-                .run_waiting_for(Duration::from_secs_f64(30.));
+                .open_bulk_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(1.));
 
-            assert!(!test_bed.is_cargo_fwd_door_locked_down());
-            assert!(test_bed.cargo_fwd_door_position() > 0.85);
+            assert!(!test_bed.all_cargo_doors_locked());
 
-            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(30.));
+            test_bed = test_bed
+                .close_bulk_cargo_door()
+                .open_aft_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(10.));
 
-            assert!(!test_bed.is_yellow_pressurised());
+            assert!(test_bed.is_bulk_cargo_door_locked());
+            assert!(!test_bed.all_cargo_doors_locked());
+
+            test_bed = test_bed
+                .close_aft_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(60.));
+
+            assert!(test_bed.all_cargo_doors_locked());
+        }
+
+        #[test]
+        fn cargo_door_opened_uses_correct_reservoir_amount() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_yellow_e_pump(false)
+                .set_ptu_state(false)
+                .run_waiting_for(Duration::from_secs_f64(20.));
+
+            assert!(test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.is_yellow_pressurised());
+
+            let pressurised_yellow_level_door_closed = test_bed.get_yellow_reservoir_volume();
+
+            test_bed = test_bed
+                .open_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(40.));
+
+            assert!(!test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.cargo_fwd_door_position() > 0.85);
+
+            let pressurised_yellow_level_door_opened = test_bed.get_yellow_reservoir_volume();
+
+            let volume_used_liter = (pressurised_yellow_level_door_closed
+                - pressurised_yellow_level_door_opened)
+                .get::<liter>();
+
+            // For one cargo door we expect losing between 0.6 to 0.8 liter of fluid into the two actuators
+            assert!((0.6..=0.8).contains(&volume_used_liter));
+        }
+
+        #[test]
+        fn cargo_door_controller_closes_the_door() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .open_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(!test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.cargo_fwd_door_position() > 0.85);
+
+            test_bed = test_bed
+                .close_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(60.));
+
+            assert!(test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.cargo_fwd_door_position() <= 0.);
+        }
+
+        #[test]
+        fn cargo_door_controller_closes_the_door_after_yellow_pump_auto_shutdown() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .open_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(!test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.cargo_fwd_door_position() > 0.85);
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(!test_bed.is_yellow_pressurised());
 
             test_bed = test_bed
                 .close_fwd_cargo_door()
@@ -7170,6 +8941,89 @@ mod tests {
             assert!(test_bed.cargo_fwd_door_position() <= 0.);
         }
 
+        #[test]
+        fn yellow_hand_pump_pressurises_only_cargo_door_branch_and_brake_accumulator() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .all_electrical_power_lost()
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(!test_bed.is_yellow_hand_pump_branch_pressurised());
+
+            test_bed = test_bed
+                .set_yellow_hand_pump_stroke_rate(1.)
+                .run_waiting_for(Duration::from_secs(60));
+
+            assert!(test_bed.is_yellow_hand_pump_branch_pressurised());
+            assert!(test_bed.get_brake_yellow_accumulator_pressure() > Pressure::new::<psi>(2500.));
+            assert!(!test_bed.is_yellow_pressurised());
+            assert!(test_bed.yellow_pressure() < Pressure::new::<psi>(100.));
+
+            test_bed = test_bed
+                .set_yellow_hand_pump_stroke_rate(0.)
+                .run_waiting_for(Duration::from_secs(60));
+
+            assert!(!test_bed.is_yellow_hand_pump_branch_pressurised());
+        }
+
+        #[test]
+        fn yellow_hand_pump_recharges_brake_accumulator_without_electrical_power() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .all_electrical_power_lost()
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(30));
+
+            let accumulator_volume_before_pumping =
+                test_bed.get_brake_yellow_accumulator_fluid_volume();
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(0.))
+                .set_right_brake(Ratio::new::<percent>(0.))
+                .set_yellow_hand_pump_stroke_rate(1.)
+                .run_waiting_for(Duration::from_secs(60));
+
+            assert!(
+                test_bed.get_brake_yellow_accumulator_fluid_volume()
+                    > accumulator_volume_before_pumping
+            );
+            assert!(test_bed.get_brake_yellow_accumulator_pressure() > Pressure::new::<psi>(2500.));
+        }
+
+        #[test]
+        fn cargo_door_opens_with_yellow_hand_pump_without_electrical_power() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .all_electrical_power_lost()
+                .open_fwd_cargo_door()
+                .run_waiting_for(Duration::from_secs(30));
+
+            assert!(!test_bed.is_cargo_fwd_door_locked_down());
+            assert!(test_bed.cargo_fwd_door_position() < 5.);
+            assert!(!test_bed.query(|a| a.is_yellow_epump_controller_pressurising()));
+
+            test_bed = test_bed
+                .set_yellow_hand_pump_stroke_rate(2.)
+                .run_waiting_for(Duration::from_secs(300));
+
+            assert!(test_bed.cargo_fwd_door_position() > 85.);
+
+            test_bed = test_bed
+                .set_yellow_hand_pump_stroke_rate(0.)
+                .close_fwd_cargo_door()
+                .set_yellow_hand_pump_stroke_rate(2.)
+                .run_waiting_for(Duration::from_secs(60));
+
+            assert!(test_bed.is_cargo_fwd_door_locked_down());
+        }
+
         #[test]
         fn nose_steering_responds_to_tiller_demand_if_yellow_pressure_and_engines() {
             let mut test_bed = test_bed_with()
@@ -7503,7 +9357,7 @@ mod tests {
         }
 
         #[test]
-        fn ailerons_droop_down_after_pressure_is_off() {
+        fn ailerons_do_not_respond_while_elac_restarts_after_power_loss() {
             let mut test_bed = test_bed_with()
                 .engines_off()
                 .on_the_ground()
@@ -7512,22 +9366,1096 @@ mod tests {
                 .set_yellow_e_pump(false)
                 .run_one_tick();
 
+            test_bed = test_bed
+                .set_ailerons_neutral()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed = test_bed
+                .all_elac_power_lost()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            test_bed = test_bed
+                .all_elac_power_restored()
+                .set_ailerons_left_turn()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            assert!(test_bed.get_left_aileron_position().get::<ratio>() < 0.6);
+
             test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(5.));
 
-            assert!(test_bed.is_yellow_pressurised());
-            assert!(test_bed.is_green_pressurised());
-            assert!(test_bed.get_left_aileron_position().get::<ratio>() > 0.4);
-            assert!(test_bed.get_right_aileron_position().get::<ratio>() > 0.4);
+            assert!(test_bed.get_left_aileron_position().get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn elevators_are_dropped_down_in_cold_and_dark() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_waiting_for(Duration::from_secs_f64(2.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() < 0.1);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() < 0.1);
+        }
+
+        #[test]
+        fn elevators_respond_with_all_circuits_pressurised() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            let neutral = 17. / 47.;
+            assert!((test_bed.get_left_elevator_position().get::<ratio>() - neutral).abs() < 0.05);
+            assert!((test_bed.get_right_elevator_position().get::<ratio>() - neutral).abs() < 0.05);
+
+            test_bed = test_bed
+                .set_elevator_full_up()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.9);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.9);
 
             test_bed = test_bed
+                .set_elevator_full_down()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() < 0.1);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() < 0.1);
+        }
+
+        #[test]
+        fn elevators_respond_with_blue_only() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
                 .set_ptu_state(false)
-                .set_yellow_e_pump(true)
-                .run_waiting_for(Duration::from_secs_f64(60.));
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_green_ed_pump(false)
+                .set_yellow_ed_pump(false)
+                .run_waiting_for(Duration::from_secs_f64(30.));
 
+            assert!(!test_bed.is_green_pressurised());
             assert!(!test_bed.is_yellow_pressurised());
+            assert!(test_bed.is_blue_pressurised());
+
+            test_bed = test_bed
+                .set_elevator_full_up()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.9);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn right_elevator_responds_with_yellow_only() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(false)
+                .set_blue_e_pump(false)
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
             assert!(!test_bed.is_green_pressurised());
-            assert!(test_bed.get_left_aileron_position().get::<ratio>() < 0.1);
-            assert!(test_bed.get_right_aileron_position().get::<ratio>() < 0.1);
+            assert!(test_bed.is_yellow_pressurised());
+            assert!(!test_bed.is_blue_pressurised());
+
+            test_bed = test_bed
+                .set_elevator_full_up()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() < 0.1);
+            assert!(test_bed.get_right_elevator_position().get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn elevators_do_not_respond_while_elac_restarts_after_power_loss() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(true)
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            test_bed = test_bed
+                .all_elac_power_lost()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            test_bed = test_bed
+                .all_elac_power_restored()
+                .set_elevator_full_up()
+                .run_waiting_for(Duration::from_secs_f64(1.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() < 0.6);
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_left_elevator_position().get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn rudder_stays_neutral_in_cold_and_dark() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_rudder_full_left()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!((test_bed.get_rudder_position().get::<ratio>() - 0.5).abs() < 0.05);
+        }
+
+        #[test]
+        fn rudder_responds_with_any_circuit_pressurised() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(false)
+                .set_blue_e_pump(false)
+                .set_yellow_e_pump(false)
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(!test_bed.is_green_pressurised());
+            assert!(test_bed.is_yellow_pressurised());
+            assert!(!test_bed.is_blue_pressurised());
+
+            test_bed = test_bed
+                .set_rudder_full_left()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_rudder_position().get::<ratio>() < 0.1);
+
+            test_bed = test_bed
+                .set_rudder_full_right()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_rudder_position().get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn rudder_holds_position_after_pressure_is_off() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(false)
+                .set_blue_e_pump(false)
+                .set_yellow_e_pump(false)
+                .set_rudder_full_right()
+                .run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.get_rudder_position().get::<ratio>() > 0.9);
+
+            test_bed = test_bed
+                .set_yellow_e_pump(true)
+                .run_waiting_for(Duration::from_secs_f64(30.));
+
+            assert!(!test_bed.is_yellow_pressurised());
+
+            test_bed = test_bed
+                .set_rudder_neutral()
+                .run_waiting_for(Duration::from_secs_f64(10.));
+
+            assert!(test_bed.get_rudder_position().get::<ratio>() > 0.8);
+        }
+
+        #[test]
+        fn ailerons_droop_down_after_pressure_is_off() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_ptu_state(true)
+                .set_yellow_e_pump(false)
+                .run_one_tick();
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs_f64(5.));
+
+            assert!(test_bed.is_yellow_pressurised());
+            assert!(test_bed.is_green_pressurised());
+            assert!(test_bed.get_left_aileron_position().get::<ratio>() > 0.4);
+            assert!(test_bed.get_right_aileron_position().get::<ratio>() > 0.4);
+
+            test_bed = test_bed
+                .set_ptu_state(false)
+                .set_yellow_e_pump(true)
+                .run_waiting_for(Duration::from_secs_f64(60.));
+
+            assert!(!test_bed.is_yellow_pressurised());
+            assert!(!test_bed.is_green_pressurised());
+            assert!(test_bed.get_left_aileron_position().get::<ratio>() < 0.1);
+            assert!(test_bed.get_right_aileron_position().get::<ratio>() < 0.1);
+        }
+
+        #[test]
+        fn gear_retracts_after_take_off_and_doors_close() {
+            let mut test_bed = test_bed_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+
+            test_bed = test_bed
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.is_gear_down_and_locked());
+
+            test_bed = test_bed
+                .rotates_on_runway()
+                .set_gear_up()
+                .run_waiting_for(Duration::from_secs(6));
+
+            assert!(!test_bed.is_gear_down_and_locked());
+            assert!(!test_bed.is_gear_up_and_locked());
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(25));
+
+            assert!(test_bed.is_gear_up_and_locked());
+            assert!(test_bed.gear_door_position("LEFT").get::<ratio>() < 0.01);
+            assert!(test_bed.gear_door_position("RIGHT").get::<ratio>() < 0.01);
+            assert!(test_bed.gear_door_position("CENTER").get::<ratio>() < 0.01);
+        }
+
+        #[test]
+        fn gear_starts_up_and_locked_when_starting_in_flight_with_gear_lever_up() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.is_gear_up_and_locked());
+            assert!(test_bed.gear_door_position("LEFT").get::<ratio>() < 0.01);
+            assert!(test_bed.gear_door_position("RIGHT").get::<ratio>() < 0.01);
+            assert!(test_bed.gear_door_position("CENTER").get::<ratio>() < 0.01);
+        }
+
+        #[test]
+        fn gear_starts_down_and_locked_when_starting_in_flight_with_gear_lever_down() {
+            let test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_down()
+                .run_one_tick();
+
+            assert!(test_bed.is_gear_down_and_locked());
+        }
+
+        #[test]
+        fn gear_starts_down_and_locked_when_starting_on_ground() {
+            let test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .run_one_tick();
+
+            assert!(test_bed.is_gear_down_and_locked());
+        }
+
+        #[test]
+        fn gear_extends_in_flight_with_green_pressure() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.is_gear_up_and_locked());
+
+            test_bed = test_bed
+                .set_gear_down()
+                .run_waiting_for(Duration::from_secs(35));
+
+            assert!(test_bed.is_gear_down_and_locked());
+            assert!(test_bed.gear_door_position("LEFT").get::<ratio>() < 0.01);
+            assert!(test_bed.gear_door_position("RIGHT").get::<ratio>() < 0.01);
+            assert!(test_bed.gear_door_position("CENTER").get::<ratio>() < 0.01);
+        }
+
+        #[test]
+        fn gear_stays_up_and_locked_without_green_pressure() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ptu_state(false)
+                .set_green_ed_pump(false)
+                .run_waiting_for(Duration::from_secs(30));
+
+            assert!(!test_bed.is_green_pressurised());
+
+            test_bed = test_bed
+                .set_gear_down()
+                .run_waiting_for(Duration::from_secs(30));
+
+            assert!(test_bed.is_gear_up_and_locked());
+        }
+
+        #[test]
+        fn gear_gravity_extension_without_green_pressure() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ptu_state(false)
+                .set_green_ed_pump(false)
+                .run_waiting_for(Duration::from_secs(30));
+
+            assert!(!test_bed.is_green_pressurised());
+
+            test_bed = test_bed
+                .set_gear_down()
+                .set_gravity_extension_handle_turns(3.)
+                .run_waiting_for(Duration::from_secs(30));
+
+            assert!(test_bed.is_gear_down_and_locked());
+        }
+
+        #[test]
+        fn gear_gravity_extension_needs_three_handle_turns() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ptu_state(false)
+                .set_green_ed_pump(false)
+                .run_waiting_for(Duration::from_secs(30));
+
+            test_bed = test_bed
+                .set_gear_down()
+                .set_gravity_extension_handle_turns(2.)
+                .run_waiting_for(Duration::from_secs(30));
+
+            assert!(test_bed.is_gear_up_and_locked());
+            assert!(test_bed.gear_door_position("LEFT").get::<ratio>() > 0.9);
+        }
+
+        #[test]
+        fn speedbrake_extends_panels_2_to_4_in_flight() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .set_speedbrake_handle(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            for side in ["LEFT", "RIGHT"] {
+                assert!(test_bed.get_spoiler_position(side, 1).get::<ratio>() < 0.01);
+                for number in 2..=4 {
+                    assert!(test_bed.get_spoiler_position(side, number).get::<ratio>() > 0.75);
+                    assert!(test_bed.get_spoiler_position(side, number).get::<ratio>() < 0.85);
+                }
+                assert!(test_bed.get_spoiler_position(side, 5).get::<ratio>() < 0.01);
+            }
+
+            test_bed = test_bed
+                .set_speedbrake_handle(Ratio::new::<ratio>(0.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            for side in ["LEFT", "RIGHT"] {
+                for number in 1..=5 {
+                    assert!(test_bed.get_spoiler_position(side, number).get::<ratio>() < 0.01);
+                }
+            }
+        }
+
+        #[test]
+        fn speedbrake_panels_on_lost_circuit_retract() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_speedbrake_handle(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.get_spoiler_position("LEFT", 2).get::<ratio>() > 0.75);
+            assert!(test_bed.get_spoiler_position("LEFT", 3).get::<ratio>() > 0.75);
+            assert!(test_bed.get_spoiler_position("LEFT", 4).get::<ratio>() > 0.75);
+
+            test_bed = test_bed
+                .set_ptu_state(false)
+                .set_yellow_ed_pump(false)
+                .run_waiting_for(Duration::from_secs(60));
+
+            assert!(!test_bed.is_yellow_pressurised());
+            assert!(test_bed.is_blue_pressurised());
+
+            for side in ["LEFT", "RIGHT"] {
+                assert!(test_bed.get_spoiler_position(side, 2).get::<ratio>() < 0.2);
+                assert!(test_bed.get_spoiler_position(side, 3).get::<ratio>() > 0.7);
+                assert!(test_bed.get_spoiler_position(side, 4).get::<ratio>() < 0.2);
+            }
+        }
+
+        #[test]
+        fn ground_spoilers_extend_at_touch_down_when_armed() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .arm_ground_spoilers(true)
+                .set_throttles_angle(Angle::new::<degree>(0.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            for number in 1..=5 {
+                assert!(test_bed.get_spoiler_position("LEFT", number).get::<ratio>() < 0.01);
+            }
+
+            test_bed = test_bed
+                .touches_down()
+                .run_waiting_for(Duration::from_secs(5));
+
+            for side in ["LEFT", "RIGHT"] {
+                for number in 1..=5 {
+                    assert!(test_bed.get_spoiler_position(side, number).get::<ratio>() > 0.95);
+                }
+            }
+        }
+
+        #[test]
+        fn ground_spoilers_do_not_extend_at_touch_down_when_not_armed() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .arm_ground_spoilers(false)
+                .set_throttles_angle(Angle::new::<degree>(0.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .touches_down()
+                .run_waiting_for(Duration::from_secs(5));
+
+            for number in 1..=5 {
+                assert!(test_bed.get_spoiler_position("LEFT", number).get::<ratio>() < 0.01);
+            }
+        }
+
+        #[test]
+        fn ground_spoilers_extend_with_reverse_when_not_armed() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .arm_ground_spoilers(false)
+                .set_throttles_angle(Angle::new::<degree>(0.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .touches_down()
+                .set_throttles_angle(Angle::new::<degree>(-20.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            for number in 1..=5 {
+                assert!(test_bed.get_spoiler_position("LEFT", number).get::<ratio>() > 0.95);
+            }
+        }
+
+        #[test]
+        fn ground_spoilers_retract_when_disarmed_after_landing() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .arm_ground_spoilers(true)
+                .set_throttles_angle(Angle::new::<degree>(0.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .touches_down()
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<knot>(20.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.get_spoiler_position("LEFT", 1).get::<ratio>() > 0.95);
+
+            test_bed = test_bed
+                .arm_ground_spoilers(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            for number in 1..=5 {
+                assert!(test_bed.get_spoiler_position("LEFT", number).get::<ratio>() < 0.01);
+            }
+        }
+
+        #[test]
+        fn ground_spoilers_retract_on_touch_and_go() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .arm_ground_spoilers(true)
+                .set_throttles_angle(Angle::new::<degree>(0.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .touches_down()
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.get_spoiler_position("LEFT", 1).get::<ratio>() > 0.95);
+
+            test_bed = test_bed
+                .set_throttles_angle(Angle::new::<degree>(45.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            for number in 1..=5 {
+                assert!(test_bed.get_spoiler_position("LEFT", number).get::<ratio>() < 0.01);
+            }
+        }
+
+        #[test]
+        fn ground_spoilers_do_not_extend_when_armed_on_ground_at_low_speed() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .arm_ground_spoilers(true)
+                .set_throttles_angle(Angle::new::<degree>(0.))
+                .set_ground_speed(Velocity::new::<knot>(20.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            for number in 1..=5 {
+                assert!(test_bed.get_spoiler_position("LEFT", number).get::<ratio>() < 0.01);
+            }
+        }
+
+        #[test]
+        fn ground_spoilers_extend_on_rejected_take_off() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .arm_ground_spoilers(true)
+                .set_throttles_angle(Angle::new::<degree>(45.))
+                .set_ground_speed(Velocity::new::<knot>(100.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            for number in 1..=5 {
+                assert!(test_bed.get_spoiler_position("LEFT", number).get::<ratio>() < 0.01);
+            }
+
+            test_bed = test_bed
+                .set_throttles_angle(Angle::new::<degree>(0.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            for number in 1..=5 {
+                assert!(test_bed.get_spoiler_position("LEFT", number).get::<ratio>() > 0.95);
+            }
+        }
+
+        #[test]
+        fn brakes_heat_up_when_braking_on_ground() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_ground_speed(Velocity::new::<knot>(30.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            let initial_temperature = test_bed.get_brake_temperature(1);
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.get_brake_temperature(1) > initial_temperature);
+            assert!(test_bed.get_brake_temperature(2) > initial_temperature);
+            assert!(
+                (test_bed.get_brake_temperature(3).get::<degree_celsius>()
+                    - initial_temperature.get::<degree_celsius>())
+                .abs()
+                    < 1.
+            );
+            assert!(
+                (test_bed.get_brake_temperature(4).get::<degree_celsius>()
+                    - initial_temperature.get::<degree_celsius>())
+                .abs()
+                    < 1.
+            );
+        }
+
+        #[test]
+        fn brakes_do_not_heat_up_in_flight() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_gear_down()
+                .set_ground_speed(Velocity::new::<knot>(140.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            let initial_temperature = test_bed.get_brake_temperature(1);
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<ratio>(1.))
+                .set_right_brake(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.get_brake_temperature(1) <= initial_temperature);
+        }
+
+        #[test]
+        fn brakes_are_hot_after_rejected_take_off() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(!test_bed.are_brakes_hot());
+
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<knot>(100.))
+                .set_left_brake(Ratio::new::<ratio>(1.))
+                .set_right_brake(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(10))
+                .set_ground_speed(Velocity::new::<knot>(0.))
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.are_brakes_hot());
+            for wheel_number in 1..=4 {
+                assert!(!test_bed.is_brake_fuse_plug_melted(wheel_number));
+            }
+
+            // Heat slowly soaks into the wheel rims until fuse plugs melt
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(30 * 60));
+
+            for wheel_number in 1..=4 {
+                assert!(test_bed.is_brake_fuse_plug_melted(wheel_number));
+            }
+        }
+
+        #[test]
+        fn brake_fan_runs_when_pressed_and_powered() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_brake_fan(true)
+                .run_one_tick();
+
+            assert!(test_bed.is_brake_fan_on());
+
+            test_bed = test_bed.set_brake_fan(false).run_one_tick();
+
+            assert!(!test_bed.is_brake_fan_on());
+
+            test_bed = test_bed.set_brake_fan(true).ac_bus_2_lost().run_one_tick();
+
+            assert!(!test_bed.is_brake_fan_on());
+        }
+
+        #[test]
+        fn brake_fan_stops_with_gear_up() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_brake_fan(true)
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(!test_bed.is_brake_fan_on());
+        }
+
+        #[test]
+        fn brake_fan_lowers_reported_temperature() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_ground_speed(Velocity::new::<knot>(60.))
+                .set_left_brake(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(10))
+                .set_ground_speed(Velocity::new::<knot>(0.))
+                .set_left_brake(Ratio::new::<ratio>(0.))
+                .set_brake_fan(true)
+                .run_waiting_for(Duration::from_secs(10 * 60));
+
+            assert!(test_bed.is_brake_fan_on());
+            assert!(
+                test_bed
+                    .get_reported_brake_temperature(1)
+                    .get::<degree_celsius>()
+                    < test_bed.get_brake_temperature(1).get::<degree_celsius>() - 10.
+            );
+        }
+
+        fn full_braking_at_speed(
+            runway_condition: RunwayCondition,
+            anti_skid: bool,
+        ) -> A320HydraulicsTestBed {
+            test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .set_anti_skid(anti_skid)
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(5))
+                .set_runway_condition(runway_condition)
+                .set_ground_speed(Velocity::new::<knot>(100.))
+                .set_left_brake(Ratio::new::<ratio>(1.))
+                .set_right_brake(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(3))
+        }
+
+        // Anti skid cycles the brakes, so the force is sampled over several cycles
+        fn left_braking_forces(mut test_bed: A320HydraulicsTestBed) -> Vec<f64> {
+            let mut forces = vec![];
+            for _ in 0..20 {
+                test_bed = test_bed.run_one_tick();
+                forces.push(test_bed.get_left_braking_force());
+            }
+
+            forces
+        }
+
+        // The lowest left and right brake pressure over several anti skid cycles
+        fn minimum_brake_pressures(
+            mut test_bed: A320HydraulicsTestBed,
+            brake_pressures: impl Fn(&mut A320HydraulicsTestBed) -> (Pressure, Pressure),
+        ) -> (Pressure, Pressure) {
+            let mut minimum_left = Pressure::new::<psi>(f64::MAX);
+            let mut minimum_right = Pressure::new::<psi>(f64::MAX);
+            for _ in 0..20 {
+                test_bed = test_bed.run_one_tick();
+                let (left, right) = brake_pressures(&mut test_bed);
+                minimum_left = minimum_left.min(left);
+                minimum_right = minimum_right.min(right);
+            }
+
+            (minimum_left, minimum_right)
+        }
+
+        fn mean(values: &[f64]) -> f64 {
+            values.iter().sum::<f64>() / values.len() as f64
+        }
+
+        fn max(values: &[f64]) -> f64 {
+            values.iter().cloned().fold(f64::MIN, f64::max)
+        }
+
+        #[test]
+        fn braking_force_is_lower_on_contaminated_runway() {
+            let dry_force =
+                full_braking_at_speed(RunwayCondition::Dry, true).get_left_braking_force();
+            let wet_force =
+                full_braking_at_speed(RunwayCondition::Wet, true).get_left_braking_force();
+            let snow_force =
+                full_braking_at_speed(RunwayCondition::Snow, true).get_left_braking_force();
+
+            assert!(dry_force > 0.9);
+            assert!(wet_force < dry_force);
+            assert!(snow_force < wet_force);
+        }
+
+        #[test]
+        fn anti_skid_does_not_release_brakes_on_dry_runway() {
+            let mut test_bed = full_braking_at_speed(RunwayCondition::Dry, true);
+
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(2300.));
+            assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(2300.));
+        }
+
+        #[test]
+        fn anti_skid_releases_normal_brakes_on_icy_runway() {
+            let mut test_bed = full_braking_at_speed(RunwayCondition::Icy, true);
+            assert!(test_bed.get_left_braking_force() > 0.);
+
+            let (left, right) = minimum_brake_pressures(test_bed, |test_bed| {
+                (
+                    test_bed.get_brake_left_green_pressure(),
+                    test_bed.get_brake_right_green_pressure(),
+                )
+            });
+
+            assert!(left < Pressure::new::<psi>(1000.));
+            assert!(right < Pressure::new::<psi>(1000.));
+        }
+
+        #[test]
+        fn anti_skid_releases_alternate_brakes_on_icy_runway() {
+            let test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .set_ptu_state(false)
+                .start_eng2(Ratio::new::<percent>(80.))
+                .run_waiting_for(Duration::from_secs(5))
+                .set_runway_condition(RunwayCondition::Icy)
+                .set_ground_speed(Velocity::new::<knot>(100.))
+                .set_left_brake(Ratio::new::<ratio>(1.))
+                .set_right_brake(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            assert!(!test_bed.is_green_pressurised());
+
+            let (left, right) = minimum_brake_pressures(test_bed, |test_bed| {
+                (
+                    test_bed.get_brake_left_yellow_pressure(),
+                    test_bed.get_brake_right_yellow_pressure(),
+                )
+            });
+
+            assert!(left < Pressure::new::<psi>(1000.));
+            assert!(right < Pressure::new::<psi>(1000.));
+        }
+
+        #[test]
+        fn wheels_lock_on_icy_runway_without_anti_skid() {
+            let mut test_bed = full_braking_at_speed(RunwayCondition::Icy, false);
+
+            // Alternate brakes keep their full pressure whatever the wheels do
+            assert!(test_bed.get_brake_left_yellow_pressure() > Pressure::new::<psi>(1000.));
+
+            let locked_wheels_forces = left_braking_forces(test_bed);
+            let anti_skid_forces =
+                left_braking_forces(full_braking_at_speed(RunwayCondition::Icy, true));
+
+            assert!(max(&locked_wheels_forces) < max(&anti_skid_forces));
+        }
+
+        #[test]
+        fn anti_skid_cycles_braking_force_on_icy_runway() {
+            let anti_skid_forces =
+                left_braking_forces(full_braking_at_speed(RunwayCondition::Icy, true));
+            let dry_forces = left_braking_forces(full_braking_at_speed(RunwayCondition::Dry, true));
+
+            // The force drops within each cycle as the brakes are released
+            assert!(anti_skid_forces
+                .windows(2)
+                .any(|pair| pair[1] < pair[0] - 0.005));
+            assert!(mean(&anti_skid_forces) < mean(&dry_forces));
+        }
+
+        #[test]
+        fn brakes_hold_aircraft_at_standstill_on_any_runway() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_runway_condition(RunwayCondition::Icy)
+                .run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.get_left_braking_force() > 0.);
+        }
+
+        fn btv_braking_with_exit_at(exit_distance: Length) -> A320HydraulicsTestBed {
+            test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10))
+                .set_autobrake_btv(exit_distance)
+                .run_waiting_for(Duration::from_secs(1))
+                .set_deploy_spoilers()
+                .run_waiting_for(Duration::from_secs(8))
+        }
+
+        #[test]
+        fn autobrakes_btv_disarms_without_selected_exit() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10))
+                .set_autobrake_btv(Length::new::<meter>(0.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::NONE);
+        }
+
+        #[test]
+        fn autobrakes_btv_arms_with_selected_exit() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10))
+                .set_autobrake_btv(Length::new::<meter>(1500.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::BTV);
+        }
+
+        #[test]
+        fn autobrakes_btv_brakes_on_spoiler_deploy() {
+            let mut test_bed = btv_braking_with_exit_at(Length::new::<meter>(1200.));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::BTV);
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(500.));
+            assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(500.));
+        }
+
+        #[test]
+        fn autobrakes_btv_decelerates_harder_for_closer_exit() {
+            let far_exit_target =
+                btv_braking_with_exit_at(Length::new::<meter>(2000.)).get_autobrake_target();
+            let close_exit_target =
+                btv_braking_with_exit_at(Length::new::<meter>(900.)).get_autobrake_target();
+
+            assert!(far_exit_target.get::<meter_per_second_squared>() < 0.);
+            assert!(close_exit_target < far_exit_target);
+        }
+
+        #[test]
+        fn autobrakes_btv_deceleration_is_limited_for_a_too_close_exit() {
+            let test_bed = btv_braking_with_exit_at(Length::new::<meter>(200.));
+
+            assert!(
+                (test_bed
+                    .get_autobrake_target()
+                    .get::<meter_per_second_squared>()
+                    + 3.)
+                    .abs()
+                    < 0.01
+            );
+        }
+
+        #[test]
+        fn autobrakes_btv_ramps_deceleration_up_after_startup() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10))
+                .set_autobrake_btv(Length::new::<meter>(200.))
+                .run_waiting_for(Duration::from_secs(1))
+                .set_deploy_spoilers();
+
+            let mut last_target = test_bed.get_autobrake_target();
+            for _ in 0..300 {
+                test_bed = test_bed.run_one_tick();
+                let target = test_bed.get_autobrake_target();
+
+                if last_target.get::<meter_per_second_squared>() <= 0. {
+                    assert!(
+                        (last_target - target).get::<meter_per_second_squared>()
+                            < 0.8 * A320Hydraulic::HYDRAULIC_SIM_TIME_STEP.as_secs_f64() + 0.01
+                    );
+                }
+
+                last_target = target;
+            }
+
+            assert!(
+                (test_bed
+                    .get_autobrake_target()
+                    .get::<meter_per_second_squared>()
+                    + 3.)
+                    .abs()
+                    < 0.01
+            );
+        }
+
+        #[test]
+        fn autobrakes_btv_releases_brakes_at_exit_speed() {
+            let mut test_bed = btv_braking_with_exit_at(Length::new::<meter>(900.));
+
+            assert!(
+                test_bed
+                    .get_autobrake_target()
+                    .get::<meter_per_second_squared>()
+                    < 0.
+            );
+
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<knot>(8.))
+                .set_btv_exit_distance(Length::new::<meter>(30.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::BTV);
+            assert!(
+                test_bed
+                    .get_autobrake_target()
+                    .get::<meter_per_second_squared>()
+                    > 0.
+            );
+        }
+
+        #[test]
+        fn autobrakes_btv_publishes_predicted_stopping_distances() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_ground_speed(Velocity::new::<knot>(100.))
+                .run_one_tick();
+
+            let dry_distance = test_bed.get_btv_dry_stopping_distance();
+            let wet_distance = test_bed.get_btv_wet_stopping_distance();
+
+            assert!((dry_distance.get::<meter>() - 264.6).abs() < 1.);
+            assert!(wet_distance > dry_distance);
+        }
+
+        #[test]
+        fn autobrakes_btv_does_not_publish_stopping_distances_in_flight_when_not_armed() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .in_flight()
+                .set_ground_speed(Velocity::new::<knot>(250.))
+                .run_one_tick();
+
+            assert!(test_bed.get_btv_dry_stopping_distance() == Length::new::<meter>(0.));
+            assert!(test_bed.get_btv_wet_stopping_distance() == Length::new::<meter>(0.));
+        }
+
+        #[test]
+        fn autobrakes_btv_resets_stopping_distances_after_take_off() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_ground_speed(Velocity::new::<knot>(140.))
+                .run_one_tick();
+
+            assert!(test_bed.get_btv_dry_stopping_distance() > Length::new::<meter>(0.));
+
+            test_bed = test_bed.in_flight().run_one_tick();
+
+            assert!(test_bed.get_btv_dry_stopping_distance() == Length::new::<meter>(0.));
+            assert!(test_bed.get_btv_wet_stopping_distance() == Length::new::<meter>(0.));
+        }
+
+        #[test]
+        fn autobrakes_btv_exit_distance_decreases_with_distance_rolled() {
+            let mut test_bed = test_bed_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_autobrake_btv(Length::new::<meter>(1500.))
+                .run_one_tick();
+
+            assert!(test_bed.get_btv_exit_distance() == Length::new::<meter>(1500.));
+
+            // 20 m/s for 10 s
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<meter_per_second>(20.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!((test_bed.get_btv_exit_distance().get::<meter>() - 1300.).abs() < 5.);
+
+            test_bed = test_bed
+                .set_btv_exit_distance(Length::new::<meter>(1000.))
+                .run_one_tick();
+
+            assert!((test_bed.get_btv_exit_distance().get::<meter>() - 1000.).abs() < 1.);
         }
     }
 }
@@ -0,0 +1,589 @@
+use nalgebra::Vector3;
+
+use std::time::Duration;
+use uom::si::{
+    angle::degree, f64::*, length::meter, mass::kilogram, pressure::psi, ratio::ratio,
+    velocity::knot, volume_rate::gallon_per_second,
+};
+
+use systems::{
+    hydraulic::linear_actuator::{
+        Actuator, BoundedLinearLength, HydraulicAssemblyController,
+        HydraulicLinearActuatorAssembly, LinearActuatedRigidBodyOnHingeAxis, LinearActuator,
+        LinearActuatorMode,
+    },
+    shared::{DelayedTrueLogicGate, HydraulicColor, LgciuSensors},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+struct A320SpoilerFactory {}
+impl A320SpoilerFactory {
+    const FLOW_CONTROL_PROPORTIONAL_GAIN: f64 = 1.5;
+    const FLOW_CONTROL_INTEGRAL_GAIN: f64 = 3.;
+    const FLOW_CONTROL_FORCE_GAIN: f64 = 200000.;
+
+    fn a320_spoiler_actuator(bounded_linear_length: &impl BoundedLinearLength) -> LinearActuator {
+        LinearActuator::new(
+            bounded_linear_length,
+            1,
+            Length::new::<meter>(0.03),
+            Length::new::<meter>(0.),
+            VolumeRate::new::<gallon_per_second>(0.01),
+            80000.,
+            1500.,
+            5000.,
+            800000.,
+            Duration::from_millis(300),
+            [1., 1., 1., 1., 1., 1.],
+            [0., 0.2, 0.21, 0.79, 0.8, 1.],
+            Self::FLOW_CONTROL_PROPORTIONAL_GAIN,
+            Self::FLOW_CONTROL_INTEGRAL_GAIN,
+            Self::FLOW_CONTROL_FORCE_GAIN,
+        )
+    }
+
+    /// Builds a spoiler panel body. Position 0 is panel retracted, position 1 is panel fully
+    /// extended at 50 degrees.
+    fn a320_spoiler_body() -> LinearActuatedRigidBodyOnHingeAxis {
+        let size = Vector3::new(1.6, 0.03, 0.5);
+        let cg_offset = Vector3::new(0., 0., -0.5 * size[2]);
+
+        let control_arm = Vector3::new(0., -0.067, 0.);
+        let anchor = Vector3::new(0., -0.067, 0.26);
+
+        LinearActuatedRigidBodyOnHingeAxis::new(
+            Mass::new::<kilogram>(16.),
+            size,
+            cg_offset,
+            control_arm,
+            anchor,
+            Angle::new::<degree>(0.),
+            Angle::new::<degree>(50.),
+            Angle::new::<degree>(0.),
+            1.,
+            false,
+            Vector3::new(1., 0., 0.),
+        )
+    }
+
+    fn a320_spoiler_assembly() -> HydraulicLinearActuatorAssembly<1> {
+        let spoiler_body = A320SpoilerFactory::a320_spoiler_body();
+        let spoiler_actuator = A320SpoilerFactory::a320_spoiler_actuator(&spoiler_body);
+
+        HydraulicLinearActuatorAssembly::new([spoiler_actuator], spoiler_body)
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub(super) enum SpoilerSide {
+    Left,
+    Right,
+}
+
+#[derive(PartialEq, PartialOrd, Clone, Copy)]
+enum GroundSpoilerExtension {
+    Retracted,
+    Partial,
+    Full,
+}
+
+struct SpoilerController {
+    mode: LinearActuatorMode,
+    requested_position: Ratio,
+}
+impl SpoilerController {
+    fn new() -> Self {
+        Self {
+            mode: LinearActuatorMode::ClosedCircuitDamping,
+            requested_position: Ratio::new::<ratio>(0.),
+        }
+    }
+
+    fn set_mode(&mut self, mode: LinearActuatorMode) {
+        self.mode = mode;
+    }
+
+    /// Receives a [0;1] position request, 0 is retracted 1 is fully extended
+    fn set_requested_position(&mut self, requested_position: Ratio) {
+        self.requested_position = requested_position
+            .min(Ratio::new::<ratio>(1.))
+            .max(Ratio::new::<ratio>(0.));
+    }
+}
+impl HydraulicAssemblyController for SpoilerController {
+    fn requested_mode(&self) -> LinearActuatorMode {
+        self.mode
+    }
+
+    fn requested_position(&self) -> Ratio {
+        self.requested_position
+    }
+
+    fn should_lock(&self) -> bool {
+        false
+    }
+
+    fn requested_lock_position(&self) -> Ratio {
+        Ratio::default()
+    }
+}
+
+/// Implements a placeholder spoiler elevator computer logic commanding the ten spoiler panels
+/// from the speedbrake lever and the ground spoiler arming logic.
+/// Panels are numbered from 1 inboard to 5 outboard. Speedbrake function uses panels 2 to 4,
+/// ground spoiler function uses all panels.
+/// A panel which hydraulic circuit is lost is no longer controlled and is left to retract.
+pub(super) struct A320SpoilerController {
+    spoilers_armed_id: VariableIdentifier,
+    speedbrake_handle_position_id: VariableIdentifier,
+    throttle_lever_1_angle_id: VariableIdentifier,
+    throttle_lever_2_angle_id: VariableIdentifier,
+    ground_speed_id: VariableIdentifier,
+
+    ground_spoilers_are_armed: bool,
+    speedbrake_handle_position: Ratio,
+    throttle_lever_1_angle: Angle,
+    throttle_lever_2_angle: Angle,
+    ground_speed: Velocity,
+
+    is_in_landing_phase: bool,
+    has_been_airborne_long_enough: DelayedTrueLogicGate,
+    ground_spoiler_extension: GroundSpoilerExtension,
+
+    left_controllers: [SpoilerController; 5],
+    right_controllers: [SpoilerController; 5],
+}
+impl A320SpoilerController {
+    const SPEEDBRAKE_PANELS_INDEXES: [usize; 3] = [1, 2, 3];
+    const SPEEDBRAKE_MAX_POSITION: f64 = 0.8;
+    const GROUND_SPOILER_PARTIAL_POSITION: f64 = 0.2;
+    const GROUND_SPOILER_FULL_POSITION: f64 = 1.;
+
+    const MIN_PRESSURE_FOR_POSITION_CONTROL_PSI: f64 = 1500.;
+
+    const MINIMUM_AIRBORNE_TIME: Duration = Duration::from_secs(5);
+    const GROUND_SPEED_CONDITION_KNOT: f64 = 72.;
+
+    const TLA_IDLE_DEGREE: f64 = 0.;
+    const TLA_CLB_DEGREE: f64 = 25.;
+    const TLA_MCT_DEGREE: f64 = 35.;
+    const TLA_TOUCH_AND_GO_DEGREE: f64 = 20.;
+
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        Self {
+            spoilers_armed_id: context.get_identifier("SPOILERS_ARMED".to_owned()),
+            speedbrake_handle_position_id: context
+                .get_identifier("SPOILERS_HANDLE_POSITION".to_owned()),
+            throttle_lever_1_angle_id: context.get_identifier("AUTOTHRUST_TLA:1".to_owned()),
+            throttle_lever_2_angle_id: context.get_identifier("AUTOTHRUST_TLA:2".to_owned()),
+            ground_speed_id: context.get_identifier("GPS GROUND SPEED".to_owned()),
+
+            ground_spoilers_are_armed: false,
+            speedbrake_handle_position: Ratio::new::<ratio>(0.),
+            throttle_lever_1_angle: Angle::new::<degree>(0.),
+            throttle_lever_2_angle: Angle::new::<degree>(0.),
+            ground_speed: Velocity::new::<knot>(0.),
+
+            is_in_landing_phase: false,
+            has_been_airborne_long_enough: DelayedTrueLogicGate::new(Self::MINIMUM_AIRBORNE_TIME),
+            ground_spoiler_extension: GroundSpoilerExtension::Retracted,
+
+            left_controllers: [
+                SpoilerController::new(),
+                SpoilerController::new(),
+                SpoilerController::new(),
+                SpoilerController::new(),
+                SpoilerController::new(),
+            ],
+            right_controllers: [
+                SpoilerController::new(),
+                SpoilerController::new(),
+                SpoilerController::new(),
+                SpoilerController::new(),
+                SpoilerController::new(),
+            ],
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        lgciu1: &impl LgciuSensors,
+        lgciu2: &impl LgciuSensors,
+        green_pressure: Pressure,
+        yellow_pressure: Pressure,
+        blue_pressure: Pressure,
+    ) {
+        let main_gears_on_ground = Self::main_gears_on_ground(lgciu1, lgciu2);
+
+        self.update_flight_phase(context, main_gears_on_ground);
+        self.update_ground_spoiler_extension(main_gears_on_ground);
+
+        self.update_requested_positions();
+        self.update_modes(green_pressure, yellow_pressure, blue_pressure);
+    }
+
+    fn main_gears_on_ground(lgciu1: &impl LgciuSensors, lgciu2: &impl LgciuSensors) -> usize {
+        let left_on_ground =
+            lgciu1.left_gear_compressed(false) || lgciu2.left_gear_compressed(false);
+        let right_on_ground =
+            lgciu1.right_gear_compressed(false) || lgciu2.right_gear_compressed(false);
+
+        left_on_ground as usize + right_on_ground as usize
+    }
+
+    fn update_flight_phase(&mut self, context: &UpdateContext, main_gears_on_ground: usize) {
+        self.has_been_airborne_long_enough
+            .update(context, main_gears_on_ground == 0);
+
+        if self.has_been_airborne_long_enough.output() {
+            self.is_in_landing_phase = true;
+        } else if main_gears_on_ground == 2 && !self.is_above_ground_speed_condition() {
+            self.is_in_landing_phase = false;
+        }
+    }
+
+    fn update_ground_spoiler_extension(&mut self, main_gears_on_ground: usize) {
+        self.ground_spoiler_extension = if self.should_retract_ground_spoilers(main_gears_on_ground)
+        {
+            GroundSpoilerExtension::Retracted
+        } else {
+            let extension = self.ground_spoiler_extension_condition(main_gears_on_ground);
+
+            if extension > self.ground_spoiler_extension {
+                extension
+            } else {
+                self.ground_spoiler_extension
+            }
+        };
+    }
+
+    fn should_retract_ground_spoilers(&self, main_gears_on_ground: usize) -> bool {
+        let is_touch_and_go = main_gears_on_ground > 0
+            && (self.throttle_lever_1_angle.get::<degree>() > Self::TLA_TOUCH_AND_GO_DEGREE
+                || self.throttle_lever_2_angle.get::<degree>() > Self::TLA_TOUCH_AND_GO_DEGREE);
+
+        let is_disarmed = !self.ground_spoilers_are_armed
+            && !self.is_speedbrake_handle_extended()
+            && !self.is_at_least_one_throttle_in_reverse();
+
+        is_touch_and_go || is_disarmed
+    }
+
+    fn ground_spoiler_extension_condition(
+        &self,
+        main_gears_on_ground: usize,
+    ) -> GroundSpoilerExtension {
+        if self.is_in_rejected_take_off_condition(main_gears_on_ground) {
+            if (self.ground_spoilers_are_armed
+                && self.are_throttles_at_or_below(Self::TLA_IDLE_DEGREE))
+                || self.is_one_throttle_in_reverse_and_other_at_or_below(Self::TLA_IDLE_DEGREE)
+            {
+                return GroundSpoilerExtension::Full;
+            }
+        } else if self.is_in_landing_phase {
+            let reverse_selected =
+                self.is_one_throttle_in_reverse_and_other_at_or_below(Self::TLA_MCT_DEGREE);
+
+            if self.ground_spoilers_are_armed || self.is_speedbrake_handle_extended() {
+                if main_gears_on_ground == 2 {
+                    if self.are_throttles_at_or_below(Self::TLA_IDLE_DEGREE) || reverse_selected {
+                        return GroundSpoilerExtension::Full;
+                    } else if self.ground_spoilers_are_armed
+                        && self.are_throttles_at_or_below(Self::TLA_CLB_DEGREE)
+                    {
+                        return GroundSpoilerExtension::Partial;
+                    }
+                } else if main_gears_on_ground == 1
+                    && self.are_throttles_at_or_below(Self::TLA_IDLE_DEGREE)
+                {
+                    return GroundSpoilerExtension::Partial;
+                }
+            } else if reverse_selected {
+                if main_gears_on_ground == 2 {
+                    return GroundSpoilerExtension::Full;
+                } else if main_gears_on_ground == 1 {
+                    return GroundSpoilerExtension::Partial;
+                }
+            }
+        }
+
+        GroundSpoilerExtension::Retracted
+    }
+
+    fn is_in_rejected_take_off_condition(&self, main_gears_on_ground: usize) -> bool {
+        !self.is_in_landing_phase
+            && main_gears_on_ground == 2
+            && self.is_above_ground_speed_condition()
+    }
+
+    fn is_above_ground_speed_condition(&self) -> bool {
+        self.ground_speed > Velocity::new::<knot>(Self::GROUND_SPEED_CONDITION_KNOT)
+    }
+
+    fn is_speedbrake_handle_extended(&self) -> bool {
+        self.speedbrake_handle_position > Ratio::new::<ratio>(0.)
+    }
+
+    fn are_throttles_at_or_below(&self, angle_degree: f64) -> bool {
+        self.throttle_lever_1_angle.get::<degree>() <= angle_degree
+            && self.throttle_lever_2_angle.get::<degree>() <= angle_degree
+    }
+
+    fn is_at_least_one_throttle_in_reverse(&self) -> bool {
+        self.throttle_lever_1_angle.get::<degree>() < Self::TLA_IDLE_DEGREE
+            || self.throttle_lever_2_angle.get::<degree>() < Self::TLA_IDLE_DEGREE
+    }
+
+    fn is_one_throttle_in_reverse_and_other_at_or_below(&self, angle_degree: f64) -> bool {
+        let tla1 = self.throttle_lever_1_angle.get::<degree>();
+        let tla2 = self.throttle_lever_2_angle.get::<degree>();
+
+        (tla1 < Self::TLA_IDLE_DEGREE && tla2 <= angle_degree)
+            || (tla2 < Self::TLA_IDLE_DEGREE && tla1 <= angle_degree)
+    }
+
+    fn ground_spoiler_position_request(&self) -> Ratio {
+        Ratio::new::<ratio>(match self.ground_spoiler_extension {
+            GroundSpoilerExtension::Retracted => 0.,
+            GroundSpoilerExtension::Partial => Self::GROUND_SPOILER_PARTIAL_POSITION,
+            GroundSpoilerExtension::Full => Self::GROUND_SPOILER_FULL_POSITION,
+        })
+    }
+
+    fn speedbrake_position_request(&self) -> Ratio {
+        self.speedbrake_handle_position * Self::SPEEDBRAKE_MAX_POSITION
+    }
+
+    fn update_requested_positions(&mut self) {
+        let ground_spoiler_request = self.ground_spoiler_position_request();
+        let speedbrake_request = self.speedbrake_position_request();
+
+        for index in 0..A320SpoilerSystem::PANELS_PER_WING {
+            let requested_position = if Self::SPEEDBRAKE_PANELS_INDEXES.contains(&index) {
+                ground_spoiler_request.max(speedbrake_request)
+            } else {
+                ground_spoiler_request
+            };
+
+            self.left_controllers[index].set_requested_position(requested_position);
+            self.right_controllers[index].set_requested_position(requested_position);
+        }
+    }
+
+    fn update_modes(
+        &mut self,
+        green_pressure: Pressure,
+        yellow_pressure: Pressure,
+        blue_pressure: Pressure,
+    ) {
+        for (index, circuit) in A320SpoilerSystem::PANELS_HYDRAULIC_CIRCUITS
+            .iter()
+            .enumerate()
+        {
+            let pressure = match circuit {
+                HydraulicColor::Green => green_pressure,
+                HydraulicColor::Yellow => yellow_pressure,
+                HydraulicColor::Blue => blue_pressure,
+            };
+
+            let mode =
+                if pressure > Pressure::new::<psi>(Self::MIN_PRESSURE_FOR_POSITION_CONTROL_PSI) {
+                    LinearActuatorMode::PositionControl
+                } else {
+                    LinearActuatorMode::ClosedCircuitDamping
+                };
+
+            self.left_controllers[index].set_mode(mode);
+            self.right_controllers[index].set_mode(mode);
+        }
+    }
+
+    fn full_ground_spoiler_extension_is_commanded(&self) -> bool {
+        self.ground_spoiler_extension == GroundSpoilerExtension::Full
+    }
+
+    fn controller(&self, side: SpoilerSide, index: usize) -> &SpoilerController {
+        match side {
+            SpoilerSide::Left => &self.left_controllers[index],
+            SpoilerSide::Right => &self.right_controllers[index],
+        }
+    }
+}
+impl SimulationElement for A320SpoilerController {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.ground_spoilers_are_armed = reader.read(&self.spoilers_armed_id);
+        self.speedbrake_handle_position =
+            Ratio::new::<ratio>(reader.read(&self.speedbrake_handle_position_id));
+        self.throttle_lever_1_angle =
+            Angle::new::<degree>(reader.read(&self.throttle_lever_1_angle_id));
+        self.throttle_lever_2_angle =
+            Angle::new::<degree>(reader.read(&self.throttle_lever_2_angle_id));
+        self.ground_speed = reader.read(&self.ground_speed_id);
+    }
+}
+
+struct SpoilerPanel {
+    hydraulic_assembly: HydraulicLinearActuatorAssembly<1>,
+
+    position_id: VariableIdentifier,
+
+    position: Ratio,
+}
+impl SpoilerPanel {
+    fn new(context: &mut InitContext, side: SpoilerSide, number: usize) -> Self {
+        let side_name = match side {
+            SpoilerSide::Left => "LEFT",
+            SpoilerSide::Right => "RIGHT",
+        };
+
+        Self {
+            hydraulic_assembly: A320SpoilerFactory::a320_spoiler_assembly(),
+            position_id: context
+                .get_identifier(format!("HYD_SPOILER_{}_{}_DEFLECTION", side_name, number)),
+            position: Ratio::new::<ratio>(0.),
+        }
+    }
+
+    fn actuator(&mut self) -> &mut impl Actuator {
+        self.hydraulic_assembly.actuator(0)
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        controller: &impl HydraulicAssemblyController,
+        current_pressure: Pressure,
+    ) {
+        self.hydraulic_assembly.update(
+            context,
+            std::slice::from_ref(controller),
+            [current_pressure],
+        );
+
+        self.position = self.hydraulic_assembly.position_normalized();
+    }
+}
+impl SimulationElement for SpoilerPanel {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.position_id, self.position.get::<ratio>());
+    }
+}
+
+/// The ten A320 spoiler panels, five on each wing, each moved by a single hydraulic actuator.
+pub(super) struct A320SpoilerSystem {
+    left_panels: [SpoilerPanel; 5],
+    right_panels: [SpoilerPanel; 5],
+}
+impl A320SpoilerSystem {
+    pub(super) const PANELS_PER_WING: usize = 5;
+
+    // Mean deflection of the ten panels above which ground spoilers are considered deployed.
+    // Still reached when the panels of a single hydraulic circuit are lost.
+    const GROUND_SPOILERS_DEPLOYED_MEAN_POSITION: f64 = 0.5;
+
+    // Hydraulic supply of panels 1 to 5, identical on both wings
+    pub(super) const PANELS_HYDRAULIC_CIRCUITS: [HydraulicColor; 5] = [
+        HydraulicColor::Green,
+        HydraulicColor::Yellow,
+        HydraulicColor::Blue,
+        HydraulicColor::Yellow,
+        HydraulicColor::Green,
+    ];
+
+    pub(super) fn new(context: &mut InitContext) -> Self {
+        Self {
+            left_panels: [
+                SpoilerPanel::new(context, SpoilerSide::Left, 1),
+                SpoilerPanel::new(context, SpoilerSide::Left, 2),
+                SpoilerPanel::new(context, SpoilerSide::Left, 3),
+                SpoilerPanel::new(context, SpoilerSide::Left, 4),
+                SpoilerPanel::new(context, SpoilerSide::Left, 5),
+            ],
+            right_panels: [
+                SpoilerPanel::new(context, SpoilerSide::Right, 1),
+                SpoilerPanel::new(context, SpoilerSide::Right, 2),
+                SpoilerPanel::new(context, SpoilerSide::Right, 3),
+                SpoilerPanel::new(context, SpoilerSide::Right, 4),
+                SpoilerPanel::new(context, SpoilerSide::Right, 5),
+            ],
+        }
+    }
+
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        controller: &A320SpoilerController,
+        green_pressure: Pressure,
+        yellow_pressure: Pressure,
+        blue_pressure: Pressure,
+    ) {
+        for (index, circuit) in Self::PANELS_HYDRAULIC_CIRCUITS.iter().enumerate() {
+            let pressure = match circuit {
+                HydraulicColor::Green => green_pressure,
+                HydraulicColor::Yellow => yellow_pressure,
+                HydraulicColor::Blue => blue_pressure,
+            };
+
+            self.left_panels[index].update(
+                context,
+                controller.controller(SpoilerSide::Left, index),
+                pressure,
+            );
+            self.right_panels[index].update(
+                context,
+                controller.controller(SpoilerSide::Right, index),
+                pressure,
+            );
+        }
+    }
+
+    /// Indexes of the panels supplied by the given hydraulic circuit
+    pub(super) fn panels_on_circuit(circuit: HydraulicColor) -> impl Iterator<Item = usize> {
+        Self::PANELS_HYDRAULIC_CIRCUITS
+            .iter()
+            .enumerate()
+            .filter(move |(_, &panel_circuit)| panel_circuit == circuit)
+            .map(|(index, _)| index)
+    }
+
+    /// Ground spoilers are considered deployed once the ground spoiler logic commands a full
+    /// extension and the panels actually reached it
+    pub(super) fn ground_spoilers_are_deployed(&self, controller: &A320SpoilerController) -> bool {
+        controller.full_ground_spoiler_extension_is_commanded()
+            && self.mean_panel_position()
+                > Ratio::new::<ratio>(Self::GROUND_SPOILERS_DEPLOYED_MEAN_POSITION)
+    }
+
+    fn mean_panel_position(&self) -> Ratio {
+        self.left_panels
+            .iter()
+            .chain(self.right_panels.iter())
+            .map(|panel| panel.position)
+            .sum::<Ratio>()
+            / (2 * Self::PANELS_PER_WING) as f64
+    }
+
+    pub(super) fn actuator(&mut self, side: SpoilerSide, index: usize) -> &mut impl Actuator {
+        match side {
+            SpoilerSide::Left => self.left_panels[index].actuator(),
+            SpoilerSide::Right => self.right_panels[index].actuator(),
+        }
+    }
+}
+impl SimulationElement for A320SpoilerSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for panel in self.left_panels.iter_mut() {
+            panel.accept(visitor);
+        }
+
+        for panel in self.right_panels.iter_mut() {
+            panel.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
@@ -17,6 +17,7 @@ use electrical::{
     APU_START_MOTOR_BUS_TYPE,
 };
 
+pub use hydraulic::A320HydraulicConfig;
 use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
 use power_consumption::A320PowerConsumption;
 use systems::simulation::InitContext;
@@ -66,6 +67,15 @@ pub struct A320 {
 }
 impl A320 {
     pub fn new(context: &mut InitContext) -> A320 {
+        Self::with_hydraulic_config(context, &A320HydraulicConfig::default())
+    }
+
+    /// Builds an A320 whose hydraulic circuits are sized by the given configuration
+    /// instead of the real aircraft ones.
+    pub fn with_hydraulic_config(
+        context: &mut InitContext,
+        hydraulic_config: &A320HydraulicConfig,
+    ) -> A320 {
         A320 {
             adirs: AirDataInertialReferenceSystem::new(context),
             adirs_overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
@@ -99,7 +109,7 @@ impl A320 {
                 2,
                 ElectricalBusType::DirectCurrent(2),
             ),
-            hydraulic: A320Hydraulic::new(context),
+            hydraulic: A320Hydraulic::new(context, hydraulic_config),
             hydraulic_overhead: A320HydraulicOverheadPanel::new(context),
             autobrake_panel: AutobrakePanel::new(context),
             landing_gear: LandingGear::new(context),
@@ -144,8 +154,11 @@ impl Aircraft for A320 {
             &self.landing_gear,
         );
 
-        self.electrical_overhead
-            .update_after_electrical(&self.electrical, electricity);
+        self.electrical_overhead.update_after_electrical(
+            &self.electrical,
+            &self.ext_pwr,
+            electricity,
+        );
         self.emergency_electrical_overhead
             .update_after_electrical(context, &self.electrical);
     }
@@ -154,6 +167,7 @@ impl Aircraft for A320 {
         self.apu.update_after_power_distribution();
         self.apu_overhead.update_after_apu(&self.apu);
 
+        self.landing_gear.update(&self.hydraulic);
         self.lgciu1.update(
             &self.landing_gear,
             self.ext_pwr.output_potential().is_powered(),
@@ -167,6 +181,7 @@ impl Aircraft for A320 {
             &self.pressurization_overhead,
             [&self.engine_1, &self.engine_2],
             [&self.lgciu1, &self.lgciu2],
+            &self.hydraulic,
         );
 
         self.hydraulic.update(
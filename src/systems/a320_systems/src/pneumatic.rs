@@ -12,6 +12,7 @@ use uom::si::{
 
 use systems::{
     accept_iterable,
+    electrical::ComputerPowerSupply,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
         valve::*, BleedMonitoringComputerChannelOperationMode,
@@ -405,10 +406,12 @@ struct BleedMonitoringComputer {
     backup_channel_engine_number: usize,
     main_channel: BleedMonitoringComputerChannel,
     backup_channel: BleedMonitoringComputerChannel,
-    powered_by: ElectricalBusType,
-    is_powered: bool,
+    power_supply: ComputerPowerSupply,
 }
 impl BleedMonitoringComputer {
+    const POWER_HOLD_UP_TIME: Duration = Duration::from_millis(20);
+    const START_UP_TIME: Duration = Duration::from_secs(3);
+
     fn new(
         main_channel_engine_number: usize,
         backup_channel_engine_number: usize,
@@ -425,8 +428,11 @@ impl BleedMonitoringComputer {
                 backup_channel_engine_number,
                 BleedMonitoringComputerChannelOperationMode::Slave,
             ),
-            powered_by,
-            is_powered: true,
+            power_supply: ComputerPowerSupply::new(
+                vec![powered_by],
+                Self::POWER_HOLD_UP_TIME,
+                Self::START_UP_TIME,
+            ),
         }
     }
 
@@ -440,6 +446,8 @@ impl BleedMonitoringComputer {
         cross_bleed_valve: &impl PneumaticValve,
         fadec: &FullAuthorityDigitalEngineControl,
     ) {
+        self.power_supply.update(context);
+
         self.main_channel.update(
             context,
             &sensors[self.main_channel_engine_number - 1],
@@ -503,17 +511,19 @@ impl BleedMonitoringComputer {
     }
 
     fn is_powered(&self) -> bool {
-        self.is_powered
+        self.power_supply.is_powered()
     }
 }
 impl SimulationElement for BleedMonitoringComputer {
-    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        self.is_powered = buses.is_powered(self.powered_by)
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.power_supply.accept(visitor);
+
+        visitor.visit(self);
     }
 }
 impl ControllerSignal<BleedMonitoringComputerIsAliveSignal> for BleedMonitoringComputer {
     fn signal(&self) -> Option<BleedMonitoringComputerIsAliveSignal> {
-        if self.is_powered() {
+        if self.power_supply.is_operational() {
             Some(BleedMonitoringComputerIsAliveSignal)
         } else {
             None
@@ -1864,6 +1874,14 @@ mod tests {
             self.query(|a| a.pneumatic.bleed_monitoring_computers[bmc_number - 1].is_powered())
         }
 
+        fn bmc_is_alive(&self, bmc_number: usize) -> bool {
+            self.query(|a| {
+                a.pneumatic.bleed_monitoring_computers[bmc_number - 1]
+                    .signal()
+                    .is_some()
+            })
+        }
+
         fn fadec_single_vs_dual_bleed_config(&self) -> bool {
             self.query(|a| a.pneumatic.fadec.is_single_vs_dual_bleed_config())
         }
@@ -2725,6 +2743,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bleed_monitoring_computer_bridges_short_power_interruption() {
+        let mut test_bed = test_bed()
+            .set_dc_2_bus_power(true)
+            .set_dc_ess_shed_bus_power(true)
+            .and_run();
+
+        test_bed = test_bed.set_dc_2_bus_power(false);
+        test_bed.run_with_delta(Duration::from_millis(10));
+        test_bed = test_bed.set_dc_2_bus_power(true);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(test_bed.bmc_is_alive(2));
+        assert_eq!(
+            test_bed.bmc_operation_mode_for_engine(2, 2),
+            BleedMonitoringComputerChannelOperationMode::Master
+        );
+    }
+
+    #[test]
+    fn bleed_monitoring_computer_restarts_after_long_power_interruption() {
+        let mut test_bed = test_bed()
+            .set_dc_2_bus_power(true)
+            .set_dc_ess_shed_bus_power(true)
+            .and_run();
+
+        test_bed = test_bed.set_dc_2_bus_power(false);
+        test_bed.run_with_delta(Duration::from_millis(100));
+        test_bed = test_bed.set_dc_2_bus_power(true);
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        assert!(test_bed.bmc_is_powered(2));
+        assert!(!test_bed.bmc_is_alive(2));
+
+        test_bed.run_with_delta(Duration::from_secs(3));
+
+        assert!(test_bed.bmc_is_alive(2));
+    }
+
     #[test]
     fn valves_powered_by_correct_busses() {
         let mut test_bed = test_bed()
@@ -58,7 +58,8 @@ impl A320PowerConsumption {
                     PowerConsumerFlightPhase::TaxiIn,
                     Power::new::<watt>(30243.1),
                 ),
-            ]),
+            ])
+            .named(context, "AC_1"),
             ac_bus_2_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::AlternatingCurrent(2),
@@ -88,7 +89,8 @@ impl A320PowerConsumption {
                     PowerConsumerFlightPhase::TaxiIn,
                     Power::new::<watt>(24475.8),
                 ),
-            ]),
+            ])
+            .named(context, "AC_2"),
             ac_ess_bus_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::AlternatingCurrentEssential,
@@ -106,7 +108,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(875.7)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(715.7)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(715.7)),
-            ]),
+            ])
+            .named(context, "AC_ESS"),
             ac_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::AlternatingCurrentEssentialShed,
@@ -124,7 +127,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(823.5)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(823.5)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(823.5)),
-            ]),
+            ])
+            .named(context, "AC_ESS_SHED"),
             ac_stat_inv_bus_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::AlternatingCurrentStaticInverter,
@@ -142,7 +146,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(135.)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(135.)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(135.)),
-            ]),
+            ])
+            .named(context, "AC_STAT_INV"),
             ac_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::AlternatingCurrentGndFltService,
@@ -160,7 +165,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(2628.)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(2628.)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(3663.)),
-            ]),
+            ])
+            .named(context, "AC_GND_FLT_SVC"),
             dc_bus_1_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::DirectCurrent(1),
@@ -178,7 +184,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(280.)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(364.)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(336.)),
-            ]),
+            ])
+            .named(context, "DC_1"),
             dc_bus_2_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::DirectCurrent(2),
@@ -196,7 +203,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(392.)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(392.)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(448.)),
-            ]),
+            ])
+            .named(context, "DC_2"),
             dc_ess_bus_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::DirectCurrentEssential,
@@ -214,7 +222,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(140.)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(168.)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(140.)),
-            ]),
+            ])
+            .named(context, "DC_ESS"),
             dc_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::DirectCurrentEssentialShed,
@@ -232,7 +241,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(196.)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(196.)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(168.)),
-            ]),
+            ])
+            .named(context, "DC_ESS_SHED"),
             dc_bat_bus_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::DirectCurrentBattery,
@@ -250,7 +260,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(28.)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(28.)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(28.)),
-            ]),
+            ])
+            .named(context, "DC_BAT"),
             dc_hot_bus_1_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::DirectCurrentHot(1),
@@ -268,7 +279,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(15.3)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(15.3)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(11.)),
-            ]),
+            ])
+            .named(context, "DC_HOT_1"),
             dc_hot_bus_2_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::DirectCurrentHot(2),
@@ -286,7 +298,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(24.3)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(24.3)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(24.3)),
-            ]),
+            ])
+            .named(context, "DC_HOT_2"),
             dc_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::DirectCurrentGndFltService,
@@ -304,7 +317,8 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Flight, Power::new::<watt>(84.)),
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(112.)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(84.)),
-            ]),
+            ])
+            .named(context, "DC_GND_FLT_SVC"),
         }
     }
 
@@ -0,0 +1,51 @@
+use std::error::Error;
+use systems_wasm::aspects::{ExecuteOn, MsfsAspectBuilder};
+use systems_wasm::Variable;
+
+// Elevator travel is 17° down and 30° up
+const MAX_DOWN_DEFLECTION_DEGREES: f64 = 17.;
+const MAX_UP_DEFLECTION_DEGREES: f64 = 30.;
+const TOTAL_TRAVEL_DEGREES: f64 = MAX_DOWN_DEFLECTION_DEGREES + MAX_UP_DEFLECTION_DEGREES;
+
+pub(super) fn elevators(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    // Inputs from FBW becomes the elevator position demand for hydraulic system
+    // MSFS uses [-1;1] range scaled on each side max travel, 1 is full UP
+    // Systems use [0;1] over the whole elevator travel, 1 is full UP
+    builder.map(
+        ExecuteOn::PreTick,
+        Variable::named("ELEVATOR_DEFLECTION_DEMAND"),
+        |value| {
+            let angle = if value >= 0. {
+                value * MAX_UP_DEFLECTION_DEGREES
+            } else {
+                value * MAX_DOWN_DEFLECTION_DEGREES
+            };
+
+            (angle + MAX_DOWN_DEFLECTION_DEGREES) / TOTAL_TRAVEL_DEGREES
+        },
+        Variable::aspect("HYD_ELEVATOR_DEMAND"),
+    );
+
+    // Elevator positions returned by hydraulic system are converted back to MSFS format
+    for (hyd_position, msfs_position) in [
+        ("HYD_ELEV_LEFT_DEFLECTION", "HYD_ELEVATOR_LEFT_DEFLECTION"),
+        ("HYD_ELEV_RIGHT_DEFLECTION", "HYD_ELEVATOR_RIGHT_DEFLECTION"),
+    ] {
+        builder.map(
+            ExecuteOn::PostTick,
+            Variable::aspect(hyd_position),
+            |value| {
+                let angle = value * TOTAL_TRAVEL_DEGREES - MAX_DOWN_DEFLECTION_DEGREES;
+
+                if angle >= 0. {
+                    angle / MAX_UP_DEFLECTION_DEGREES
+                } else {
+                    angle / MAX_DOWN_DEFLECTION_DEGREES
+                }
+            },
+            Variable::named(msfs_position),
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use msfs::sim_connect;
+use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+use std::error::Error;
+use systems_wasm::aspects::{MsfsAspectBuilder, VariablesToObject};
+use systems_wasm::{set_data_on_sim_object, Variable};
+
+pub(super) fn gear(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    // Gear positions are simulated by the hydraulic system and written back to the sim
+    // so that the gear animations and aerodynamics follow the simulated actuators.
+    builder.variables_to_object(Box::new(GearPosition {
+        center: 100.,
+        left: 100.,
+        right: 100.,
+    }));
+
+    Ok(())
+}
+
+#[sim_connect::data_definition]
+struct GearPosition {
+    #[name = "GEAR CENTER POSITION"]
+    #[unit = "Percent"]
+    center: f64,
+
+    #[name = "GEAR LEFT POSITION"]
+    #[unit = "Percent"]
+    left: f64,
+
+    #[name = "GEAR RIGHT POSITION"]
+    #[unit = "Percent"]
+    right: f64,
+}
+
+impl VariablesToObject for GearPosition {
+    fn variables(&self) -> Vec<Variable> {
+        vec![
+            Variable::named("GEAR_CENTER_POSITION"),
+            Variable::named("GEAR_LEFT_POSITION"),
+            Variable::named("GEAR_RIGHT_POSITION"),
+        ]
+    }
+
+    fn write(&mut self, values: Vec<f64>) {
+        self.center = values[0];
+        self.left = values[1];
+        self.right = values[2];
+    }
+
+    set_data_on_sim_object!();
+}
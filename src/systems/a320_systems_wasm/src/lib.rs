@@ -2,15 +2,21 @@
 mod ailerons;
 mod autobrakes;
 mod brakes;
+mod elevators;
 mod flaps;
+mod gear;
 mod nose_wheel_steering;
+mod rudder;
 
 use a320_systems::A320;
 use ailerons::ailerons;
 use autobrakes::autobrakes;
 use brakes::brakes;
+use elevators::elevators;
 use flaps::flaps;
+use gear::gear;
 use nose_wheel_steering::nose_wheel_steering;
+use rudder::rudder;
 use std::error::Error;
 use systems::shared::ElectricalBusType;
 use systems::{failures::FailureType, shared::HydraulicColor};
@@ -44,6 +50,16 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
                 (24_000, FailureType::TransformerRectifier(1)),
                 (24_001, FailureType::TransformerRectifier(2)),
                 (24_002, FailureType::TransformerRectifier(3)),
+                (24_003, FailureType::IdgOilLeak(1)),
+                (24_004, FailureType::IdgOilLeak(2)),
+                (24_005, FailureType::GroundPowerUnitOvervoltage),
+                (24_006, FailureType::GroundPowerUnitWrongFrequency),
+                (24_007, FailureType::GroundPowerUnitIncorrectPhaseSequence),
+                (24_008, FailureType::GroundPowerUnitOpenPhase),
+                (27_000, FailureType::FlapTransmission(1)),
+                (27_001, FailureType::FlapTransmission(2)),
+                (27_002, FailureType::SlatTransmission(1)),
+                (27_003, FailureType::SlatTransmission(2)),
                 (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
                 (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
                 (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
@@ -82,14 +98,12 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
             .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
             .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 2)?
-            .provides_aircraft_variable("GEAR CENTER POSITION", "Percent", 0)?
-            .provides_aircraft_variable("GEAR LEFT POSITION", "Percent", 0)?
-            .provides_aircraft_variable("GEAR RIGHT POSITION", "Percent", 0)?
             .provides_aircraft_variable("GEAR HANDLE POSITION", "Bool", 0)?
             .provides_aircraft_variable("GENERAL ENG STARTER ACTIVE", "Bool", 1)?
             .provides_aircraft_variable("GENERAL ENG STARTER ACTIVE", "Bool", 2)?
             .provides_aircraft_variable("GPS GROUND SPEED", "Knots", 0)?
             .provides_aircraft_variable("GPS GROUND MAGNETIC TRACK", "Degrees", 0)?
+            .provides_aircraft_variable("INCIDENCE ALPHA", "Degrees", 0)?
             .provides_aircraft_variable("INDICATED ALTITUDE", "Feet", 0)?
             .provides_aircraft_variable("INTERACTIVE POINT OPEN:0", "Percent", 0)?
             .provides_aircraft_variable("INTERACTIVE POINT OPEN:3", "Percent", 0)?
@@ -102,6 +116,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             .provides_aircraft_variable("PUSHBACK ANGLE", "Radians", 0)?
             .provides_aircraft_variable("SEA LEVEL PRESSURE", "Millibars", 0)?
             .provides_aircraft_variable("SIM ON GROUND", "Bool", 0)?
+            .provides_aircraft_variable("SURFACE CONDITION", "Enum", 0)?
             .provides_aircraft_variable("TOTAL AIR TEMPERATURE", "celsius", 0)?
             .provides_aircraft_variable("TRAILING EDGE FLAPS LEFT PERCENT", "Percent", 0)?
             .provides_aircraft_variable("TRAILING EDGE FLAPS RIGHT PERCENT", "Percent", 0)?
@@ -126,10 +141,6 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
                     Variable::aspect("OVHD_PNEU_ENG_2_BLEED_PB_IS_AUTO"),
                 );
 
-                builder.copy(
-                    Variable::aircraft("EXTERNAL POWER AVAILABLE", "Bool", 1),
-                    Variable::aspect("OVHD_ELEC_EXT_PWR_PB_IS_AVAILABLE"),
-                );
                 builder.copy(
                     Variable::aircraft("EXTERNAL POWER ON", "Bool", 1),
                     Variable::aspect("OVHD_ELEC_EXT_PWR_PB_IS_ON"),
@@ -150,6 +161,18 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
                     |value| if value > 0. { 1. } else { 0. },
                     Variable::aspect("FWD_DOOR_CARGO_OPEN_REQ"),
                 );
+                builder.map(
+                    ExecuteOn::PreTick,
+                    Variable::aircraft("INTERACTIVE POINT OPEN", "Position", 4),
+                    |value| if value > 0. { 1. } else { 0. },
+                    Variable::aspect("AFT_DOOR_CARGO_OPEN_REQ"),
+                );
+                builder.map(
+                    ExecuteOn::PreTick,
+                    Variable::aircraft("INTERACTIVE POINT OPEN", "Position", 6),
+                    |value| if value > 0. { 1. } else { 0. },
+                    Variable::aspect("BULK_DOOR_CARGO_OPEN_REQ"),
+                );
 
                 Ok(())
             })?
@@ -157,7 +180,10 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             .with_aspect(autobrakes)?
             .with_aspect(nose_wheel_steering)?
             .with_aspect(flaps)?
+            .with_aspect(gear)?
             .with_aspect(ailerons)?
+            .with_aspect(elevators)?
+            .with_aspect(rudder)?
             .build(A320::new)?;
 
     while let Some(event) = gauge.next_event().await {
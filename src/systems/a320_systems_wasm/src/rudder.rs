@@ -0,0 +1,24 @@
+use std::error::Error;
+use systems_wasm::aspects::{ExecuteOn, MsfsAspectBuilder};
+use systems_wasm::Variable;
+
+pub(super) fn rudder(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    // Inputs from FBW becomes the rudder position demand for hydraulic system
+    // MSFS uses [-1;1] range, systems use [0;1], 0.5 being neutral
+    builder.map(
+        ExecuteOn::PreTick,
+        Variable::named("RUDDER_DEFLECTION_DEMAND"),
+        |value| (value + 1.) / 2.,
+        Variable::aspect("HYD_RUDDER_DEMAND"),
+    );
+
+    // Rudder position returned by hydraulic system is converted back to MSFS format
+    builder.map(
+        ExecuteOn::PostTick,
+        Variable::aspect("HYD_RUD_DEFLECTION"),
+        |value| value * 2. - 1.,
+        Variable::named("HYD_RUDDER_DEFLECTION"),
+    );
+
+    Ok(())
+}
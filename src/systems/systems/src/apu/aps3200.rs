@@ -7,8 +7,9 @@ use uom::si::{
 
 use crate::{
     electrical::{
-        ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-        ElectricalStateWriter, ElectricitySource, Potential, ProvideFrequency, ProvideLoad,
+        ElectricalElement, ElectricalElementDescription, ElectricalElementIdentifier,
+        ElectricalElementIdentifierProvider, ElectricalElementKind, ElectricalStateWriter,
+        ElectricitySource, GeneratorOverloadProtection, Potential, ProvideFrequency, ProvideLoad,
         ProvidePotential,
     },
     shared::{
@@ -567,6 +568,7 @@ pub struct Aps3200ApuGenerator {
     output_potential: ElectricPotential,
     load: Ratio,
     is_emergency_shutdown: bool,
+    overload_protection: GeneratorOverloadProtection,
 }
 impl Aps3200ApuGenerator {
     pub(super) const APU_GEN_POWERED_N: f64 = 84.;
@@ -581,6 +583,7 @@ impl Aps3200ApuGenerator {
             output_frequency: Frequency::new::<hertz>(0.),
             load: Ratio::new::<percent>(0.),
             is_emergency_shutdown: false,
+            overload_protection: GeneratorOverloadProtection::new(),
         }
     }
 
@@ -639,11 +642,18 @@ impl ApuGenerator for Aps3200ApuGenerator {
     /// Indicates if the provided electricity's potential and frequency
     /// are within normal parameters. Use this to decide if the
     /// generator contactor should close.
-    /// Load shouldn't be taken into account, as overloading causes an
-    /// overtemperature which over time will trigger a mechanical
-    /// disconnect of the generator.
+    /// Load shouldn't be taken into account directly. A sustained overload
+    /// trips the generator, after which the output is no longer considered
+    /// to be within normal parameters.
     fn output_within_normal_parameters(&self) -> bool {
-        self.should_provide_output() && self.potential_normal() && self.frequency_normal()
+        self.should_provide_output()
+            && self.potential_normal()
+            && self.frequency_normal()
+            && !self.overload_protection.has_tripped()
+    }
+
+    fn requires_load_shedding(&self) -> bool {
+        self.overload_protection.requires_load_shedding()
     }
 }
 provide_potential!(Aps3200ApuGenerator, (110.0..=120.0));
@@ -675,13 +685,21 @@ impl ElectricitySource for Aps3200ApuGenerator {
     }
 }
 impl SimulationElement for Aps3200ApuGenerator {
+    fn describe_electrical_element(&self) -> Option<ElectricalElementDescription> {
+        Some(ElectricalElementDescription::new(
+            self,
+            format!("APU_GEN_{}", self.number),
+            ElectricalElementKind::Source(PotentialOrigin::ApuGenerator(self.number)),
+        ))
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.writer.write_alternating_with_load(self, writer);
     }
 
     fn process_power_consumption_report<T: PowerConsumptionReport>(
         &mut self,
-        _: &UpdateContext,
+        context: &UpdateContext,
         report: &T,
     ) {
         self.output_potential = if self.should_provide_output() {
@@ -704,6 +722,13 @@ impl SimulationElement for Aps3200ApuGenerator {
         self.load = Ratio::new::<percent>(
             (power_consumption * power_factor_correction / maximum_load) * 100.,
         );
+
+        // The generator control unit resets when the generator spins down.
+        if self.should_provide_output() {
+            self.overload_protection.update(context, self.load);
+        } else {
+            self.overload_protection.reset();
+        }
     }
 }
 
@@ -951,6 +976,26 @@ mod apu_generator_tests {
         assert!(!test_bed.load_within_normal_range());
     }
 
+    #[test]
+    fn output_within_normal_parameters_when_load_slightly_exceeds_maximum() {
+        let test_bed = test_bed_with()
+            .running_apu()
+            .power_demand(Power::new::<watt>((90000. / 0.8) + 1.))
+            .run(Duration::from_secs(1_000));
+
+        assert!(test_bed.apu_generator_output_within_normal_parameters());
+    }
+
+    #[test]
+    fn output_not_within_normal_parameters_when_overloaded_for_sustained_period() {
+        let test_bed = test_bed_with()
+            .running_apu()
+            .power_demand(Power::new::<watt>((90000. / 0.8) * 2.))
+            .run(Duration::from_secs(10));
+
+        assert!(!test_bed.apu_generator_output_within_normal_parameters());
+    }
+
     #[test]
     fn when_apu_emergency_shutdown_provides_no_output() {
         let test_bed = test_bed_with()
@@ -190,6 +190,10 @@ impl<T: ApuGenerator, U: ApuStartMotor> AuxiliaryPowerUnitElectrical for Auxilia
     fn output_within_normal_parameters(&self) -> bool {
         self.generator.output_within_normal_parameters()
     }
+
+    fn requires_load_shedding(&self) -> bool {
+        self.generator.requires_load_shedding()
+    }
 }
 impl<T: ApuGenerator, U: ApuStartMotor> ApuAvailable for AuxiliaryPowerUnit<T, U> {
     fn is_available(&self) -> bool {
@@ -282,6 +286,9 @@ pub trait ApuGenerator:
 {
     fn update(&mut self, n: Ratio, is_emergency_shutdown: bool);
     fn output_within_normal_parameters(&self) -> bool;
+    /// Indicates if the generator is overloaded to such an extent that
+    /// commercial loads should be shed.
+    fn requires_load_shedding(&self) -> bool;
 }
 
 pub struct AuxiliaryPowerUnitFireOverheadPanel {
@@ -940,7 +947,7 @@ pub mod tests {
             self.read_by_name("APU_BLEED_AIR_VALVE_OPEN")
         }
 
-        fn apu_generator_output_within_normal_parameters(&self) -> bool {
+        pub fn apu_generator_output_within_normal_parameters(&self) -> bool {
             self.query(|a| a.apu_generator_output_within_normal_parameters_after_processing_power_consumption_report())
         }
 
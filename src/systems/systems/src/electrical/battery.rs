@@ -1,17 +1,28 @@
 use uom::si::{
-    electric_charge::ampere_hour, electric_current::ampere, electric_potential::volt,
-    electrical_resistance::ohm, f64::*, time::second,
+    electric_charge::ampere_hour,
+    electric_current::ampere,
+    electric_potential::volt,
+    electrical_resistance::ohm,
+    f64::*,
+    power::watt,
+    ratio::{percent, ratio},
+    thermodynamic_temperature::degree_celsius,
+    time::second,
 };
 
 use crate::{
-    shared::{ConsumePower, PowerConsumptionReport},
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    shared::{
+        calculate_towards_target_temperature, interpolation, ConsumePower, PowerConsumptionReport,
+    },
+    simulation::{
+        InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    },
 };
 
 use super::{
-    ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-    ElectricalStateWriter, ElectricitySource, Potential, PotentialOrigin, ProvideCurrent,
-    ProvidePotential,
+    ElectricalElement, ElectricalElementDescription, ElectricalElementIdentifier,
+    ElectricalElementIdentifierProvider, ElectricalElementKind, ElectricalStateWriter,
+    ElectricitySource, Potential, PotentialOrigin, ProvideCurrent, ProvidePotential,
 };
 
 pub struct Battery {
@@ -21,11 +32,35 @@ pub struct Battery {
     charge: ElectricCharge,
     input_potential: ElectricPotential,
     output_potential: ElectricPotential,
+    terminal_potential: ElectricPotential,
     current: ElectricCurrent,
+    temperature_id: VariableIdentifier,
+    temperature: ThermodynamicTemperature,
+    temperature_is_initialised: bool,
+    state_of_health: Ratio,
+    is_deeply_discharged: bool,
 }
 impl Battery {
     const RATED_CAPACITY_AMPERE_HOURS: f64 = 23.;
 
+    const OPEN_CIRCUIT_POTENTIAL_STATE_OF_CHARGE: [f64; 9] =
+        [0., 0.02, 0.05, 0.1, 0.15, 0.5, 0.975, 0.99, 1.];
+    const OPEN_CIRCUIT_POTENTIAL_VOLT: [f64; 9] = [
+        0., 5.995, 13.401, 21.512, 24.333, 25.46, 26.99, 27.494, 27.964,
+    ];
+
+    const INTERNAL_RESISTANCE_OHM: f64 = 0.011;
+    const INTERNAL_RESISTANCE_TEMPERATURE_CELSIUS: [f64; 5] = [-40., -20., 0., 20., 40.];
+    const INTERNAL_RESISTANCE_TEMPERATURE_FACTOR: [f64; 5] = [5., 3., 1.6, 1., 0.9];
+
+    const THERMAL_RESISTANCE_KELVIN_PER_WATT: f64 = 0.05;
+    const HEATING_COEFFICIENT: f64 = 0.05;
+    const COOLING_COEFFICIENT: f64 = 0.01;
+
+    const DEEP_DISCHARGE_BELOW_AMPERE_HOURS: f64 = Battery::RATED_CAPACITY_AMPERE_HOURS * 0.2;
+    const STATE_OF_HEALTH_LOSS_PER_DEEP_DISCHARGE_PERCENT: f64 = 1.;
+    const MINIMUM_STATE_OF_HEALTH_PERCENT: f64 = 50.;
+
     pub fn full(context: &mut InitContext, number: usize) -> Battery {
         Battery::new(
             context,
@@ -47,14 +82,21 @@ impl Battery {
     }
 
     pub fn new(context: &mut InitContext, number: usize, charge: ElectricCharge) -> Self {
+        let output_potential = Battery::calculate_open_circuit_potential_for_charge(charge);
         Self {
             number,
             identifier: context.next_electrical_identifier(),
             writer: ElectricalStateWriter::new(context, &format!("BAT_{}", number)),
             charge,
             input_potential: ElectricPotential::new::<volt>(0.),
-            output_potential: Battery::calculate_output_potential_for_charge(charge),
+            output_potential,
+            terminal_potential: output_potential,
             current: ElectricCurrent::new::<ampere>(0.),
+            temperature_id: context.get_identifier(format!("ELEC_BAT_{}_TEMPERATURE", number)),
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            temperature_is_initialised: false,
+            state_of_health: Ratio::new::<percent>(100.),
+            is_deeply_discharged: Battery::is_deep_discharge(charge),
         }
     }
 
@@ -62,6 +104,14 @@ impl Battery {
         self.charge <= ElectricCharge::new::<ampere_hour>(Battery::RATED_CAPACITY_AMPERE_HOURS - 3.)
     }
 
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    pub fn state_of_health(&self) -> Ratio {
+        self.state_of_health
+    }
+
     fn is_powered_by_other_potential(&self) -> bool {
         self.input_potential > self.output_potential
     }
@@ -75,7 +125,15 @@ impl Battery {
     fn set_charge(&mut self, charge: ElectricCharge) {
         self.charge = charge;
         self.input_potential = ElectricPotential::new::<volt>(0.);
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.output_potential =
+            Battery::calculate_open_circuit_potential_for_charge(self.usable_charge());
+        self.terminal_potential = self.output_potential;
+        self.is_deeply_discharged = Battery::is_deep_discharge(self.usable_charge());
+    }
+
+    #[cfg(test)]
+    fn set_state_of_health(&mut self, state_of_health: Ratio) {
+        self.state_of_health = state_of_health;
     }
 
     #[cfg(test)]
@@ -95,29 +153,99 @@ impl Battery {
         self.set_charge(ElectricCharge::new::<ampere_hour>(0.))
     }
 
-    fn calculate_output_potential_for_charge(charge: ElectricCharge) -> ElectricPotential {
-        // There are four distinct charges, being:
-        // 1. No charge, giving no potential.
-        // 2. Low charge, rapidly decreasing from 26.578V.
-        // 3. Regular charge, linear from 26.578V to 27.33V.
-        // 4. High charge, rapidly increasing from 27.33V to 28.958V.
-        // Refer to Battery.md for details.
-        let charge = charge.get::<ampere_hour>();
-        ElectricPotential::new::<volt>(if charge <= 0. {
-            0.
-        } else if charge <= 3.488 {
-            (13.95303731988 * charge) - 2. * charge.powi(2)
-        } else if charge < 22.449 {
-            23.85 + 0.14 * charge
+    /// The charge which is available for use. A battery which lost some of its
+    /// state of health can no longer use part of its capacity.
+    fn usable_charge(&self) -> ElectricCharge {
+        let lost_capacity = ElectricCharge::new::<ampere_hour>(
+            Battery::RATED_CAPACITY_AMPERE_HOURS * (1. - self.state_of_health.get::<ratio>()),
+        );
+
+        (self.charge - lost_capacity).max(ElectricCharge::new::<ampere_hour>(0.))
+    }
+
+    /// The internal resistance increases as the battery gets colder
+    /// and as its state of health degrades.
+    fn internal_resistance(&self) -> ElectricalResistance {
+        let temperature_factor = interpolation(
+            &Battery::INTERNAL_RESISTANCE_TEMPERATURE_CELSIUS,
+            &Battery::INTERNAL_RESISTANCE_TEMPERATURE_FACTOR,
+            self.temperature.get::<degree_celsius>(),
+        );
+
+        ElectricalResistance::new::<ohm>(
+            Battery::INTERNAL_RESISTANCE_OHM * temperature_factor
+                / self.state_of_health.get::<ratio>(),
+        )
+    }
+
+    /// The potential at the battery's terminals drops below the open circuit potential
+    /// when discharging, as the current flows through the battery's internal resistance.
+    fn calculate_terminal_potential(&self) -> ElectricPotential {
+        if self.current < ElectricCurrent::new::<ampere>(0.) {
+            (self.output_potential + self.current * self.internal_resistance())
+                .max(ElectricPotential::new::<volt>(0.))
         } else {
-            8483298.
-                + (-2373273.312763873 * charge)
-                + (276476.10619333945 * charge.powi(2))
-                + (-17167.409762003314 * charge.powi(3))
-                + (599.2597390001015 * charge.powi(4))
-                + (-11.149802489333474 * charge.powi(5))
-                + (0.08638809969727154 * charge.powi(6))
-        })
+            self.output_potential
+        }
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext) {
+        if !self.temperature_is_initialised {
+            // The battery starts soaked at the ambient temperature.
+            self.temperature = context.ambient_temperature();
+            self.temperature_is_initialised = true;
+        }
+
+        let dissipated_power: Power = self.current * self.current * self.internal_resistance();
+        let target = ThermodynamicTemperature::new::<degree_celsius>(
+            context.ambient_temperature().get::<degree_celsius>()
+                + dissipated_power.get::<watt>() * Battery::THERMAL_RESISTANCE_KELVIN_PER_WATT,
+        );
+
+        self.temperature = calculate_towards_target_temperature(
+            self.temperature,
+            target,
+            if self.temperature < target {
+                Battery::HEATING_COEFFICIENT
+            } else {
+                Battery::COOLING_COEFFICIENT
+            },
+            context.delta(),
+        );
+    }
+
+    /// Each deep discharge permanently reduces the battery's state of health.
+    /// A discharge only counts once, until the battery has been recharged.
+    fn update_state_of_health(&mut self) {
+        if !self.is_deeply_discharged && Battery::is_deep_discharge(self.usable_charge()) {
+            self.is_deeply_discharged = true;
+            self.state_of_health = Ratio::new::<percent>(
+                (self.state_of_health.get::<percent>()
+                    - Battery::STATE_OF_HEALTH_LOSS_PER_DEEP_DISCHARGE_PERCENT)
+                    .max(Battery::MINIMUM_STATE_OF_HEALTH_PERCENT),
+            );
+        } else if self.is_deeply_discharged && !self.needs_charging() {
+            self.is_deeply_discharged = false;
+        }
+    }
+
+    /// Whether the given usable charge is low enough to be a deep discharge.
+    fn is_deep_discharge(usable_charge: ElectricCharge) -> bool {
+        usable_charge
+            < ElectricCharge::new::<ampere_hour>(Battery::DEEP_DISCHARGE_BELOW_AMPERE_HOURS)
+    }
+
+    /// The open circuit potential follows the discharge curve of the battery, which
+    /// drops rapidly at low and rises rapidly at high states of charge.
+    /// Refer to Battery.md for details.
+    fn calculate_open_circuit_potential_for_charge(charge: ElectricCharge) -> ElectricPotential {
+        let state_of_charge = charge.get::<ampere_hour>() / Battery::RATED_CAPACITY_AMPERE_HOURS;
+
+        ElectricPotential::new::<volt>(interpolation(
+            &Battery::OPEN_CIRCUIT_POTENTIAL_STATE_OF_CHARGE,
+            &Battery::OPEN_CIRCUIT_POTENTIAL_VOLT,
+            state_of_charge,
+        ))
     }
 
     fn calculate_charging_current(
@@ -145,7 +273,11 @@ impl ProvideCurrent for Battery {
 }
 impl ProvidePotential for Battery {
     fn potential(&self) -> ElectricPotential {
-        self.output_potential.max(self.input_potential)
+        if self.is_powered_by_other_potential() {
+            self.input_potential
+        } else {
+            self.terminal_potential
+        }
     }
 
     fn potential_normal(&self) -> bool {
@@ -176,12 +308,31 @@ impl ElectricitySource for Battery {
     }
 }
 impl SimulationElement for Battery {
+    fn describe_electrical_element(&self) -> Option<ElectricalElementDescription> {
+        Some(ElectricalElementDescription::new(
+            self,
+            format!("BAT_{}", self.number),
+            ElectricalElementKind::Source(PotentialOrigin::Battery(self.number)),
+        ))
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.writer.write_direct(self, writer);
+        writer.write(&self.temperature_id, self.temperature);
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
-        self.input_potential = consumption.input_of(self).raw();
+        // The battery's own potential is present at its input whenever it powers the bus.
+        // That potential shouldn't be mistaken for another source, as the terminal potential
+        // would then no longer drop under load.
+        self.input_potential = {
+            let input = consumption.input_of(self);
+            if input.is_single(PotentialOrigin::Battery(self.number)) {
+                ElectricPotential::new::<volt>(0.)
+            } else {
+                input.raw()
+            }
+        };
 
         if self.is_powered_by_other_potential() {
             self.current =
@@ -215,7 +366,12 @@ impl SimulationElement for Battery {
             }
         }
 
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.update_state_of_health();
+        self.update_temperature(context);
+
+        self.output_potential =
+            Battery::calculate_open_circuit_potential_for_charge(self.usable_charge());
+        self.terminal_potential = self.calculate_terminal_potential();
     }
 }
 
@@ -239,7 +395,6 @@ mod tests {
             },
         };
         use std::time::Duration;
-        use uom::si::power::watt;
 
         struct BatteryTestBed {
             test_bed: SimulationTestBed<TestAircraft>,
@@ -332,6 +487,20 @@ mod tests {
             fn potential(&mut self, number: usize) -> ElectricPotential {
                 self.read_by_name(&format!("ELEC_BAT_{}_POTENTIAL", number))
             }
+
+            fn temperature(&mut self, number: usize) -> ThermodynamicTemperature {
+                self.read_by_name(&format!("ELEC_BAT_{}_TEMPERATURE", number))
+            }
+
+            fn potential_under_load_at(&mut self, ambient_temperature: f64) -> ElectricPotential {
+                self.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+                    ambient_temperature,
+                ));
+                self.command(|a| a.power_demand(Power::new::<watt>(3000.)));
+                self.run();
+
+                self.potential(1)
+            }
         }
         impl TestBed for BatteryTestBed {
             type Aircraft = TestAircraft;
@@ -396,6 +565,14 @@ mod tests {
                 self.battery_2.charge()
             }
 
+            fn battery_1_state_of_health(&self) -> Ratio {
+                self.battery_1.state_of_health()
+            }
+
+            fn set_battery_1_state_of_health(&mut self, state_of_health: Ratio) {
+                self.battery_1.set_state_of_health(state_of_health);
+            }
+
             fn bat_bus_is_powered(&self, electricity: &Electricity) -> bool {
                 electricity.is_powered(&self.bat_bus)
             }
@@ -695,5 +872,175 @@ mod tests {
                     < ElectricCharge::new::<ampere_hour>(0.001)
             );
         }
+
+        #[test]
+        fn potential_under_load_is_lower_than_open_circuit_potential() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.run();
+
+            let open_circuit_potential = test_bed.potential(1);
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(3000.)));
+            test_bed.run();
+
+            assert!(test_bed.potential(1) < open_circuit_potential);
+        }
+
+        #[test]
+        fn cold_battery_provides_lower_potential_under_load() {
+            let mut warm_test_bed = BatteryTestBed::with_full_batteries();
+            let mut cold_test_bed = BatteryTestBed::with_full_batteries();
+
+            assert!(
+                cold_test_bed.potential_under_load_at(-30.)
+                    < warm_test_bed.potential_under_load_at(20.)
+            );
+        }
+
+        #[test]
+        fn starts_at_ambient_temperature() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+            test_bed.run_with_delta(Duration::from_secs(0));
+
+            assert_eq!(
+                test_bed.temperature(1),
+                ThermodynamicTemperature::new::<degree_celsius>(-20.)
+            );
+        }
+
+        #[test]
+        fn heavy_discharge_warms_up_battery() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(0.));
+            test_bed.run_with_delta(Duration::from_secs(0));
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            for _ in 0..60 {
+                test_bed.run_with_delta(Duration::from_secs(1));
+            }
+
+            assert!(test_bed.temperature(1) > ThermodynamicTemperature::new::<degree_celsius>(0.));
+        }
+
+        #[test]
+        fn warm_battery_cools_down_to_ambient_temperature() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(0.));
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            for _ in 0..60 {
+                test_bed.run_with_delta(Duration::from_secs(1));
+            }
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(0.)));
+            for _ in 0..60 {
+                test_bed.run_with_delta(Duration::from_secs(60));
+            }
+
+            assert_eq!(
+                test_bed.temperature(1),
+                ThermodynamicTemperature::new::<degree_celsius>(0.)
+            );
+        }
+
+        #[test]
+        fn deep_discharge_reduces_state_of_health() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            for _ in 0..20 {
+                test_bed.run_with_delta(Duration::from_secs(10));
+            }
+
+            assert!(
+                test_bed.query(|a| a.battery_1_state_of_health()) < Ratio::new::<percent>(100.)
+            );
+        }
+
+        #[test]
+        fn deep_discharge_reduces_state_of_health_only_once_until_recharged() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            for _ in 0..20 {
+                test_bed.run_with_delta(Duration::from_secs(10));
+            }
+
+            let state_of_health = test_bed.query(|a| a.battery_1_state_of_health());
+
+            for _ in 0..20 {
+                test_bed.run_with_delta(Duration::from_secs(10));
+            }
+
+            assert_eq!(
+                test_bed.query(|a| a.battery_1_state_of_health()),
+                state_of_health
+            );
+        }
+
+        #[test]
+        fn degraded_battery_is_deeply_discharged_at_a_higher_charge() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.command(|a| a.set_battery_1_state_of_health(Ratio::new::<percent>(55.)));
+            test_bed.run();
+
+            assert!(test_bed.query(|a| a.battery_1_state_of_health()) < Ratio::new::<percent>(55.));
+        }
+
+        #[test]
+        fn open_circuit_potential_is_continuous_over_charge() {
+            let mut previous =
+                Battery::calculate_open_circuit_potential_for_charge(ElectricCharge::new::<
+                    ampere_hour,
+                >(0.));
+            for tenth_ampere_hour in 1..=230 {
+                let potential =
+                    Battery::calculate_open_circuit_potential_for_charge(ElectricCharge::new::<
+                        ampere_hour,
+                    >(
+                        tenth_ampere_hour as f64 / 10.,
+                    ));
+
+                assert!(potential > previous);
+                assert!(potential - previous < ElectricPotential::new::<volt>(1.5));
+                previous = potential;
+            }
+        }
+
+        #[test]
+        fn partial_discharge_does_not_reduce_state_of_health() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(5000.)));
+            for _ in 0..20 {
+                test_bed.run_with_delta(Duration::from_secs(10));
+            }
+
+            assert_eq!(
+                test_bed.query(|a| a.battery_1_state_of_health()),
+                Ratio::new::<percent>(100.)
+            );
+        }
+
+        #[test]
+        fn degraded_battery_provides_lower_potential() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.run();
+
+            let healthy_potential = test_bed.potential(1);
+
+            test_bed.command(|a| a.set_battery_1_state_of_health(Ratio::new::<percent>(80.)));
+            test_bed.run();
+
+            assert!(test_bed.potential(1) < healthy_potential);
+        }
+
+        #[test]
+        fn writes_its_temperature() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.run();
+
+            assert!(test_bed.contains_variable_with_name("ELEC_BAT_1_TEMPERATURE"));
+        }
     }
 }
@@ -562,7 +562,7 @@ mod tests {
     mod battery_charge_limiter_tests {
         use std::time::Duration;
 
-        use uom::si::{length::foot, power::watt};
+        use uom::si::{length::foot, power::watt, thermodynamic_temperature::degree_celsius};
 
         use crate::{
             electrical::{
@@ -604,6 +604,13 @@ mod tests {
                 self
             }
 
+            fn ambient_temperature_of(mut self, ambient_temperature: f64) -> Self {
+                self.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+                    ambient_temperature,
+                ));
+                self
+            }
+
             fn run(mut self, delta: Duration) -> Self {
                 // The battery's current is updated after the BCL, thus we need two ticks.
                 self.run_with_delta(Duration::from_secs(0));
@@ -1315,6 +1322,36 @@ mod tests {
             assert!(!test_bed.battery_contactor_is_closed());
         }
 
+        #[test]
+        fn cold_soaked_battery_supplying_apu_start_triggers_discharge_protection_on_the_ground() {
+            let test_bed = test_bed_with()
+                .ambient_temperature_of(-35.)
+                .ground_bat_only_state(Velocity::new::<knot>(0.))
+                .power_demand_of(Power::new::<watt>(5000.))
+                .run(Duration::from_secs(1))
+                .then_continue_with()
+                .run(Duration::from_secs(
+                    Closed::BATTERY_DISCHARGE_PROTECTION_DELAY_SECONDS,
+                ));
+
+            assert!(!test_bed.battery_contactor_is_closed());
+        }
+
+        #[test]
+        fn warm_battery_supplying_apu_start_does_not_trigger_discharge_protection_on_the_ground() {
+            let test_bed = test_bed_with()
+                .ambient_temperature_of(20.)
+                .ground_bat_only_state(Velocity::new::<knot>(0.))
+                .power_demand_of(Power::new::<watt>(5000.))
+                .run(Duration::from_secs(1))
+                .then_continue_with()
+                .run(Duration::from_secs(
+                    Closed::BATTERY_DISCHARGE_PROTECTION_DELAY_SECONDS,
+                ));
+
+            assert!(test_bed.battery_contactor_is_closed());
+        }
+
         #[test]
         fn contactor_open_when_not_bat_only_on_ground_below_100_knots() {
             let test_bed = test_bed_with()
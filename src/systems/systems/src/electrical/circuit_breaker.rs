@@ -0,0 +1,263 @@
+use uom::si::{f64::*, ratio::ratio};
+
+use crate::simulation::{
+    InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+    VariableIdentifier, Write,
+};
+
+use super::overload_protection::TimeCurrentCharacteristic;
+
+/// A thermal circuit breaker which protects the wiring between an electrical bus
+/// and a power consumer.
+///
+/// The circuit breaker trips (pops out) when the current exceeds its rating for
+/// longer than allowed by its trip curve. A tripped circuit breaker is indistinguishable
+/// from a circuit breaker pulled by the pilot. Both are reset by pushing the circuit
+/// breaker in again, which is done by setting the `ELEC_CB_{id}_IS_PULLED` variable to false.
+pub struct CircuitBreaker {
+    id: String,
+    is_pulled_id: VariableIdentifier,
+    is_pulled: bool,
+    rated_current: ElectricCurrent,
+    trip_characteristic: TimeCurrentCharacteristic,
+}
+impl CircuitBreaker {
+    const TRIP_PICKUP_PERCENT: f64 = 115.;
+    const TRIP_LOAD_PERCENT: [f64; 5] = [115., 138., 200., 400., 1000.];
+    const TRIP_ALLOWED_TIME_SECONDS: [f64; 5] = [3600., 600., 30., 2., 0.1];
+    const TRIP_RESET_TIME_SECONDS: f64 = 60.;
+
+    pub fn new(context: &mut InitContext, id: &str, rated_current: ElectricCurrent) -> Self {
+        Self {
+            id: id.to_owned(),
+            is_pulled_id: context.get_identifier(format!("ELEC_CB_{}_IS_PULLED", id)),
+            is_pulled: false,
+            rated_current,
+            trip_characteristic: TimeCurrentCharacteristic::new(
+                Self::TRIP_PICKUP_PERCENT,
+                &Self::TRIP_LOAD_PERCENT,
+                &Self::TRIP_ALLOWED_TIME_SECONDS,
+                Self::TRIP_RESET_TIME_SECONDS,
+            ),
+        }
+    }
+
+    /// Updates the circuit breaker with the current flowing through it.
+    pub fn update(&mut self, context: &UpdateContext, current: ElectricCurrent) {
+        let load = if self.is_pulled {
+            Ratio::new::<ratio>(0.)
+        } else {
+            current / self.rated_current
+        };
+
+        self.trip_characteristic.update(context, load);
+        if self.trip_characteristic.is_exceeded() {
+            self.is_pulled = true;
+        }
+    }
+
+    pub fn is_pulled(&self) -> bool {
+        self.is_pulled
+    }
+
+    pub fn is_closed(&self) -> bool {
+        !self.is_pulled
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn rated_current(&self) -> ElectricCurrent {
+        self.rated_current
+    }
+}
+impl SimulationElement for CircuitBreaker {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_pulled = reader.read(&self.is_pulled_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_pulled_id, self.is_pulled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{
+            consumption::PowerConsumer, test::TestElectricitySource, ElectricalBus, Electricity,
+        },
+        shared::{ElectricalBusType, PotentialOrigin},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+    use std::time::Duration;
+    use uom::si::{electric_current::ampere, power::watt};
+
+    const FRAME: Duration = Duration::from_millis(100);
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        bus: ElectricalBus,
+        consumer: PowerConsumer,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let circuit_breaker =
+                CircuitBreaker::new(context, "TEST", ElectricCurrent::new::<ampere>(10.));
+
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::TransformerRectifier(1),
+                ),
+                bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                consumer: PowerConsumer::from(ElectricalBusType::DirectCurrent(1))
+                    .protected_by(context, circuit_breaker),
+            }
+        }
+
+        fn demand(&mut self, power: Power) {
+            self.consumer.demand(power);
+        }
+
+        fn consumer_is_powered(&self) -> bool {
+            self.consumer.is_powered()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.bus);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.bus.accept(visitor);
+            self.consumer.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_with_demand(watts: f64) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.command(|a| a.demand(Power::new::<watt>(watts)));
+
+        test_bed
+    }
+
+    fn is_pulled(test_bed: &mut SimulationTestBed<TestAircraft>) -> bool {
+        test_bed.read_by_name("ELEC_CB_TEST_IS_PULLED")
+    }
+
+    #[test]
+    fn writes_its_state() {
+        let mut test_bed = test_bed_with_demand(0.);
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("ELEC_CB_TEST_IS_PULLED"));
+    }
+
+    #[test]
+    fn consumer_is_powered_when_circuit_breaker_is_in() {
+        let mut test_bed = test_bed_with_demand(100.);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.consumer_is_powered()));
+        assert!(!is_pulled(&mut test_bed));
+    }
+
+    #[test]
+    fn consumer_is_unpowered_when_circuit_breaker_is_pulled() {
+        let mut test_bed = test_bed_with_demand(100.);
+        test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", true);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.consumer_is_powered()));
+    }
+
+    #[test]
+    fn consumer_is_powered_again_after_circuit_breaker_is_reset() {
+        let mut test_bed = test_bed_with_demand(100.);
+        test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", true);
+        test_bed.run();
+
+        test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", false);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.consumer_is_powered()));
+    }
+
+    #[test]
+    fn pulled_circuit_breaker_prevents_consumption() {
+        let mut test_bed = test_bed_with_demand(100.);
+        test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", true);
+        test_bed.run();
+
+        let topology = test_bed.electrical_topology();
+
+        assert_eq!(
+            topology.node("DC_1").unwrap().power(),
+            Power::new::<watt>(0.)
+        );
+    }
+
+    #[test]
+    fn does_not_trip_at_rated_current() {
+        // 280 W at 28 V equals the 10 A rating.
+        let mut test_bed = test_bed_with_demand(280.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(7200), FRAME);
+
+        assert!(!is_pulled(&mut test_bed));
+    }
+
+    #[test]
+    fn does_not_trip_on_short_overcurrent() {
+        let mut test_bed = test_bed_with_demand(560.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(20), FRAME);
+
+        assert!(!is_pulled(&mut test_bed));
+        assert!(test_bed.query(|a| a.consumer_is_powered()));
+    }
+
+    #[test]
+    fn trips_on_sustained_overcurrent() {
+        let mut test_bed = test_bed_with_demand(560.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(31), FRAME);
+
+        assert!(is_pulled(&mut test_bed));
+        assert!(!test_bed.query(|a| a.consumer_is_powered()));
+    }
+
+    #[test]
+    fn trips_sooner_on_higher_overcurrent() {
+        let mut test_bed = test_bed_with_demand(1120.);
+        test_bed.run_with_fixed_frames(Duration::from_millis(2100), FRAME);
+
+        assert!(is_pulled(&mut test_bed));
+    }
+
+    #[test]
+    fn tripped_circuit_breaker_remains_pulled_until_reset() {
+        let mut test_bed = test_bed_with_demand(1120.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(3), FRAME);
+
+        test_bed.command(|a| a.demand(Power::new::<watt>(100.)));
+        test_bed.run_with_fixed_frames(Duration::from_secs(120), FRAME);
+        assert!(is_pulled(&mut test_bed));
+
+        test_bed.write_by_name("ELEC_CB_TEST_IS_PULLED", false);
+        test_bed.run();
+        assert!(!is_pulled(&mut test_bed));
+        assert!(test_bed.query(|a| a.consumer_is_powered()));
+    }
+}
@@ -0,0 +1,260 @@
+use std::time::Duration;
+
+use crate::{
+    shared::{ElectricalBusType, ElectricalBuses},
+    simulation::{SimulationElement, UpdateContext},
+};
+
+/// The power supply unit of an electronic computer.
+///
+/// The power supply stores enough energy to bridge short interruptions, such as those
+/// occurring during power transfers. When an interruption lasts longer than the hold-up
+/// time, the computer shuts down. Once power is restored it resets and has to complete
+/// its start-up before it is operational again.
+pub struct ComputerPowerSupply {
+    powered_by: Vec<ElectricalBusType>,
+    hold_up_time: Duration,
+    start_up_time: Duration,
+    is_supplied: bool,
+    unsupplied_duration: Duration,
+    is_powered: bool,
+    has_reset: bool,
+    remaining_start_up_time: Duration,
+    is_initialised: bool,
+}
+impl ComputerPowerSupply {
+    pub fn new(
+        powered_by: Vec<ElectricalBusType>,
+        hold_up_time: Duration,
+        start_up_time: Duration,
+    ) -> Self {
+        Self {
+            powered_by,
+            hold_up_time,
+            start_up_time,
+            is_supplied: false,
+            unsupplied_duration: Duration::from_secs(0),
+            is_powered: false,
+            has_reset: false,
+            remaining_start_up_time: Duration::from_secs(0),
+            is_initialised: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.has_reset = false;
+
+        // A computer supplied from the start, such as when starting on the runway or in the air,
+        // is already running.
+        if !self.is_initialised {
+            self.is_powered = self.is_supplied;
+            self.is_initialised = true;
+        }
+
+        if self.is_supplied {
+            if !self.is_powered {
+                self.has_reset = true;
+                self.remaining_start_up_time = self.start_up_time;
+            } else {
+                self.remaining_start_up_time = Duration::from_secs_f64(
+                    (self.remaining_start_up_time.as_secs_f64() - context.delta_as_secs_f64())
+                        .max(0.),
+                );
+            }
+
+            self.is_powered = true;
+            self.unsupplied_duration = Duration::from_secs(0);
+        } else {
+            self.unsupplied_duration += context.delta();
+            self.is_powered = self.is_powered && self.unsupplied_duration <= self.hold_up_time;
+        }
+    }
+
+    /// Indicates if the computer is running, either on external supply
+    /// or on the energy stored in its power supply.
+    pub fn is_powered(&self) -> bool {
+        self.is_powered
+    }
+
+    /// Indicates if the computer is running and has completed its start-up.
+    pub fn is_operational(&self) -> bool {
+        self.is_powered && self.remaining_start_up_time == Duration::from_secs(0)
+    }
+
+    /// Indicates if the computer reset during the last update, due to power returning
+    /// after an interruption longer than the hold-up time.
+    pub fn has_reset(&self) -> bool {
+        self.has_reset
+    }
+}
+impl SimulationElement for ComputerPowerSupply {
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_supplied = buses.any_is_powered(&self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{SimulationTestBed, TestBed},
+            Aircraft, InitContext, SimulationElementVisitor,
+        },
+    };
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        bus: ElectricalBus,
+        power_supply: ComputerPowerSupply,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::TransformerRectifier(1),
+                ),
+                bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                power_supply: ComputerPowerSupply::new(
+                    vec![ElectricalBusType::DirectCurrent(1)],
+                    Duration::from_millis(50),
+                    Duration::from_secs(3),
+                ),
+            }
+        }
+
+        fn power(&mut self) {
+            self.electricity_source.power();
+        }
+
+        fn unpower(&mut self) {
+            self.electricity_source.unpower();
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.bus);
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.power_supply.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.bus.accept(visitor);
+            self.power_supply.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed
+    }
+
+    #[test]
+    fn is_not_powered_before_first_update() {
+        let test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        assert!(!test_bed.query(|a| a.power_supply.is_powered()));
+    }
+
+    #[test]
+    fn starts_unpowered_without_supply() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.command(|a| a.unpower());
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(!test_bed.query(|a| a.power_supply.is_powered()));
+        assert!(!test_bed.query(|a| a.power_supply.has_reset()));
+    }
+
+    #[test]
+    fn starts_operational() {
+        let test_bed = test_bed();
+
+        assert!(test_bed.query(|a| a.power_supply.is_operational()));
+        assert!(!test_bed.query(|a| a.power_supply.has_reset()));
+    }
+
+    #[test]
+    fn bridges_interruption_shorter_than_hold_up_time() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.unpower());
+        test_bed.run_with_delta(Duration::from_millis(30));
+        assert!(test_bed.query(|a| a.power_supply.is_operational()));
+
+        test_bed.command(|a| a.power());
+        test_bed.run_with_delta(Duration::from_millis(10));
+        assert!(test_bed.query(|a| a.power_supply.is_operational()));
+        assert!(!test_bed.query(|a| a.power_supply.has_reset()));
+    }
+
+    #[test]
+    fn shuts_down_on_interruption_longer_than_hold_up_time() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.unpower());
+        test_bed.run_with_delta(Duration::from_millis(30));
+        test_bed.run_with_delta(Duration::from_millis(30));
+
+        assert!(!test_bed.query(|a| a.power_supply.is_powered()));
+        assert!(!test_bed.query(|a| a.power_supply.is_operational()));
+    }
+
+    #[test]
+    fn resets_when_power_returns_after_shut_down() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.unpower());
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        test_bed.command(|a| a.power());
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(test_bed.query(|a| a.power_supply.has_reset()));
+        assert!(test_bed.query(|a| a.power_supply.is_powered()));
+        assert!(!test_bed.query(|a| a.power_supply.is_operational()));
+    }
+
+    #[test]
+    fn reset_is_only_indicated_once() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.unpower());
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        test_bed.command(|a| a.power());
+        test_bed.run_with_delta(Duration::from_millis(10));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(!test_bed.query(|a| a.power_supply.has_reset()));
+    }
+
+    #[test]
+    fn becomes_operational_after_start_up() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.unpower());
+        test_bed.run_with_delta(Duration::from_millis(100));
+
+        test_bed.command(|a| a.power());
+        test_bed.run_with_delta(Duration::from_millis(10));
+        test_bed.run_with_delta(Duration::from_secs(3));
+
+        assert!(test_bed.query(|a| a.power_supply.is_operational()));
+    }
+}
@@ -16,8 +16,10 @@
 //! 6. The total load is passed to the various origins so that they can calculate their
 //!    load %, voltage, frequency and current.
 
-use super::ElectricalBusType;
+use super::{CircuitBreaker, ElectricalBusType, PowerConsumerDescription};
 use crate::simulation::{InitContext, VariableIdentifier};
+#[cfg(feature = "power-consumer-simvars")]
+use crate::simulation::{SimulatorWriter, Write};
 use crate::{
     shared::{random_number, ConsumePower, ElectricalBuses, FwcFlightPhase},
     simulation::{
@@ -26,36 +28,130 @@ use crate::{
 };
 use num_traits::FromPrimitive;
 use std::time::Duration;
-use uom::si::{f64::*, power::watt};
+use uom::si::{electric_current::ampere, electric_potential::volt, f64::*, power::watt};
 
 /// A generic consumer of power.
 pub struct PowerConsumer {
+    name: Option<(String, VariableIdentifier)>,
     is_powered: bool,
     demand: Power,
     powered_by_bus: ElectricalBusType,
+    bus_potential: ElectricPotential,
+    circuit_breaker: Option<CircuitBreaker>,
 }
 impl PowerConsumer {
     /// Create a power consumer which consumes power from the given bus type.
     pub fn from(bus_type: ElectricalBusType) -> Self {
         PowerConsumer {
+            name: None,
             is_powered: Default::default(),
             demand: Power::new::<watt>(0.),
             powered_by_bus: bus_type,
+            bus_potential: ElectricPotential::new::<volt>(0.),
+            circuit_breaker: None,
         }
     }
 
+    /// Protect the consumer with the given circuit breaker. When the circuit
+    /// breaker is pulled, the consumer is unpowered and consumes no power.
+    /// A consumer which isn't named yet is named after its circuit breaker.
+    pub fn protected_by(
+        mut self,
+        context: &mut InitContext,
+        circuit_breaker: CircuitBreaker,
+    ) -> Self {
+        if self.name.is_none() {
+            self = self.named(context, circuit_breaker.id());
+        }
+
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Name the consumer. Named consumers are included in the
+    /// [`PowerConsumptionBreakdown`]. With the `power-consumer-simvars` feature
+    /// enabled, they also write the power they consume to the simulator.
+    ///
+    /// [`PowerConsumptionBreakdown`]: ../struct.PowerConsumptionBreakdown.html
+    pub fn named(mut self, context: &mut InitContext, name: &str) -> Self {
+        self.name = Some((
+            name.to_owned(),
+            context.get_identifier(format!("ELEC_CONSUMER_{}_POWER", name)),
+        ));
+        self
+    }
+
     /// Set the amount of power that is demanded by the consumer when powered.
     pub fn demand(&mut self, power: Power) {
         self.demand = power;
     }
+
+    pub fn is_powered(&self) -> bool {
+        self.is_powered
+    }
+
+    #[cfg(feature = "power-consumer-simvars")]
+    fn consumption(&self) -> Power {
+        if self.is_powered {
+            self.demand
+        } else {
+            Power::new::<watt>(0.)
+        }
+    }
+
+    fn circuit_breaker_is_closed(&self) -> bool {
+        self.circuit_breaker
+            .as_ref()
+            .map_or(true, |circuit_breaker| circuit_breaker.is_closed())
+    }
 }
 impl SimulationElement for PowerConsumer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        if let Some(circuit_breaker) = &mut self.circuit_breaker {
+            circuit_breaker.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
-        self.is_powered = buses.is_powered(self.powered_by_bus);
+        self.is_powered = buses.is_powered(self.powered_by_bus) && self.circuit_breaker_is_closed();
+        self.bus_potential = buses.potential_of(self.powered_by_bus).raw();
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
+        if let Some(circuit_breaker) = &mut self.circuit_breaker {
+            let current =
+                if self.is_powered && self.bus_potential > ElectricPotential::new::<volt>(0.) {
+                    self.demand / self.bus_potential
+                } else {
+                    ElectricCurrent::new::<ampere>(0.)
+                };
+
+            circuit_breaker.update(context, current);
+        }
+
+        if self.circuit_breaker_is_closed() {
+            consumption.consume_from_bus(self.powered_by_bus, self.demand);
+        }
+    }
+
+    #[cfg(feature = "power-consumer-simvars")]
+    fn write(&self, writer: &mut SimulatorWriter) {
+        if let Some((_, consumption_id)) = &self.name {
+            writer.write(consumption_id, self.consumption().get::<watt>());
+        }
     }
 
-    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
-        consumption.consume_from_bus(self.powered_by_bus, self.demand);
+    fn describe_power_consumer(&self) -> Option<PowerConsumerDescription> {
+        self.name.as_ref().map(|(name, _)| {
+            PowerConsumerDescription::new(
+                name.as_str(),
+                self.powered_by_bus,
+                self.demand,
+                self.is_powered,
+            )
+        })
     }
 }
 
@@ -103,6 +199,24 @@ impl FlightPhasePowerConsumer {
 
         self
     }
+
+    pub fn protected_by(
+        mut self,
+        context: &mut InitContext,
+        circuit_breaker: CircuitBreaker,
+    ) -> Self {
+        self.consumer = self.consumer.protected_by(context, circuit_breaker);
+        self
+    }
+
+    pub fn named(mut self, context: &mut InitContext, name: &str) -> Self {
+        self.consumer = self.consumer.named(context, name);
+        self
+    }
+
+    pub fn is_powered(&self) -> bool {
+        self.consumer.is_powered()
+    }
 }
 impl SimulationElement for FlightPhasePowerConsumer {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
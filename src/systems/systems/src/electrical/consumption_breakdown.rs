@@ -0,0 +1,489 @@
+use std::fmt::Write;
+
+use uom::si::{f64::*, power::watt};
+
+use crate::{
+    shared::{ElectricalBusType, ElectricalBuses, PotentialOrigin},
+    simulation::{SimulationElement, SimulationElementVisitor},
+};
+
+use super::Electricity;
+
+/// Describes a named power consumer. Consumers provide their description through
+/// [`SimulationElement::describe_power_consumer`].
+pub struct PowerConsumerDescription {
+    name: String,
+    bus_type: ElectricalBusType,
+    demand: Power,
+    is_powered: bool,
+}
+impl PowerConsumerDescription {
+    pub fn new<T: Into<String>>(
+        name: T,
+        bus_type: ElectricalBusType,
+        demand: Power,
+        is_powered: bool,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            bus_type,
+            demand,
+            is_powered,
+        }
+    }
+}
+
+/// A breakdown of the power consumed by each named power consumer, as it was
+/// at the end of the last tick. Consumption is aggregated per bus and per source,
+/// which is useful when validating load figures against the electrical load analysis.
+///
+/// When a bus is powered by multiple sources, its consumption is divided equally
+/// among them, just as the electrical system does when determining the load of sources.
+pub struct PowerConsumptionBreakdown {
+    consumers: Vec<PowerConsumptionBreakdownEntry>,
+}
+impl PowerConsumptionBreakdown {
+    pub(super) fn new(
+        descriptions: Vec<PowerConsumerDescription>,
+        electricity: &Electricity,
+    ) -> Self {
+        let mut consumers: Vec<PowerConsumptionBreakdownEntry> = descriptions
+            .into_iter()
+            .map(|description| PowerConsumptionBreakdownEntry::new(description, electricity))
+            .collect();
+        consumers.sort_by(|a, b| {
+            a.bus_type
+                .to_string()
+                .cmp(&b.bus_type.to_string())
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        Self { consumers }
+    }
+
+    pub fn consumer(&self, name: &str) -> Option<&PowerConsumptionBreakdownEntry> {
+        self.consumers.iter().find(|consumer| consumer.name == name)
+    }
+
+    pub fn consumers(&self) -> impl Iterator<Item = &PowerConsumptionBreakdownEntry> {
+        self.consumers.iter()
+    }
+
+    /// The total power consumed by the consumers on the given bus.
+    pub fn consumption_of_bus(&self, bus_type: ElectricalBusType) -> Power {
+        self.consumers
+            .iter()
+            .filter(|consumer| consumer.bus_type == bus_type)
+            .map(|consumer| consumer.consumption())
+            .fold(Power::new::<watt>(0.), |total, power| total + power)
+    }
+
+    /// The total power consumed by the consumers from the given source.
+    pub fn consumption_of_source(&self, origin: PotentialOrigin) -> Power {
+        self.consumers
+            .iter()
+            .map(|consumer| consumer.consumption_from(origin))
+            .fold(Power::new::<watt>(0.), |total, power| total + power)
+    }
+
+    /// The buses which have at least one consumer, ordered by name.
+    pub fn buses(&self) -> Vec<ElectricalBusType> {
+        let mut buses: Vec<ElectricalBusType> = Vec::new();
+        for consumer in &self.consumers {
+            if !buses.contains(&consumer.bus_type) {
+                buses.push(consumer.bus_type);
+            }
+        }
+
+        buses
+    }
+
+    /// The sources which supply at least one consumer, ordered by name.
+    pub fn sources(&self) -> Vec<PotentialOrigin> {
+        let mut sources: Vec<PotentialOrigin> = Vec::new();
+        for origin in self.consumers.iter().flat_map(|consumer| &consumer.origins) {
+            if !sources.contains(origin) {
+                sources.push(*origin);
+            }
+        }
+        sources.sort_by_key(|origin| origin.to_string());
+
+        sources
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        writeln!(csv, "consumer,bus,demand_w,powered,consumption_w,sources").unwrap();
+
+        for consumer in &self.consumers {
+            writeln!(
+                csv,
+                "{},{},{:.1},{},{:.1},{}",
+                escape(&consumer.name),
+                consumer.bus_type,
+                consumer.demand.get::<watt>(),
+                consumer.is_powered,
+                consumer.consumption().get::<watt>(),
+                consumer
+                    .origins
+                    .iter()
+                    .map(|origin| origin.to_string())
+                    .collect::<Vec<_>>()
+                    .join("+")
+            )
+            .unwrap();
+        }
+
+        csv
+    }
+}
+
+/// A named power consumer within the [`PowerConsumptionBreakdown`].
+pub struct PowerConsumptionBreakdownEntry {
+    name: String,
+    bus_type: ElectricalBusType,
+    demand: Power,
+    is_powered: bool,
+    origins: Vec<PotentialOrigin>,
+}
+impl PowerConsumptionBreakdownEntry {
+    fn new(description: PowerConsumerDescription, electricity: &Electricity) -> Self {
+        let mut origins: Vec<PotentialOrigin> = if description.is_powered {
+            electricity
+                .potential_of(description.bus_type)
+                .origins()
+                .copied()
+                .collect()
+        } else {
+            Vec::new()
+        };
+        origins.sort_by_key(|origin| origin.to_string());
+
+        Self {
+            name: description.name,
+            bus_type: description.bus_type,
+            demand: description.demand,
+            is_powered: description.is_powered,
+            origins,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn bus_type(&self) -> ElectricalBusType {
+        self.bus_type
+    }
+
+    /// The power demanded by the consumer, regardless of it being powered.
+    pub fn demand(&self) -> Power {
+        self.demand
+    }
+
+    pub fn is_powered(&self) -> bool {
+        self.is_powered
+    }
+
+    /// The power actually consumed, which is zero when the consumer is unpowered.
+    pub fn consumption(&self) -> Power {
+        if self.is_powered {
+            self.demand
+        } else {
+            Power::new::<watt>(0.)
+        }
+    }
+
+    /// The origins of the potential which feeds the consumer.
+    pub fn origins(&self) -> &[PotentialOrigin] {
+        &self.origins
+    }
+
+    fn consumption_from(&self, origin: PotentialOrigin) -> Power {
+        if self.origins.contains(&origin) {
+            self.consumption() / self.origins.len() as f64
+        } else {
+            Power::new::<watt>(0.)
+        }
+    }
+}
+
+pub(super) struct DescribePowerConsumerVisitor {
+    descriptions: Vec<PowerConsumerDescription>,
+}
+impl DescribePowerConsumerVisitor {
+    pub(super) fn new() -> Self {
+        Self {
+            descriptions: Vec::new(),
+        }
+    }
+
+    pub(super) fn into_descriptions(self) -> Vec<PowerConsumerDescription> {
+        self.descriptions
+    }
+}
+impl SimulationElementVisitor for DescribePowerConsumerVisitor {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        if let Some(description) = visited.describe_power_consumer() {
+            self.descriptions.push(description);
+        }
+    }
+}
+
+fn escape(value: &str) -> String {
+    if value.contains(',') || value.contains('"') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{
+            consumption::PowerConsumer, test::TestElectricitySource, CircuitBreaker, ElectricalBus,
+        },
+        simulation::{
+            test::{SimulationTestBed, TestBed},
+            Aircraft, InitContext, UpdateContext,
+        },
+    };
+    use uom::si::electric_current::ampere;
+
+    struct TestAircraft {
+        engine_gen_1: TestElectricitySource,
+        engine_gen_2: TestElectricitySource,
+        ac_bus: ElectricalBus,
+        dc_bus: ElectricalBus,
+        galley: PowerConsumer,
+        avionics: PowerConsumer,
+        lights: PowerConsumer,
+        unnamed: PowerConsumer,
+        engine_gen_2_is_connected: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let mut galley = PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1))
+                .named(context, "GALLEY");
+            galley.demand(Power::new::<watt>(1000.));
+            let circuit_breaker =
+                CircuitBreaker::new(context, "AVIONICS", ElectricCurrent::new::<ampere>(10.));
+            let mut avionics = PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1))
+                .protected_by(context, circuit_breaker);
+            avionics.demand(Power::new::<watt>(500.));
+            let mut lights =
+                PowerConsumer::from(ElectricalBusType::DirectCurrent(1)).named(context, "LIGHTS");
+            lights.demand(Power::new::<watt>(200.));
+            let mut unnamed = PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1));
+            unnamed.demand(Power::new::<watt>(300.));
+
+            Self {
+                engine_gen_1: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                engine_gen_2: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(2),
+                ),
+                ac_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                dc_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                galley,
+                avionics,
+                lights,
+                unnamed,
+                engine_gen_2_is_connected: false,
+            }
+        }
+
+        fn connect_engine_gen_2(&mut self) {
+            self.engine_gen_2_is_connected = true;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.engine_gen_1);
+            electricity.flow(&self.engine_gen_1, &self.ac_bus);
+
+            if self.engine_gen_2_is_connected {
+                electricity.supplied_by(&self.engine_gen_2);
+                electricity.flow(&self.engine_gen_2, &self.ac_bus);
+            }
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.ac_bus.accept(visitor);
+            self.dc_bus.accept(visitor);
+            self.galley.accept(visitor);
+            self.avionics.accept(visitor);
+            self.lights.accept(visitor);
+            self.unnamed.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.run();
+
+        test_bed
+    }
+
+    #[test]
+    fn only_includes_named_consumers() {
+        let mut test_bed = test_bed();
+
+        let breakdown = test_bed.power_consumption_breakdown();
+
+        assert_eq!(
+            breakdown
+                .consumers()
+                .map(|consumer| consumer.name())
+                .collect::<Vec<_>>(),
+            vec!["AVIONICS", "GALLEY", "LIGHTS"]
+        );
+    }
+
+    #[test]
+    fn powered_consumer_consumes_its_demand() {
+        let mut test_bed = test_bed();
+
+        let breakdown = test_bed.power_consumption_breakdown();
+        let galley = breakdown.consumer("GALLEY").unwrap();
+
+        assert_eq!(galley.bus_type(), ElectricalBusType::AlternatingCurrent(1));
+        assert!(galley.is_powered());
+        assert_eq!(galley.consumption(), Power::new::<watt>(1000.));
+        assert_eq!(galley.origins(), &[PotentialOrigin::EngineGenerator(1)]);
+    }
+
+    #[test]
+    fn unpowered_consumer_consumes_nothing_but_retains_its_demand() {
+        let mut test_bed = test_bed();
+
+        let breakdown = test_bed.power_consumption_breakdown();
+        let lights = breakdown.consumer("LIGHTS").unwrap();
+
+        assert!(!lights.is_powered());
+        assert_eq!(lights.demand(), Power::new::<watt>(200.));
+        assert_eq!(lights.consumption(), Power::new::<watt>(0.));
+        assert!(lights.origins().is_empty());
+    }
+
+    #[test]
+    fn aggregates_consumption_per_bus() {
+        let mut test_bed = test_bed();
+
+        let breakdown = test_bed.power_consumption_breakdown();
+
+        assert_eq!(
+            breakdown.buses(),
+            vec![
+                ElectricalBusType::AlternatingCurrent(1),
+                ElectricalBusType::DirectCurrent(1)
+            ]
+        );
+        assert_eq!(
+            breakdown.consumption_of_bus(ElectricalBusType::AlternatingCurrent(1)),
+            Power::new::<watt>(1500.)
+        );
+        assert_eq!(
+            breakdown.consumption_of_bus(ElectricalBusType::DirectCurrent(1)),
+            Power::new::<watt>(0.)
+        );
+    }
+
+    #[test]
+    fn aggregates_consumption_per_source() {
+        let mut test_bed = test_bed();
+
+        let breakdown = test_bed.power_consumption_breakdown();
+
+        assert_eq!(
+            breakdown.sources(),
+            vec![PotentialOrigin::EngineGenerator(1)]
+        );
+        assert_eq!(
+            breakdown.consumption_of_source(PotentialOrigin::EngineGenerator(1)),
+            Power::new::<watt>(1500.)
+        );
+    }
+
+    #[test]
+    fn divides_consumption_equally_among_sources_powering_the_same_bus() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.connect_engine_gen_2());
+        test_bed.run();
+
+        let breakdown = test_bed.power_consumption_breakdown();
+
+        assert_eq!(
+            breakdown.consumption_of_source(PotentialOrigin::EngineGenerator(1)),
+            Power::new::<watt>(750.)
+        );
+        assert_eq!(
+            breakdown.consumption_of_source(PotentialOrigin::EngineGenerator(2)),
+            Power::new::<watt>(750.)
+        );
+    }
+
+    #[test]
+    fn exports_to_csv() {
+        let mut test_bed = test_bed();
+
+        let csv = test_bed.power_consumption_breakdown().to_csv();
+
+        assert_eq!(
+            csv,
+            "consumer,bus,demand_w,powered,consumption_w,sources\n\
+             AVIONICS,AC_1,500.0,true,500.0,EngineGenerator(1)\n\
+             GALLEY,AC_1,1000.0,true,1000.0,EngineGenerator(1)\n\
+             LIGHTS,DC_1,200.0,false,0.0,\n"
+        );
+    }
+
+    #[test]
+    fn consumer_protected_by_circuit_breaker_is_named_after_it() {
+        let mut test_bed = test_bed();
+
+        let breakdown = test_bed.power_consumption_breakdown();
+
+        assert_eq!(
+            breakdown.consumer("AVIONICS").unwrap().consumption(),
+            Power::new::<watt>(500.)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "power-consumer-simvars")]
+    fn named_consumer_writes_its_consumption() {
+        use crate::simulation::test::ReadByName;
+
+        let mut test_bed = test_bed();
+
+        let galley_power: f64 = test_bed.read_by_name("ELEC_CONSUMER_GALLEY_POWER");
+        let avionics_power: f64 = test_bed.read_by_name("ELEC_CONSUMER_AVIONICS_POWER");
+        let lights_power: f64 = test_bed.read_by_name("ELEC_CONSUMER_LIGHTS_POWER");
+
+        assert!((galley_power - 1000.).abs() < f64::EPSILON);
+        assert!((avionics_power - 500.).abs() < f64::EPSILON);
+        assert!(lights_power.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    #[cfg(not(feature = "power-consumer-simvars"))]
+    fn named_consumer_does_not_write_its_consumption_by_default() {
+        let test_bed = test_bed();
+
+        assert!(!test_bed.contains_variable_with_name("ELEC_CONSUMER_GALLEY_POWER"));
+        assert!(!test_bed.contains_variable_with_name("ELEC_CONSUMER_AVIONICS_POWER"));
+    }
+}
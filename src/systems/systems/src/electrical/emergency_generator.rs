@@ -1,9 +1,9 @@
 use crate::simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext};
 
 use super::{
-    ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-    ElectricalStateWriter, ElectricitySource, Potential, PotentialOrigin, ProvideFrequency,
-    ProvidePotential,
+    ElectricalElement, ElectricalElementDescription, ElectricalElementIdentifier,
+    ElectricalElementIdentifierProvider, ElectricalElementKind, ElectricalStateWriter,
+    ElectricitySource, Potential, PotentialOrigin, ProvideFrequency, ProvidePotential,
 };
 use crate::shared::{
     EmergencyGeneratorPower, HydraulicGeneratorControlUnit, PowerConsumptionReport,
@@ -97,6 +97,14 @@ impl EmergencyGeneratorPower for EmergencyGenerator {
     }
 }
 impl SimulationElement for EmergencyGenerator {
+    fn describe_electrical_element(&self) -> Option<ElectricalElementDescription> {
+        Some(ElectricalElementDescription::new(
+            self,
+            "EMER_GEN",
+            ElectricalElementKind::Source(PotentialOrigin::EmergencyGenerator),
+        ))
+    }
+
     fn process_power_consumption_report<T: PowerConsumptionReport>(
         &mut self,
         _: &UpdateContext,
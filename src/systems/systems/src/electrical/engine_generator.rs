@@ -1,11 +1,17 @@
 use std::cmp::min;
 
 use uom::si::{
-    electric_potential::volt, f64::*, frequency::hertz, power::watt, ratio::percent,
+    electric_potential::volt,
+    f64::*,
+    frequency::hertz,
+    power::watt,
+    pressure::psi,
+    ratio::{percent, ratio},
     thermodynamic_temperature::degree_celsius,
 };
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{
         calculate_towards_target_temperature, EngineCorrectedN2, EngineFirePushButtons,
         PowerConsumptionReport,
@@ -17,8 +23,9 @@ use crate::{
 };
 
 use super::{
-    ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-    ElectricalStateWriter, ElectricitySource, EngineGeneratorPushButtons, Potential,
+    ElectricalElement, ElectricalElementDescription, ElectricalElementIdentifier,
+    ElectricalElementIdentifierProvider, ElectricalElementKind, ElectricalStateWriter,
+    ElectricitySource, EngineGeneratorPushButtons, GeneratorOverloadProtection, Potential,
     PotentialOrigin, ProvideFrequency, ProvideLoad, ProvidePotential,
 };
 
@@ -32,6 +39,7 @@ pub struct EngineGenerator {
     output_frequency: Frequency,
     output_potential: ElectricPotential,
     load: Ratio,
+    overload_protection: GeneratorOverloadProtection,
 }
 impl EngineGenerator {
     pub fn new(context: &mut InitContext, number: usize) -> EngineGenerator {
@@ -43,6 +51,7 @@ impl EngineGenerator {
             output_frequency: Frequency::new::<hertz>(0.),
             output_potential: ElectricPotential::new::<volt>(0.),
             load: Ratio::new::<percent>(0.),
+            overload_protection: GeneratorOverloadProtection::new(),
         }
     }
 
@@ -54,17 +63,41 @@ impl EngineGenerator {
         fire_buttons: &impl EngineFirePushButtons,
     ) {
         self.idg
-            .update(context, engine, generator_buttons, fire_buttons);
+            .update(context, engine, generator_buttons, fire_buttons, self.load);
+
+        // Switching the generator off and on again resets the generator control unit.
+        if !generator_buttons.engine_gen_push_button_is_on(self.number) {
+            self.overload_protection.reset();
+        }
     }
 
     /// Indicates if the provided electricity's potential and frequency
     /// are within normal parameters. Use this to decide if the
     /// generator contactor should close.
-    /// Load shouldn't be taken into account, as overloading causes an
-    /// overtemperature which over time will trigger a mechanical
-    /// disconnect of the generator.
+    /// Load shouldn't be taken into account directly. A sustained overload
+    /// trips the generator, after which the output is no longer considered
+    /// to be within normal parameters.
     pub fn output_within_normal_parameters(&self) -> bool {
-        self.should_provide_output() && self.frequency_normal() && self.potential_normal()
+        self.should_provide_output()
+            && self.frequency_normal()
+            && self.potential_normal()
+            && !self.overload_protection.has_tripped()
+    }
+
+    /// Indicates if the generator is overloaded to such an extent that
+    /// commercial loads should be shed.
+    pub fn requires_load_shedding(&self) -> bool {
+        self.overload_protection.requires_load_shedding()
+    }
+
+    /// Indicates if the IDG has a fault, due to its oil overheating or
+    /// its oil pressure being low.
+    pub fn idg_has_fault(&self) -> bool {
+        self.idg.has_fault()
+    }
+
+    pub fn idg_is_connected(&self) -> bool {
+        self.idg.connected
     }
 
     fn should_provide_output(&self) -> bool {
@@ -100,6 +133,14 @@ impl ElectricalElement for EngineGenerator {
     }
 }
 impl SimulationElement for EngineGenerator {
+    fn describe_electrical_element(&self) -> Option<ElectricalElementDescription> {
+        Some(ElectricalElementDescription::new(
+            self,
+            format!("ENG_GEN_{}", self.number),
+            ElectricalElementKind::Source(PotentialOrigin::EngineGenerator(self.number)),
+        ))
+    }
+
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.idg.accept(visitor);
 
@@ -108,7 +149,7 @@ impl SimulationElement for EngineGenerator {
 
     fn process_power_consumption_report<T: PowerConsumptionReport>(
         &mut self,
-        _: &UpdateContext,
+        context: &UpdateContext,
         report: &T,
     ) {
         self.output_frequency = if self.should_provide_output() {
@@ -131,6 +172,8 @@ impl SimulationElement for EngineGenerator {
         self.load = Ratio::new::<percent>(
             (power_consumption * power_factor_correction / maximum_true_power) * 100.,
         );
+
+        self.overload_protection.update(context, self.load);
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -138,13 +181,31 @@ impl SimulationElement for EngineGenerator {
     }
 }
 
+/// The integrated drive generator (IDG) drives the generator at a constant speed.
+/// Its oil lubricates and cools the IDG, and is itself cooled by a fuel/oil heat exchanger.
+/// The heat generated depends on the engine's N2 and the electrical load, while the cooling
+/// deteriorates as oil is lost.
+///
+/// An overheating IDG or an IDG with low oil pressure has a fault. An IDG which overheats
+/// further disconnects automatically.
 struct IntegratedDriveGenerator {
     oil_outlet_temperature_id: VariableIdentifier,
     oil_outlet_temperature: ThermodynamicTemperature,
+    /// The oil outlet temperature the IDG would have without overload or oil loss.
+    normal_oil_outlet_temperature: ThermodynamicTemperature,
+    oil_quantity_id: VariableIdentifier,
+    oil_quantity: Ratio,
+    oil_pressure_id: VariableIdentifier,
+    oil_pressure: Pressure,
+    oil_overheat_id: VariableIdentifier,
+    oil_pressure_low_id: VariableIdentifier,
+    has_fault_id: VariableIdentifier,
     is_connected_id: VariableIdentifier,
     connected: bool,
     activated: bool,
+    engine_running: bool,
     number: usize,
+    oil_leak_failure: Failure,
 
     time_above_threshold_in_milliseconds: u64,
 }
@@ -152,6 +213,17 @@ impl IntegratedDriveGenerator {
     pub const ENGINE_N2_POWER_UP_OUTPUT_THRESHOLD: f64 = 58.;
     pub const ENGINE_N2_POWER_DOWN_OUTPUT_THRESHOLD: f64 = 56.;
 
+    // Temperature rise above the normal oil outlet temperature
+    const OIL_OVERHEAT_TEMPERATURE_RISE_DEGREE_CELSIUS: f64 = 10.;
+    const AUTOMATIC_DISCONNECT_TEMPERATURE_RISE_DEGREE_CELSIUS: f64 = 40.;
+    const NOMINAL_OIL_PRESSURE_PSI: f64 = 200.;
+    const LOW_OIL_PRESSURE_PSI: f64 = 140.;
+    const OIL_LEAK_RATE_PERCENT_PER_SECOND: f64 = 0.5;
+
+    const N2_TEMPERATURE_RISE_DEGREE_CELSIUS_PER_PERCENT: f64 = 1.8;
+    const OVERLOAD_TEMPERATURE_RISE_DEGREE_CELSIUS_PER_PERCENT: f64 = 1.5;
+    const MINIMUM_COOLING_EFFECTIVENESS: f64 = 0.25;
+
     fn new(context: &mut InitContext, number: usize) -> IntegratedDriveGenerator {
         IntegratedDriveGenerator {
             oil_outlet_temperature_id: context.get_identifier(format!(
@@ -159,11 +231,25 @@ impl IntegratedDriveGenerator {
                 number
             )),
             oil_outlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            normal_oil_outlet_temperature: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            oil_quantity_id: context
+                .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_OIL_QUANTITY", number)),
+            oil_quantity: Ratio::new::<percent>(100.),
+            oil_pressure_id: context
+                .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_OIL_PRESSURE", number)),
+            oil_pressure: Pressure::new::<psi>(0.),
+            oil_overheat_id: context
+                .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_OIL_OVERHEAT", number)),
+            oil_pressure_low_id: context
+                .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_OIL_PRESSURE_LOW", number)),
+            has_fault_id: context.get_identifier(format!("ELEC_ENG_GEN_{}_IDG_HAS_FAULT", number)),
             is_connected_id: context
                 .get_identifier(format!("ELEC_ENG_GEN_{}_IDG_IS_CONNECTED", number)),
             connected: true,
             activated: true,
+            engine_running: false,
             number,
+            oil_leak_failure: Failure::new(FailureType::IdgOilLeak(number)),
 
             time_above_threshold_in_milliseconds:
                 INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
@@ -176,6 +262,7 @@ impl IntegratedDriveGenerator {
         engine: &impl EngineCorrectedN2,
         generator_buttons: &impl EngineGeneratorPushButtons,
         fire_buttons: &impl EngineFirePushButtons,
+        load: Ratio,
     ) {
         if generator_buttons.idg_push_button_is_released(self.number) {
             // The IDG cannot be reconnected.
@@ -184,12 +271,25 @@ impl IntegratedDriveGenerator {
 
         self.activated = generator_buttons.engine_gen_push_button_is_on(self.number)
             && !fire_buttons.is_released(self.number);
+        self.engine_running = engine.corrected_n2()
+            >= Ratio::new::<percent>(IntegratedDriveGenerator::ENGINE_N2_POWER_UP_OUTPUT_THRESHOLD);
 
-        self.update_stable_time(context, engine.corrected_n2());
+        self.update_oil_quantity(context);
+        self.update_oil_pressure(engine.corrected_n2());
         self.update_temperature(
             context,
-            self.get_target_temperature(context, engine.corrected_n2()),
+            self.get_normal_target_temperature(context, engine.corrected_n2()),
+            self.get_target_temperature(context, engine.corrected_n2(), load),
         );
+
+        if self.abnormal_temperature_rise()
+            > IntegratedDriveGenerator::AUTOMATIC_DISCONNECT_TEMPERATURE_RISE_DEGREE_CELSIUS
+        {
+            // The IDG disconnects itself to protect it from further damage.
+            self.connected = false;
+        }
+
+        self.update_stable_time(context, engine.corrected_n2());
     }
 
     fn provides_stable_power_output(&self) -> bool {
@@ -197,6 +297,66 @@ impl IntegratedDriveGenerator {
             == INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS
     }
 
+    /// The oil overheats when overload or oil loss heat it beyond its normal temperature,
+    /// which itself depends on the engine's N2 and the ambient temperature.
+    fn oil_overheat(&self) -> bool {
+        self.abnormal_temperature_rise()
+            > IntegratedDriveGenerator::OIL_OVERHEAT_TEMPERATURE_RISE_DEGREE_CELSIUS
+    }
+
+    fn abnormal_temperature_rise(&self) -> f64 {
+        self.oil_outlet_temperature.get::<degree_celsius>()
+            - self.normal_oil_outlet_temperature.get::<degree_celsius>()
+    }
+
+    /// Low oil pressure is only detected while the engine is running,
+    /// as the IDG's oil pump is driven by the engine.
+    fn oil_pressure_low(&self) -> bool {
+        self.connected
+            && self.engine_running
+            && self.oil_pressure
+                < Pressure::new::<psi>(IntegratedDriveGenerator::LOW_OIL_PRESSURE_PSI)
+    }
+
+    /// The fault is no longer indicated once the IDG is disconnected.
+    fn has_fault(&self) -> bool {
+        self.connected && (self.oil_overheat() || self.oil_pressure_low())
+    }
+
+    fn update_oil_quantity(&mut self, context: &UpdateContext) {
+        if self.oil_leak_failure.is_active() {
+            self.oil_quantity = (self.oil_quantity
+                - Ratio::new::<percent>(
+                    IntegratedDriveGenerator::OIL_LEAK_RATE_PERCENT_PER_SECOND
+                        * context.delta_as_secs_f64(),
+                ))
+            .max(Ratio::new::<percent>(0.));
+        }
+    }
+
+    fn update_oil_pressure(&mut self, corrected_n2: Ratio) {
+        self.oil_pressure = if self.connected {
+            let n2_factor = (corrected_n2.get::<percent>()
+                / IntegratedDriveGenerator::ENGINE_N2_POWER_UP_OUTPUT_THRESHOLD)
+                .min(1.);
+
+            Pressure::new::<psi>(
+                IntegratedDriveGenerator::NOMINAL_OIL_PRESSURE_PSI
+                    * n2_factor
+                    * self.oil_quantity_pressure_factor(),
+            )
+        } else {
+            Pressure::new::<psi>(0.)
+        };
+    }
+
+    /// The oil pump delivers full pressure as long as enough oil remains.
+    fn oil_quantity_pressure_factor(&self) -> f64 {
+        ((self.oil_quantity.get::<percent>() - 20.) / 40.)
+            .max(0.)
+            .min(1.)
+    }
+
     fn update_stable_time(&mut self, context: &UpdateContext, corrected_n2: Ratio) {
         if !self.connected {
             self.time_above_threshold_in_milliseconds = 0;
@@ -236,23 +396,45 @@ impl IntegratedDriveGenerator {
         );
     }
 
-    fn update_temperature(&mut self, context: &UpdateContext, target: ThermodynamicTemperature) {
+    fn update_temperature(
+        &mut self,
+        context: &UpdateContext,
+        normal_target: ThermodynamicTemperature,
+        target: ThermodynamicTemperature,
+    ) {
+        self.normal_oil_outlet_temperature = IntegratedDriveGenerator::towards_target_temperature(
+            context,
+            self.normal_oil_outlet_temperature,
+            normal_target,
+        );
+        self.oil_outlet_temperature = IntegratedDriveGenerator::towards_target_temperature(
+            context,
+            self.oil_outlet_temperature,
+            target,
+        );
+    }
+
+    fn towards_target_temperature(
+        context: &UpdateContext,
+        current: ThermodynamicTemperature,
+        target: ThermodynamicTemperature,
+    ) -> ThermodynamicTemperature {
         const IDG_HEATING_COEFFICIENT: f64 = 1.4;
         const IDG_COOLING_COEFFICIENT: f64 = 0.4;
 
-        self.oil_outlet_temperature = calculate_towards_target_temperature(
-            self.oil_outlet_temperature,
+        calculate_towards_target_temperature(
+            current,
             target,
-            if self.oil_outlet_temperature < target {
+            if current < target {
                 IDG_HEATING_COEFFICIENT
             } else {
                 IDG_COOLING_COEFFICIENT
             },
             context.delta(),
-        );
+        )
     }
 
-    fn get_target_temperature(
+    fn get_normal_target_temperature(
         &self,
         context: &UpdateContext,
         corrected_n2: Ratio,
@@ -261,18 +443,56 @@ impl IntegratedDriveGenerator {
             return context.ambient_temperature();
         }
 
-        let mut target_idg = corrected_n2.get::<percent>() * 1.8;
-        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
-        target_idg += ambient_temperature;
+        ThermodynamicTemperature::new::<degree_celsius>(
+            context.ambient_temperature().get::<degree_celsius>()
+                + corrected_n2.get::<percent>()
+                    * IntegratedDriveGenerator::N2_TEMPERATURE_RISE_DEGREE_CELSIUS_PER_PERCENT,
+        )
+    }
 
-        // TODO improve this function with feedback @komp provides.
+    fn get_target_temperature(
+        &self,
+        context: &UpdateContext,
+        corrected_n2: Ratio,
+        load: Ratio,
+    ) -> ThermodynamicTemperature {
+        if !self.connected {
+            return context.ambient_temperature();
+        }
 
-        ThermodynamicTemperature::new::<degree_celsius>(target_idg)
+        // Under normal conditions the temperature rise depends on the engine's N2.
+        // An overloaded generator's losses heat the oil further, while the heat exchanger
+        // removes less heat when there is less oil to circulate through it.
+        let overload = (load.get::<percent>() - 100.).max(0.);
+        let temperature_rise = corrected_n2.get::<percent>()
+            * IntegratedDriveGenerator::N2_TEMPERATURE_RISE_DEGREE_CELSIUS_PER_PERCENT
+            + overload
+                * IntegratedDriveGenerator::OVERLOAD_TEMPERATURE_RISE_DEGREE_CELSIUS_PER_PERCENT;
+        let cooling_effectiveness = self
+            .oil_quantity
+            .get::<ratio>()
+            .max(IntegratedDriveGenerator::MINIMUM_COOLING_EFFECTIVENESS);
+
+        ThermodynamicTemperature::new::<degree_celsius>(
+            context.ambient_temperature().get::<degree_celsius>()
+                + temperature_rise / cooling_effectiveness,
+        )
     }
 }
 impl SimulationElement for IntegratedDriveGenerator {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.oil_leak_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.oil_outlet_temperature_id, self.oil_outlet_temperature);
+        writer.write(&self.oil_quantity_id, self.oil_quantity);
+        writer.write(&self.oil_pressure_id, self.oil_pressure);
+        writer.write(&self.oil_overheat_id, self.oil_overheat());
+        writer.write(&self.oil_pressure_low_id, self.oil_pressure_low());
+        writer.write(&self.has_fault_id, self.has_fault());
         writer.write(&self.is_connected_id, self.connected);
     }
 }
@@ -361,6 +581,9 @@ mod tests {
                 Aircraft,
             },
         };
+        use std::time::Duration;
+
+        const FRAME: Duration = Duration::from_secs(1);
 
         struct EngineGeneratorTestBed {
             test_bed: SimulationTestBed<TestAircraft>,
@@ -451,6 +674,14 @@ mod tests {
                 self.gen_push_button_on = false;
             }
 
+            fn gen_push_button_on(&mut self) {
+                self.gen_push_button_on = true;
+            }
+
+            fn generator_requires_load_shedding(&self) -> bool {
+                self.engine_gen.requires_load_shedding()
+            }
+
             fn release_fire_push_button(&mut self) {
                 self.fire_push_button_released = true;
             }
@@ -731,6 +962,43 @@ mod tests {
             assert!(test_bed.query(|a| a.generator_output_within_normal_parameters_after_processing_power_consumption_report()));
         }
 
+        #[test]
+        fn sustained_overload_requires_load_shedding() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>((90000. / 0.8) * 1.25)));
+            test_bed.run_with_fixed_frames(Duration::from_secs(10), FRAME);
+
+            assert!(test_bed.query(|a| a.generator_requires_load_shedding()));
+            assert!(test_bed.query(|a| a.generator_output_within_normal_parameters_after_processing_power_consumption_report()));
+        }
+
+        #[test]
+        fn output_not_within_normal_parameters_after_sustained_overload() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>((90000. / 0.8) * 2.)));
+            test_bed.run_with_fixed_frames(Duration::from_secs(10), FRAME);
+
+            assert!(!test_bed.query(|a| a.generator_output_within_normal_parameters_after_processing_power_consumption_report()));
+        }
+
+        #[test]
+        fn overload_trip_is_reset_by_switching_generator_off_and_on() {
+            let mut test_bed = EngineGeneratorTestBed::with_running_engine();
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>((90000. / 0.8) * 2.)));
+            test_bed.run_with_fixed_frames(Duration::from_secs(10), FRAME);
+
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(0.)));
+            test_bed.command(|a| a.gen_push_button_off());
+            test_bed.run();
+            test_bed.command(|a| a.gen_push_button_on());
+            test_bed.run_with_delta(Duration::from_millis(500));
+
+            assert!(test_bed.query(|a| a.generator_output_within_normal_parameters_after_processing_power_consumption_report()));
+        }
+
         #[test]
         fn output_not_within_normal_parameters_when_engine_not_running() {
             let mut test_bed = EngineGeneratorTestBed::with_shutdown_engine();
@@ -782,9 +1050,12 @@ mod tests {
 
     #[cfg(test)]
     mod integrated_drive_generator_tests {
-        use crate::simulation::test::{ElementCtorFn, SimulationTestBed, TestBed};
+        use crate::simulation::test::{ElementCtorFn, SimulationTestBed, TestAircraft, TestBed};
+
+        const FRAME: Duration = Duration::from_secs(1);
 
         use super::*;
+        use ntest::assert_about_eq;
         use std::time::Duration;
 
         fn idg(context: &mut InitContext) -> IntegratedDriveGenerator {
@@ -800,6 +1071,11 @@ mod tests {
                 test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_OIL_OUTLET_TEMPERATURE")
             );
             assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_IS_CONNECTED"));
+            assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_OIL_QUANTITY"));
+            assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_OIL_PRESSURE"));
+            assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_OIL_OVERHEAT"));
+            assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_OIL_PRESSURE_LOW"));
+            assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_HAS_FAULT"));
         }
 
         #[test]
@@ -901,12 +1177,152 @@ mod tests {
             assert!(test_bed.query_element(|e| e.oil_outlet_temperature) < starting_temperature);
         }
 
+        #[test]
+        fn normal_load_does_not_overheat_idg() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(50.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+
+            assert!(!test_bed.query_element(|e| e.oil_overheat()));
+            assert!(!test_bed.query_element(|e| e.has_fault()));
+        }
+
+        #[test]
+        fn rated_load_at_max_n2_on_hot_day_does_not_overheat_idg() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_at_n2_with_load(105., 100.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(50.));
+            test_bed.run_with_fixed_frames(Duration::from_secs(1200), FRAME);
+
+            // Steady state is reached
+            assert_about_eq!(
+                test_bed
+                    .query_element(|e| e.oil_outlet_temperature)
+                    .get::<degree_celsius>(),
+                50. + 105.
+                    * IntegratedDriveGenerator::N2_TEMPERATURE_RISE_DEGREE_CELSIUS_PER_PERCENT
+            );
+            assert!(!test_bed.query_element(|e| e.oil_overheat()));
+            assert!(!test_bed.query_element(|e| e.has_fault()));
+            assert!(test_bed.query_element(|e| e.connected));
+        }
+
+        #[test]
+        fn load_within_rating_does_not_change_idg_temperature() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(0.));
+            test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+            let unloaded_temperature = test_bed.query_element(|e| e.oil_outlet_temperature);
+
+            test_bed.set_update_after_power_distribution(engine_running_with_load(100.));
+            test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+
+            assert_about_eq!(
+                test_bed
+                    .query_element(|e| e.oil_outlet_temperature)
+                    .get::<degree_celsius>(),
+                unloaded_temperature.get::<degree_celsius>()
+            );
+        }
+
+        #[test]
+        fn overload_heats_idg_more() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(0.));
+            test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+            let unloaded_temperature = test_bed.query_element(|e| e.oil_outlet_temperature);
+
+            test_bed.set_update_after_power_distribution(engine_running_with_load(120.));
+            test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+
+            assert!(test_bed.query_element(|e| e.oil_outlet_temperature) > unloaded_temperature);
+        }
+
+        #[test]
+        fn sustained_overload_on_hot_day_overheats_idg() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(110.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(35.));
+            test_bed.run_with_fixed_frames(Duration::from_secs(300), FRAME);
+
+            assert!(test_bed.query_element(|e| e.oil_overheat()));
+            assert!(test_bed.query_element(|e| e.has_fault()));
+            assert!(test_bed.query_element(|e| e.connected));
+        }
+
+        #[test]
+        fn idg_disconnects_automatically_when_oil_overheats_beyond_limit() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(150.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(35.));
+            test_bed.run_with_fixed_frames(Duration::from_secs(300), FRAME);
+
+            assert!(!test_bed.query_element(|e| e.connected));
+            assert!(!test_bed.query_element(|e| e.provides_stable_power_output()));
+        }
+
+        #[test]
+        fn disconnected_idg_has_no_fault() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_with_load(150.));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(35.));
+            test_bed.run_with_fixed_frames(Duration::from_secs(300), FRAME);
+
+            assert!(!test_bed.query_element(|e| e.has_fault()));
+        }
+
+        #[test]
+        fn running_engine_provides_oil_pressure() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_above_threshold(false));
+            test_bed.run();
+
+            assert!(!test_bed.query_element(|e| e.oil_pressure_low()));
+            assert_eq!(
+                test_bed.query_element(|e| e.oil_pressure),
+                Pressure::new::<psi>(200.)
+            );
+        }
+
+        #[test]
+        fn oil_leak_reduces_oil_quantity() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_above_threshold(false));
+            test_bed.fail(FailureType::IdgOilLeak(1));
+            test_bed.run_with_fixed_frames(Duration::from_secs(10), FRAME);
+
+            assert!(test_bed.query_element(|e| e.oil_quantity) < Ratio::new::<percent>(100.));
+        }
+
+        #[test]
+        fn oil_leak_results_in_low_oil_pressure_fault() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_running_above_threshold(false));
+            test_bed.fail(FailureType::IdgOilLeak(1));
+            test_bed.run_with_fixed_frames(Duration::from_secs(110), FRAME);
+
+            assert!(test_bed.query_element(|e| e.oil_pressure_low()));
+            assert!(test_bed.query_element(|e| e.has_fault()));
+        }
+
+        #[test]
+        fn low_oil_pressure_not_detected_when_engine_not_running() {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(idg))
+                .with_update_after_power_distribution(engine_not_running);
+            test_bed.run();
+
+            assert!(!test_bed.query_element(|e| e.oil_pressure_low()));
+            assert!(!test_bed.query_element(|e| e.has_fault()));
+        }
+
         fn engine_not_running(idg: &mut IntegratedDriveGenerator, context: &UpdateContext) {
             idg.update(
                 context,
                 &TestEngine::new(Ratio::new::<percent>(0.)),
                 &TestOverhead::new(false, false),
                 &TestFireOverhead::new(false),
+                Ratio::new::<percent>(0.),
             )
         }
 
@@ -919,6 +1335,28 @@ mod tests {
                     &TestEngine::new(Ratio::new::<percent>(80.)),
                     &TestOverhead::new(true, idg_push_button_is_released),
                     &TestFireOverhead::new(false),
+                    Ratio::new::<percent>(0.),
+                )
+            }
+        }
+
+        fn engine_running_with_load(
+            load_percent: f64,
+        ) -> impl Fn(&mut IntegratedDriveGenerator, &UpdateContext) {
+            engine_running_at_n2_with_load(80., load_percent)
+        }
+
+        fn engine_running_at_n2_with_load(
+            n2_percent: f64,
+            load_percent: f64,
+        ) -> impl Fn(&mut IntegratedDriveGenerator, &UpdateContext) {
+            move |idg: &mut IntegratedDriveGenerator, context: &UpdateContext| {
+                idg.update(
+                    context,
+                    &TestEngine::new(Ratio::new::<percent>(n2_percent)),
+                    &TestOverhead::new(true, false),
+                    &TestFireOverhead::new(false),
+                    Ratio::new::<percent>(load_percent),
                 )
             }
         }
@@ -1,35 +1,164 @@
+use std::ops::RangeInclusive;
+
 use uom::si::{electric_potential::volt, f64::*, frequency::hertz};
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::PowerConsumptionReport,
     simulation::{
-        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
-        VariableIdentifier,
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 
 use super::{
-    ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-    ElectricalStateWriter, ElectricitySource, Potential, PotentialOrigin, ProvideFrequency,
-    ProvidePotential,
+    ElectricalElement, ElectricalElementDescription, ElectricalElementIdentifier,
+    ElectricalElementIdentifierProvider, ElectricalElementKind, ElectricalStateWriter,
+    ElectricitySource, Potential, PotentialOrigin, ProvideFrequency, ProvidePotential,
 };
 
+/// The quality of the phases of the three phase power supplied by a ground power unit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PhaseQuality {
+    Normal,
+    /// The phases are connected in the wrong order.
+    IncorrectSequence,
+    /// One of the phases doesn't supply any power.
+    OpenPhase,
+}
+
+/// A ground power unit (GPU) connected to the aircraft's external power receptacle.
+///
+/// The GPU's potential and frequency can be adjusted through the `ELEC_GPU_POTENTIAL` and
+/// `ELEC_GPU_FREQUENCY` variables. When these are not set, the GPU supplies the potential
+/// and frequency it was constructed with.
+pub struct GroundPowerUnit {
+    potential_id: VariableIdentifier,
+    frequency_id: VariableIdentifier,
+    overvoltage_failure: Failure,
+    wrong_frequency_failure: Failure,
+    incorrect_phase_sequence_failure: Failure,
+    open_phase_failure: Failure,
+    potential: ElectricPotential,
+    frequency: Frequency,
+}
+impl GroundPowerUnit {
+    const NOMINAL_POTENTIAL_VOLTS: f64 = 115.;
+    const NOMINAL_FREQUENCY_HZ: f64 = 400.;
+    const OVERVOLTAGE_POTENTIAL_VOLTS: f64 = 130.;
+    const WRONG_FREQUENCY_HZ: f64 = 360.;
+
+    pub fn new(
+        context: &mut InitContext,
+        potential: ElectricPotential,
+        frequency: Frequency,
+    ) -> Self {
+        Self {
+            potential_id: context.get_identifier("ELEC_GPU_POTENTIAL".to_owned()),
+            frequency_id: context.get_identifier("ELEC_GPU_FREQUENCY".to_owned()),
+            overvoltage_failure: Failure::new(FailureType::GroundPowerUnitOvervoltage),
+            wrong_frequency_failure: Failure::new(FailureType::GroundPowerUnitWrongFrequency),
+            incorrect_phase_sequence_failure: Failure::new(
+                FailureType::GroundPowerUnitIncorrectPhaseSequence,
+            ),
+            open_phase_failure: Failure::new(FailureType::GroundPowerUnitOpenPhase),
+            potential,
+            frequency,
+        }
+    }
+
+    pub fn nominal(context: &mut InitContext) -> Self {
+        Self::new(
+            context,
+            ElectricPotential::new::<volt>(Self::NOMINAL_POTENTIAL_VOLTS),
+            Frequency::new::<hertz>(Self::NOMINAL_FREQUENCY_HZ),
+        )
+    }
+
+    pub fn potential(&self) -> ElectricPotential {
+        if self.overvoltage_failure.is_active() {
+            ElectricPotential::new::<volt>(Self::OVERVOLTAGE_POTENTIAL_VOLTS)
+        } else {
+            self.potential
+        }
+    }
+
+    pub fn frequency(&self) -> Frequency {
+        if self.wrong_frequency_failure.is_active() {
+            Frequency::new::<hertz>(Self::WRONG_FREQUENCY_HZ)
+        } else {
+            self.frequency
+        }
+    }
+
+    pub fn phase_quality(&self) -> PhaseQuality {
+        if self.open_phase_failure.is_active() {
+            PhaseQuality::OpenPhase
+        } else if self.incorrect_phase_sequence_failure.is_active() {
+            PhaseQuality::IncorrectSequence
+        } else {
+            PhaseQuality::Normal
+        }
+    }
+}
+impl SimulationElement for GroundPowerUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.overvoltage_failure.accept(visitor);
+        self.wrong_frequency_failure.accept(visitor);
+        self.incorrect_phase_sequence_failure.accept(visitor);
+        self.open_phase_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        // An unset variable reads as zero, in which case the configured value is kept.
+        let potential: ElectricPotential = reader.read(&self.potential_id);
+        if potential > ElectricPotential::new::<volt>(0.) {
+            self.potential = potential;
+        }
+
+        let frequency: Frequency = reader.read(&self.frequency_id);
+        if frequency > Frequency::new::<hertz>(0.) {
+            self.frequency = frequency;
+        }
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.potential_id, self.potential);
+        writer.write(&self.frequency_id, self.frequency);
+    }
+}
+
 pub struct ExternalPowerSource {
     external_power_available_id: VariableIdentifier,
 
     identifier: ElectricalElementIdentifier,
     writer: ElectricalStateWriter,
+    ground_power_unit: GroundPowerUnit,
     is_connected: bool,
     output_frequency: Frequency,
     output_potential: ElectricPotential,
 }
 impl ExternalPowerSource {
+    const NORMAL_POTENTIAL_RANGE_VOLTS: RangeInclusive<f64> = 110.0..=120.0;
+    const NORMAL_FREQUENCY_RANGE_HZ: RangeInclusive<f64> = 390.0..=410.0;
+
     pub fn new(context: &mut InitContext) -> ExternalPowerSource {
+        let ground_power_unit = GroundPowerUnit::nominal(context);
+        Self::new_with_ground_power_unit(context, ground_power_unit)
+    }
+
+    pub fn new_with_ground_power_unit(
+        context: &mut InitContext,
+        ground_power_unit: GroundPowerUnit,
+    ) -> ExternalPowerSource {
         ExternalPowerSource {
             external_power_available_id: context
                 .get_identifier("EXTERNAL POWER AVAILABLE:1".to_owned()),
             identifier: context.next_electrical_identifier(),
             writer: ElectricalStateWriter::new(context, "EXT_PWR"),
+            ground_power_unit,
             is_connected: false,
             output_frequency: Frequency::new::<hertz>(0.),
             output_potential: ElectricPotential::new::<volt>(0.),
@@ -41,8 +170,17 @@ impl ExternalPowerSource {
     /// Indicates if the provided electricity's potential and frequency
     /// are within normal parameters. Use this to decide if the
     /// external power contactor should close.
+    ///
+    /// The external power monitoring checks the power supplied by the ground power
+    /// unit directly, such that a change in the supplied power is noticed immediately.
+    /// Power with an incorrect phase sequence or an open phase is rejected too.
     pub fn output_within_normal_parameters(&self) -> bool {
-        self.should_provide_output() && self.potential_normal() && self.frequency_normal()
+        self.should_provide_output()
+            && Self::NORMAL_POTENTIAL_RANGE_VOLTS
+                .contains(&self.ground_power_unit.potential().get::<volt>())
+            && Self::NORMAL_FREQUENCY_RANGE_HZ
+                .contains(&self.ground_power_unit.frequency().get::<hertz>())
+            && self.ground_power_unit.phase_quality() == PhaseQuality::Normal
     }
 
     fn should_provide_output(&self) -> bool {
@@ -71,9 +209,29 @@ impl ElectricitySource for ExternalPowerSource {
         }
     }
 }
-provide_potential!(ExternalPowerSource, (110.0..=120.0));
-provide_frequency!(ExternalPowerSource, (390.0..=410.0));
+provide_potential!(
+    ExternalPowerSource,
+    ExternalPowerSource::NORMAL_POTENTIAL_RANGE_VOLTS
+);
+provide_frequency!(
+    ExternalPowerSource,
+    ExternalPowerSource::NORMAL_FREQUENCY_RANGE_HZ
+);
 impl SimulationElement for ExternalPowerSource {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.ground_power_unit.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn describe_electrical_element(&self) -> Option<ElectricalElementDescription> {
+        Some(ElectricalElementDescription::new(
+            self,
+            "EXT_PWR",
+            ElectricalElementKind::Source(PotentialOrigin::External),
+        ))
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.is_connected = reader.read(&self.external_power_available_id);
     }
@@ -88,13 +246,13 @@ impl SimulationElement for ExternalPowerSource {
         _: &T,
     ) {
         self.output_frequency = if self.should_provide_output() {
-            Frequency::new::<hertz>(400.)
+            self.ground_power_unit.frequency()
         } else {
             Frequency::new::<hertz>(0.)
         };
 
         self.output_potential = if self.should_provide_output() {
-            ElectricPotential::new::<volt>(115.)
+            self.ground_power_unit.potential()
         } else {
             ElectricPotential::new::<volt>(0.)
         };
@@ -124,6 +282,15 @@ mod external_power_source_tests {
             }
         }
 
+        fn new_with_ground_power_unit(potential: ElectricPotential, frequency: Frequency) -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(|context| {
+                    let ground_power_unit = GroundPowerUnit::new(context, potential, frequency);
+                    TestAircraft::new_with_ground_power_unit(context, ground_power_unit)
+                }),
+            }
+        }
+
         fn with_disconnected_external_power(mut self) -> Self {
             self.disconnect_external_power();
             self
@@ -149,6 +316,13 @@ mod external_power_source_tests {
         fn ext_pwr_is_powered(&self) -> bool {
             self.query_elec(|a, elec| a.ext_pwr_is_powered(elec))
         }
+
+        fn output_within_normal_parameters(&self) -> bool {
+            self.query(|a| {
+                a.ext_pwr_output_within_normal_parameters_after_processing_power_consumption_report(
+                )
+            })
+        }
     }
     impl TestBed for ExternalPowerTestBed {
         type Aircraft = TestAircraft;
@@ -174,6 +348,16 @@ mod external_power_source_tests {
             }
         }
 
+        fn new_with_ground_power_unit(
+            context: &mut InitContext,
+            ground_power_unit: GroundPowerUnit,
+        ) -> Self {
+            Self {
+                ext_pwr: ExternalPowerSource::new_with_ground_power_unit(context, ground_power_unit),
+                ext_pwr_output_within_normal_parameters_before_processing_power_consumption_report: false,
+            }
+        }
+
         fn ext_pwr_is_powered(&self, electricity: &Electricity) -> bool {
             electricity.is_powered(&self.ext_pwr)
         }
@@ -300,6 +484,126 @@ mod external_power_source_tests {
             .ext_pwr_output_within_normal_parameters_before_processing_power_consumption_report()));
     }
 
+    #[test]
+    fn output_not_within_normal_parameters_when_ground_power_unit_overvoltage() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.fail(FailureType::GroundPowerUnitOvervoltage);
+
+        test_bed.run();
+
+        assert!(test_bed.ext_pwr_is_powered());
+        assert!(!test_bed.potential_is_normal());
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn output_not_within_normal_parameters_when_ground_power_unit_frequency_wrong() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.fail(FailureType::GroundPowerUnitWrongFrequency);
+
+        test_bed.run();
+
+        assert!(!test_bed.frequency_is_normal());
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn output_not_within_normal_parameters_when_ground_power_unit_phase_sequence_incorrect() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.fail(FailureType::GroundPowerUnitIncorrectPhaseSequence);
+
+        test_bed.run();
+
+        assert!(test_bed.potential_is_normal());
+        assert!(test_bed.frequency_is_normal());
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn output_not_within_normal_parameters_when_ground_power_unit_has_open_phase() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.fail(FailureType::GroundPowerUnitOpenPhase);
+
+        test_bed.run();
+
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn output_within_normal_parameters_adapts_to_ground_power_unit_failure_instantaneously() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.run();
+
+        test_bed.fail(FailureType::GroundPowerUnitOvervoltage);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a
+            .ext_pwr_output_within_normal_parameters_before_processing_power_consumption_report()));
+    }
+
+    #[test]
+    fn provides_the_potential_and_frequency_of_the_ground_power_unit() {
+        let mut test_bed = ExternalPowerTestBed::new_with_ground_power_unit(
+            ElectricPotential::new::<volt>(118.),
+            Frequency::new::<hertz>(405.),
+        )
+        .with_connected_external_power();
+
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.ext_pwr.potential()),
+            ElectricPotential::new::<volt>(118.)
+        );
+        assert_eq!(
+            test_bed.query(|a| a.ext_pwr.frequency()),
+            Frequency::new::<hertz>(405.)
+        );
+        assert!(test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn output_not_within_normal_parameters_when_ground_power_unit_potential_too_low() {
+        let mut test_bed = ExternalPowerTestBed::new_with_ground_power_unit(
+            ElectricPotential::new::<volt>(100.),
+            Frequency::new::<hertz>(400.),
+        )
+        .with_connected_external_power();
+
+        test_bed.run();
+
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn ground_power_unit_potential_and_frequency_can_be_set() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.write_by_name("ELEC_GPU_POTENTIAL", 118.);
+        test_bed.write_by_name("ELEC_GPU_FREQUENCY", 405.);
+
+        test_bed.run();
+
+        assert_eq!(
+            test_bed.query(|a| a.ext_pwr.potential()),
+            ElectricPotential::new::<volt>(118.)
+        );
+        assert_eq!(
+            test_bed.query(|a| a.ext_pwr.frequency()),
+            Frequency::new::<hertz>(405.)
+        );
+    }
+
+    #[test]
+    fn output_not_within_normal_parameters_when_ground_power_unit_frequency_set_out_of_tolerance() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.run();
+
+        test_bed.write_by_name("ELEC_GPU_FREQUENCY", 380.);
+        test_bed.run();
+
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
     #[test]
     fn writes_its_state() {
         let mut test_bed = SimulationTestBed::new(TestAircraft::new);
@@ -310,5 +614,7 @@ mod external_power_source_tests {
         assert!(test_bed.contains_variable_with_name("ELEC_EXT_PWR_POTENTIAL_NORMAL"));
         assert!(test_bed.contains_variable_with_name("ELEC_EXT_PWR_FREQUENCY"));
         assert!(test_bed.contains_variable_with_name("ELEC_EXT_PWR_FREQUENCY_NORMAL"));
+        assert!(test_bed.contains_variable_with_name("ELEC_GPU_POTENTIAL"));
+        assert!(test_bed.contains_variable_with_name("ELEC_GPU_FREQUENCY"));
     }
 }
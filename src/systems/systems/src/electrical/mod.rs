@@ -2,11 +2,17 @@
 
 mod battery;
 mod battery_charge_limiter;
+mod circuit_breaker;
+mod computer_power_supply;
 pub mod consumption;
+mod consumption_breakdown;
 mod emergency_generator;
 mod engine_generator;
 mod external_power_source;
+mod overload_protection;
+mod power_transfer;
 mod static_inverter;
+mod topology;
 mod transformer_rectifier;
 use std::{
     cell::{Ref, RefCell},
@@ -25,13 +31,23 @@ use crate::{
 };
 pub use battery::Battery;
 pub use battery_charge_limiter::BatteryChargeLimiter;
+pub use circuit_breaker::CircuitBreaker;
+pub use computer_power_supply::ComputerPowerSupply;
+pub use consumption_breakdown::{
+    PowerConsumerDescription, PowerConsumptionBreakdown, PowerConsumptionBreakdownEntry,
+};
 pub use emergency_generator::EmergencyGenerator;
 pub use engine_generator::{
     EngineGenerator, INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
 };
-pub use external_power_source::ExternalPowerSource;
+pub use external_power_source::{ExternalPowerSource, GroundPowerUnit, PhaseQuality};
 use fxhash::{FxHashMap, FxHashSet};
+pub use overload_protection::GeneratorOverloadProtection;
+pub use power_transfer::PowerTransfer;
 pub use static_inverter::StaticInverter;
+pub use topology::{
+    ElectricalElementDescription, ElectricalElementKind, ElectricalTopology, ElectricalTopologyNode,
+};
 pub use transformer_rectifier::TransformerRectifier;
 use uom::si::{electric_potential::volt, f64::*, power::watt, velocity::knot};
 
@@ -55,6 +71,7 @@ pub trait BatteryPushButtons {
 #[derive(Debug)]
 pub struct Contactor {
     identifier: ElectricalElementIdentifier,
+    name: String,
     closed_id: VariableIdentifier,
     closed: bool,
 }
@@ -62,6 +79,7 @@ impl Contactor {
     pub fn new(context: &mut InitContext, id: &str) -> Contactor {
         Contactor {
             identifier: context.next_electrical_identifier(),
+            name: id.to_owned(),
             closed_id: context.get_identifier(format!("ELEC_CONTACTOR_{}_IS_CLOSED", id)),
             closed: false,
         }
@@ -96,6 +114,14 @@ impl SimulationElement for Contactor {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.closed_id, self.is_closed());
     }
+
+    fn describe_electrical_element(&self) -> Option<ElectricalElementDescription> {
+        Some(ElectricalElementDescription::new(
+            self,
+            self.name.as_str(),
+            ElectricalElementKind::Contactor,
+        ))
+    }
 }
 
 pub struct ElectricalBus {
@@ -161,6 +187,14 @@ impl SimulationElement for ElectricalBus {
         }
     }
 
+    fn describe_electrical_element(&self) -> Option<ElectricalElementDescription> {
+        Some(ElectricalElementDescription::new(
+            self,
+            self.bus_type.to_string(),
+            ElectricalElementKind::Bus,
+        ))
+    }
+
     fn receive_power(&mut self, buses: &impl ElectricalBuses) {
         self.potential = buses.potential_of(self.bus_type).raw();
     }
@@ -343,6 +377,8 @@ pub struct Electricity {
     buses: FxHashMap<ElectricalBusType, ElectricalElementIdentifier>,
     potential: PotentialCollection,
     none_potential: RefCell<Potential>,
+    connections: Vec<(ElectricalElementIdentifier, ElectricalElementIdentifier)>,
+    is_recording_connections: bool,
 }
 impl Electricity {
     pub fn new() -> Self {
@@ -351,11 +387,21 @@ impl Electricity {
             buses: Default::default(),
             potential: PotentialCollection::new(),
             none_potential: RefCell::new(Potential::none()),
+            connections: Vec::new(),
+            is_recording_connections: false,
         }
     }
 
+    /// Starts recording the connections made by [flow](`Self::flow()`), such that the
+    /// [`ElectricalTopology`] can be built from them. Recording is off by default,
+    /// as it isn't needed while flying.
+    pub fn start_recording_connections(&mut self) {
+        self.is_recording_connections = true;
+    }
+
     pub(super) fn pre_tick(&mut self) {
         self.potential.clear();
+        self.connections.clear();
     }
 
     /// Flows electricity from the given output element to the given input element as long
@@ -388,6 +434,11 @@ impl Electricity {
         from_output: &impl ElectricalElement,
         to_input: &impl ElectricalElement,
     ) {
+        if self.is_recording_connections {
+            self.connections
+                .push((from_output.output_identifier(), to_input.input_identifier()));
+        }
+
         if from_output.is_conductive() && to_input.is_conductive() {
             self.potential
                 .flow(from_output.output_identifier(), to_input.input_identifier());
@@ -505,6 +556,35 @@ impl Electricity {
         element.accept(&mut visitor);
     }
 
+    /// Returns the topology of the electrical network as it was during the last tick.
+    /// The given element is visited for elements which describe themselves.
+    pub fn topology_of(&self, element: &mut impl SimulationElement) -> ElectricalTopology {
+        let mut visitor = topology::DescribeElectricalElementVisitor::new();
+        element.accept(&mut visitor);
+
+        ElectricalTopology::new(visitor.into_descriptions(), self)
+    }
+
+    /// Returns the power consumed by each named power consumer during the last tick.
+    /// The given element is visited for consumers which describe themselves.
+    pub fn consumption_breakdown_of(
+        &self,
+        element: &mut impl SimulationElement,
+    ) -> PowerConsumptionBreakdown {
+        let mut visitor = consumption_breakdown::DescribePowerConsumerVisitor::new();
+        element.accept(&mut visitor);
+
+        PowerConsumptionBreakdown::new(visitor.into_descriptions(), self)
+    }
+
+    fn connections(&self) -> &[(ElectricalElementIdentifier, ElectricalElementIdentifier)] {
+        &self.connections
+    }
+
+    fn consumption_from(&self, identifier: ElectricalElementIdentifier) -> Power {
+        self.potential.consumption_from(identifier)
+    }
+
     #[cfg(test)]
     fn identifier_for(&self, bus_type: ElectricalBusType) -> Option<&ElectricalElementIdentifier> {
         self.buses.get(&bus_type)
@@ -793,18 +873,21 @@ impl Default for Potential {
 struct PotentialCollection {
     items: FxHashMap<ElectricalElementIdentifier, Rc<RefCell<Potential>>>,
     consumption_per_origin: FxHashMap<PotentialOrigin, Power>,
+    consumption_per_element: FxHashMap<ElectricalElementIdentifier, Power>,
 }
 impl PotentialCollection {
     fn new() -> Self {
         Self {
             items: Default::default(),
             consumption_per_origin: Default::default(),
+            consumption_per_element: Default::default(),
         }
     }
 
     fn clear(&mut self) {
         self.items.clear();
         self.consumption_per_origin.clear();
+        self.consumption_per_element.clear();
     }
 
     fn flow(
@@ -877,6 +960,10 @@ impl PotentialCollection {
     fn consume_from(&mut self, identifier: ElectricalElementIdentifier, power: Power) {
         if let Some(potential) = self.items.get_mut(&identifier) {
             let potential = potential.as_ref().borrow();
+            if potential.is_powered() {
+                *self.consumption_per_element.entry(identifier).or_default() += power;
+            }
+
             for origin in potential.origins() {
                 let y = self.consumption_per_origin.entry(*origin).or_default();
                 *y += power / potential.origin_count() as f64;
@@ -890,6 +977,13 @@ impl PotentialCollection {
             None => Power::new::<watt>(0.),
         }
     }
+
+    fn consumption_from(&self, identifier: ElectricalElementIdentifier) -> Power {
+        match self.consumption_per_element.get(&identifier) {
+            Some(power) => *power,
+            None => Power::new::<watt>(0.),
+        }
+    }
 }
 
 #[cfg(test)]
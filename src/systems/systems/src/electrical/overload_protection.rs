@@ -0,0 +1,293 @@
+use uom::si::{f64::*, ratio::percent};
+
+use crate::{shared::interpolation, simulation::UpdateContext};
+
+/// A time-current characteristic, also known as an inverse time characteristic.
+/// Loads above the pickup load are tolerated for a limited amount of time,
+/// which decreases as the load increases.
+///
+/// The overload is accumulated as the fraction of the allowed time which has passed,
+/// such that a varying load is handled correctly. Once the load drops below the
+/// pickup load, the accumulated overload decays over the reset time.
+pub(super) struct TimeCurrentCharacteristic {
+    pickup_load_percent: f64,
+    load_percent: &'static [f64],
+    allowed_time_seconds: &'static [f64],
+    reset_time_seconds: f64,
+    accumulated_overload: f64,
+}
+impl TimeCurrentCharacteristic {
+    pub(super) fn new(
+        pickup_load_percent: f64,
+        load_percent: &'static [f64],
+        allowed_time_seconds: &'static [f64],
+        reset_time_seconds: f64,
+    ) -> Self {
+        Self {
+            pickup_load_percent,
+            load_percent,
+            allowed_time_seconds,
+            reset_time_seconds,
+            accumulated_overload: 0.,
+        }
+    }
+
+    pub(super) fn update(&mut self, context: &UpdateContext, load: Ratio) {
+        let load = load.get::<percent>();
+        if load > self.pickup_load_percent {
+            let allowed_time =
+                interpolation(self.load_percent, self.allowed_time_seconds, load).max(0.1);
+            self.accumulated_overload += context.delta_as_secs_f64() / allowed_time;
+        } else {
+            self.accumulated_overload -= context.delta_as_secs_f64() / self.reset_time_seconds;
+        }
+
+        self.accumulated_overload = self.accumulated_overload.max(0.).min(1.);
+    }
+
+    pub(super) fn is_exceeded(&self) -> bool {
+        self.accumulated_overload >= 1.
+    }
+
+    pub(super) fn is_reset(&self) -> bool {
+        self.accumulated_overload <= 0.
+    }
+
+    pub(super) fn reset(&mut self) {
+        self.accumulated_overload = 0.;
+    }
+}
+
+/// Protects a generator against overload, as done by its generator control unit (GCU).
+///
+/// A generator which is overloaded first requests load shedding of commercial loads,
+/// such as the galleys. When the overload is sustained beyond the generator's capability,
+/// the generator is tripped. A tripped generator remains so until it is reset.
+pub struct GeneratorOverloadProtection {
+    load_shedding_characteristic: TimeCurrentCharacteristic,
+    trip_characteristic: TimeCurrentCharacteristic,
+    requires_load_shedding: bool,
+    has_tripped: bool,
+}
+impl GeneratorOverloadProtection {
+    const LOAD_SHEDDING_PICKUP_PERCENT: f64 = 100.;
+    const LOAD_SHEDDING_LOAD_PERCENT: [f64; 4] = [100., 110., 125., 150.];
+    const LOAD_SHEDDING_ALLOWED_TIME_SECONDS: [f64; 4] = [20., 10., 5., 1.];
+    const LOAD_SHEDDING_RESET_TIME_SECONDS: f64 = 60.;
+
+    const TRIP_PICKUP_PERCENT: f64 = 110.;
+    const TRIP_LOAD_PERCENT: [f64; 4] = [110., 125., 150., 200.];
+    const TRIP_ALLOWED_TIME_SECONDS: [f64; 4] = [1800., 300., 120., 5.];
+    const TRIP_RESET_TIME_SECONDS: f64 = 300.;
+
+    pub fn new() -> Self {
+        Self {
+            load_shedding_characteristic: TimeCurrentCharacteristic::new(
+                Self::LOAD_SHEDDING_PICKUP_PERCENT,
+                &Self::LOAD_SHEDDING_LOAD_PERCENT,
+                &Self::LOAD_SHEDDING_ALLOWED_TIME_SECONDS,
+                Self::LOAD_SHEDDING_RESET_TIME_SECONDS,
+            ),
+            trip_characteristic: TimeCurrentCharacteristic::new(
+                Self::TRIP_PICKUP_PERCENT,
+                &Self::TRIP_LOAD_PERCENT,
+                &Self::TRIP_ALLOWED_TIME_SECONDS,
+                Self::TRIP_RESET_TIME_SECONDS,
+            ),
+            requires_load_shedding: false,
+            has_tripped: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, load: Ratio) {
+        self.load_shedding_characteristic.update(context, load);
+        self.trip_characteristic.update(context, load);
+
+        // Once shed, loads are only restored when the overload has fully decayed.
+        // This prevents continuously shedding and restoring loads.
+        if self.load_shedding_characteristic.is_exceeded() {
+            self.requires_load_shedding = true;
+        } else if self.load_shedding_characteristic.is_reset() {
+            self.requires_load_shedding = false;
+        }
+
+        if self.trip_characteristic.is_exceeded() {
+            self.has_tripped = true;
+        }
+    }
+
+    /// Resets the protection, as happens when the generator control unit is reset
+    /// by switching the generator off and on again.
+    pub fn reset(&mut self) {
+        self.load_shedding_characteristic.reset();
+        self.trip_characteristic.reset();
+        self.requires_load_shedding = false;
+        self.has_tripped = false;
+    }
+
+    pub fn requires_load_shedding(&self) -> bool {
+        self.requires_load_shedding && !self.has_tripped
+    }
+
+    pub fn has_tripped(&self) -> bool {
+        self.has_tripped
+    }
+}
+impl Default for GeneratorOverloadProtection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::Electricity;
+    use crate::simulation::test::{SimulationTestBed, TestBed};
+    use crate::simulation::{Aircraft, SimulationElement};
+    use std::time::Duration;
+
+    const FRAME: Duration = Duration::from_millis(100);
+
+    struct TestAircraft {
+        protection: GeneratorOverloadProtection,
+        load: Ratio,
+    }
+    impl TestAircraft {
+        fn new() -> Self {
+            Self {
+                protection: GeneratorOverloadProtection::new(),
+                load: Ratio::new::<percent>(0.),
+            }
+        }
+
+        fn set_load(&mut self, load: Ratio) {
+            self.load = load;
+        }
+
+        fn reset(&mut self) {
+            self.protection.reset();
+        }
+
+        fn requires_load_shedding(&self) -> bool {
+            self.protection.requires_load_shedding()
+        }
+
+        fn has_tripped(&self) -> bool {
+            self.protection.has_tripped()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _: &mut Electricity,
+        ) {
+            self.protection.update(context, self.load);
+        }
+    }
+    impl SimulationElement for TestAircraft {}
+
+    fn test_bed_with_load(load_percent: f64) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(|_| TestAircraft::new());
+        test_bed.command(|a| a.set_load(Ratio::new::<percent>(load_percent)));
+
+        test_bed
+    }
+
+    #[test]
+    fn starts_without_shedding_and_not_tripped() {
+        let mut test_bed = test_bed_with_load(0.);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.requires_load_shedding()));
+        assert!(!test_bed.query(|a| a.has_tripped()));
+    }
+
+    #[test]
+    fn load_at_maximum_does_not_shed_or_trip() {
+        let mut test_bed = test_bed_with_load(100.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(3600), FRAME);
+
+        assert!(!test_bed.query(|a| a.requires_load_shedding()));
+        assert!(!test_bed.query(|a| a.has_tripped()));
+    }
+
+    #[test]
+    fn short_overload_does_not_shed() {
+        let mut test_bed = test_bed_with_load(125.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(4), FRAME);
+
+        assert!(!test_bed.query(|a| a.requires_load_shedding()));
+    }
+
+    #[test]
+    fn sustained_overload_sheds() {
+        let mut test_bed = test_bed_with_load(125.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(6), FRAME);
+
+        assert!(test_bed.query(|a| a.requires_load_shedding()));
+        assert!(!test_bed.query(|a| a.has_tripped()));
+    }
+
+    #[test]
+    fn higher_overload_sheds_sooner() {
+        let mut test_bed = test_bed_with_load(150.);
+        test_bed.run_with_fixed_frames(Duration::from_millis(1500), FRAME);
+
+        assert!(test_bed.query(|a| a.requires_load_shedding()));
+    }
+
+    #[test]
+    fn shedding_remains_until_overload_decayed() {
+        let mut test_bed = test_bed_with_load(150.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(2), FRAME);
+
+        test_bed.command(|a| a.set_load(Ratio::new::<percent>(80.)));
+        test_bed.run_with_fixed_frames(Duration::from_secs(30), FRAME);
+        assert!(test_bed.query(|a| a.requires_load_shedding()));
+
+        test_bed.run_with_fixed_frames(Duration::from_secs(31), FRAME);
+        assert!(!test_bed.query(|a| a.requires_load_shedding()));
+    }
+
+    #[test]
+    fn overload_beyond_trip_characteristic_trips() {
+        let mut test_bed = test_bed_with_load(200.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(6), FRAME);
+
+        assert!(test_bed.query(|a| a.has_tripped()));
+        assert!(!test_bed.query(|a| a.requires_load_shedding()));
+    }
+
+    #[test]
+    fn overload_within_trip_characteristic_does_not_trip() {
+        let mut test_bed = test_bed_with_load(150.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(100), FRAME);
+
+        assert!(!test_bed.query(|a| a.has_tripped()));
+    }
+
+    #[test]
+    fn trip_remains_after_load_removed() {
+        let mut test_bed = test_bed_with_load(200.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(6), FRAME);
+
+        test_bed.command(|a| a.set_load(Ratio::new::<percent>(0.)));
+        test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+
+        assert!(test_bed.query(|a| a.has_tripped()));
+    }
+
+    #[test]
+    fn reset_clears_trip() {
+        let mut test_bed = test_bed_with_load(200.);
+        test_bed.run_with_fixed_frames(Duration::from_secs(6), FRAME);
+
+        test_bed.command(|a| a.set_load(Ratio::new::<percent>(0.)));
+        test_bed.command(|a| a.reset());
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.has_tripped()));
+    }
+}
@@ -0,0 +1,194 @@
+use std::time::Duration;
+
+use crate::simulation::UpdateContext;
+
+/// Models the transfer of an electrical bus from one power source to another.
+///
+/// When the outgoing source is still within normal parameters, the generator control
+/// units synchronise both sources and the bus is transferred without interruption
+/// (no-break power transfer). Otherwise the outgoing source's contactor has to open before
+/// the incoming source's contactor may close, leaving the bus unpowered for a short moment.
+pub struct PowerTransfer<T> {
+    break_duration: Duration,
+    source: Option<T>,
+    remaining_break_duration: Duration,
+}
+impl<T: Copy + PartialEq> PowerTransfer<T> {
+    pub fn new(break_duration: Duration) -> Self {
+        Self {
+            break_duration,
+            source: None,
+            remaining_break_duration: Duration::from_secs(0),
+        }
+    }
+
+    /// Updates the transfer with the source which should power the bus.
+    /// `is_within_normal_parameters` indicates whether or not the given source
+    /// currently provides output within normal parameters.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        source: Option<T>,
+        is_within_normal_parameters: impl Fn(T) -> bool,
+    ) {
+        if source != self.source {
+            self.remaining_break_duration = match (self.source, source) {
+                (Some(outgoing), Some(_)) if !is_within_normal_parameters(outgoing) => {
+                    self.break_duration
+                }
+                _ => Duration::from_secs(0),
+            };
+
+            self.source = source;
+        }
+
+        self.remaining_break_duration = Duration::from_secs_f64(
+            (self.remaining_break_duration.as_secs_f64() - context.delta_as_secs_f64()).max(0.),
+        );
+    }
+
+    /// The source powering the bus, or which will power the bus once the transfer
+    /// is complete.
+    pub fn source(&self) -> Option<T> {
+        self.source
+    }
+
+    /// Indicates if the bus is currently unpowered due to a transfer between sources.
+    pub fn is_interrupting(&self) -> bool {
+        self.remaining_break_duration > Duration::from_secs(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::electrical::Electricity;
+    use crate::simulation::test::{SimulationTestBed, TestBed};
+    use crate::simulation::{Aircraft, SimulationElement};
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum TestSource {
+        A,
+        B,
+    }
+
+    struct TestAircraft {
+        transfer: PowerTransfer<TestSource>,
+        source: Option<TestSource>,
+        a_within_normal_parameters: bool,
+    }
+    impl TestAircraft {
+        fn new() -> Self {
+            Self {
+                transfer: PowerTransfer::new(Duration::from_millis(50)),
+                source: None,
+                a_within_normal_parameters: true,
+            }
+        }
+
+        fn select(&mut self, source: Option<TestSource>) {
+            self.source = source;
+        }
+
+        fn fail_a(&mut self) {
+            self.a_within_normal_parameters = false;
+        }
+
+        fn is_interrupting(&self) -> bool {
+            self.transfer.is_interrupting()
+        }
+
+        fn transfer_source(&self) -> Option<TestSource> {
+            self.transfer.source()
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            _: &mut Electricity,
+        ) {
+            let a_within_normal_parameters = self.a_within_normal_parameters;
+            self.transfer
+                .update(context, self.source, |source| match source {
+                    TestSource::A => a_within_normal_parameters,
+                    TestSource::B => true,
+                });
+        }
+    }
+    impl SimulationElement for TestAircraft {}
+
+    fn test_bed_powered_by_a() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(|_| TestAircraft::new());
+        test_bed.command(|a| a.select(Some(TestSource::A)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        test_bed
+    }
+
+    #[test]
+    fn powering_an_unpowered_bus_is_immediate() {
+        let test_bed = test_bed_powered_by_a();
+
+        assert!(!test_bed.query(|a| a.is_interrupting()));
+        assert!(test_bed.query(|a| a.transfer_source()) == Some(TestSource::A));
+    }
+
+    #[test]
+    fn transfer_from_source_within_normal_parameters_has_no_break() {
+        let mut test_bed = test_bed_powered_by_a();
+
+        test_bed.command(|a| a.select(Some(TestSource::B)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(!test_bed.query(|a| a.is_interrupting()));
+        assert!(test_bed.query(|a| a.transfer_source()) == Some(TestSource::B));
+    }
+
+    #[test]
+    fn transfer_from_lost_source_has_a_break() {
+        let mut test_bed = test_bed_powered_by_a();
+
+        test_bed.command(|a| a.fail_a());
+        test_bed.command(|a| a.select(Some(TestSource::B)));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(test_bed.query(|a| a.is_interrupting()));
+    }
+
+    #[test]
+    fn break_ends_after_break_duration() {
+        let mut test_bed = test_bed_powered_by_a();
+
+        test_bed.command(|a| a.fail_a());
+        test_bed.command(|a| a.select(Some(TestSource::B)));
+        test_bed.run_with_delta(Duration::from_millis(30));
+        assert!(test_bed.query(|a| a.is_interrupting()));
+
+        test_bed.run_with_delta(Duration::from_millis(30));
+        assert!(!test_bed.query(|a| a.is_interrupting()));
+    }
+
+    #[test]
+    fn break_shorter_than_a_single_tick_is_not_observed() {
+        let mut test_bed = test_bed_powered_by_a();
+
+        test_bed.command(|a| a.fail_a());
+        test_bed.command(|a| a.select(Some(TestSource::B)));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.is_interrupting()));
+    }
+
+    #[test]
+    fn losing_all_sources_has_no_break() {
+        let mut test_bed = test_bed_powered_by_a();
+
+        test_bed.command(|a| a.fail_a());
+        test_bed.command(|a| a.select(None));
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(!test_bed.query(|a| a.is_interrupting()));
+        assert!(test_bed.query(|a| a.transfer_source()).is_none());
+    }
+}
@@ -3,9 +3,9 @@ use std::cell::Ref;
 use uom::si::{electric_potential::volt, f64::*, frequency::hertz};
 
 use super::{
-    ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-    ElectricalStateWriter, ElectricityTransformer, Potential, PotentialOrigin, ProvideFrequency,
-    ProvidePotential,
+    ElectricalElement, ElectricalElementDescription, ElectricalElementIdentifier,
+    ElectricalElementIdentifierProvider, ElectricalElementKind, ElectricalStateWriter,
+    ElectricityTransformer, Potential, PotentialOrigin, ProvideFrequency, ProvidePotential,
 };
 use crate::{
     shared::{ConsumePower, PowerConsumptionReport},
@@ -55,6 +55,14 @@ impl ElectricityTransformer for StaticInverter {
     }
 }
 impl SimulationElement for StaticInverter {
+    fn describe_electrical_element(&self) -> Option<ElectricalElementDescription> {
+        Some(ElectricalElementDescription::new(
+            self,
+            "STAT_INV",
+            ElectricalElementKind::Transformer(PotentialOrigin::StaticInverter),
+        ))
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.writer.write_alternating(self, writer);
     }
@@ -0,0 +1,549 @@
+use std::fmt::Write;
+
+use fxhash::{FxHashMap, FxHashSet};
+use uom::si::{electric_potential::volt, f64::*, power::watt};
+
+use crate::{
+    shared::{PotentialOrigin, PowerConsumptionReport},
+    simulation::{SimulationElement, SimulationElementVisitor},
+};
+
+use super::{ElectricalElement, ElectricalElementIdentifier, Electricity};
+
+/// The kind of an element within the electrical network.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ElectricalElementKind {
+    Source(PotentialOrigin),
+    Transformer(PotentialOrigin),
+    Contactor,
+    Bus,
+    /// An element which is part of the network, but didn't describe itself.
+    Other,
+}
+impl ElectricalElementKind {
+    fn name(&self) -> &'static str {
+        match self {
+            ElectricalElementKind::Source(_) => "source",
+            ElectricalElementKind::Transformer(_) => "transformer",
+            ElectricalElementKind::Contactor => "contactor",
+            ElectricalElementKind::Bus => "bus",
+            ElectricalElementKind::Other => "other",
+        }
+    }
+
+    fn dot_shape(&self) -> &'static str {
+        match self {
+            ElectricalElementKind::Source(_) => "house",
+            ElectricalElementKind::Transformer(_) => "diamond",
+            ElectricalElementKind::Contactor => "circle",
+            ElectricalElementKind::Bus => "box",
+            ElectricalElementKind::Other => "plaintext",
+        }
+    }
+}
+
+/// Describes an element which is part of the electrical network.
+/// Elements provide their description through
+/// [`SimulationElement::describe_electrical_element`].
+pub struct ElectricalElementDescription {
+    name: String,
+    kind: ElectricalElementKind,
+    input_identifier: ElectricalElementIdentifier,
+    output_identifier: ElectricalElementIdentifier,
+    is_conductive: bool,
+}
+impl ElectricalElementDescription {
+    pub fn new<T: Into<String>>(
+        element: &impl ElectricalElement,
+        name: T,
+        kind: ElectricalElementKind,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            input_identifier: element.input_identifier(),
+            output_identifier: element.output_identifier(),
+            is_conductive: element.is_conductive(),
+        }
+    }
+}
+
+/// A snapshot of the electrical network, as it was at the end of the last tick.
+/// Connections are only known once the simulation started recording them.
+/// The snapshot can be exported to Graphviz DOT or JSON, which is useful when
+/// debugging the electrical network's reconfiguration.
+pub struct ElectricalTopology {
+    nodes: Vec<ElectricalTopologyNode>,
+    connections: Vec<ElectricalTopologyConnection>,
+}
+impl ElectricalTopology {
+    pub(super) fn new(
+        descriptions: Vec<ElectricalElementDescription>,
+        electricity: &Electricity,
+    ) -> Self {
+        let mut nodes: Vec<ElectricalTopologyNode> = Vec::new();
+        let mut node_index_by_identifier: FxHashMap<ElectricalElementIdentifier, usize> =
+            FxHashMap::default();
+
+        for description in descriptions {
+            let index = nodes.len();
+            node_index_by_identifier.insert(description.input_identifier, index);
+            node_index_by_identifier.insert(description.output_identifier, index);
+            nodes.push(ElectricalTopologyNode::new(description, electricity));
+        }
+
+        let mut connections = Vec::new();
+        let mut known_connections = FxHashSet::default();
+        for &(from, to) in electricity.connections() {
+            let from =
+                Self::node_index(&mut nodes, &mut node_index_by_identifier, from, electricity);
+            let to = Self::node_index(&mut nodes, &mut node_index_by_identifier, to, electricity);
+
+            if from != to && known_connections.insert((from, to)) {
+                connections.push(ElectricalTopologyConnection {
+                    from,
+                    to,
+                    is_conducting: nodes[from].is_conductive && nodes[to].is_conductive,
+                });
+            }
+        }
+
+        Self { nodes, connections }
+    }
+
+    fn node_index(
+        nodes: &mut Vec<ElectricalTopologyNode>,
+        node_index_by_identifier: &mut FxHashMap<ElectricalElementIdentifier, usize>,
+        identifier: ElectricalElementIdentifier,
+        electricity: &Electricity,
+    ) -> usize {
+        *node_index_by_identifier
+            .entry(identifier)
+            .or_insert_with(|| {
+                nodes.push(ElectricalTopologyNode::for_undescribed(
+                    identifier,
+                    electricity,
+                ));
+                nodes.len() - 1
+            })
+    }
+
+    pub fn node(&self, name: &str) -> Option<&ElectricalTopologyNode> {
+        self.nodes.iter().find(|node| node.name == name)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &ElectricalTopologyNode> {
+        self.nodes.iter()
+    }
+
+    /// Indicates if the elements with the given names are directly connected
+    /// and electricity can flow between them.
+    pub fn is_conducting_between(&self, from: &str, to: &str) -> bool {
+        self.connections.iter().any(|connection| {
+            connection.is_conducting
+                && ((self.nodes[connection.from].name == from
+                    && self.nodes[connection.to].name == to)
+                    || (self.nodes[connection.from].name == to
+                        && self.nodes[connection.to].name == from))
+        })
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph electrical {{").unwrap();
+        writeln!(dot, "    rankdir=LR;").unwrap();
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let mut label = format!(
+                "{}\\n{:.1} V",
+                escape(&node.name),
+                node.potential.get::<volt>()
+            );
+            for origin in &node.origins {
+                write!(label, "\\n{}", origin).unwrap();
+            }
+            if node.power > Power::new::<watt>(0.) {
+                write!(label, "\\n{:.0} W", node.power.get::<watt>()).unwrap();
+            }
+
+            writeln!(
+                dot,
+                "    n{} [label=\"{}\", shape={}, style=filled, fillcolor={}];",
+                index,
+                label,
+                node.kind.dot_shape(),
+                if node.is_powered() {
+                    "palegreen"
+                } else {
+                    "lightgrey"
+                }
+            )
+            .unwrap();
+        }
+
+        for connection in &self.connections {
+            writeln!(
+                dot,
+                "    n{} -> n{} [style={}];",
+                connection.from,
+                connection.to,
+                if connection.is_conducting {
+                    "solid"
+                } else {
+                    "dashed"
+                }
+            )
+            .unwrap();
+        }
+
+        writeln!(dot, "}}").unwrap();
+
+        dot
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        write!(json, "{{\"elements\":[").unwrap();
+        for (index, node) in self.nodes.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+
+            write!(
+                json,
+                "{{\"name\":\"{}\",\"kind\":\"{}\",\"conductive\":{},\"potential\":{},\"origins\":[{}],\"power\":{}}}",
+                escape(&node.name),
+                node.kind.name(),
+                node.is_conductive,
+                node.potential.get::<volt>(),
+                node.origins
+                    .iter()
+                    .map(|origin| format!("\"{}\"", origin))
+                    .collect::<Vec<_>>()
+                    .join(","),
+                node.power.get::<watt>()
+            )
+            .unwrap();
+        }
+
+        write!(json, "],\"connections\":[").unwrap();
+        for (index, connection) in self.connections.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+
+            write!(
+                json,
+                "{{\"from\":\"{}\",\"to\":\"{}\",\"conducting\":{}}}",
+                escape(&self.nodes[connection.from].name),
+                escape(&self.nodes[connection.to].name),
+                connection.is_conducting
+            )
+            .unwrap();
+        }
+        write!(json, "]}}").unwrap();
+
+        json
+    }
+}
+
+/// An element within the [`ElectricalTopology`].
+pub struct ElectricalTopologyNode {
+    name: String,
+    kind: ElectricalElementKind,
+    is_conductive: bool,
+    potential: ElectricPotential,
+    origins: Vec<PotentialOrigin>,
+    power: Power,
+}
+impl ElectricalTopologyNode {
+    fn new(description: ElectricalElementDescription, electricity: &Electricity) -> Self {
+        let (potential, origins) = Self::potential_of(description.output_identifier, electricity);
+        let power = match description.kind {
+            ElectricalElementKind::Source(origin) | ElectricalElementKind::Transformer(origin) => {
+                electricity.total_consumption_of(origin)
+            }
+            _ => electricity.consumption_from(description.input_identifier),
+        };
+
+        Self {
+            name: description.name,
+            kind: description.kind,
+            is_conductive: description.is_conductive,
+            potential,
+            origins,
+            power,
+        }
+    }
+
+    fn for_undescribed(identifier: ElectricalElementIdentifier, electricity: &Electricity) -> Self {
+        let (potential, origins) = Self::potential_of(identifier, electricity);
+
+        Self {
+            name: format!("ELEMENT_{}", identifier.0),
+            kind: ElectricalElementKind::Other,
+            is_conductive: true,
+            potential,
+            origins,
+            power: electricity.consumption_from(identifier),
+        }
+    }
+
+    fn potential_of(
+        identifier: ElectricalElementIdentifier,
+        electricity: &Electricity,
+    ) -> (ElectricPotential, Vec<PotentialOrigin>) {
+        match electricity.potential.get(identifier) {
+            Some(potential) if potential.is_powered() => {
+                let mut origins: Vec<PotentialOrigin> = potential.origins().copied().collect();
+                origins.sort_by_key(|origin| origin.to_string());
+
+                (potential.raw(), origins)
+            }
+            _ => (ElectricPotential::new::<volt>(0.), Vec::new()),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> ElectricalElementKind {
+        self.kind
+    }
+
+    pub fn is_conductive(&self) -> bool {
+        self.is_conductive
+    }
+
+    pub fn is_powered(&self) -> bool {
+        !self.origins.is_empty()
+    }
+
+    pub fn potential(&self) -> ElectricPotential {
+        self.potential
+    }
+
+    /// The origins of the potential which feeds the element.
+    pub fn origins(&self) -> &[PotentialOrigin] {
+        &self.origins
+    }
+
+    /// For sources and transformers, the power they supply. For other
+    /// elements, the power consumed directly from the element.
+    pub fn power(&self) -> Power {
+        self.power
+    }
+}
+
+struct ElectricalTopologyConnection {
+    from: usize,
+    to: usize,
+    is_conducting: bool,
+}
+
+pub(super) struct DescribeElectricalElementVisitor {
+    descriptions: Vec<ElectricalElementDescription>,
+}
+impl DescribeElectricalElementVisitor {
+    pub(super) fn new() -> Self {
+        Self {
+            descriptions: Vec::new(),
+        }
+    }
+
+    pub(super) fn into_descriptions(self) -> Vec<ElectricalElementDescription> {
+        self.descriptions
+    }
+}
+impl SimulationElementVisitor for DescribeElectricalElementVisitor {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        if let Some(description) = visited.describe_electrical_element() {
+            self.descriptions.push(description);
+        }
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{
+            consumption::PowerConsumer, Battery, Contactor, ElectricalBus, ElectricalBusType,
+        },
+        simulation::{
+            test::{SimulationTestBed, TestBed},
+            Aircraft, InitContext, UpdateContext,
+        },
+    };
+
+    struct TestAircraft {
+        battery: Battery,
+        contactor: Contactor,
+        bus: ElectricalBus,
+        consumer: PowerConsumer,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            let mut consumer = PowerConsumer::from(ElectricalBusType::DirectCurrentBattery);
+            consumer.demand(Power::new::<watt>(100.));
+
+            Self {
+                battery: Battery::full(context, 1),
+                contactor: Contactor::new(context, "TEST"),
+                bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentBattery),
+                consumer,
+            }
+        }
+
+        fn close_contactor(&mut self) {
+            self.contactor.close_when(true);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.battery);
+            electricity.flow(&self.battery, &self.contactor);
+            electricity.flow(&self.contactor, &self.bus);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.battery.accept(visitor);
+            self.contactor.accept(visitor);
+            self.bus.accept(visitor);
+            self.consumer.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.start_recording_electrical_topology();
+
+        test_bed
+    }
+
+    #[test]
+    fn contains_described_elements() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let topology = test_bed.electrical_topology();
+
+        assert_eq!(
+            topology.node("BAT_1").unwrap().kind(),
+            ElectricalElementKind::Source(PotentialOrigin::Battery(1))
+        );
+        assert_eq!(
+            topology.node("TEST").unwrap().kind(),
+            ElectricalElementKind::Contactor
+        );
+        assert_eq!(
+            topology.node("DC_BAT").unwrap().kind(),
+            ElectricalElementKind::Bus
+        );
+    }
+
+    #[test]
+    fn open_contactor_does_not_conduct() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let topology = test_bed.electrical_topology();
+
+        assert!(!topology.node("TEST").unwrap().is_conductive());
+        assert!(!topology.is_conducting_between("TEST", "DC_BAT"));
+        assert!(!topology.node("DC_BAT").unwrap().is_powered());
+    }
+
+    #[test]
+    fn closed_contactor_conducts() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.close_contactor());
+        test_bed.run();
+
+        let topology = test_bed.electrical_topology();
+
+        assert!(topology.node("TEST").unwrap().is_conductive());
+        assert!(topology.is_conducting_between("BAT_1", "TEST"));
+        assert!(topology.is_conducting_between("TEST", "DC_BAT"));
+    }
+
+    #[test]
+    fn bus_includes_feeding_origins() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.close_contactor());
+        test_bed.run();
+
+        let topology = test_bed.electrical_topology();
+
+        assert_eq!(
+            topology.node("DC_BAT").unwrap().origins(),
+            &[PotentialOrigin::Battery(1)]
+        );
+    }
+
+    #[test]
+    fn includes_power_flow() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.close_contactor());
+        test_bed.run();
+
+        let topology = test_bed.electrical_topology();
+
+        assert_eq!(
+            topology.node("BAT_1").unwrap().power(),
+            Power::new::<watt>(100.)
+        );
+        assert_eq!(
+            topology.node("DC_BAT").unwrap().power(),
+            Power::new::<watt>(100.)
+        );
+    }
+
+    #[test]
+    fn exports_dot() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        let dot = test_bed.electrical_topology().to_dot();
+
+        assert!(dot.starts_with("digraph electrical {"));
+        assert!(dot.contains("BAT_1"));
+        assert!(dot.contains("style=dashed"));
+    }
+
+    #[test]
+    fn exports_json() {
+        let mut test_bed = test_bed();
+        test_bed.command(|a| a.close_contactor());
+        test_bed.run();
+
+        let json = test_bed.electrical_topology().to_json();
+
+        assert!(json.contains("{\"name\":\"TEST\",\"kind\":\"contactor\",\"conductive\":true"));
+        assert!(json.contains("{\"from\":\"TEST\",\"to\":\"DC_BAT\",\"conducting\":true}"));
+    }
+
+    #[test]
+    fn does_not_record_connections_by_default() {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.command(|a| a.close_contactor());
+        test_bed.run();
+
+        let topology = test_bed.electrical_topology();
+
+        assert!(!topology.is_conducting_between("TEST", "DC_BAT"));
+        assert!(topology.node("DC_BAT").unwrap().is_powered());
+    }
+}
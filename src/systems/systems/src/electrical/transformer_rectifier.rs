@@ -3,9 +3,9 @@ use std::cell::Ref;
 use uom::si::{electric_current::ampere, electric_potential::volt, f64::*};
 
 use super::{
-    ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
-    ElectricalStateWriter, ElectricityTransformer, Potential, PotentialOrigin, ProvideCurrent,
-    ProvidePotential,
+    ElectricalElement, ElectricalElementDescription, ElectricalElementIdentifier,
+    ElectricalElementIdentifierProvider, ElectricalElementKind, ElectricalStateWriter,
+    ElectricityTransformer, Potential, PotentialOrigin, ProvideCurrent, ProvidePotential,
 };
 use crate::{
     failures::{Failure, FailureType},
@@ -77,6 +77,14 @@ impl ElectricityTransformer for TransformerRectifier {
     }
 }
 impl SimulationElement for TransformerRectifier {
+    fn describe_electrical_element(&self) -> Option<ElectricalElementDescription> {
+        Some(ElectricalElementDescription::new(
+            self,
+            format!("TR_{}", self.number),
+            ElectricalElementKind::Transformer(PotentialOrigin::TransformerRectifier(self.number)),
+        ))
+    }
+
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.failure.accept(visitor);
 
@@ -1,12 +1,23 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use fxhash::FxHashMap;
+
 use crate::shared::HydraulicColor;
 use crate::simulation::SimulationElement;
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum FailureType {
     TransformerRectifier(usize),
+    IdgOilLeak(usize),
+    GroundPowerUnitOvervoltage,
+    GroundPowerUnitWrongFrequency,
+    GroundPowerUnitIncorrectPhaseSequence,
+    GroundPowerUnitOpenPhase,
     ReservoirLeak(HydraulicColor),
     ReservoirAirLeak(HydraulicColor),
     ReservoirReturnLeak(HydraulicColor),
+    FlapTransmission(usize),
+    SlatTransmission(usize),
 }
 
 pub struct Failure {
@@ -33,6 +44,111 @@ impl SimulationElement for Failure {
     }
 }
 
+/// A simulator variable through which failures are requested.
+/// The variable contains the identifier of the requested failure, and is
+/// reset to zero once the request has been taken into account.
+pub trait FailureRequestVariable {
+    fn read(&self) -> f64;
+    fn write(&mut self, value: f64);
+}
+
+/// Collects failure activation and deactivation requests from the simulator and keeps
+/// track of the failures which are currently active.
+///
+/// Requests are queued when read, so that all requests received between two
+/// simulation ticks can be applied in the next tick.
+pub struct FailureRequests<T: FailureRequestVariable> {
+    activate_variable: T,
+    deactivate_variable: T,
+    identifier_to_failure_type: FxHashMap<u64, FailureType>,
+    queued_requests: VecDeque<(u64, bool)>,
+    active_identifiers: BTreeSet<u64>,
+}
+impl<T: FailureRequestVariable> FailureRequests<T> {
+    pub fn new(activate_variable: T, deactivate_variable: T) -> Self {
+        Self {
+            activate_variable,
+            deactivate_variable,
+            identifier_to_failure_type: FxHashMap::default(),
+            queued_requests: VecDeque::new(),
+            active_identifiers: BTreeSet::new(),
+        }
+    }
+
+    pub fn add(&mut self, identifier: u64, failure_type: FailureType) {
+        self.identifier_to_failure_type
+            .insert(identifier, failure_type);
+    }
+
+    pub fn len(&self) -> usize {
+        self.identifier_to_failure_type.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.identifier_to_failure_type.is_empty()
+    }
+
+    /// Reads the activation and deactivation variables, queueing any request for a known failure.
+    /// This function can be called as often as needed; each call acknowledges the requests it read
+    /// by resetting the variables, allowing the simulator to write the next request.
+    pub fn read(&mut self) {
+        if let Some(identifier) = Self::read_request(
+            &mut self.activate_variable,
+            &self.identifier_to_failure_type,
+        ) {
+            self.queued_requests.push_back((identifier, true));
+        }
+
+        if let Some(identifier) = Self::read_request(
+            &mut self.deactivate_variable,
+            &self.identifier_to_failure_type,
+        ) {
+            self.queued_requests.push_back((identifier, false));
+        }
+    }
+
+    fn read_request(
+        variable: &mut T,
+        identifier_to_failure_type: &FxHashMap<u64, FailureType>,
+    ) -> Option<u64> {
+        let identifier = variable.read() as u64;
+        if identifier_to_failure_type.contains_key(&identifier) {
+            variable.write(0.);
+            Some(identifier)
+        } else {
+            None
+        }
+    }
+
+    /// Applies all queued requests in the order they were received.
+    pub fn apply<U: FnMut(FailureType, bool)>(&mut self, mut apply_fn: U) {
+        while let Some((identifier, is_active)) = self.queued_requests.pop_front() {
+            if let Some(failure_type) = self.identifier_to_failure_type.get(&identifier) {
+                if is_active {
+                    self.active_identifiers.insert(identifier);
+                } else {
+                    self.active_identifiers.remove(&identifier);
+                }
+
+                (apply_fn)(*failure_type, is_active);
+            }
+        }
+    }
+
+    pub fn has_queued_requests(&self) -> bool {
+        !self.queued_requests.is_empty()
+    }
+
+    /// The identifiers of all active failures, in ascending order.
+    pub fn active_identifiers(&self) -> impl Iterator<Item = &u64> {
+        self.active_identifiers.iter()
+    }
+
+    pub fn is_active(&self, identifier: u64) -> bool {
+        self.active_identifiers.contains(&identifier)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::simulation::test::{SimulationTestBed, TestBed};
@@ -64,4 +180,151 @@ mod tests {
 
         assert!(test_bed.query_element(|el| !el.is_active()));
     }
+
+    mod failure_requests_tests {
+        use super::*;
+
+        struct TestFailureRequestVariable {
+            value: f64,
+        }
+        impl TestFailureRequestVariable {
+            fn new() -> Self {
+                Self { value: 0. }
+            }
+        }
+        impl FailureRequestVariable for TestFailureRequestVariable {
+            fn read(&self) -> f64 {
+                self.value
+            }
+
+            fn write(&mut self, value: f64) {
+                self.value = value;
+            }
+        }
+
+        fn failure_requests() -> FailureRequests<TestFailureRequestVariable> {
+            let mut requests = FailureRequests::new(
+                TestFailureRequestVariable::new(),
+                TestFailureRequestVariable::new(),
+            );
+            requests.add(24_000, FailureType::TransformerRectifier(1));
+            requests.add(24_001, FailureType::TransformerRectifier(2));
+            requests.add(29_000, FailureType::ReservoirLeak(HydraulicColor::Green));
+
+            requests
+        }
+
+        fn request_activation(
+            requests: &mut FailureRequests<TestFailureRequestVariable>,
+            identifier: u64,
+        ) {
+            requests.activate_variable.write(identifier as f64);
+            requests.read();
+        }
+
+        fn request_deactivation(
+            requests: &mut FailureRequests<TestFailureRequestVariable>,
+            identifier: u64,
+        ) {
+            requests.deactivate_variable.write(identifier as f64);
+            requests.read();
+        }
+
+        fn applied(
+            requests: &mut FailureRequests<TestFailureRequestVariable>,
+        ) -> Vec<(FailureType, bool)> {
+            let mut applied = vec![];
+            requests.apply(|failure_type, is_active| applied.push((failure_type, is_active)));
+
+            applied
+        }
+
+        #[test]
+        fn starts_without_active_failures() {
+            let requests = failure_requests();
+
+            assert_eq!(requests.active_identifiers().count(), 0);
+            assert!(!requests.has_queued_requests());
+        }
+
+        #[test]
+        fn reading_a_known_failure_resets_the_variable() {
+            let mut requests = failure_requests();
+            request_activation(&mut requests, 24_000);
+
+            assert_eq!(requests.activate_variable.read(), 0.);
+        }
+
+        #[test]
+        fn reading_an_unknown_failure_leaves_the_variable_untouched() {
+            let mut requests = failure_requests();
+            request_activation(&mut requests, 12_345);
+
+            assert_eq!(requests.activate_variable.read(), 12_345.);
+            assert!(!requests.has_queued_requests());
+        }
+
+        #[test]
+        fn multiple_requests_read_before_a_tick_are_all_applied() {
+            let mut requests = failure_requests();
+            request_activation(&mut requests, 24_000);
+            request_activation(&mut requests, 24_001);
+            request_activation(&mut requests, 29_000);
+
+            assert_eq!(
+                applied(&mut requests),
+                vec![
+                    (FailureType::TransformerRectifier(1), true),
+                    (FailureType::TransformerRectifier(2), true),
+                    (FailureType::ReservoirLeak(HydraulicColor::Green), true),
+                ]
+            );
+            assert!(!requests.has_queued_requests());
+        }
+
+        #[test]
+        fn requests_are_applied_in_the_order_they_were_received() {
+            let mut requests = failure_requests();
+            request_activation(&mut requests, 24_000);
+            request_deactivation(&mut requests, 24_000);
+
+            assert_eq!(
+                applied(&mut requests),
+                vec![
+                    (FailureType::TransformerRectifier(1), true),
+                    (FailureType::TransformerRectifier(1), false),
+                ]
+            );
+            assert!(!requests.is_active(24_000));
+        }
+
+        #[test]
+        fn activated_failures_are_reported_as_active_in_ascending_order() {
+            let mut requests = failure_requests();
+            request_activation(&mut requests, 29_000);
+            request_activation(&mut requests, 24_001);
+            applied(&mut requests);
+
+            assert_eq!(
+                requests.active_identifiers().copied().collect::<Vec<u64>>(),
+                vec![24_001, 29_000]
+            );
+        }
+
+        #[test]
+        fn deactivated_failures_are_no_longer_reported_as_active() {
+            let mut requests = failure_requests();
+            request_activation(&mut requests, 24_000);
+            request_activation(&mut requests, 24_001);
+            applied(&mut requests);
+
+            request_deactivation(&mut requests, 24_000);
+            applied(&mut requests);
+
+            assert_eq!(
+                requests.active_identifiers().copied().collect::<Vec<u64>>(),
+                vec![24_001]
+            );
+        }
+    }
 }
@@ -0,0 +1,531 @@
+use crate::{shared::pid::PidController, simulation::UpdateContext};
+
+use std::time::Duration;
+
+use uom::si::{
+    angular_velocity::radian_per_second, f64::*, force::newton, length::meter, ratio::ratio,
+    torque::newton_meter, velocity::knot, velocity::meter_per_second,
+};
+
+/// Condition of the runway surface as reported by the simulator
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum RunwayCondition {
+    Dry = 0,
+    Wet = 1,
+    Icy = 2,
+    Snow = 3,
+}
+impl RunwayCondition {
+    /// Peak friction coefficient a tyre can get from the runway
+    pub fn friction_coefficient(&self) -> Ratio {
+        Ratio::new::<ratio>(match self {
+            RunwayCondition::Dry => 0.8,
+            RunwayCondition::Wet => 0.5,
+            RunwayCondition::Icy => 0.1,
+            RunwayCondition::Snow => 0.25,
+        })
+    }
+}
+impl From<f64> for RunwayCondition {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            1 => RunwayCondition::Wet,
+            2 => RunwayCondition::Icy,
+            3 => RunwayCondition::Snow,
+            _ => RunwayCondition::Dry,
+        }
+    }
+}
+
+/// Longitudinal dynamics of a braked wheel rolling on the runway.
+///
+/// The tyre force is computed from the slip ratio between the wheel and the ground speed,
+/// using a Burckhardt friction curve scaled to the runway friction coefficient: friction peaks
+/// around 17% slip, then drops to 65% of its peak value once the wheel is locked.
+///
+/// The wheel speed results from the balance between the tyre force spinning it up and the brake
+/// torque slowing it down. As tyre force is very stiff against wheel speed at low ground speeds,
+/// it is integrated with a semi implicit scheme.
+pub struct BrakedWheel {
+    radius: Length,
+    moment_of_inertia_kg_m2: f64,
+
+    angular_speed: AngularVelocity,
+    slip_ratio: Ratio,
+    tyre_force: Force,
+}
+impl BrakedWheel {
+    const BURCKHARDT_C1: f64 = 1.28;
+    const BURCKHARDT_C2: f64 = 23.99;
+    const BURCKHARDT_C3: f64 = 0.52;
+
+    // Under that speed slip ratio is meaningless: the wheel is considered rolling at ground speed
+    const MIN_GROUND_SPEED_FOR_SLIP_METER_PER_SECOND: f64 = 1.;
+
+    const INTEGRATION_TIME_STEP: Duration = Duration::from_millis(5);
+
+    pub fn new(radius: Length, moment_of_inertia_kg_m2: f64) -> Self {
+        Self {
+            radius,
+            moment_of_inertia_kg_m2,
+
+            angular_speed: AngularVelocity::new::<radian_per_second>(0.),
+            slip_ratio: Ratio::new::<ratio>(0.),
+            tyre_force: Force::new::<newton>(0.),
+        }
+    }
+
+    /// Updates wheel speed and tyre force. A null normal load means the wheel is not touching
+    /// the ground.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        brake_torque: Torque,
+        normal_load: Force,
+        runway_friction: Ratio,
+    ) {
+        let mut remaining_time = context.delta();
+        while remaining_time > Duration::from_secs(0) {
+            let time_step = remaining_time.min(Self::INTEGRATION_TIME_STEP);
+            self.update_one_step(
+                time_step.as_secs_f64(),
+                context.ground_speed().get::<meter_per_second>().abs(),
+                brake_torque.get::<newton_meter>().max(0.),
+                normal_load.get::<newton>().max(0.),
+                runway_friction.get::<ratio>(),
+            );
+            remaining_time -= time_step;
+        }
+    }
+
+    fn update_one_step(
+        &mut self,
+        delta_seconds: f64,
+        ground_speed: f64,
+        brake_torque: f64,
+        normal_load: f64,
+        runway_friction: f64,
+    ) {
+        let radius = self.radius.get::<meter>();
+        let mut angular_speed = self.angular_speed.get::<radian_per_second>();
+
+        if normal_load <= 0. {
+            // Wheel in the air: only slowed down by its brake
+            angular_speed = (angular_speed
+                - brake_torque * delta_seconds / self.moment_of_inertia_kg_m2)
+                .max(0.);
+            self.slip_ratio = Ratio::new::<ratio>(0.);
+            self.tyre_force = Force::new::<newton>(0.);
+        } else if ground_speed < Self::MIN_GROUND_SPEED_FOR_SLIP_METER_PER_SECOND {
+            angular_speed = ground_speed / radius;
+            self.slip_ratio = Ratio::new::<ratio>(0.);
+            self.tyre_force =
+                Force::new::<newton>((brake_torque / radius).min(runway_friction * normal_load));
+        } else {
+            let free_rolling_speed = ground_speed / radius;
+            let slip = Self::slip(angular_speed, free_rolling_speed);
+
+            let tyre_force = runway_friction * Self::normalized_friction(slip) * normal_load;
+
+            // Tyre torque increases when wheel slows down: its derivative vs wheel speed is used
+            // to make the integration implicit and stable whatever the time step
+            let tyre_torque_stiffness = (runway_friction
+                * Self::normalized_friction_derivative(slip)
+                * normal_load
+                * radius
+                / free_rolling_speed)
+                .max(0.);
+
+            let net_torque = tyre_force * radius - brake_torque;
+
+            // A locked wheel stays locked as long as brake torque overcomes tyre torque
+            if angular_speed > 0. || net_torque > 0. {
+                angular_speed += net_torque * delta_seconds
+                    / (self.moment_of_inertia_kg_m2 + tyre_torque_stiffness * delta_seconds);
+            }
+            angular_speed = angular_speed.max(0.).min(free_rolling_speed);
+
+            let slip = Self::slip(angular_speed, free_rolling_speed);
+            self.slip_ratio = Ratio::new::<ratio>(slip);
+            self.tyre_force = Force::new::<newton>(
+                runway_friction * Self::normalized_friction(slip) * normal_load,
+            );
+        }
+
+        self.angular_speed = AngularVelocity::new::<radian_per_second>(angular_speed);
+    }
+
+    fn slip(angular_speed: f64, free_rolling_speed: f64) -> f64 {
+        (1. - angular_speed / free_rolling_speed).clamp(0., 1.)
+    }
+
+    fn peak_burckhardt_friction() -> f64 {
+        let peak_slip = (Self::BURCKHARDT_C1 * Self::BURCKHARDT_C2 / Self::BURCKHARDT_C3).ln()
+            / Self::BURCKHARDT_C2;
+
+        Self::burckhardt_friction(peak_slip)
+    }
+
+    fn burckhardt_friction(slip: f64) -> f64 {
+        Self::BURCKHARDT_C1 * (1. - (-Self::BURCKHARDT_C2 * slip).exp())
+            - Self::BURCKHARDT_C3 * slip
+    }
+
+    /// Friction coefficient for a given slip ratio, relative to the peak friction coefficient
+    fn normalized_friction(slip: f64) -> f64 {
+        Self::burckhardt_friction(slip) / Self::peak_burckhardt_friction()
+    }
+
+    fn normalized_friction_derivative(slip: f64) -> f64 {
+        (Self::BURCKHARDT_C1 * Self::BURCKHARDT_C2 * (-Self::BURCKHARDT_C2 * slip).exp()
+            - Self::BURCKHARDT_C3)
+            / Self::peak_burckhardt_friction()
+    }
+
+    pub fn slip_ratio(&self) -> Ratio {
+        self.slip_ratio
+    }
+
+    /// Longitudinal force the tyre applies on the runway
+    pub fn tyre_force(&self) -> Force {
+        self.tyre_force
+    }
+
+    pub fn angular_speed(&self) -> AngularVelocity {
+        self.angular_speed
+    }
+}
+
+/// Anti-skid controller of a group of braked wheels.
+///
+/// It computes a limit on the brake demand of the wheels so that their slip ratio is held around
+/// the optimal one, where tyre friction is at its highest. The limit is released when wheels
+/// slip too much and reapplied when they spin back up.
+pub struct AntiSkidController {
+    pid_controller: PidController,
+    is_active: bool,
+}
+impl AntiSkidController {
+    // Anti-skid is deactivated under that ground speed
+    const MIN_GROUND_SPEED_KNOT: f64 = 20.;
+
+    pub fn new(target_slip_ratio: Ratio, proportional_gain: f64, integral_gain: f64) -> Self {
+        let mut pid_controller = PidController::new(
+            proportional_gain,
+            integral_gain,
+            0.,
+            0.,
+            1.,
+            target_slip_ratio.get::<ratio>(),
+            1.,
+        );
+        pid_controller.reset_with_output(1.);
+
+        Self {
+            pid_controller,
+            is_active: false,
+        }
+    }
+
+    /// Updates the brake demand limit from the slip ratio of the worst skidding wheel
+    pub fn update(&mut self, context: &UpdateContext, is_enabled: bool, slip_ratio: Ratio) {
+        self.is_active =
+            is_enabled && context.ground_speed().get::<knot>().abs() > Self::MIN_GROUND_SPEED_KNOT;
+
+        if self.is_active {
+            self.pid_controller
+                .next_control_output(slip_ratio.get::<ratio>(), Some(context.delta()));
+        } else {
+            self.pid_controller.reset_with_output(1.);
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn brake_demand_limit(&self) -> Ratio {
+        Ratio::new::<ratio>(self.pid_controller.output())
+    }
+
+    pub fn limited_demand(&self, demand: Ratio) -> Ratio {
+        demand.min(self.brake_demand_limit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{SimulationTestBed, TestBed, WriteByName};
+    use crate::simulation::{Aircraft, InitContext, SimulationElement, SimulationElementVisitor};
+    use uom::si::acceleration::meter_per_second_squared;
+
+    // A quarter of an A320 on one of its main wheels
+    const MASS_ON_WHEEL_KG: f64 = 16000.;
+    const MAX_BRAKE_TORQUE_NEWTON_METER: f64 = 50000.;
+
+    struct TestWheelOnRunway {
+        wheel: BrakedWheel,
+        anti_skid: Option<AntiSkidController>,
+        runway_condition: RunwayCondition,
+        brake_demand: Ratio,
+
+        ground_speed: Velocity,
+        stopping_distance: Length,
+    }
+    impl TestWheelOnRunway {
+        fn new(anti_skid: Option<AntiSkidController>) -> Self {
+            Self {
+                wheel: BrakedWheel::new(Length::new::<meter>(0.57), 30.),
+                anti_skid,
+                runway_condition: RunwayCondition::Dry,
+                brake_demand: Ratio::new::<ratio>(0.),
+
+                ground_speed: Velocity::new::<knot>(0.),
+                stopping_distance: Length::new::<meter>(0.),
+            }
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            if let Some(anti_skid) = &mut self.anti_skid {
+                anti_skid.update(context, true, self.wheel.slip_ratio());
+            }
+
+            let demand = match &self.anti_skid {
+                Some(anti_skid) => anti_skid.limited_demand(self.brake_demand),
+                None => self.brake_demand,
+            };
+
+            self.wheel.update(
+                context,
+                Torque::new::<newton_meter>(demand.get::<ratio>() * MAX_BRAKE_TORQUE_NEWTON_METER),
+                Force::new::<newton>(MASS_ON_WHEEL_KG * 9.81),
+                self.runway_condition.friction_coefficient(),
+            );
+
+            let deceleration = Acceleration::new::<meter_per_second_squared>(
+                self.wheel.tyre_force().get::<newton>() / MASS_ON_WHEEL_KG,
+            );
+            self.stopping_distance += self.ground_speed * context.delta_as_time();
+            self.ground_speed = (self.ground_speed - deceleration * context.delta_as_time())
+                .max(Velocity::new::<knot>(0.));
+        }
+    }
+    impl Aircraft for TestWheelOnRunway {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
+        }
+    }
+    impl SimulationElement for TestWheelOnRunway {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed(anti_skid: Option<AntiSkidController>) -> SimulationTestBed<TestWheelOnRunway> {
+        let mut test_bed =
+            SimulationTestBed::new(|_: &mut InitContext| TestWheelOnRunway::new(anti_skid));
+        test_bed.set_on_ground(true);
+
+        test_bed
+    }
+
+    fn anti_skid() -> Option<AntiSkidController> {
+        Some(AntiSkidController::new(Ratio::new::<ratio>(0.12), 2., 10.))
+    }
+
+    fn brake_from_speed(
+        test_bed: &mut SimulationTestBed<TestWheelOnRunway>,
+        runway_condition: RunwayCondition,
+        demand: f64,
+        speed: Velocity,
+    ) {
+        test_bed.command(|a| {
+            a.runway_condition = runway_condition;
+            a.ground_speed = speed;
+            a.wheel.angular_speed = AngularVelocity::new::<radian_per_second>(
+                speed.get::<meter_per_second>() / a.wheel.radius.get::<meter>(),
+            );
+            a.brake_demand = Ratio::new::<ratio>(demand);
+        });
+    }
+
+    fn run_one_frame(test_bed: &mut SimulationTestBed<TestWheelOnRunway>) {
+        let ground_speed = test_bed.query(|a| a.ground_speed);
+        test_bed.write_by_name(UpdateContext::GROUND_SPEED_KEY, ground_speed);
+        test_bed.run_with_delta(Duration::from_millis(33));
+    }
+
+    fn run_until_stopped(test_bed: &mut SimulationTestBed<TestWheelOnRunway>) -> Length {
+        for _ in 0..2000 {
+            run_one_frame(test_bed);
+            if test_bed.query(|a| a.ground_speed) <= Velocity::new::<knot>(0.) {
+                break;
+            }
+        }
+
+        test_bed.query(|a| a.stopping_distance)
+    }
+
+    fn stopping_distance(
+        anti_skid: Option<AntiSkidController>,
+        runway_condition: RunwayCondition,
+        demand: f64,
+    ) -> Length {
+        let mut test_bed = test_bed(anti_skid);
+        brake_from_speed(
+            &mut test_bed,
+            runway_condition,
+            demand,
+            Velocity::new::<knot>(130.),
+        );
+
+        run_until_stopped(&mut test_bed)
+    }
+
+    #[test]
+    fn runway_condition_from_simulator_value() {
+        assert!(RunwayCondition::from(0.) == RunwayCondition::Dry);
+        assert!(RunwayCondition::from(1.) == RunwayCondition::Wet);
+        assert!(RunwayCondition::from(2.) == RunwayCondition::Icy);
+        assert!(RunwayCondition::from(3.) == RunwayCondition::Snow);
+    }
+
+    #[test]
+    fn friction_peaks_at_low_slip() {
+        let peak = BrakedWheel::normalized_friction(0.17);
+
+        assert!((peak - 1.).abs() < 0.001);
+        assert!(BrakedWheel::normalized_friction(0.05) < peak);
+        assert!(BrakedWheel::normalized_friction(0.3) < peak);
+        assert!((BrakedWheel::normalized_friction(1.) - 0.65).abs() < 0.01);
+    }
+
+    #[test]
+    fn unbraked_wheel_rolls_without_slip() {
+        let mut test_bed = test_bed(None);
+        brake_from_speed(
+            &mut test_bed,
+            RunwayCondition::Dry,
+            0.,
+            Velocity::new::<knot>(100.),
+        );
+        for _ in 0..30 {
+            run_one_frame(&mut test_bed);
+        }
+
+        assert!(test_bed.query(|a| a.wheel.slip_ratio()) < Ratio::new::<ratio>(0.001));
+        assert!(test_bed.query(|a| a.wheel.tyre_force()) < Force::new::<newton>(1.));
+    }
+
+    #[test]
+    fn wheel_spins_up_at_touchdown() {
+        let mut test_bed = test_bed(None);
+        test_bed.command(|a| a.ground_speed = Velocity::new::<knot>(130.));
+        test_bed.run_with_delta(Duration::from_millis(33));
+
+        assert!(test_bed.query(|a| a.wheel.slip_ratio()) < Ratio::new::<ratio>(0.01));
+    }
+
+    #[test]
+    fn moderate_braking_on_dry_runway_does_not_skid() {
+        let mut test_bed = test_bed(None);
+        brake_from_speed(
+            &mut test_bed,
+            RunwayCondition::Dry,
+            0.5,
+            Velocity::new::<knot>(100.),
+        );
+        for _ in 0..30 {
+            run_one_frame(&mut test_bed);
+        }
+
+        assert!(test_bed.query(|a| a.wheel.slip_ratio()) < Ratio::new::<ratio>(0.05));
+    }
+
+    #[test]
+    fn full_braking_on_icy_runway_locks_wheel_without_anti_skid() {
+        let mut test_bed = test_bed(None);
+        brake_from_speed(
+            &mut test_bed,
+            RunwayCondition::Icy,
+            1.,
+            Velocity::new::<knot>(100.),
+        );
+        for _ in 0..30 {
+            run_one_frame(&mut test_bed);
+        }
+
+        assert!(test_bed.query(|a| a.wheel.slip_ratio()) > Ratio::new::<ratio>(0.99));
+    }
+
+    #[test]
+    fn anti_skid_holds_slip_around_optimum_on_icy_runway() {
+        let mut test_bed = test_bed(anti_skid());
+        brake_from_speed(
+            &mut test_bed,
+            RunwayCondition::Icy,
+            1.,
+            Velocity::new::<knot>(100.),
+        );
+        for _ in 0..60 {
+            run_one_frame(&mut test_bed);
+        }
+
+        let slip = test_bed.query(|a| a.wheel.slip_ratio());
+        assert!(slip > Ratio::new::<ratio>(0.05) && slip < Ratio::new::<ratio>(0.25));
+    }
+
+    #[test]
+    fn anti_skid_does_not_limit_braking_without_skid() {
+        let mut test_bed = test_bed(anti_skid());
+        brake_from_speed(
+            &mut test_bed,
+            RunwayCondition::Dry,
+            0.5,
+            Velocity::new::<knot>(100.),
+        );
+        for _ in 0..30 {
+            run_one_frame(&mut test_bed);
+        }
+
+        assert!(
+            test_bed.query(|a| a.anti_skid.as_ref().unwrap().brake_demand_limit())
+                > Ratio::new::<ratio>(0.99)
+        );
+    }
+
+    #[test]
+    fn anti_skid_is_inactive_at_low_speed() {
+        let mut test_bed = test_bed(anti_skid());
+        brake_from_speed(
+            &mut test_bed,
+            RunwayCondition::Icy,
+            1.,
+            Velocity::new::<knot>(15.),
+        );
+        test_bed.run_with_delta(Duration::from_millis(33));
+
+        assert!(!test_bed.query(|a| a.anti_skid.as_ref().unwrap().is_active()));
+    }
+
+    #[test]
+    fn stopping_distance_increases_with_runway_contamination() {
+        let dry = stopping_distance(anti_skid(), RunwayCondition::Dry, 1.);
+        let wet = stopping_distance(anti_skid(), RunwayCondition::Wet, 1.);
+        let snow = stopping_distance(anti_skid(), RunwayCondition::Snow, 1.);
+        let icy = stopping_distance(anti_skid(), RunwayCondition::Icy, 1.);
+
+        assert!(dry < wet);
+        assert!(wet < snow);
+        assert!(snow < icy);
+    }
+
+    #[test]
+    fn anti_skid_shortens_stopping_distance_on_wet_runway() {
+        let with_anti_skid = stopping_distance(anti_skid(), RunwayCondition::Wet, 1.);
+        let without_anti_skid = stopping_distance(None, RunwayCondition::Wet, 1.);
+
+        assert!(with_anti_skid < without_anti_skid * 0.9);
+    }
+}
@@ -0,0 +1,380 @@
+use crate::simulation::{
+    InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+};
+
+use uom::si::{
+    f64::*, power::watt, thermodynamic_temperature::degree_celsius, velocity::meter_per_second,
+};
+
+/// Thermal model of a wheel carbon brake.
+///
+/// The heat sink receives the braking energy and loses heat to the ambient air by convection,
+/// enhanced by the airflow around the wheel and by the brake fan, and by radiation at high
+/// temperatures. The wheel rim is heated by the heat sink: once it gets too hot, the fuse plugs
+/// it holds melt and deflate the tyre before its pressure can rise dangerously.
+///
+/// The temperature probe is placed in the brake fan airflow, which cools it faster than the heat
+/// sink itself: with the fan running, reported temperature can be much lower than actual one.
+pub struct WheelBrakeTemperature {
+    brake_temperature_id: VariableIdentifier,
+    reported_temperature_id: VariableIdentifier,
+    fuse_plug_melted_id: VariableIdentifier,
+
+    is_initialised: bool,
+    heat_sink_temperature: ThermodynamicTemperature,
+    wheel_rim_temperature: ThermodynamicTemperature,
+    probe_cooling_offset_celsius: f64,
+    fuse_plug_melted: bool,
+}
+impl WheelBrakeTemperature {
+    const HEAT_SINK_HEAT_CAPACITY_JOULE_PER_KELVIN: f64 = 66000.;
+    const WHEEL_RIM_HEAT_CAPACITY_JOULE_PER_KELVIN: f64 = 54000.;
+
+    const HEAT_SINK_CONVECTION_WATT_PER_KELVIN: f64 = 14.;
+    const WHEEL_RIM_CONVECTION_WATT_PER_KELVIN: f64 = 10.;
+    const HEAT_SINK_TO_WHEEL_RIM_CONDUCTANCE_WATT_PER_KELVIN: f64 = 40.;
+
+    // Each m/s of airflow around the wheel adds that ratio of the still air convection
+    const AIRFLOW_CONVECTION_FACTOR_PER_METER_PER_SECOND: f64 = 0.05;
+    const BRAKE_FAN_CONVECTION_FACTOR: f64 = 3.;
+
+    const STEFAN_BOLTZMANN_CONSTANT: f64 = 5.670374e-8;
+    const HEAT_SINK_EMISSIVITY_TIMES_AREA_SQUARE_METER: f64 = 0.24;
+
+    const FUSE_PLUG_MELTING_POINT_DEGREE_CELSIUS: f64 = 177.;
+
+    // With brake fan running, probe converges slowly toward the middle of heat sink and ambient
+    // temperatures. When fan stops, it quickly goes back to the heat sink temperature.
+    const PROBE_COOLING_BY_FAN_TIME_CONSTANT_SECONDS: f64 = 1200.;
+    const PROBE_EQUALIZING_TIME_CONSTANT_SECONDS: f64 = 330.;
+    const PROBE_COOLING_BY_FAN_RATIO: f64 = 0.5;
+
+    pub fn new(context: &mut InitContext, wheel_number: usize) -> Self {
+        Self {
+            brake_temperature_id: context
+                .get_identifier(format!("BRAKE_TEMPERATURE_{}", wheel_number)),
+            reported_temperature_id: context
+                .get_identifier(format!("REPORTED_BRAKE_TEMPERATURE_{}", wheel_number)),
+            fuse_plug_melted_id: context
+                .get_identifier(format!("BRAKE_FUSE_PLUG_MELTED_{}", wheel_number)),
+
+            is_initialised: false,
+            heat_sink_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            wheel_rim_temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            probe_cooling_offset_celsius: 0.,
+            fuse_plug_melted: false,
+        }
+    }
+
+    /// Updates temperatures given the power currently dissipated by the brake, the speed of the
+    /// air flowing around the wheel and the brake fan state.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        braking_power: Power,
+        airflow_speed: Velocity,
+        brake_fan_is_on: bool,
+    ) {
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        if !self.is_initialised {
+            // Brakes are assumed to be cold soaked at ambient temperature.
+            self.heat_sink_temperature = context.ambient_temperature();
+            self.wheel_rim_temperature = context.ambient_temperature();
+            self.is_initialised = true;
+        }
+
+        let delta_seconds = context.delta_as_secs_f64();
+        let heat_sink = self.heat_sink_temperature.get::<degree_celsius>();
+        let wheel_rim = self.wheel_rim_temperature.get::<degree_celsius>();
+
+        let convection_factor = Self::convection_factor(airflow_speed, brake_fan_is_on);
+
+        let heat_sink_to_rim_power =
+            Self::HEAT_SINK_TO_WHEEL_RIM_CONDUCTANCE_WATT_PER_KELVIN * (heat_sink - wheel_rim);
+        let heat_sink_losses =
+            convection_factor * Self::HEAT_SINK_CONVECTION_WATT_PER_KELVIN * (heat_sink - ambient)
+                + Self::radiated_power(heat_sink, ambient)
+                + heat_sink_to_rim_power;
+        let wheel_rim_losses =
+            convection_factor * Self::WHEEL_RIM_CONVECTION_WATT_PER_KELVIN * (wheel_rim - ambient);
+
+        self.heat_sink_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            heat_sink
+                + (braking_power.get::<watt>().max(0.) - heat_sink_losses) * delta_seconds
+                    / Self::HEAT_SINK_HEAT_CAPACITY_JOULE_PER_KELVIN,
+        );
+        self.wheel_rim_temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            wheel_rim
+                + (heat_sink_to_rim_power - wheel_rim_losses) * delta_seconds
+                    / Self::WHEEL_RIM_HEAT_CAPACITY_JOULE_PER_KELVIN,
+        );
+
+        if self.wheel_rim_temperature.get::<degree_celsius>()
+            > Self::FUSE_PLUG_MELTING_POINT_DEGREE_CELSIUS
+        {
+            self.fuse_plug_melted = true;
+        }
+
+        self.update_probe(delta_seconds, ambient, brake_fan_is_on);
+    }
+
+    fn convection_factor(airflow_speed: Velocity, brake_fan_is_on: bool) -> f64 {
+        let airflow_factor = 1.
+            + Self::AIRFLOW_CONVECTION_FACTOR_PER_METER_PER_SECOND
+                * airflow_speed.get::<meter_per_second>().abs();
+
+        if brake_fan_is_on {
+            airflow_factor * Self::BRAKE_FAN_CONVECTION_FACTOR
+        } else {
+            airflow_factor
+        }
+    }
+
+    fn radiated_power(temperature_celsius: f64, ambient_celsius: f64) -> f64 {
+        let temperature_kelvin = temperature_celsius + 273.15;
+        let ambient_kelvin = ambient_celsius + 273.15;
+
+        Self::STEFAN_BOLTZMANN_CONSTANT
+            * Self::HEAT_SINK_EMISSIVITY_TIMES_AREA_SQUARE_METER
+            * (temperature_kelvin.powi(4) - ambient_kelvin.powi(4))
+    }
+
+    fn update_probe(&mut self, delta_seconds: f64, ambient: f64, brake_fan_is_on: bool) {
+        let (target_offset, time_constant) = if brake_fan_is_on {
+            (
+                Self::PROBE_COOLING_BY_FAN_RATIO
+                    * (self.heat_sink_temperature.get::<degree_celsius>() - ambient).max(0.),
+                Self::PROBE_COOLING_BY_FAN_TIME_CONSTANT_SECONDS,
+            )
+        } else {
+            (0., Self::PROBE_EQUALIZING_TIME_CONSTANT_SECONDS)
+        };
+
+        self.probe_cooling_offset_celsius += (target_offset - self.probe_cooling_offset_celsius)
+            * (1. - (-delta_seconds / time_constant).exp());
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.heat_sink_temperature
+    }
+
+    /// Temperature measured by the brake temperature probe
+    pub fn reported_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(
+            self.heat_sink_temperature.get::<degree_celsius>() - self.probe_cooling_offset_celsius,
+        )
+    }
+
+    pub fn is_fuse_plug_melted(&self) -> bool {
+        self.fuse_plug_melted
+    }
+}
+impl SimulationElement for WheelBrakeTemperature {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.brake_temperature_id, self.temperature());
+        writer.write(&self.reported_temperature_id, self.reported_temperature());
+        writer.write(&self.fuse_plug_melted_id, self.fuse_plug_melted);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{ElementCtorFn, SimulationTestBed, TestAircraft, TestBed};
+    use crate::simulation::SimulationElementVisitor;
+    use std::time::Duration;
+
+    const FRAME: Duration = Duration::from_secs(1);
+
+    struct TestBrake {
+        brake: WheelBrakeTemperature,
+        braking_power: Power,
+        airflow_speed: Velocity,
+        brake_fan_is_on: bool,
+    }
+    impl TestBrake {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                brake: WheelBrakeTemperature::new(context, 1),
+                braking_power: Power::new::<watt>(0.),
+                airflow_speed: Velocity::new::<meter_per_second>(0.),
+                brake_fan_is_on: false,
+            }
+        }
+
+        fn set_braking_power(&mut self, braking_power: Power) {
+            self.braking_power = braking_power;
+        }
+
+        fn set_airflow_speed(&mut self, airflow_speed: Velocity) {
+            self.airflow_speed = airflow_speed;
+        }
+
+        fn set_brake_fan(&mut self, is_on: bool) {
+            self.brake_fan_is_on = is_on;
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            self.brake.update(
+                context,
+                self.braking_power,
+                self.airflow_speed,
+                self.brake_fan_is_on,
+            );
+        }
+
+        fn temperature(&self) -> f64 {
+            self.brake.temperature().get::<degree_celsius>()
+        }
+
+        fn reported_temperature(&self) -> f64 {
+            self.brake.reported_temperature().get::<degree_celsius>()
+        }
+
+        fn is_fuse_plug_melted(&self) -> bool {
+            self.brake.is_fuse_plug_melted()
+        }
+    }
+    impl SimulationElement for TestBrake {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.brake.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft<TestBrake>> {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(TestBrake::new))
+            .with_update_after_power_distribution(|brake, context| brake.update(context));
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+
+        test_bed
+    }
+
+    /// Dissipates given energy in the brake over the given duration
+    fn brake_with_energy(
+        test_bed: &mut SimulationTestBed<TestAircraft<TestBrake>>,
+        energy_megajoule: f64,
+        duration: Duration,
+    ) {
+        let power = Power::new::<watt>(energy_megajoule * 1e6 / duration.as_secs_f64());
+        test_bed.command_element(|e| e.set_braking_power(power));
+        test_bed.run_with_fixed_frames(duration, FRAME);
+        test_bed.command_element(|e| e.set_braking_power(Power::new::<watt>(0.)));
+    }
+
+    #[test]
+    fn starts_at_ambient_temperature() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(0));
+
+        assert!((test_bed.query_element(|e| e.temperature()) - 15.).abs() < 0.01);
+        assert!((test_bed.query_element(|e| e.reported_temperature()) - 15.).abs() < 0.01);
+        assert!(!test_bed.query_element(|e| e.is_fuse_plug_melted()));
+    }
+
+    #[test]
+    fn heats_up_when_braking() {
+        let mut test_bed = test_bed();
+
+        brake_with_energy(&mut test_bed, 10., Duration::from_secs(30));
+
+        let temperature = test_bed.query_element(|e| e.temperature());
+        assert!(temperature > 120. && temperature < 200.);
+        assert!(!test_bed.query_element(|e| e.is_fuse_plug_melted()));
+    }
+
+    #[test]
+    fn cools_down_slowly_on_ground() {
+        let mut test_bed = test_bed();
+        brake_with_energy(&mut test_bed, 20., Duration::from_secs(30));
+        let hot_temperature = test_bed.query_element(|e| e.temperature());
+
+        test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+        let temperature = test_bed.query_element(|e| e.temperature());
+
+        assert!(temperature < hot_temperature);
+        assert!(temperature > hot_temperature - 150.);
+    }
+
+    #[test]
+    fn cools_down_faster_with_airflow() {
+        let mut test_bed = test_bed();
+        brake_with_energy(&mut test_bed, 20., Duration::from_secs(30));
+        test_bed.command_element(|e| e.set_airflow_speed(Velocity::new::<meter_per_second>(80.)));
+
+        test_bed.run_with_fixed_frames(Duration::from_secs(1200), FRAME);
+
+        assert!(test_bed.query_element(|e| e.temperature()) < 100.);
+    }
+
+    #[test]
+    fn brake_fan_cools_probe_more_than_brake() {
+        let mut test_bed = test_bed();
+        brake_with_energy(&mut test_bed, 20., Duration::from_secs(30));
+
+        test_bed.command_element(|e| e.set_brake_fan(true));
+        test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+
+        let temperature = test_bed.query_element(|e| e.temperature());
+        let reported_temperature = test_bed.query_element(|e| e.reported_temperature());
+        assert!(reported_temperature < temperature - 20.);
+
+        test_bed.command_element(|e| e.set_brake_fan(false));
+        test_bed.run_with_fixed_frames(Duration::from_secs(1200), FRAME);
+
+        let temperature = test_bed.query_element(|e| e.temperature());
+        let reported_temperature = test_bed.query_element(|e| e.reported_temperature());
+        assert!((reported_temperature - temperature).abs() < 5.);
+    }
+
+    #[test]
+    fn brake_fan_cools_brake_faster() {
+        let mut test_bed_no_fan = test_bed();
+        let mut test_bed_fan = test_bed();
+        brake_with_energy(&mut test_bed_no_fan, 20., Duration::from_secs(30));
+        brake_with_energy(&mut test_bed_fan, 20., Duration::from_secs(30));
+
+        test_bed_fan.command_element(|e| e.set_brake_fan(true));
+        test_bed_no_fan.run_with_fixed_frames(Duration::from_secs(900), FRAME);
+        test_bed_fan.run_with_fixed_frames(Duration::from_secs(900), FRAME);
+
+        assert!(
+            test_bed_fan.query_element(|e| e.temperature())
+                < test_bed_no_fan.query_element(|e| e.temperature()) - 30.
+        );
+    }
+
+    #[test]
+    fn fuse_plug_melts_after_rejected_take_off() {
+        let mut test_bed = test_bed();
+        brake_with_energy(&mut test_bed, 50., Duration::from_secs(25));
+
+        assert!(test_bed.query_element(|e| e.temperature()) > 600.);
+        assert!(!test_bed.query_element(|e| e.is_fuse_plug_melted()));
+
+        test_bed.run_with_fixed_frames(Duration::from_secs(1800), FRAME);
+
+        assert!(test_bed.query_element(|e| e.is_fuse_plug_melted()));
+    }
+
+    #[test]
+    fn fuse_plug_does_not_melt_after_normal_landing() {
+        let mut test_bed = test_bed();
+        brake_with_energy(&mut test_bed, 10., Duration::from_secs(30));
+
+        test_bed.run_with_fixed_frames(Duration::from_secs(3600), FRAME);
+
+        assert!(!test_bed.query_element(|e| e.is_fuse_plug_melted()));
+    }
+
+    #[test]
+    fn writes_its_state() {
+        let mut test_bed = test_bed();
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.contains_variable_with_name("BRAKE_TEMPERATURE_1"));
+        assert!(test_bed.contains_variable_with_name("REPORTED_BRAKE_TEMPERATURE_1"));
+        assert!(test_bed.contains_variable_with_name("BRAKE_FUSE_PLUG_MELTED_1"));
+    }
+}
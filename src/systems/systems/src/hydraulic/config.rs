@@ -0,0 +1,500 @@
+#[cfg(feature = "config-files")]
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt::Display};
+#[cfg(feature = "config-files")]
+use std::{fs, path::Path};
+
+use uom::si::{
+    f64::*,
+    pressure::psi,
+    ratio::percent,
+    volume::{gallon, liter},
+};
+
+use super::{HydraulicCircuit, PressureSwitchType};
+
+/// Error raised when a hydraulic configuration cannot be parsed or describes a circuit
+/// the simulation cannot represent.
+#[derive(Debug, PartialEq)]
+pub enum HydraulicConfigError {
+    Read(String),
+    Parse(String),
+    Invalid(String),
+}
+impl Display for HydraulicConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read(message) => write!(f, "cannot read hydraulic configuration: {}", message),
+            Self::Parse(message) => write!(f, "cannot parse hydraulic configuration: {}", message),
+            Self::Invalid(message) => write!(f, "invalid hydraulic configuration: {}", message),
+        }
+    }
+}
+impl Error for HydraulicConfigError {}
+
+/// Parses a TOML document into a configuration. Validation is left to the caller, which knows
+/// what the whole aircraft expects.
+#[cfg(feature = "config-files")]
+pub fn parse_toml<T: for<'de> Deserialize<'de>>(content: &str) -> Result<T, HydraulicConfigError> {
+    toml::from_str(content).map_err(|error| HydraulicConfigError::Parse(error.to_string()))
+}
+
+#[cfg(feature = "config-files")]
+pub fn read_toml<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T, HydraulicConfigError> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| HydraulicConfigError::Read(format!("{}: {}", path.display(), error)))?;
+
+    parse_toml(&content)
+}
+
+/// Writes a configuration as a TOML document, which can be edited and loaded back.
+#[cfg(feature = "config-files")]
+pub fn to_toml<T: Serialize>(config: &T) -> Result<String, HydraulicConfigError> {
+    // Going through a value orders plain values before tables, as TOML requires.
+    toml::Value::try_from(config)
+        .map(|value| value.to_string())
+        .map_err(|error| HydraulicConfigError::Parse(error.to_string()))
+}
+
+fn invalid(message: String) -> Result<(), HydraulicConfigError> {
+    Err(HydraulicConfigError::Invalid(message))
+}
+
+fn validate_finite(name: &str, value: f64) -> Result<(), HydraulicConfigError> {
+    if !value.is_finite() {
+        return invalid(format!("{} {} must be a finite number", name, value));
+    }
+
+    Ok(())
+}
+
+/// Validation shared by every description of a hydraulic circuit, whatever its file layout.
+pub fn validate_circuit(
+    pump_sections: usize,
+    priming: Ratio,
+    high_pressure_volume: Volume,
+) -> Result<(), HydraulicConfigError> {
+    validate_finite("priming", priming.get::<percent>())?;
+    validate_finite("high pressure volume", high_pressure_volume.get::<gallon>())?;
+
+    if pump_sections == 0 {
+        return invalid("a circuit requires at least one pump section".to_owned());
+    }
+
+    if !(0. ..=100.).contains(&priming.get::<percent>()) {
+        return invalid(format!(
+            "priming {} % must be between 0 and 100 %",
+            priming.get::<percent>()
+        ));
+    }
+
+    let pump_sections_volume =
+        Volume::new::<gallon>(HydraulicCircuit::PUMP_SECTION_MAX_VOLUME_GAL) * pump_sections as f64;
+    if high_pressure_volume <= pump_sections_volume {
+        return invalid(format!(
+            "high pressure volume {} gal must exceed the {} gal of the pump sections",
+            high_pressure_volume.get::<gallon>(),
+            pump_sections_volume.get::<gallon>()
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_pressure_switch(
+    name: &str,
+    low: Pressure,
+    high: Pressure,
+) -> Result<(), HydraulicConfigError> {
+    validate_finite(&format!("{} low threshold", name), low.get::<psi>())?;
+    validate_finite(&format!("{} high threshold", name), high.get::<psi>())?;
+
+    if low >= high {
+        return invalid(format!(
+            "{} low threshold {} psi must be below its high threshold {} psi",
+            name,
+            low.get::<psi>(),
+            high.get::<psi>()
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_reservoir(
+    max_capacity: Volume,
+    max_gaugeable: Volume,
+    level: Volume,
+    low_level_threshold: Volume,
+) -> Result<(), HydraulicConfigError> {
+    validate_finite("reservoir capacity", max_capacity.get::<liter>())?;
+    validate_finite("reservoir gaugeable volume", max_gaugeable.get::<liter>())?;
+    validate_finite("reservoir level", level.get::<liter>())?;
+    validate_finite(
+        "reservoir low level threshold",
+        low_level_threshold.get::<liter>(),
+    )?;
+
+    if max_capacity <= Volume::new::<liter>(0.) {
+        return invalid("reservoir capacity must be positive".to_owned());
+    }
+
+    if max_gaugeable <= Volume::new::<liter>(0.) || max_gaugeable > max_capacity {
+        return invalid(format!(
+            "reservoir gaugeable volume {} l must be within its capacity {} l",
+            max_gaugeable.get::<liter>(),
+            max_capacity.get::<liter>()
+        ));
+    }
+
+    if level < Volume::new::<liter>(0.) || level > max_capacity {
+        return invalid(format!(
+            "reservoir level {} gal must be within its capacity {} gal",
+            level.get::<gallon>(),
+            max_capacity.get::<gallon>()
+        ));
+    }
+
+    if low_level_threshold < Volume::new::<liter>(0.) || low_level_threshold >= max_capacity {
+        return invalid(format!(
+            "reservoir low level threshold {} l must be below its capacity {} l",
+            low_level_threshold.get::<liter>(),
+            max_capacity.get::<liter>()
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn validate_accumulator(
+    gas_precharge: Pressure,
+    total_volume: Volume,
+    fluid_volume: Volume,
+) -> Result<(), HydraulicConfigError> {
+    validate_finite("accumulator precharge", gas_precharge.get::<psi>())?;
+    validate_finite("accumulator volume", total_volume.get::<gallon>())?;
+    validate_finite("accumulator fluid volume", fluid_volume.get::<gallon>())?;
+
+    if gas_precharge <= Pressure::new::<psi>(0.) || total_volume <= Volume::new::<gallon>(0.) {
+        return invalid("accumulator precharge and volume must be positive".to_owned());
+    }
+
+    if fluid_volume < Volume::new::<gallon>(0.) || fluid_volume > total_volume {
+        return invalid(format!(
+            "accumulator fluid volume {} gal must be within its volume {} gal",
+            fluid_volume.get::<gallon>(),
+            total_volume.get::<gallon>()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Hysteresis thresholds of a section pressure switch.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "config-files",
+    derive(Deserialize, Serialize),
+    serde(deny_unknown_fields)
+)]
+pub struct PressureSwitchHysteresisConfig {
+    pub low_psi: f64,
+    pub high_psi: f64,
+}
+impl PressureSwitchHysteresisConfig {
+    fn validate(&self, name: &str) -> Result<(), HydraulicConfigError> {
+        validate_pressure_switch(
+            name,
+            Pressure::new::<psi>(self.low_psi),
+            Pressure::new::<psi>(self.high_psi),
+        )
+    }
+}
+
+/// Reservoir air pressure switch.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "config-files",
+    derive(Deserialize, Serialize),
+    serde(deny_unknown_fields)
+)]
+pub struct AirPressureSwitchConfig {
+    pub low_psi: f64,
+    pub high_psi: f64,
+    pub kind: PressureSwitchType,
+}
+
+/// Reservoir sizes are given in liters as in the maintenance manual, its initial level in gallons.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "config-files",
+    derive(Deserialize, Serialize),
+    serde(deny_unknown_fields)
+)]
+pub struct ReservoirConfig {
+    pub max_capacity_liter: f64,
+    pub max_gaugeable_liter: f64,
+    pub level_gallon: f64,
+    pub low_level_threshold_liter: f64,
+    pub air_pressure_switches: Vec<AirPressureSwitchConfig>,
+    #[cfg_attr(feature = "config-files", serde(default))]
+    pub fuel_heat_exchanger: bool,
+}
+impl ReservoirConfig {
+    fn validate(&self) -> Result<(), HydraulicConfigError> {
+        validate_reservoir(
+            Volume::new::<liter>(self.max_capacity_liter),
+            Volume::new::<liter>(self.max_gaugeable_liter),
+            Volume::new::<gallon>(self.level_gallon),
+            Volume::new::<liter>(self.low_level_threshold_liter),
+        )?;
+
+        if self.air_pressure_switches.is_empty() {
+            return invalid("reservoir requires at least one air pressure switch".to_owned());
+        }
+
+        for switch in &self.air_pressure_switches {
+            validate_pressure_switch(
+                "reservoir air pressure switch",
+                Pressure::new::<psi>(switch.low_psi),
+                Pressure::new::<psi>(switch.high_psi),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// System section accumulator, precharged with nitrogen.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "config-files",
+    derive(Deserialize, Serialize),
+    serde(deny_unknown_fields)
+)]
+pub struct AccumulatorConfig {
+    pub gas_precharge_psi: f64,
+    pub total_volume_gallon: f64,
+    #[cfg_attr(feature = "config-files", serde(default))]
+    pub fluid_volume_gallon: f64,
+}
+impl AccumulatorConfig {
+    fn validate(&self) -> Result<(), HydraulicConfigError> {
+        validate_accumulator(
+            Pressure::new::<psi>(self.gas_precharge_psi),
+            Volume::new::<gallon>(self.total_volume_gallon),
+            Volume::new::<gallon>(self.fluid_volume_gallon),
+        )
+    }
+}
+
+/// Everything needed to build a [`HydraulicCircuit`] and its reservoir.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "config-files",
+    derive(Deserialize, Serialize),
+    serde(deny_unknown_fields)
+)]
+pub struct HydraulicCircuitConfig {
+    pub pump_sections: usize,
+    pub priming_percent: f64,
+    pub high_pressure_volume_gallon: f64,
+    pub system_pressure_switch: PressureSwitchHysteresisConfig,
+    pub pump_pressure_switch: PressureSwitchHysteresisConfig,
+    #[cfg_attr(feature = "config-files", serde(default))]
+    pub connected_to_ptu_left_side: bool,
+    #[cfg_attr(feature = "config-files", serde(default))]
+    pub connected_to_ptu_right_side: bool,
+    pub reservoir: ReservoirConfig,
+    pub accumulator: AccumulatorConfig,
+}
+impl HydraulicCircuitConfig {
+    pub fn validate(&self) -> Result<(), HydraulicConfigError> {
+        validate_circuit(
+            self.pump_sections,
+            Ratio::new::<percent>(self.priming_percent),
+            Volume::new::<gallon>(self.high_pressure_volume_gallon),
+        )?;
+
+        if self.connected_to_ptu_left_side && self.connected_to_ptu_right_side {
+            return invalid("a circuit cannot be connected to both sides of the PTU".to_owned());
+        }
+
+        self.system_pressure_switch
+            .validate("system section pressure switch")?;
+        self.pump_pressure_switch
+            .validate("pump section pressure switch")?;
+        self.reservoir.validate()?;
+        self.accumulator.validate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "config-files")]
+    const CIRCUIT: &str = r#"
+        pump_sections = 1
+        priming_percent = 100.0
+        high_pressure_volume_gallon = 10.0
+        system_pressure_switch = { low_psi = 1450.0, high_psi = 1750.0 }
+        pump_pressure_switch = { low_psi = 1740.0, high_psi = 2200.0 }
+        connected_to_ptu_left_side = true
+
+        [reservoir]
+        max_capacity_liter = 23.0
+        max_gaugeable_liter = 18.0
+        level_gallon = 3.6
+        low_level_threshold_liter = 3.0
+        air_pressure_switches = [{ low_psi = 22.0, high_psi = 25.0, kind = "relative" }]
+        fuel_heat_exchanger = true
+
+        [accumulator]
+        gas_precharge_psi = 1885.0
+        total_volume_gallon = 0.264
+    "#;
+
+    fn circuit_config() -> HydraulicCircuitConfig {
+        HydraulicCircuitConfig {
+            pump_sections: 1,
+            priming_percent: 100.,
+            high_pressure_volume_gallon: 10.,
+            system_pressure_switch: PressureSwitchHysteresisConfig {
+                low_psi: 1450.,
+                high_psi: 1750.,
+            },
+            pump_pressure_switch: PressureSwitchHysteresisConfig {
+                low_psi: 1740.,
+                high_psi: 2200.,
+            },
+            connected_to_ptu_left_side: true,
+            connected_to_ptu_right_side: false,
+            reservoir: ReservoirConfig {
+                max_capacity_liter: 23.,
+                max_gaugeable_liter: 18.,
+                level_gallon: 3.6,
+                low_level_threshold_liter: 3.,
+                air_pressure_switches: vec![AirPressureSwitchConfig {
+                    low_psi: 22.,
+                    high_psi: 25.,
+                    kind: PressureSwitchType::Relative,
+                }],
+                fuel_heat_exchanger: true,
+            },
+            accumulator: AccumulatorConfig {
+                gas_precharge_psi: 1885.,
+                total_volume_gallon: 0.264,
+                fluid_volume_gallon: 0.,
+            },
+        }
+    }
+
+    #[test]
+    fn circuit_config_is_valid() {
+        assert_eq!(circuit_config().validate(), Ok(()));
+    }
+
+    #[test]
+    #[cfg(feature = "config-files")]
+    fn parses_circuit_config() {
+        assert_eq!(parse_toml(CIRCUIT), Ok(circuit_config()));
+    }
+
+    #[test]
+    #[cfg(feature = "config-files")]
+    fn circuit_config_round_trips_through_toml() {
+        let content = to_toml(&circuit_config()).unwrap();
+
+        assert_eq!(parse_toml(&content), Ok(circuit_config()));
+    }
+
+    #[test]
+    #[cfg(feature = "config-files")]
+    fn unknown_field_is_a_parse_error() {
+        let content = CIRCUIT.replace("pump_sections", "pump_section");
+
+        assert!(matches!(
+            parse_toml::<HydraulicCircuitConfig>(&content),
+            Err(HydraulicConfigError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn reservoir_level_above_capacity_is_invalid() {
+        let mut config = circuit_config();
+        config.reservoir.level_gallon = 7.;
+
+        assert!(matches!(
+            config.validate(),
+            Err(HydraulicConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn accumulator_fluid_above_its_volume_is_invalid() {
+        let mut config = circuit_config();
+        config.accumulator.fluid_volume_gallon = 0.3;
+
+        assert!(matches!(
+            config.validate(),
+            Err(HydraulicConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn high_pressure_volume_smaller_than_pump_sections_is_invalid() {
+        let mut config = circuit_config();
+        config.pump_sections = 2;
+        config.high_pressure_volume_gallon = 1.5;
+
+        assert!(matches!(
+            config.validate(),
+            Err(HydraulicConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn inverted_pressure_switch_is_invalid() {
+        let mut config = circuit_config();
+        config.pump_pressure_switch.low_psi = 2500.;
+
+        assert!(matches!(
+            config.validate(),
+            Err(HydraulicConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn circuit_on_both_ptu_sides_is_invalid() {
+        let mut config = circuit_config();
+        config.connected_to_ptu_right_side = true;
+
+        assert!(matches!(
+            config.validate(),
+            Err(HydraulicConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn not_a_number_is_invalid() {
+        let mut config = circuit_config();
+        config.reservoir.level_gallon = f64::NAN;
+
+        assert!(matches!(
+            config.validate(),
+            Err(HydraulicConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn infinite_value_is_invalid() {
+        let mut config = circuit_config();
+        config.system_pressure_switch.high_psi = f64::INFINITY;
+
+        assert!(matches!(
+            config.validate(),
+            Err(HydraulicConfigError::Invalid(_))
+        ));
+    }
+}
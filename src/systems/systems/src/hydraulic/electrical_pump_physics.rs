@@ -6,11 +6,12 @@ use uom::si::{
     f64::*,
     power::watt,
     pressure::psi,
+    ratio::ratio,
     torque::{newton_meter, pound_force_inch},
     volume::cubic_inch,
 };
 
-use crate::hydraulic::SectionPressure;
+use crate::hydraulic::{Fluid, SectionPressure};
 use crate::shared::{pid::PidController, ConsumePower, ElectricalBusType, ElectricalBuses};
 use crate::simulation::{
     InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
@@ -33,6 +34,7 @@ pub(super) struct ElectricalPumpPhysics {
 
     generated_torque: Torque,
     resistant_torque: Torque,
+    dynamic_friction_torque: Torque,
 
     current_controller: PidController,
 }
@@ -71,6 +73,7 @@ impl ElectricalPumpPhysics {
             output_current: ElectricCurrent::new::<ampere>(0.),
             generated_torque: Torque::new::<newton_meter>(0.),
             resistant_torque: Torque::new::<newton_meter>(0.),
+            dynamic_friction_torque: Torque::new::<newton_meter>(0.),
             current_controller: PidController::new(
                 Self::DEFAULT_P_GAIN,
                 Self::DEFAULT_I_GAIN,
@@ -88,8 +91,9 @@ impl ElectricalPumpPhysics {
         context: &UpdateContext,
         section: &impl SectionPressure,
         current_displacement: Volume,
+        fluid_temperature: ThermodynamicTemperature,
     ) {
-        self.update_pump_resistant_torque(section, current_displacement);
+        self.update_pump_resistant_torque(section, current_displacement, fluid_temperature);
         self.update_pump_generated_torque(context);
         self.update_pump_speed(context);
     }
@@ -112,9 +116,15 @@ impl ElectricalPumpPhysics {
         &mut self,
         section: &impl SectionPressure,
         current_displacement: Volume,
+        fluid_temperature: ThermodynamicTemperature,
     ) {
-        let dynamic_friction_torque = Torque::new::<newton_meter>(
-            Self::DEFAULT_DYNAMIC_FRICTION_CONSTANT * self.speed.get::<revolution_per_minute>(),
+        // Viscous drag in the pump grows with fluid viscosity, so a cold pump needs more torque
+        self.dynamic_friction_torque = Torque::new::<newton_meter>(
+            Self::DEFAULT_DYNAMIC_FRICTION_CONSTANT
+                * self.speed.get::<revolution_per_minute>()
+                * Fluid::viscosity_ratio(fluid_temperature)
+                    .get::<ratio>()
+                    .sqrt(),
         );
 
         let pumping_torque = if self.is_active && self.is_powered {
@@ -130,7 +140,7 @@ impl ElectricalPumpPhysics {
             Torque::new::<newton_meter>(Self::DEFAULT_RESISTANT_TORQUE_WHEN_OFF_NEWTON_METER)
         };
 
-        self.resistant_torque = pumping_torque + dynamic_friction_torque;
+        self.resistant_torque = pumping_torque + self.dynamic_friction_torque;
     }
 
     fn update_current_control(&mut self, context: &UpdateContext) {
@@ -190,6 +200,12 @@ impl ElectricalPumpPhysics {
     pub fn speed(&self) -> AngularVelocity {
         self.speed
     }
+
+    /// Heat dissipated into the fluid by the electrical losses of the motor.
+    /// Pump mechanical losses are accounted by the pump itself.
+    pub fn heat_generated(&self) -> Power {
+        self.consumed_power * (1. - Self::ELECTRICAL_EFFICIENCY)
+    }
 }
 impl SimulationElement for ElectricalPumpPhysics {
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -219,7 +235,7 @@ mod tests {
 
     use crate::simulation::test::{SimulationTestBed, TestBed};
     use std::time::Duration;
-    use uom::si::{pressure::psi, volume::gallon};
+    use uom::si::{pressure::psi, thermodynamic_temperature::degree_celsius, volume::gallon};
 
     struct TestHydraulicSection {
         current_pressure: Pressure,
@@ -250,6 +266,7 @@ mod tests {
         pump: ElectricalPumpPhysics,
         hydraulic_section: TestHydraulicSection,
         current_displacement: Volume,
+        fluid_temperature: ThermodynamicTemperature,
 
         powered_source_ac: TestElectricitySource,
         ac_1_bus: ElectricalBus,
@@ -262,6 +279,7 @@ mod tests {
                 pump: physical_pump(context),
                 hydraulic_section: TestHydraulicSection::new(),
                 current_displacement: Volume::new::<gallon>(0.),
+                fluid_temperature: ThermodynamicTemperature::new::<degree_celsius>(38.),
                 powered_source_ac: TestElectricitySource::powered(
                     context,
                     PotentialOrigin::EngineGenerator(1),
@@ -279,6 +297,10 @@ mod tests {
             self.current_displacement = current_displacement;
         }
 
+        fn set_fluid_temperature(&mut self, fluid_temperature: ThermodynamicTemperature) {
+            self.fluid_temperature = fluid_temperature;
+        }
+
         fn set_ac_1_power(&mut self, is_powered: bool) {
             self.is_ac_1_powered = is_powered;
         }
@@ -306,6 +328,7 @@ mod tests {
                     &context.with_delta(cur_time_step),
                     &self.hydraulic_section,
                     self.current_displacement,
+                    self.fluid_temperature,
                 );
             }
         }
@@ -412,6 +435,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pump_consumes_more_power_with_cold_fluid() {
+        let warm_test_bed =
+            test_bed_with_running_pump(ThermodynamicTemperature::new::<degree_celsius>(38.));
+        let cold_test_bed =
+            test_bed_with_running_pump(ThermodynamicTemperature::new::<degree_celsius>(-40.));
+
+        assert!(
+            cold_test_bed.query(|a| a.pump.consumed_power)
+                > warm_test_bed.query(|a| a.pump.consumed_power)
+        );
+        assert!(
+            cold_test_bed.query(|a| a.pump.heat_generated())
+                > warm_test_bed.query(|a| a.pump.heat_generated())
+        );
+    }
+
+    fn test_bed_with_running_pump(
+        fluid_temperature: ThermodynamicTemperature,
+    ) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+
+        test_bed.command(|a| a.set_fluid_temperature(fluid_temperature));
+        test_bed.command(|a| a.set_ac_1_power(true));
+        test_bed.command(|a| a.pump.set_active(true));
+        test_bed.command(|a| a.set_current_displacement(Volume::new::<cubic_inch>(0.131)));
+        test_bed.command(|a| a.set_current_pressure(Pressure::new::<psi>(3000.)));
+
+        test_bed.run_with_delta(Duration::from_secs_f64(2.));
+
+        test_bed
+    }
+
     fn physical_pump(context: &mut InitContext) -> ElectricalPumpPhysics {
         ElectricalPumpPhysics::new(
             context,
@@ -1,7 +1,12 @@
 use super::linear_actuator::Actuator;
-use crate::shared::{interpolation, low_pass_filter::LowPassFilter, FeedbackPositionPickoffUnit};
+use crate::failures::{Failure, FailureType};
+use crate::shared::{
+    interpolation, low_pass_filter::LowPassFilter, AsymmetryPositionPickoffUnit,
+    FeedbackPositionPickoffUnit,
+};
 use crate::simulation::{
-    InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+    VariableIdentifier, Write,
 };
 
 use uom::si::{
@@ -107,14 +112,28 @@ impl Actuator for FlapSlatHydraulicMotor {
     }
 }
 
+/// Flap or slat transmission driven by a Power Control Unit (PCU) made of two hydraulic motors.
+///
+/// The PCU drives the left and right surfaces through a torque shaft. Wing Tip Brakes (WTB) at the
+/// end of the shaft can lock the whole transmission, in which case the PCU stalls.
+/// A transmission failure on one side disconnects that side's surfaces from the torque shaft:
+/// as the surface actuators are irreversible, the disconnected side stays where it was while the other
+/// side keeps following the PCU.
 pub struct FlapSlatAssembly {
     position_left_percent_id: VariableIdentifier,
     position_right_percent_id: VariableIdentifier,
     angle_left_id: VariableIdentifier,
     angle_right_id: VariableIdentifier,
     is_moving_id: VariableIdentifier,
+    wing_tip_brakes_engaged_id: VariableIdentifier,
 
     surface_control_arm_position: Angle,
+    left_surface_control_arm_position: Angle,
+    right_surface_control_arm_position: Angle,
+
+    wing_tip_brakes_engaged: bool,
+    left_transmission_failure: Failure,
+    right_transmission_failure: Failure,
 
     max_synchro_gear_position: Angle,
     final_requested_synchro_gear_position: Angle,
@@ -145,6 +164,8 @@ impl FlapSlatAssembly {
     pub fn new(
         context: &mut InitContext,
         id: &str,
+        left_transmission_failure: FailureType,
+        right_transmission_failure: FailureType,
         motor_displacement: Volume,
         full_pressure_max_speed: AngularVelocity,
         max_synchro_gear_position: Angle,
@@ -164,8 +185,16 @@ impl FlapSlatAssembly {
             angle_right_id: context.get_identifier(format!("RIGHT_{}_ANGLE", id)),
 
             is_moving_id: context.get_identifier(format!("IS_{}_MOVING", id)),
+            wing_tip_brakes_engaged_id: context
+                .get_identifier(format!("{}_WING_TIP_BRAKES_ENGAGED", id)),
 
             surface_control_arm_position: Angle::new::<radian>(0.),
+            left_surface_control_arm_position: Angle::new::<radian>(0.),
+            right_surface_control_arm_position: Angle::new::<radian>(0.),
+
+            wing_tip_brakes_engaged: false,
+            left_transmission_failure: Failure::new(left_transmission_failure),
+            right_transmission_failure: Failure::new(right_transmission_failure),
             max_synchro_gear_position,
             final_requested_synchro_gear_position: Angle::new::<radian>(0.),
             speed: AngularVelocity::new::<radian_per_second>(0.),
@@ -192,9 +221,12 @@ impl FlapSlatAssembly {
         context: &UpdateContext,
         sfcc1_surface_position_request: Option<Angle>,
         sfcc2_surface_position_request: Option<Angle>,
+        wing_tip_brakes_engaged: bool,
         left_pressure: Pressure,
         right_pressure: Pressure,
     ) {
+        self.wing_tip_brakes_engaged = wing_tip_brakes_engaged;
+
         self.update_final_ffpu_angle_request(
             sfcc1_surface_position_request,
             sfcc2_surface_position_request,
@@ -210,6 +242,8 @@ impl FlapSlatAssembly {
 
         self.update_speed_and_position(context);
 
+        self.update_wing_surfaces_position();
+
         self.update_motors_speed(left_pressure, right_pressure, context);
 
         self.update_motors_flow(context);
@@ -245,6 +279,16 @@ impl FlapSlatAssembly {
             .min(self.synchro_angle_to_surface_angle(self.max_synchro_gear_position));
     }
 
+    fn update_wing_surfaces_position(&mut self) {
+        if !self.left_transmission_failure.is_active() {
+            self.left_surface_control_arm_position = self.surface_control_arm_position;
+        }
+
+        if !self.right_transmission_failure.is_active() {
+            self.right_surface_control_arm_position = self.surface_control_arm_position;
+        }
+    }
+
     fn update_final_ffpu_angle_request(
         &mut self,
         sfcc1_angle_request: Option<Angle>,
@@ -296,9 +340,14 @@ impl FlapSlatAssembly {
             new_theoretical_max_speed *= Self::ANGULAR_SPEED_LIMIT_FACTOR_WHEN_APROACHING_POSITION;
         }
 
-        // Final max speed filtered to simulate smooth movements
-        self.current_max_speed
-            .update(context.delta(), new_theoretical_max_speed);
+        // Wing tip brakes lock the transmission instantly whatever the motors are doing
+        if self.wing_tip_brakes_engaged {
+            self.current_max_speed.reset(AngularVelocity::default());
+        } else {
+            // Final max speed filtered to simulate smooth movements
+            self.current_max_speed
+                .update(context.delta(), new_theoretical_max_speed);
+        }
     }
 
     fn max_speed_factor_from_pressure(current_pressure: Pressure) -> f64 {
@@ -385,6 +434,18 @@ impl FlapSlatAssembly {
         self.surface_control_arm_position * self.surface_to_synchro_gear_ratio.get::<ratio>()
     }
 
+    pub fn left_position_feedback(&self) -> Angle {
+        self.left_surface_control_arm_position * self.surface_to_synchro_gear_ratio.get::<ratio>()
+    }
+
+    pub fn right_position_feedback(&self) -> Angle {
+        self.right_surface_control_arm_position * self.surface_to_synchro_gear_ratio.get::<ratio>()
+    }
+
+    pub fn wing_tip_brakes_engaged(&self) -> bool {
+        self.wing_tip_brakes_engaged
+    }
+
     pub fn left_motor(&mut self) -> &mut impl Actuator {
         &mut self.left_motor
     }
@@ -406,11 +467,16 @@ impl FlapSlatAssembly {
     }
 
     /// Gets flap surface angle from current Feedback Position Pickup Unit (FPPU) position
+    #[cfg(test)]
     fn flap_surface_angle(&self) -> Angle {
+        self.surface_angle_from_feedback_angle(self.position_feedback())
+    }
+
+    fn surface_angle_from_feedback_angle(&self, feedback_angle: Angle) -> Angle {
         Angle::new::<degree>(interpolation(
             &self.synchro_gear_breakpoints,
             &self.final_surface_angle_carac,
-            self.position_feedback().get::<degree>(),
+            feedback_angle.get::<degree>(),
         ))
     }
 
@@ -438,25 +504,43 @@ impl FlapSlatAssembly {
     }
 }
 impl SimulationElement for FlapSlatAssembly {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.left_transmission_failure.accept(visitor);
+        self.right_transmission_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(
             &self.position_left_percent_id,
-            self.position_feedback().get::<degree>()
+            self.left_position_feedback().get::<degree>()
                 / self.max_synchro_gear_position.get::<degree>()
                 * 100.,
         );
         writer.write(
             &self.position_right_percent_id,
-            self.position_feedback().get::<degree>()
+            self.right_position_feedback().get::<degree>()
                 / self.max_synchro_gear_position.get::<degree>()
                 * 100.,
         );
 
-        let flaps_surface_angle = self.flap_surface_angle();
-        writer.write(&self.angle_left_id, flaps_surface_angle.get::<degree>());
-        writer.write(&self.angle_right_id, flaps_surface_angle.get::<degree>());
+        writer.write(
+            &self.angle_left_id,
+            self.surface_angle_from_feedback_angle(self.left_position_feedback())
+                .get::<degree>(),
+        );
+        writer.write(
+            &self.angle_right_id,
+            self.surface_angle_from_feedback_angle(self.right_position_feedback())
+                .get::<degree>(),
+        );
 
         writer.write(&self.is_moving_id, self.is_surface_moving());
+        writer.write(
+            &self.wing_tip_brakes_engaged_id,
+            self.wing_tip_brakes_engaged,
+        );
     }
 }
 impl FeedbackPositionPickoffUnit for FlapSlatAssembly {
@@ -464,6 +548,15 @@ impl FeedbackPositionPickoffUnit for FlapSlatAssembly {
         self.position_feedback()
     }
 }
+impl AsymmetryPositionPickoffUnit for FlapSlatAssembly {
+    fn left_angle(&self) -> Angle {
+        self.left_position_feedback()
+    }
+
+    fn right_angle(&self) -> Angle {
+        self.right_position_feedback()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -487,6 +580,7 @@ mod tests {
 
         left_motor_angle_request: Option<Angle>,
         right_motor_angle_request: Option<Angle>,
+        wing_tip_brakes_engaged: bool,
 
         left_motor_pressure: Pressure,
         right_motor_pressure: Pressure,
@@ -498,6 +592,7 @@ mod tests {
                 flaps_slats: flap_system(context, max_speed),
                 left_motor_angle_request: None,
                 right_motor_angle_request: None,
+                wing_tip_brakes_engaged: false,
                 left_motor_pressure: Pressure::new::<psi>(0.),
                 right_motor_pressure: Pressure::new::<psi>(0.),
             }
@@ -525,6 +620,10 @@ mod tests {
             self.left_motor_angle_request = angle_request_sfcc1;
             self.right_motor_angle_request = angle_request_sfcc2;
         }
+
+        fn set_wing_tip_brakes(&mut self, engaged: bool) {
+            self.wing_tip_brakes_engaged = engaged;
+        }
     }
     impl Aircraft for TestAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
@@ -535,6 +634,7 @@ mod tests {
                     &context.with_delta(cur_time_step),
                     self.left_motor_angle_request,
                     self.right_motor_angle_request,
+                    self.wing_tip_brakes_engaged,
                     self.left_motor_pressure,
                     self.right_motor_pressure,
                 );
@@ -1011,10 +1111,127 @@ mod tests {
         }
     }
 
+    #[test]
+    fn flap_slat_assembly_wing_tip_brakes_lock_transmission() {
+        let max_speed = AngularVelocity::new::<radian_per_second>(0.11);
+        let mut test_bed = SimulationTestBed::new(|context| TestAircraft::new(context, max_speed));
+
+        test_bed.command(|a| a.set_angle_request(Some(Angle::new::<degree>(40.))));
+        test_bed.command(|a| {
+            a.set_current_pressure(
+                Pressure::new::<psi>(FlapSlatAssembly::MAX_CIRCUIT_PRESSURE_PSI),
+                Pressure::new::<psi>(FlapSlatAssembly::MAX_CIRCUIT_PRESSURE_PSI),
+            )
+        });
+
+        test_bed.run_multiple_frames(Duration::from_millis(5000));
+        assert!(test_bed.query(|a| a.flaps_slats.is_surface_moving()));
+
+        test_bed.command(|a| a.set_wing_tip_brakes(true));
+        test_bed.run_with_delta(Duration::from_millis(33));
+
+        let locked_position = test_bed.query(|a| a.flaps_slats.position_feedback());
+        assert!(test_bed.query(|a| a.flaps_slats.wing_tip_brakes_engaged()));
+        assert!(test_bed.query(|a| a.flaps_slats.speed) == AngularVelocity::default());
+
+        test_bed.run_multiple_frames(Duration::from_millis(5000));
+
+        assert!(test_bed.query(|a| a.flaps_slats.position_feedback()) == locked_position);
+        assert!(!test_bed.query(|a| a.flaps_slats.is_surface_moving()));
+        assert!(
+            test_bed.query(|a| a.flaps_slats.left_motor.flow())
+                <= VolumeRate::new::<gallon_per_minute>(0.01)
+        );
+        assert!(
+            test_bed.query(|a| a.flaps_slats.right_motor.flow())
+                <= VolumeRate::new::<gallon_per_minute>(0.01)
+        );
+    }
+
+    #[test]
+    fn flap_slat_assembly_moves_again_when_wing_tip_brakes_released() {
+        let max_speed = AngularVelocity::new::<radian_per_second>(0.11);
+        let mut test_bed = SimulationTestBed::new(|context| TestAircraft::new(context, max_speed));
+
+        test_bed.command(|a| a.set_angle_request(Some(Angle::new::<degree>(40.))));
+        test_bed.command(|a| a.set_wing_tip_brakes(true));
+        test_bed.command(|a| {
+            a.set_current_pressure(
+                Pressure::new::<psi>(FlapSlatAssembly::MAX_CIRCUIT_PRESSURE_PSI),
+                Pressure::new::<psi>(FlapSlatAssembly::MAX_CIRCUIT_PRESSURE_PSI),
+            )
+        });
+
+        test_bed.run_multiple_frames(Duration::from_millis(5000));
+        assert!(test_bed.query(|a| a.flaps_slats.position_feedback()) == Angle::default());
+
+        test_bed.command(|a| a.set_wing_tip_brakes(false));
+        test_bed.run_multiple_frames(Duration::from_millis(5000));
+        assert!(test_bed.query(|a| a.flaps_slats.position_feedback()) > Angle::default());
+    }
+
+    #[test]
+    fn flap_slat_assembly_left_transmission_failure_freezes_left_surfaces_only() {
+        let max_speed = AngularVelocity::new::<radian_per_second>(0.11);
+        let mut test_bed = SimulationTestBed::new(|context| TestAircraft::new(context, max_speed));
+
+        test_bed.command(|a| {
+            a.set_current_pressure(
+                Pressure::new::<psi>(FlapSlatAssembly::MAX_CIRCUIT_PRESSURE_PSI),
+                Pressure::new::<psi>(FlapSlatAssembly::MAX_CIRCUIT_PRESSURE_PSI),
+            )
+        });
+        test_bed.command(|a| a.set_angle_request(Some(Angle::new::<degree>(10.))));
+        test_bed.run_multiple_frames(Duration::from_millis(20000));
+
+        let left_position_before_failure =
+            test_bed.query(|a| a.flaps_slats.left_position_feedback());
+        assert!(left_position_before_failure > Angle::default());
+
+        test_bed.fail(FailureType::FlapTransmission(1));
+        test_bed.command(|a| a.set_angle_request(Some(Angle::new::<degree>(40.))));
+        test_bed.run_multiple_frames(Duration::from_millis(20000));
+
+        assert!(
+            test_bed.query(|a| a.flaps_slats.left_position_feedback())
+                == left_position_before_failure
+        );
+        assert!(
+            test_bed.query(|a| a.flaps_slats.right_position_feedback())
+                == test_bed.query(|a| a.flaps_slats.position_feedback())
+        );
+        assert!(
+            (test_bed.query(|a| a.flaps_slats.right_angle())
+                - test_bed.query(|a| a.flaps_slats.left_angle()))
+                > Angle::new::<degree>(100.)
+        );
+    }
+
+    #[test]
+    fn flap_slat_assembly_right_transmission_failure_freezes_right_surfaces_only() {
+        let max_speed = AngularVelocity::new::<radian_per_second>(0.11);
+        let mut test_bed = SimulationTestBed::new(|context| TestAircraft::new(context, max_speed));
+
+        test_bed.command(|a| {
+            a.set_current_pressure(
+                Pressure::new::<psi>(FlapSlatAssembly::MAX_CIRCUIT_PRESSURE_PSI),
+                Pressure::new::<psi>(FlapSlatAssembly::MAX_CIRCUIT_PRESSURE_PSI),
+            )
+        });
+        test_bed.fail(FailureType::FlapTransmission(2));
+        test_bed.command(|a| a.set_angle_request(Some(Angle::new::<degree>(40.))));
+        test_bed.run_multiple_frames(Duration::from_millis(20000));
+
+        assert!(test_bed.query(|a| a.flaps_slats.right_position_feedback()) == Angle::default());
+        assert!(test_bed.query(|a| a.flaps_slats.left_position_feedback()) > Angle::default());
+    }
+
     fn flap_system(context: &mut InitContext, max_speed: AngularVelocity) -> FlapSlatAssembly {
         FlapSlatAssembly::new(
             context,
             "FLAPS",
+            FailureType::FlapTransmission(1),
+            FailureType::FlapTransmission(2),
             Volume::new::<cubic_inch>(0.32),
             max_speed,
             Angle::new::<degree>(251.97),
@@ -0,0 +1,252 @@
+use std::time::Duration;
+
+use uom::si::{f64::*, power::watt, thermodynamic_temperature::degree_celsius, volume::liter};
+
+use crate::{shared::low_pass_filter::LowPassFilter, simulation::UpdateContext};
+
+/// Lumped thermal model of the fluid of a hydraulic circuit.
+///
+/// All fluid of the circuit is considered to be at the same temperature, which is the
+/// temperature measured in the reservoir. Heat is received from pump losses, PTU losses and
+/// throttling through actuators and internal leaks. Heat is lost to the ambient air and,
+/// when equipped, to the fuel through the fuel heat exchanger in the return line.
+pub(super) struct FluidTemperature {
+    temperature: ThermodynamicTemperature,
+    is_initialised: bool,
+    fuel_heat_exchanger: Option<FuelHeatExchanger>,
+    is_overheat: bool,
+}
+impl FluidTemperature {
+    const FLUID_DENSITY_KILOGRAM_PER_LITER: f64 = 1.;
+    const FLUID_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN: f64 = 1750.;
+
+    // Pipes, pump bodies and the reservoir itself are at fluid temperature
+    // and thus add to the heat capacity of the circuit.
+    const CIRCUIT_STRUCTURE_HEAT_CAPACITY_JOULE_PER_KELVIN: f64 = 20000.;
+
+    // Heat lost through the pipes and components of the circuit to the surrounding air
+    const AMBIENT_HEAT_TRANSFER_WATT_PER_KELVIN: f64 = 80.;
+
+    const OVERHEAT_THRESHOLD_DEGREE_CELSIUS: f64 = 95.;
+    const OVERHEAT_RESET_DEGREE_CELSIUS: f64 = 85.;
+
+    pub(super) fn new() -> Self {
+        Self {
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(15.),
+            is_initialised: false,
+            fuel_heat_exchanger: None,
+            is_overheat: false,
+        }
+    }
+
+    pub(super) fn with_fuel_heat_exchanger(mut self) -> Self {
+        self.fuel_heat_exchanger = Some(FuelHeatExchanger::new());
+        self
+    }
+
+    /// Updates the fluid temperature given the heat received by the fluid
+    /// and the total volume of fluid in the circuit.
+    pub(super) fn update(
+        &mut self,
+        context: &UpdateContext,
+        heat_input: Power,
+        fluid_volume: Volume,
+    ) {
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        if !self.is_initialised {
+            // The aircraft is assumed to be cold soaked at ambient temperature.
+            self.temperature = context.ambient_temperature();
+            self.is_initialised = true;
+        }
+
+        let temperature = self.temperature.get::<degree_celsius>();
+        let mut heat_loss = Self::AMBIENT_HEAT_TRANSFER_WATT_PER_KELVIN * (temperature - ambient);
+        if let Some(heat_exchanger) = &mut self.fuel_heat_exchanger {
+            heat_exchanger.update(context);
+            heat_loss += heat_exchanger.heat_transfer(temperature);
+        }
+
+        let heat_capacity = fluid_volume.get::<liter>()
+            * Self::FLUID_DENSITY_KILOGRAM_PER_LITER
+            * Self::FLUID_SPECIFIC_HEAT_JOULE_PER_KILOGRAM_KELVIN
+            + Self::CIRCUIT_STRUCTURE_HEAT_CAPACITY_JOULE_PER_KELVIN;
+
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            temperature
+                + (heat_input.get::<watt>() - heat_loss) * context.delta_as_secs_f64()
+                    / heat_capacity,
+        );
+
+        self.update_overheat();
+    }
+
+    fn update_overheat(&mut self) {
+        let temperature = self.temperature.get::<degree_celsius>();
+        if temperature >= Self::OVERHEAT_THRESHOLD_DEGREE_CELSIUS {
+            self.is_overheat = true;
+        } else if temperature <= Self::OVERHEAT_RESET_DEGREE_CELSIUS {
+            self.is_overheat = false;
+        }
+    }
+
+    pub(super) fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    pub(super) fn is_overheat(&self) -> bool {
+        self.is_overheat
+    }
+}
+
+/// Heat exchanger submerged in a wing fuel tank, cooling the return fluid.
+/// The fuel temperature slowly follows the ambient temperature.
+struct FuelHeatExchanger {
+    fuel_temperature: LowPassFilter<f64>,
+    is_initialised: bool,
+}
+impl FuelHeatExchanger {
+    const HEAT_TRANSFER_WATT_PER_KELVIN: f64 = 120.;
+    const FUEL_TEMPERATURE_TIME_CONSTANT: Duration = Duration::from_secs(3600);
+
+    fn new() -> Self {
+        Self {
+            fuel_temperature: LowPassFilter::new(Self::FUEL_TEMPERATURE_TIME_CONSTANT),
+            is_initialised: false,
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        if !self.is_initialised {
+            self.fuel_temperature.reset(ambient);
+            self.is_initialised = true;
+        }
+
+        self.fuel_temperature.update(context.delta(), ambient);
+    }
+
+    fn heat_transfer(&self, fluid_temperature_degree_celsius: f64) -> f64 {
+        Self::HEAT_TRANSFER_WATT_PER_KELVIN
+            * (fluid_temperature_degree_celsius - self.fuel_temperature.output())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::test::{SimulationTestBed, TestAircraft, TestBed};
+    use crate::simulation::SimulationElement;
+    use uom::si::volume::gallon;
+
+    const FRAME: Duration = Duration::from_secs(1);
+
+    struct TestFluid {
+        fluid_temperature: FluidTemperature,
+        heat_input: Power,
+    }
+    impl TestFluid {
+        fn new(fluid_temperature: FluidTemperature) -> Self {
+            Self {
+                fluid_temperature,
+                heat_input: Power::new::<watt>(0.),
+            }
+        }
+
+        fn set_heat_input(&mut self, heat_input: Power) {
+            self.heat_input = heat_input;
+        }
+
+        fn update(&mut self, context: &UpdateContext) {
+            self.fluid_temperature
+                .update(context, self.heat_input, Volume::new::<gallon>(12.));
+        }
+
+        fn temperature(&self) -> f64 {
+            self.fluid_temperature.temperature().get::<degree_celsius>()
+        }
+
+        fn is_overheat(&self) -> bool {
+            self.fluid_temperature.is_overheat()
+        }
+    }
+    impl SimulationElement for TestFluid {}
+
+    fn test_bed(fluid_temperature: FluidTemperature) -> SimulationTestBed<TestAircraft<TestFluid>> {
+        let mut test_bed = SimulationTestBed::from(TestFluid::new(fluid_temperature))
+            .with_update_after_power_distribution(|fluid, context| fluid.update(context));
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+
+        test_bed
+    }
+
+    #[test]
+    fn starts_at_ambient_temperature() {
+        let mut test_bed = test_bed(FluidTemperature::new());
+        test_bed.run_with_delta(Duration::from_secs(0));
+
+        assert!((test_bed.query_element(|e| e.temperature()) - 15.).abs() < 0.01);
+    }
+
+    #[test]
+    fn heats_up_when_receiving_heat() {
+        let mut test_bed = test_bed(FluidTemperature::new());
+        test_bed.command_element(|e| e.set_heat_input(Power::new::<watt>(3000.)));
+
+        test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+
+        assert!(test_bed.query_element(|e| e.temperature()) > 25.);
+    }
+
+    #[test]
+    fn cools_down_towards_ambient_temperature_without_heat() {
+        let mut test_bed = test_bed(FluidTemperature::new());
+        test_bed.command_element(|e| e.set_heat_input(Power::new::<watt>(3000.)));
+        test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+        let heated_temperature = test_bed.query_element(|e| e.temperature());
+
+        test_bed.command_element(|e| e.set_heat_input(Power::new::<watt>(0.)));
+        test_bed.run_with_fixed_frames(Duration::from_secs(600), FRAME);
+
+        let temperature = test_bed.query_element(|e| e.temperature());
+        assert!(temperature < heated_temperature);
+        assert!(temperature > 15.);
+    }
+
+    #[test]
+    fn fuel_heat_exchanger_limits_temperature() {
+        let mut without_exchanger = test_bed(FluidTemperature::new());
+        let mut with_exchanger = test_bed(FluidTemperature::new().with_fuel_heat_exchanger());
+        without_exchanger.command_element(|e| e.set_heat_input(Power::new::<watt>(3000.)));
+        with_exchanger.command_element(|e| e.set_heat_input(Power::new::<watt>(3000.)));
+
+        without_exchanger.run_with_fixed_frames(Duration::from_secs(1200), FRAME);
+        with_exchanger.run_with_fixed_frames(Duration::from_secs(1200), FRAME);
+
+        assert!(
+            with_exchanger.query_element(|e| e.temperature())
+                < without_exchanger.query_element(|e| e.temperature()) - 5.
+        );
+    }
+
+    #[test]
+    fn overheats_above_threshold_and_resets_below_reset_threshold() {
+        let mut test_bed = test_bed(FluidTemperature::new());
+        test_bed.command_element(|e| e.set_heat_input(Power::new::<watt>(10000.)));
+
+        while test_bed.query_element(|e| e.temperature()) < 96. {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+        assert!(test_bed.query_element(|e| e.is_overheat()));
+
+        test_bed.command_element(|e| e.set_heat_input(Power::new::<watt>(0.)));
+        while test_bed.query_element(|e| e.temperature()) > 90. {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+        assert!(test_bed.query_element(|e| e.is_overheat()));
+
+        while test_bed.query_element(|e| e.temperature()) > 84. {
+            test_bed.run_with_delta(Duration::from_secs(1));
+        }
+        assert!(!test_bed.query_element(|e| e.is_overheat()));
+    }
+}
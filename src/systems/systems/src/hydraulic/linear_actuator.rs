@@ -902,7 +902,7 @@ impl LinearActuatedRigidBodyOnHingeAxis {
         if self.actuator_extension_gives_positive_angle() {
             self.lock_position_request.get::<ratio>() * self.total_travel + self.min_angle
         } else {
-            self.lock_position_request.get::<ratio>() * self.total_travel + self.max_angle
+            self.max_angle - self.lock_position_request.get::<ratio>() * self.total_travel
         }
     }
 
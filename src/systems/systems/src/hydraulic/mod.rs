@@ -1,3 +1,5 @@
+use self::config::{HydraulicCircuitConfig, ReservoirConfig};
+use self::fluid_temperature::FluidTemperature;
 use self::linear_actuator::Actuator;
 use crate::failures::{Failure, FailureType};
 use crate::hydraulic::electrical_pump_physics::ElectricalPumpPhysics;
@@ -7,26 +9,35 @@ use crate::shared::{
     HydraulicColor,
 };
 use crate::simulation::{
-    InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
-    VariableIdentifier, Write,
+    InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
 };
 
+#[cfg(feature = "config-files")]
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use uom::si::{
     angular_velocity::{radian_per_second, revolution_per_minute},
     f64::*,
+    power::watt,
     pressure::{pascal, psi},
-    ratio::ratio,
+    ratio::{percent, ratio},
+    thermodynamic_temperature::degree_celsius,
+    time::second,
     torque::newton_meter,
     velocity::knot,
-    volume::{cubic_inch, cubic_meter, gallon},
-    volume_rate::{gallon_per_minute, gallon_per_second},
+    volume::{cubic_inch, cubic_meter, gallon, liter},
+    volume_rate::{cubic_meter_per_second, gallon_per_minute, gallon_per_second},
 };
 
+pub mod anti_skid;
 pub mod brake_circuit;
+pub mod brake_temperature;
+pub mod config;
 pub mod electrical_generator;
 pub mod electrical_pump_physics;
 pub mod flap_slat;
+mod fluid_temperature;
 pub mod linear_actuator;
 pub mod nose_steering;
 
@@ -48,16 +59,29 @@ pub trait PressureSource {
         is_pump_connected_to_reservoir: bool,
     );
 
+    /// Flow delivered into the section, internal leakage excluded
     fn flow(&self) -> VolumeRate;
 
+    /// Internal leakage of the pump, going back to the reservoir through its case drain line
+    fn case_drain_flow(&self) -> VolumeRate;
+
     /// This is the physical displacement of the pump
     fn displacement(&self) -> Volume;
+
+    /// Heat dissipated into the fluid by the losses of the pump
+    fn heat_generated(&self) -> Power;
 }
 
 pub struct Fluid {
     current_bulk: Pressure,
 }
 impl Fluid {
+    const VISCOSITY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS: [f64; 10] =
+        [-54., -40., -20., 0., 20., 38., 60., 80., 99., 120.];
+    const VISCOSITY_MAP_CENTISTOKES: [f64; 10] =
+        [1200., 400., 110., 42., 19., 11.4, 7., 5., 3.9, 3.];
+    const REFERENCE_VISCOSITY_CENTISTOKES: f64 = 11.4;
+
     pub fn new(bulk: Pressure) -> Self {
         Self { current_bulk: bulk }
     }
@@ -65,6 +89,23 @@ impl Fluid {
     pub fn bulk_mod(&self) -> Pressure {
         self.current_bulk
     }
+
+    /// Kinematic viscosity in centistokes of phosphate ester fluid at given temperature
+    pub fn kinematic_viscosity(temperature: ThermodynamicTemperature) -> f64 {
+        interpolation(
+            &Self::VISCOSITY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS,
+            &Self::VISCOSITY_MAP_CENTISTOKES,
+            temperature.get::<degree_celsius>(),
+        )
+    }
+
+    /// Viscosity at given temperature relative to the viscosity at 38°C
+    /// which is the reference temperature for pump characteristics
+    pub fn viscosity_ratio(temperature: ThermodynamicTemperature) -> Ratio {
+        Ratio::new::<ratio>(
+            Self::kinematic_viscosity(temperature) / Self::REFERENCE_VISCOSITY_CENTISTOKES,
+        )
+    }
 }
 
 #[derive(PartialEq, Clone, Copy)]
@@ -73,6 +114,12 @@ pub enum PressureSwitchState {
     NotPressurised,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "config-files",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
 pub enum PressureSwitchType {
     Relative,
     Absolute,
@@ -178,6 +225,8 @@ pub struct PowerTransferUnit {
     control_valve_opened: bool,
 
     shaft_speed: AngularVelocity,
+
+    heat_generated: Power,
 }
 impl PowerTransferUnit {
     const ACTIVATION_DELTA_PRESSURE_PSI: f64 = 500.;
@@ -234,6 +283,8 @@ impl PowerTransferUnit {
             control_valve_opened: false,
 
             shaft_speed: AngularVelocity::new::<radian_per_second>(0.),
+
+            heat_generated: Power::new::<watt>(0.),
         }
     }
 
@@ -241,6 +292,11 @@ impl PowerTransferUnit {
         self.last_flow
     }
 
+    /// Heat dissipated by the PTU, shared between the two circuits it connects
+    pub fn heat_generated(&self) -> Power {
+        self.heat_generated
+    }
+
     pub fn is_enabled(&self) -> bool {
         self.is_enabled
     }
@@ -266,6 +322,7 @@ impl PowerTransferUnit {
         self.update_active_state();
         self.update_shaft_physics(context, loop_left_section, loop_right_section);
         self.update_flows();
+        self.update_heat_generated(loop_left_section, loop_right_section);
     }
 
     fn update_displacement(
@@ -401,6 +458,23 @@ impl PowerTransferUnit {
             speed.get::<revolution_per_minute>() * displacement.get::<cubic_inch>() / 231. / 60.,
         )
     }
+
+    /// Losses are the hydraulic power received by the motor side minus the
+    /// hydraulic power delivered by the pump side
+    fn update_heat_generated(
+        &mut self,
+        loop_left_section: &impl SectionPressure,
+        loop_right_section: &impl SectionPressure,
+    ) {
+        let left_power = Self::hydraulic_power(self.flow_to_left, loop_left_section.pressure());
+        let right_power = Self::hydraulic_power(self.flow_to_right, loop_right_section.pressure());
+
+        self.heat_generated = Power::new::<watt>((-(left_power + right_power)).max(0.));
+    }
+
+    fn hydraulic_power(flow: VolumeRate, pressure: Pressure) -> f64 {
+        flow.get::<cubic_meter_per_second>() * pressure.get::<pascal>()
+    }
 }
 impl SimulationElement for PowerTransferUnit {
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -644,6 +718,50 @@ impl HydraulicCircuit {
         }
     }
 
+    pub fn from_config(
+        context: &mut InitContext,
+        id: HydraulicColor,
+        config: &HydraulicCircuitConfig,
+    ) -> Self {
+        let reservoir = Reservoir::from_config(context, id, &config.reservoir);
+
+        Self::new(
+            context,
+            id,
+            config.pump_sections,
+            Ratio::new::<percent>(config.priming_percent),
+            Volume::new::<gallon>(config.high_pressure_volume_gallon),
+            reservoir,
+            Pressure::new::<psi>(config.system_pressure_switch.low_psi),
+            Pressure::new::<psi>(config.system_pressure_switch.high_psi),
+            Pressure::new::<psi>(config.pump_pressure_switch.low_psi),
+            Pressure::new::<psi>(config.pump_pressure_switch.high_psi),
+            config.connected_to_ptu_left_side,
+            config.connected_to_ptu_right_side,
+        )
+        .with_system_accumulator(
+            Pressure::new::<psi>(config.accumulator.gas_precharge_psi),
+            Volume::new::<gallon>(config.accumulator.total_volume_gallon),
+            Volume::new::<gallon>(config.accumulator.fluid_volume_gallon),
+        )
+    }
+
+    /// Replaces the default system section accumulator by one of the given size and precharge
+    pub fn with_system_accumulator(
+        mut self,
+        gas_precharge: Pressure,
+        total_volume: Volume,
+        fluid_volume_at_init: Volume,
+    ) -> Self {
+        self.system_section.accumulator = Some(Accumulator::new(
+            gas_precharge,
+            total_volume,
+            fluid_volume_at_init,
+            false,
+        ));
+        self
+    }
+
     pub fn is_fire_shutoff_valve_open(&self, pump_id: usize) -> bool {
         self.pump_sections[pump_id].fire_valve_is_open()
     }
@@ -688,6 +806,31 @@ impl HydraulicCircuit {
         self.update_pumps(context, main_section_pumps, system_section_pump);
 
         self.update_final_delta_vol_and_pressure(context);
+
+        self.update_fluid_temperature(context, ptu);
+    }
+
+    fn update_fluid_temperature(
+        &mut self,
+        context: &UpdateContext,
+        ptu: Option<&PowerTransferUnit>,
+    ) {
+        let mut heat_generated = self.system_section.heat_generated();
+        let mut fluid_volume =
+            self.system_section.current_volume + self.system_section.accumulator_volume();
+        for section in &self.pump_sections {
+            heat_generated += section.heat_generated();
+            fluid_volume += section.current_volume;
+        }
+
+        if let Some(ptu) = ptu {
+            if self.system_section.is_connected_to_ptu() {
+                heat_generated += ptu.heat_generated() * 0.5;
+            }
+        }
+
+        self.reservoir
+            .update_fluid_temperature(context, heat_generated, fluid_volume);
     }
 
     fn update_delta_vol_from_valves(&mut self) {
@@ -829,6 +972,23 @@ impl HydraulicCircuit {
         &self.reservoir
     }
 
+    /// Updates a branch fed by the system section through a check valve, which the hand pump
+    /// pressurises on its own otherwise
+    pub fn update_hand_pump_branch(
+        &mut self,
+        context: &UpdateContext,
+        branch: &mut HandPumpBranch,
+        hand_pump: &mut HandPump,
+    ) {
+        branch.update(
+            context,
+            hand_pump,
+            &mut self.system_section,
+            &mut self.reservoir,
+            &self.fluid,
+        );
+    }
+
     pub fn system_section(&self) -> &impl SectionPressure {
         &self.system_section
     }
@@ -881,6 +1041,9 @@ pub struct Section {
 
     total_actuator_consumed_volume: Volume,
     total_actuator_returned_volume: Volume,
+
+    throttling_heat: Power,
+    pump_heat: Power,
 }
 impl Section {
     pub fn new(
@@ -928,6 +1091,9 @@ impl Section {
 
             total_actuator_consumed_volume: Volume::new::<gallon>(0.),
             total_actuator_returned_volume: Volume::new::<gallon>(0.),
+
+            throttling_heat: Power::new::<watt>(0.),
+            pump_heat: Power::new::<watt>(0.),
         }
     }
 
@@ -1003,11 +1169,35 @@ impl Section {
         delta_volume_flow_pass -= self.total_actuator_consumed_volume;
         reservoir.add_return_volume(self.total_actuator_returned_volume);
 
+        self.update_throttling_heat(context, static_leak);
+
         self.delta_volume_flow_pass = delta_volume_flow_pass;
 
         self.reset_actuator_volumes();
     }
 
+    /// Fluid going through leaks and actuators back to the reservoir loses all of its
+    /// pressure, which ends up as heat in the fluid
+    fn update_throttling_heat(&mut self, context: &UpdateContext, static_leak: Volume) {
+        let throttled_volume =
+            (static_leak + self.total_actuator_consumed_volume).max(Volume::new::<gallon>(0.));
+        let pressure_drop =
+            (self.current_pressure - Pressure::new::<psi>(14.7)).max(Pressure::new::<psi>(0.));
+
+        self.throttling_heat = Power::new::<watt>(
+            throttled_volume.get::<cubic_meter>() * pressure_drop.get::<pascal>()
+                / context.delta_as_secs_f64(),
+        );
+    }
+
+    fn heat_generated(&self) -> Power {
+        self.throttling_heat + self.pump_heat
+    }
+
+    fn is_connected_to_ptu(&self) -> bool {
+        self.connected_to_ptu_left_side || self.connected_to_ptu_right_side
+    }
+
     fn update_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
         self.total_actuator_consumed_volume += actuator.used_volume();
         self.total_actuator_returned_volume += actuator.reservoir_return();
@@ -1055,6 +1245,8 @@ impl Section {
             self.fire_valve_is_open(),
         );
         self.total_volume_pumped = pump.flow() * context.delta_as_time();
+        reservoir.add_return_volume(pump.case_drain_flow() * context.delta_as_time());
+        self.pump_heat = pump.heat_generated();
     }
 
     pub fn update_final_delta_vol_and_pressure(&mut self, context: &UpdateContext, fluid: &Fluid) {
@@ -1266,6 +1458,8 @@ impl CheckValve {
 pub struct Reservoir {
     level_id: VariableIdentifier,
     low_level_id: VariableIdentifier,
+    fluid_temperature_id: VariableIdentifier,
+    overheat_id: VariableIdentifier,
 
     max_capacity: Volume,
     max_gaugeable: Volume,
@@ -1280,6 +1474,8 @@ pub struct Reservoir {
 
     leak_failure: Failure,
     return_failure: Failure,
+
+    fluid_temperature: FluidTemperature,
 }
 impl Reservoir {
     const MIN_USABLE_VOLUME_GAL: f64 = 0.2;
@@ -1302,6 +1498,9 @@ impl Reservoir {
             level_id: context.get_identifier(format!("HYD_{}_RESERVOIR_LEVEL", hyd_loop_id)),
             low_level_id: context
                 .get_identifier(format!("HYD_{}_RESERVOIR_LEVEL_IS_LOW", hyd_loop_id)),
+            fluid_temperature_id: context
+                .get_identifier(format!("HYD_{}_FLUID_TEMPERATURE", hyd_loop_id)),
+            overheat_id: context.get_identifier(format!("HYD_{}_RESERVOIR_OVHT", hyd_loop_id)),
             max_capacity,
             max_gaugeable,
             current_level,
@@ -1311,9 +1510,48 @@ impl Reservoir {
             return_failure: Failure::new(FailureType::ReservoirReturnLeak(hyd_loop_id)),
             air_pressure_switches,
             level_switch: LevelSwitch::new(low_level_threshold),
+            fluid_temperature: FluidTemperature::new(),
         }
     }
 
+    pub fn from_config(
+        context: &mut InitContext,
+        hyd_loop_id: HydraulicColor,
+        config: &ReservoirConfig,
+    ) -> Self {
+        let reservoir = Self::new(
+            context,
+            hyd_loop_id,
+            Volume::new::<liter>(config.max_capacity_liter),
+            Volume::new::<liter>(config.max_gaugeable_liter),
+            Volume::new::<gallon>(config.level_gallon),
+            config
+                .air_pressure_switches
+                .iter()
+                .map(|switch| {
+                    PressureSwitch::new(
+                        Pressure::new::<psi>(switch.high_psi),
+                        Pressure::new::<psi>(switch.low_psi),
+                        switch.kind,
+                    )
+                })
+                .collect(),
+            Volume::new::<liter>(config.low_level_threshold_liter),
+        );
+
+        if config.fuel_heat_exchanger {
+            reservoir.with_fuel_heat_exchanger()
+        } else {
+            reservoir
+        }
+    }
+
+    /// Fluid returning to this reservoir is cooled by a heat exchanger in a fuel tank
+    pub fn with_fuel_heat_exchanger(mut self) -> Self {
+        self.fluid_temperature = self.fluid_temperature.with_fuel_heat_exchanger();
+        self
+    }
+
     fn update(&mut self, context: &UpdateContext, air_pressure: Pressure) {
         self.air_pressure = air_pressure;
 
@@ -1324,6 +1562,19 @@ impl Reservoir {
         self.update_leak_failure(context);
     }
 
+    fn update_fluid_temperature(
+        &mut self,
+        context: &UpdateContext,
+        heat_generated: Power,
+        circuit_fluid_volume: Volume,
+    ) {
+        self.fluid_temperature.update(
+            context,
+            heat_generated,
+            self.current_level + circuit_fluid_volume,
+        );
+    }
+
     fn update_leak_failure(&mut self, context: &UpdateContext) {
         if self.leak_failure.is_active() {
             self.current_level -=
@@ -1402,6 +1653,14 @@ impl Reservoir {
     pub fn is_low_level(&self) -> bool {
         self.level_switch.is_low_level()
     }
+
+    pub fn fluid_temperature(&self) -> ThermodynamicTemperature {
+        self.fluid_temperature.temperature()
+    }
+
+    pub fn is_overheat(&self) -> bool {
+        self.fluid_temperature.is_overheat()
+    }
 }
 impl SimulationElement for Reservoir {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -1414,6 +1673,8 @@ impl SimulationElement for Reservoir {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.level_id, self.fluid_level_from_gauge());
         writer.write(&self.low_level_id, self.is_low_level());
+        writer.write(&self.fluid_temperature_id, self.fluid_temperature());
+        writer.write(&self.overheat_id, self.is_overheat());
     }
 }
 impl PressurizeableReservoir for Reservoir {
@@ -1435,8 +1696,14 @@ pub struct Pump {
     displacement_carac: [f64; 9],
 
     speed: AngularVelocity,
+    section_pressure: Pressure,
 
     cavitation_efficiency: Ratio,
+
+    leakage_at_max_pressure: VolumeRate,
+    wear: Ratio,
+    leakage_flow: VolumeRate,
+    case_drain_flow: VolumeRate,
 }
 impl Pump {
     const SECONDS_PER_MINUTES: f64 = 60.;
@@ -1444,7 +1711,20 @@ impl Pump {
     const AIR_PRESSURE_BREAKPTS_PSI: [f64; 9] = [0., 5., 10., 15., 20., 30., 50., 70., 100.];
     const AIR_PRESSURE_CARAC_RATIO: [f64; 9] = [0.0, 0.1, 0.6, 0.8, 0.9, 1., 1., 1., 1.];
 
-    fn new(press_breakpoints: [f64; 9], displacement_carac: [f64; 9]) -> Self {
+    // Mechanical efficiency of the pumping. Losses are dissipated into the fluid.
+    // Volumetric losses are given by the internal leakage
+    const MECHANICAL_EFFICIENCY: f64 = 0.9;
+
+    const LEAKAGE_REFERENCE_PRESSURE_PSI: f64 = 3000.;
+
+    // Leakage of a fully worn pump relative to a new one
+    const FULLY_WORN_LEAKAGE_RATIO: f64 = 5.;
+
+    fn new(
+        press_breakpoints: [f64; 9],
+        displacement_carac: [f64; 9],
+        leakage_at_max_pressure: VolumeRate,
+    ) -> Self {
         Self {
             delta_vol_max: Volume::new::<gallon>(0.),
             current_displacement: Volume::new::<gallon>(0.),
@@ -1454,11 +1734,25 @@ impl Pump {
             displacement_carac,
 
             speed: AngularVelocity::new::<revolution_per_minute>(0.),
+            section_pressure: Pressure::new::<psi>(14.7),
 
             cavitation_efficiency: Ratio::new::<ratio>(1.),
+
+            leakage_at_max_pressure,
+            wear: Ratio::new::<ratio>(0.),
+            leakage_flow: VolumeRate::new::<gallon_per_second>(0.),
+            case_drain_flow: VolumeRate::new::<gallon_per_second>(0.),
         }
     }
 
+    /// Wear of the pump from 0 (new) to 1 (fully worn). A worn pump has larger internal
+    /// clearances and leaks more to its case drain.
+    fn set_wear(&mut self, wear: Ratio) {
+        self.wear = wear
+            .max(Ratio::new::<ratio>(0.))
+            .min(Ratio::new::<ratio>(1.));
+    }
+
     fn update<T: PumpController>(
         &mut self,
         context: &UpdateContext,
@@ -1468,8 +1762,10 @@ impl Pump {
         controller: &T,
     ) {
         self.speed = speed;
+        self.section_pressure = section.pressure();
 
         self.update_cavitation(reservoir);
+        self.update_leakage(reservoir);
 
         let theoretical_displacement = self.calculate_displacement(section, controller);
 
@@ -1481,7 +1777,23 @@ impl Pump {
         let max_flow_available_from_reservoir =
             reservoir.request_flow_availability(context, max_flow);
 
-        self.delta_vol_max = max_flow_available_from_reservoir * context.delta_as_time();
+        self.delta_vol_max = (max_flow_available_from_reservoir - self.leakage_flow)
+            .max(VolumeRate::new::<gallon_per_second>(0.))
+            * context.delta_as_time();
+    }
+
+    /// Laminar leakage through the pump clearances: it grows with pressure and pump wear,
+    /// and as the fluid gets thinner with temperature
+    fn update_leakage(&mut self, reservoir: &Reservoir) {
+        let pressure_ratio = (self.section_pressure - Pressure::new::<psi>(14.7))
+            .max(Pressure::new::<psi>(0.))
+            .get::<psi>()
+            / Self::LEAKAGE_REFERENCE_PRESSURE_PSI;
+
+        let wear_ratio = 1. + self.wear.get::<ratio>() * (Self::FULLY_WORN_LEAKAGE_RATIO - 1.);
+
+        self.leakage_flow = self.leakage_at_max_pressure * pressure_ratio * wear_ratio
+            / Fluid::viscosity_ratio(reservoir.fluid_temperature()).get::<ratio>();
     }
 
     fn update_cavitation(&mut self, reservoir: &Reservoir) {
@@ -1555,6 +1867,15 @@ impl Pump {
     fn cavitation_efficiency(&self) -> Ratio {
         self.cavitation_efficiency
     }
+
+    fn power_at_section_pressure(&self, flow: VolumeRate) -> Power {
+        Power::new::<watt>(
+            flow.get::<cubic_meter_per_second>()
+                * (self.section_pressure - Pressure::new::<psi>(14.7))
+                    .max(Pressure::new::<psi>(0.))
+                    .get::<pascal>(),
+        )
+    }
 }
 impl PressureSource for Pump {
     fn delta_vol_max(&self) -> Volume {
@@ -1568,24 +1889,38 @@ impl PressureSource for Pump {
         reservoir: &mut Reservoir,
         is_pump_connected_to_reservoir: bool,
     ) {
-        let required_flow = volume_required / context.delta_as_time();
+        // Displacement also has to make up for what leaks to the case drain
+        let required_flow = volume_required / context.delta_as_time() + self.leakage_flow;
         self.current_displacement = self.calculate_displacement_from_required_flow(required_flow);
         let max_current_flow = self.get_max_flow();
 
-        self.current_flow = if is_pump_connected_to_reservoir {
+        let flow_taken_from_reservoir = if is_pump_connected_to_reservoir {
             reservoir.try_take_flow(context, max_current_flow)
         } else {
             VolumeRate::new::<gallon_per_second>(0.)
-        }
+        };
+
+        self.case_drain_flow = self.leakage_flow.min(flow_taken_from_reservoir);
+        self.current_flow = flow_taken_from_reservoir - self.case_drain_flow;
     }
 
     fn flow(&self) -> VolumeRate {
         self.current_flow
     }
 
+    fn case_drain_flow(&self) -> VolumeRate {
+        self.case_drain_flow
+    }
+
     fn displacement(&self) -> Volume {
         self.current_displacement
     }
+
+    /// Mechanical losses plus the leakage losing all of its pressure to the case drain
+    fn heat_generated(&self) -> Power {
+        self.power_at_section_pressure(self.current_flow) * (1. / Self::MECHANICAL_EFFICIENCY - 1.)
+            + self.power_at_section_pressure(self.case_drain_flow)
+    }
 }
 
 pub struct ElectricPump {
@@ -1599,6 +1934,7 @@ impl ElectricPump {
         0.0, 500.0, 1000.0, 1500.0, 2175.0, 2850.0, 3080.0, 3100.0, 3500.0,
     ];
     const DISPLACEMENT_MAP: [f64; 9] = [0.263, 0.263, 0.263, 0.263, 0.263, 0.2, 0.0, 0.0, 0.0];
+    const LEAKAGE_AT_MAX_PRESSURE_GPM: f64 = 0.15;
 
     pub fn new(
         context: &mut InitContext,
@@ -1608,7 +1944,11 @@ impl ElectricPump {
     ) -> Self {
         Self {
             cavitation_id: context.get_identifier(format!("HYD_{}_EPUMP_CAVITATION", id)),
-            pump: Pump::new(Self::DISPLACEMENT_BREAKPTS, Self::DISPLACEMENT_MAP),
+            pump: Pump::new(
+                Self::DISPLACEMENT_BREAKPTS,
+                Self::DISPLACEMENT_MAP,
+                VolumeRate::new::<gallon_per_minute>(Self::LEAKAGE_AT_MAX_PRESSURE_GPM),
+            ),
             pump_physics: ElectricalPumpPhysics::new(
                 context,
                 id,
@@ -1627,8 +1967,12 @@ impl ElectricPump {
         controller: &T,
     ) {
         self.pump_physics.set_active(controller.should_pressurise());
-        self.pump_physics
-            .update(context, section, self.pump.displacement());
+        self.pump_physics.update(
+            context,
+            section,
+            self.pump.displacement(),
+            reservoir.fluid_temperature(),
+        );
 
         self.pump.update(
             context,
@@ -1646,6 +1990,10 @@ impl ElectricPump {
     pub fn flow(&self) -> VolumeRate {
         self.pump.flow()
     }
+
+    pub fn set_wear(&mut self, wear: Ratio) {
+        self.pump.set_wear(wear);
+    }
 }
 impl PressureSource for ElectricPump {
     fn delta_vol_max(&self) -> Volume {
@@ -1671,9 +2019,17 @@ impl PressureSource for ElectricPump {
         self.pump.flow()
     }
 
+    fn case_drain_flow(&self) -> VolumeRate {
+        self.pump.case_drain_flow()
+    }
+
     fn displacement(&self) -> Volume {
         self.pump.displacement()
     }
+
+    fn heat_generated(&self) -> Power {
+        self.pump.heat_generated() + self.pump_physics.heat_generated()
+    }
 }
 impl SimulationElement for ElectricPump {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -1702,13 +2058,18 @@ impl EngineDrivenPump {
         0.0, 500.0, 1000.0, 1500.0, 2800.0, 2950.0, 3000.0, 3020.0, 3500.0,
     ];
     const DISPLACEMENT_MAP: [f64; 9] = [2.4, 2.4, 2.4, 2.4, 2.4, 2.4, 2.2, 1.0, 0.0];
+    const LEAKAGE_AT_MAX_PRESSURE_GPM: f64 = 0.6;
 
     pub fn new(context: &mut InitContext, id: &str) -> Self {
         Self {
             active_id: context.get_identifier(format!("HYD_{}_EDPUMP_ACTIVE", id)),
             is_active: false,
             speed: AngularVelocity::new::<revolution_per_minute>(0.),
-            pump: Pump::new(Self::DISPLACEMENT_BREAKPTS, Self::DISPLACEMENT_MAP),
+            pump: Pump::new(
+                Self::DISPLACEMENT_BREAKPTS,
+                Self::DISPLACEMENT_MAP,
+                VolumeRate::new::<gallon_per_minute>(Self::LEAKAGE_AT_MAX_PRESSURE_GPM),
+            ),
         }
     }
 
@@ -1725,6 +2086,10 @@ impl EngineDrivenPump {
             .update(context, section, reservoir, pump_speed, controller);
         self.is_active = controller.should_pressurise();
     }
+
+    pub fn set_wear(&mut self, wear: Ratio) {
+        self.pump.set_wear(wear);
+    }
 }
 impl PressureSource for EngineDrivenPump {
     fn delta_vol_max(&self) -> Volume {
@@ -1750,9 +2115,17 @@ impl PressureSource for EngineDrivenPump {
         self.pump.flow()
     }
 
+    fn case_drain_flow(&self) -> VolumeRate {
+        self.pump.case_drain_flow()
+    }
+
     fn displacement(&self) -> Volume {
         self.pump.current_displacement
     }
+
+    fn heat_generated(&self) -> Power {
+        self.pump.heat_generated()
+    }
 }
 impl SimulationElement for EngineDrivenPump {
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -1897,6 +2270,7 @@ impl RamAirTurbine {
         0.0, 500.0, 1000.0, 1500.0, 2100.0, 2300.0, 2600.0, 2700.0, 3500.0,
     ];
     const DISPLACEMENT_MAP: [f64; 9] = [0.5, 0.8, 1.15, 1.15, 1.15, 0.8, 0.3, 0.0, 0.0];
+    const LEAKAGE_AT_MAX_PRESSURE_GPM: f64 = 0.1;
 
     // Speed to go from 0 to 1 stow position per sec. 1 means full deploying in 1s
     const STOWING_SPEED: f64 = 1.;
@@ -1906,7 +2280,11 @@ impl RamAirTurbine {
             stow_position_id: context.get_identifier("HYD_RAT_STOW_POSITION".to_owned()),
 
             deployment_commanded: false,
-            pump: Pump::new(Self::DISPLACEMENT_BREAKPTS, Self::DISPLACEMENT_MAP),
+            pump: Pump::new(
+                Self::DISPLACEMENT_BREAKPTS,
+                Self::DISPLACEMENT_MAP,
+                VolumeRate::new::<gallon_per_minute>(Self::LEAKAGE_AT_MAX_PRESSURE_GPM),
+            ),
             pump_controller: AlwaysPressurisePumpController::new(),
             wind_turbine: WindTurbine::new(context),
             position: 0.,
@@ -1984,9 +2362,17 @@ impl PressureSource for RamAirTurbine {
         self.pump.flow()
     }
 
+    fn case_drain_flow(&self) -> VolumeRate {
+        self.pump.case_drain_flow()
+    }
+
     fn displacement(&self) -> Volume {
         self.pump.displacement()
     }
+
+    fn heat_generated(&self) -> Power {
+        self.pump.heat_generated()
+    }
 }
 impl SimulationElement for RamAirTurbine {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -2000,13 +2386,235 @@ impl SimulationElement for RamAirTurbine {
     }
 }
 
+/// Pump manually operated by the ground crew, delivering a fixed volume of fluid at each stroke
+/// of its handle. Stroke rate is given by the simulator.
+///
+/// Close to its maximum pressure the handle gets too hard to push for full strokes, so delivered
+/// volume decreases until no fluid can be pumped anymore.
+pub struct HandPump {
+    stroke_rate_id: VariableIdentifier,
+
+    stroke_rate: f64,
+    displacement_per_stroke: Volume,
+
+    delta_vol_max: Volume,
+    current_flow: VolumeRate,
+    current_displacement: Volume,
+}
+impl HandPump {
+    const MAX_STROKES_PER_SECOND: f64 = 2.;
+
+    const PRESSURE_EFFORT_BREAKPTS_PSI: [f64; 2] = [2500., 3000.];
+    const PRESSURE_EFFORT_STROKE_RATIO: [f64; 2] = [1., 0.];
+
+    pub fn new(context: &mut InitContext, id: &str, displacement_per_stroke: Volume) -> Self {
+        Self {
+            stroke_rate_id: context.get_identifier(format!("HYD_{}_HAND_PUMP_STROKE_RATE", id)),
+
+            stroke_rate: 0.,
+            displacement_per_stroke,
+
+            delta_vol_max: Volume::new::<gallon>(0.),
+            current_flow: VolumeRate::new::<gallon_per_second>(0.),
+            current_displacement: Volume::new::<gallon>(0.),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        section: &impl SectionPressure,
+        reservoir: &Reservoir,
+    ) {
+        let stroke_ratio = interpolation(
+            &Self::PRESSURE_EFFORT_BREAKPTS_PSI,
+            &Self::PRESSURE_EFFORT_STROKE_RATIO,
+            section.pressure().get::<psi>(),
+        );
+
+        let max_flow = VolumeRate::new::<gallon_per_second>(
+            self.displacement_per_stroke.get::<gallon>() * stroke_ratio * self.stroke_rate,
+        );
+
+        self.delta_vol_max =
+            reservoir.request_flow_availability(context, max_flow) * context.delta_as_time();
+    }
+
+    pub fn is_operated(&self) -> bool {
+        self.stroke_rate > 0.
+    }
+}
+impl PressureSource for HandPump {
+    fn delta_vol_max(&self) -> Volume {
+        self.delta_vol_max
+    }
+
+    fn update_after_pressure_regulation(
+        &mut self,
+        context: &UpdateContext,
+        volume_required: Volume,
+        reservoir: &mut Reservoir,
+        is_pump_connected_to_reservoir: bool,
+    ) {
+        let volume_pumped = volume_required
+            .min(self.delta_vol_max)
+            .max(Volume::new::<gallon>(0.));
+
+        self.current_flow = if is_pump_connected_to_reservoir {
+            reservoir.try_take_flow(context, volume_pumped / context.delta_as_time())
+        } else {
+            VolumeRate::new::<gallon_per_second>(0.)
+        };
+
+        self.current_displacement = if self.is_operated() {
+            self.current_flow * Time::new::<second>(1. / self.stroke_rate)
+        } else {
+            Volume::new::<gallon>(0.)
+        };
+    }
+
+    fn flow(&self) -> VolumeRate {
+        self.current_flow
+    }
+
+    fn case_drain_flow(&self) -> VolumeRate {
+        VolumeRate::new::<gallon_per_second>(0.)
+    }
+
+    fn displacement(&self) -> Volume {
+        self.current_displacement
+    }
+
+    fn heat_generated(&self) -> Power {
+        Power::new::<watt>(0.)
+    }
+}
+impl SimulationElement for HandPump {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let stroke_rate: f64 = reader.read(&self.stroke_rate_id);
+        self.stroke_rate = stroke_rate.clamp(0., Self::MAX_STROKES_PER_SECOND);
+    }
+}
+
+/// Part of a circuit downstream of a check valve from its system section, which a hand pump can
+/// pressurise on its own when the system section is not pressurised.
+///
+/// Actuators of the branch draw from the fluid delivered by the hand pump. Whenever the branch
+/// pressure falls below the system section pressure, the check valve opens and the system section
+/// provides the fluid needed to equalise both pressures.
+pub struct HandPumpBranch {
+    high_pressure_volume: Volume,
+    current_volume: Volume,
+    current_pressure: Pressure,
+
+    total_actuator_consumed_volume: Volume,
+    total_actuator_returned_volume: Volume,
+}
+impl HandPumpBranch {
+    const STATIC_LEAK_AT_MAX_PRESSURE_GAL_P_S: f64 = 0.0005;
+    const MAX_PRESSURE_PSI: f64 = 3000.;
+    const PRESSURISED_THRESHOLD_PSI: f64 = 1450.;
+
+    pub fn new(high_pressure_volume: Volume) -> Self {
+        Self {
+            high_pressure_volume,
+            current_volume: high_pressure_volume,
+            current_pressure: Pressure::new::<psi>(14.7),
+
+            total_actuator_consumed_volume: Volume::new::<gallon>(0.),
+            total_actuator_returned_volume: Volume::new::<gallon>(0.),
+        }
+    }
+
+    pub fn update_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
+        self.total_actuator_consumed_volume += actuator.used_volume();
+        self.total_actuator_returned_volume += actuator.reservoir_return();
+        actuator.reset_volumes();
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        hand_pump: &mut HandPump,
+        system_section: &mut Section,
+        reservoir: &mut Reservoir,
+        fluid: &Fluid,
+    ) {
+        let volume_to_max_pressure = self
+            .volume_to_reach(Pressure::new::<psi>(Self::MAX_PRESSURE_PSI), fluid)
+            + self.total_actuator_consumed_volume;
+        hand_pump.update_after_pressure_regulation(
+            context,
+            volume_to_max_pressure.max(Volume::new::<gallon>(0.)),
+            reservoir,
+            true,
+        );
+
+        let static_leak = self.static_leak(context);
+        reservoir.add_return_volume(static_leak + self.total_actuator_returned_volume);
+
+        self.current_volume += hand_pump.flow() * context.delta_as_time()
+            - static_leak
+            - self.total_actuator_consumed_volume;
+        self.update_pressure(fluid);
+
+        if self.current_pressure < system_section.pressure() {
+            let volume_from_system = self.volume_to_reach(system_section.pressure(), fluid);
+            system_section.total_actuator_consumed_volume += volume_from_system;
+
+            self.current_volume += volume_from_system;
+            self.update_pressure(fluid);
+        }
+
+        self.total_actuator_consumed_volume = Volume::new::<gallon>(0.);
+        self.total_actuator_returned_volume = Volume::new::<gallon>(0.);
+    }
+
+    fn volume_to_reach(&self, target_pressure: Pressure, fluid: &Fluid) -> Volume {
+        self.high_pressure_volume - self.current_volume
+            + (target_pressure - Pressure::new::<psi>(14.7)) * self.high_pressure_volume
+                / fluid.bulk_mod()
+    }
+
+    fn static_leak(&self, context: &UpdateContext) -> Volume {
+        VolumeRate::new::<gallon_per_second>(Self::STATIC_LEAK_AT_MAX_PRESSURE_GAL_P_S)
+            * context.delta_as_time()
+            * (self.current_pressure - Pressure::new::<psi>(14.7))
+            / Pressure::new::<psi>(Self::MAX_PRESSURE_PSI)
+    }
+
+    fn update_pressure(&mut self, fluid: &Fluid) {
+        let fluid_volume_compressed = self.current_volume - self.high_pressure_volume;
+
+        self.current_pressure = (Pressure::new::<psi>(14.7)
+            + fluid_volume_compressed / self.high_pressure_volume * fluid.bulk_mod())
+        .max(Pressure::new::<psi>(14.7));
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.current_pressure
+    }
+}
+impl SectionPressure for HandPumpBranch {
+    fn pressure(&self) -> Pressure {
+        self.current_pressure
+    }
+
+    fn is_pressure_switch_pressurised(&self) -> bool {
+        self.current_pressure.get::<psi>() > Self::PRESSURISED_THRESHOLD_PSI
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::simulation::test::{ElementCtorFn, ReadByName, SimulationTestBed, TestBed};
     use crate::simulation::InitContext;
     use ntest::assert_about_eq;
 
-    use uom::si::{f64::*, pressure::psi, ratio::percent, volume::gallon};
+    use uom::si::{
+        f64::*, pressure::psi, ratio::percent, thermodynamic_temperature::degree_celsius,
+        volume::gallon,
+    };
 
     use super::*;
 
@@ -2183,6 +2791,66 @@ mod tests {
         assert!(is_low);
     }
 
+    #[test]
+    fn reservoir_writes_fluid_temperature_at_ambient_temperature_when_cold() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(5.),
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(context, Pressure::new::<psi>(50.));
+            reservoir.update_fluid_temperature(
+                context,
+                Power::new::<watt>(0.),
+                Volume::new::<gallon>(10.),
+            );
+        });
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.));
+        test_bed.run();
+
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("HYD_GREEN_FLUID_TEMPERATURE");
+        assert_about_eq!(temperature.get::<degree_celsius>(), 20., 0.1);
+
+        let is_overheat: bool = test_bed.read_by_name("HYD_GREEN_RESERVOIR_OVHT");
+        assert!(!is_overheat);
+    }
+
+    #[test]
+    fn reservoir_fluid_heats_up_with_heat_from_circuit() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            reservoir(
+                context,
+                HydraulicColor::Green,
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(5.),
+                Volume::new::<gallon>(5.),
+            )
+        }));
+
+        test_bed.set_update_after_power_distribution(|reservoir, context| {
+            reservoir.update(context, Pressure::new::<psi>(50.));
+            reservoir.update_fluid_temperature(
+                context,
+                Power::new::<watt>(5000.),
+                Volume::new::<gallon>(10.),
+            );
+        });
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.));
+        test_bed.run_multiple_frames(Duration::from_secs(300));
+
+        assert!(
+            test_bed.query_element(|r| r.fluid_temperature())
+                > ThermodynamicTemperature::new::<degree_celsius>(25.)
+        );
+    }
+
     fn section(
         context: &mut InitContext,
         loop_id: HydraulicColor,
@@ -2268,6 +2936,19 @@ mod tests {
         EngineDrivenPump::new(context, "DEFAULT")
     }
 
+    struct TestHydraulicSection {
+        current_pressure: Pressure,
+    }
+    impl SectionPressure for TestHydraulicSection {
+        fn pressure(&self) -> Pressure {
+            self.current_pressure
+        }
+
+        fn is_pressure_switch_pressurised(&self) -> bool {
+            self.current_pressure.get::<psi>() > 1450.
+        }
+    }
+
     #[cfg(test)]
     mod edp_tests {
         use super::*;
@@ -2281,4 +2962,502 @@ mod tests {
             assert!(test_bed.query_element(|e| !e.is_active));
         }
     }
+
+    #[cfg(test)]
+    mod hand_pump_tests {
+        use super::*;
+
+        use crate::simulation::test::{
+            ElementCtorFn, SimulationTestBed, TestAircraft, WriteByName,
+        };
+
+        const DISPLACEMENT_PER_STROKE_GAL: f64 = 0.05;
+        const FRAME: Duration = Duration::from_millis(100);
+
+        struct TestHandPumpSystem {
+            pump: HandPump,
+            reservoir: Reservoir,
+            section: TestHydraulicSection,
+
+            volume_required: Volume,
+            volume_pumped: Volume,
+        }
+        impl TestHandPumpSystem {
+            fn new(context: &mut InitContext) -> Self {
+                Self {
+                    pump: HandPump::new(
+                        context,
+                        "MAIN",
+                        Volume::new::<gallon>(DISPLACEMENT_PER_STROKE_GAL),
+                    ),
+                    reservoir: reservoir(
+                        context,
+                        HydraulicColor::Yellow,
+                        Volume::new::<gallon>(5.),
+                        Volume::new::<gallon>(5.),
+                        Volume::new::<gallon>(5.),
+                    ),
+                    section: TestHydraulicSection {
+                        current_pressure: Pressure::new::<psi>(14.7),
+                    },
+
+                    volume_required: Volume::new::<gallon>(1.),
+                    volume_pumped: Volume::new::<gallon>(0.),
+                }
+            }
+
+            fn update(&mut self, context: &UpdateContext) {
+                self.pump.update(context, &self.section, &self.reservoir);
+                self.pump.update_after_pressure_regulation(
+                    context,
+                    self.volume_required,
+                    &mut self.reservoir,
+                    true,
+                );
+
+                self.volume_pumped += self.pump.flow() * context.delta_as_time();
+            }
+        }
+        impl SimulationElement for TestHandPumpSystem {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.pump.accept(visitor);
+                self.reservoir.accept(visitor);
+
+                visitor.visit(self);
+            }
+        }
+
+        fn test_bed() -> SimulationTestBed<TestAircraft<TestHandPumpSystem>> {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(TestHandPumpSystem::new));
+            test_bed.set_update_after_power_distribution(|system, context| system.update(context));
+
+            test_bed
+        }
+
+        #[test]
+        fn delivers_nothing_when_not_operated() {
+            let mut test_bed = test_bed();
+
+            test_bed.run_with_fixed_frames(Duration::from_secs(5), FRAME);
+
+            assert!(!test_bed.query_element(|s| s.pump.is_operated()));
+            assert_about_eq!(
+                test_bed.query_element(|s| s.volume_pumped.get::<gallon>()),
+                0.
+            );
+        }
+
+        #[test]
+        fn delivers_one_displacement_per_stroke() {
+            let mut test_bed = test_bed();
+
+            test_bed.write_by_name("HYD_MAIN_HAND_PUMP_STROKE_RATE", 1.);
+            test_bed.run_with_fixed_frames(Duration::from_secs(10), FRAME);
+
+            assert!(test_bed.query_element(|s| s.pump.is_operated()));
+            assert_about_eq!(
+                test_bed.query_element(|s| s.volume_pumped.get::<gallon>()),
+                10. * DISPLACEMENT_PER_STROKE_GAL,
+                0.01
+            );
+            assert_about_eq!(
+                test_bed.query_element(|s| s.reservoir.fluid_level_real().get::<gallon>()),
+                5. - 10. * DISPLACEMENT_PER_STROKE_GAL,
+                0.01
+            );
+        }
+
+        #[test]
+        fn stroke_rate_is_limited() {
+            let mut test_bed = test_bed();
+
+            test_bed.write_by_name("HYD_MAIN_HAND_PUMP_STROKE_RATE", 10.);
+            test_bed.run_with_fixed_frames(Duration::from_secs(10), FRAME);
+
+            assert_about_eq!(
+                test_bed.query_element(|s| s.volume_pumped.get::<gallon>()),
+                10. * HandPump::MAX_STROKES_PER_SECOND * DISPLACEMENT_PER_STROKE_GAL,
+                0.01
+            );
+        }
+
+        #[test]
+        fn delivers_only_volume_required() {
+            let mut test_bed = test_bed();
+
+            test_bed.command_element(|s| s.volume_required = Volume::new::<gallon>(0.));
+            test_bed.write_by_name("HYD_MAIN_HAND_PUMP_STROKE_RATE", 1.);
+            test_bed.run_with_fixed_frames(Duration::from_secs(5), FRAME);
+
+            assert_about_eq!(
+                test_bed.query_element(|s| s.volume_pumped.get::<gallon>()),
+                0.
+            );
+        }
+
+        #[test]
+        fn cannot_pump_at_max_pressure() {
+            let mut test_bed = test_bed();
+
+            test_bed.command_element(|s| s.section.current_pressure = Pressure::new::<psi>(3000.));
+            test_bed.write_by_name("HYD_MAIN_HAND_PUMP_STROKE_RATE", 1.);
+            test_bed.run_with_fixed_frames(Duration::from_secs(5), FRAME);
+
+            assert_about_eq!(
+                test_bed.query_element(|s| s.volume_pumped.get::<gallon>()),
+                0.
+            );
+        }
+
+        #[test]
+        fn pumps_less_per_stroke_close_to_max_pressure() {
+            let mut test_bed = test_bed();
+
+            test_bed.command_element(|s| s.section.current_pressure = Pressure::new::<psi>(2750.));
+            test_bed.write_by_name("HYD_MAIN_HAND_PUMP_STROKE_RATE", 1.);
+            test_bed.run_with_fixed_frames(Duration::from_secs(10), FRAME);
+
+            assert_about_eq!(
+                test_bed.query_element(|s| s.volume_pumped.get::<gallon>()),
+                0.5 * 10. * DISPLACEMENT_PER_STROKE_GAL,
+                0.01
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod hand_pump_branch_tests {
+        use super::*;
+
+        use crate::simulation::test::{
+            ElementCtorFn, SimulationTestBed, TestAircraft, WriteByName,
+        };
+
+        const FRAME: Duration = Duration::from_millis(100);
+
+        struct TestHandPumpBranchSystem {
+            pump: HandPump,
+            branch: HandPumpBranch,
+            system_section: Section,
+            reservoir: Reservoir,
+            fluid: Fluid,
+        }
+        impl TestHandPumpBranchSystem {
+            fn new(context: &mut InitContext) -> Self {
+                Self {
+                    pump: HandPump::new(context, "MAIN", Volume::new::<cubic_inch>(2.)),
+                    branch: HandPumpBranch::new(Volume::new::<gallon>(0.05)),
+                    system_section: section(context, HydraulicColor::Yellow, "SYSTEM", 1),
+                    reservoir: reservoir(
+                        context,
+                        HydraulicColor::Yellow,
+                        Volume::new::<gallon>(5.),
+                        Volume::new::<gallon>(5.),
+                        Volume::new::<gallon>(5.),
+                    ),
+                    fluid: Fluid::new(Pressure::new::<pascal>(
+                        HydraulicCircuit::FLUID_BULK_MODULUS_PASCAL,
+                    )),
+                }
+            }
+
+            fn update(&mut self, context: &UpdateContext) {
+                self.pump.update(context, &self.branch, &self.reservoir);
+                self.branch.update(
+                    context,
+                    &mut self.pump,
+                    &mut self.system_section,
+                    &mut self.reservoir,
+                    &self.fluid,
+                );
+            }
+
+            fn volume_drawn_from_system(&self) -> Volume {
+                self.system_section.total_actuator_consumed_volume
+            }
+        }
+        impl SimulationElement for TestHandPumpBranchSystem {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.pump.accept(visitor);
+                self.system_section.accept(visitor);
+                self.reservoir.accept(visitor);
+
+                visitor.visit(self);
+            }
+        }
+
+        fn test_bed() -> SimulationTestBed<TestAircraft<TestHandPumpBranchSystem>> {
+            let mut test_bed =
+                SimulationTestBed::from(ElementCtorFn(TestHandPumpBranchSystem::new));
+            test_bed.set_update_after_power_distribution(|system, context| system.update(context));
+
+            test_bed
+        }
+
+        #[test]
+        fn is_not_pressurised_without_system_pressure_or_hand_pump() {
+            let mut test_bed = test_bed();
+
+            test_bed.run_with_fixed_frames(Duration::from_secs(5), FRAME);
+
+            assert!(!test_bed.query_element(|s| s.branch.is_pressure_switch_pressurised()));
+        }
+
+        #[test]
+        fn follows_system_section_pressure() {
+            let mut test_bed = test_bed();
+
+            test_bed.command_element(|s| {
+                s.system_section.current_pressure = Pressure::new::<psi>(3000.)
+            });
+            test_bed.run_with_fixed_frames(FRAME, FRAME);
+
+            assert_about_eq!(
+                test_bed.query_element(|s| s.branch.pressure().get::<psi>()),
+                3000.,
+                1.
+            );
+            assert!(test_bed.query_element(|s| s.volume_drawn_from_system().get::<gallon>()) > 0.);
+        }
+
+        #[test]
+        fn hand_pump_pressurises_branch_without_drawing_from_system() {
+            let mut test_bed = test_bed();
+
+            test_bed.write_by_name("HYD_MAIN_HAND_PUMP_STROKE_RATE", 1.);
+            test_bed.run_with_fixed_frames(Duration::from_secs(5), FRAME);
+
+            assert!(test_bed.query_element(|s| s.branch.is_pressure_switch_pressurised()));
+            assert!(test_bed.query_element(|s| s.branch.pressure().get::<psi>()) <= 3000.);
+            assert_about_eq!(
+                test_bed.query_element(|s| s.volume_drawn_from_system().get::<gallon>()),
+                0.
+            );
+        }
+
+        #[test]
+        fn pressure_decays_once_hand_pump_stops() {
+            let mut test_bed = test_bed();
+
+            test_bed.write_by_name("HYD_MAIN_HAND_PUMP_STROKE_RATE", 1.);
+            test_bed.run_with_fixed_frames(Duration::from_secs(5), FRAME);
+
+            test_bed.write_by_name("HYD_MAIN_HAND_PUMP_STROKE_RATE", 0.);
+            test_bed.run_with_fixed_frames(Duration::from_secs(30), FRAME);
+
+            assert!(!test_bed.query_element(|s| s.branch.is_pressure_switch_pressurised()));
+        }
+    }
+
+    #[cfg(test)]
+    mod pump_leakage_tests {
+        use super::*;
+
+        use crate::simulation::test::{ElementCtorFn, SimulationTestBed, TestAircraft};
+
+        const RESERVOIR_LEVEL_GAL: f64 = 5.;
+
+        struct TestPumpSystem {
+            pump: EngineDrivenPump,
+            reservoir: Reservoir,
+            section: Section,
+            test_section: TestHydraulicSection,
+
+            volume_required: Volume,
+            volume_delivered: Volume,
+        }
+        impl TestPumpSystem {
+            fn new(context: &mut InitContext) -> Self {
+                Self {
+                    pump: engine_driven_pump(context),
+                    reservoir: reservoir(
+                        context,
+                        HydraulicColor::Green,
+                        Volume::new::<gallon>(RESERVOIR_LEVEL_GAL),
+                        Volume::new::<gallon>(RESERVOIR_LEVEL_GAL),
+                        Volume::new::<gallon>(RESERVOIR_LEVEL_GAL),
+                    ),
+                    section: section(context, HydraulicColor::Green, "PUMP", 1),
+                    test_section: TestHydraulicSection {
+                        current_pressure: Pressure::new::<psi>(3000.),
+                    },
+
+                    volume_required: Volume::new::<gallon>(0.),
+                    volume_delivered: Volume::new::<gallon>(0.),
+                }
+            }
+
+            fn update(&mut self, context: &UpdateContext) {
+                self.reservoir.update(context, Pressure::new::<psi>(50.));
+                self.reservoir.update_fluid_temperature(
+                    context,
+                    Power::new::<watt>(0.),
+                    Volume::new::<gallon>(RESERVOIR_LEVEL_GAL),
+                );
+                self.pump.update(
+                    context,
+                    &self.test_section,
+                    &self.reservoir,
+                    AngularVelocity::new::<revolution_per_minute>(4000.),
+                    &AlwaysPressurisePumpController::new(),
+                );
+
+                self.section.volume_target = self.volume_required;
+                self.section
+                    .update_pump_state(context, &mut self.pump, &mut self.reservoir);
+
+                self.volume_delivered += self.pump.flow() * context.delta_as_time();
+            }
+        }
+        impl SimulationElement for TestPumpSystem {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.pump.accept(visitor);
+                self.reservoir.accept(visitor);
+                self.section.accept(visitor);
+
+                visitor.visit(self);
+            }
+        }
+
+        fn test_bed() -> SimulationTestBed<TestAircraft<TestPumpSystem>> {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(TestPumpSystem::new));
+            test_bed.set_update_after_power_distribution(|system, context| system.update(context));
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(38.));
+
+            test_bed
+        }
+
+        fn case_drain_flow(test_bed: &SimulationTestBed<TestAircraft<TestPumpSystem>>) -> f64 {
+            test_bed.query_element(|s| s.pump.case_drain_flow().get::<gallon_per_minute>())
+        }
+
+        #[test]
+        fn leaks_to_case_drain_at_max_pressure() {
+            let mut test_bed = test_bed();
+
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            assert_about_eq!(
+                case_drain_flow(&test_bed),
+                EngineDrivenPump::LEAKAGE_AT_MAX_PRESSURE_GPM,
+                0.05
+            );
+        }
+
+        #[test]
+        fn leaks_less_at_lower_pressure() {
+            let mut test_bed = test_bed();
+
+            test_bed.run_with_delta(Duration::from_millis(100));
+            let leakage_at_max_pressure = case_drain_flow(&test_bed);
+
+            test_bed
+                .command_element(|s| s.test_section.current_pressure = Pressure::new::<psi>(1000.));
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            assert!(case_drain_flow(&test_bed) < 0.5 * leakage_at_max_pressure);
+        }
+
+        #[test]
+        fn leaks_more_with_hot_fluid() {
+            let mut cold_test_bed = test_bed();
+            cold_test_bed
+                .set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-20.));
+            cold_test_bed.run_with_delta(Duration::from_millis(100));
+
+            let mut hot_test_bed = test_bed();
+            hot_test_bed
+                .set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(80.));
+            hot_test_bed.run_with_delta(Duration::from_millis(100));
+
+            assert!(case_drain_flow(&hot_test_bed) > 2. * case_drain_flow(&cold_test_bed));
+        }
+
+        #[test]
+        fn worn_pump_leaks_more() {
+            let mut test_bed = test_bed();
+
+            test_bed.run_with_delta(Duration::from_millis(100));
+            let new_pump_leakage = case_drain_flow(&test_bed);
+
+            test_bed.command_element(|s| s.pump.set_wear(Ratio::new::<ratio>(1.)));
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            assert_about_eq!(
+                case_drain_flow(&test_bed),
+                Pump::FULLY_WORN_LEAKAGE_RATIO * new_pump_leakage,
+                0.01
+            );
+        }
+
+        #[test]
+        fn worn_pump_delivers_less_at_full_displacement() {
+            let mut test_bed = test_bed();
+
+            test_bed.command_element(|s| {
+                s.test_section.current_pressure = Pressure::new::<psi>(2000.);
+                s.volume_required = Volume::new::<gallon>(1.);
+            });
+            test_bed.run_with_delta(Duration::from_millis(100));
+            let new_pump_flow =
+                test_bed.query_element(|s| s.pump.flow().get::<gallon_per_minute>());
+
+            test_bed.command_element(|s| s.pump.set_wear(Ratio::new::<ratio>(1.)));
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            assert!(
+                test_bed.query_element(|s| s.pump.flow().get::<gallon_per_minute>())
+                    < new_pump_flow - 1.
+            );
+        }
+
+        #[test]
+        fn displacement_makes_up_for_leakage_when_regulating() {
+            let mut test_bed = test_bed();
+
+            test_bed.command_element(|s| s.pump.set_wear(Ratio::new::<ratio>(1.)));
+            test_bed.command_element(|s| s.volume_required = Volume::new::<gallon>(0.001));
+            for _ in 0..10 {
+                test_bed.run_with_delta(Duration::from_millis(100));
+            }
+
+            assert_about_eq!(
+                test_bed.query_element(|s| s.volume_delivered.get::<gallon>()),
+                0.01,
+                0.0001
+            );
+            assert!(case_drain_flow(&test_bed) > 0.);
+        }
+
+        #[test]
+        fn case_drain_returns_leakage_to_reservoir() {
+            let mut test_bed = test_bed();
+
+            test_bed.command_element(|s| s.pump.set_wear(Ratio::new::<ratio>(1.)));
+            test_bed.command_element(|s| s.volume_required = Volume::new::<gallon>(0.001));
+            for _ in 0..10 {
+                test_bed.run_with_delta(Duration::from_millis(100));
+            }
+
+            assert_about_eq!(
+                test_bed.query_element(|s| s.reservoir.fluid_level_real().get::<gallon>()),
+                RESERVOIR_LEVEL_GAL
+                    - test_bed.query_element(|s| s.volume_delivered.get::<gallon>()),
+                0.0001
+            );
+        }
+
+        #[test]
+        fn leakage_heats_the_fluid() {
+            let mut test_bed = test_bed();
+
+            test_bed.run_with_delta(Duration::from_millis(100));
+            let new_pump_heat = test_bed.query_element(|s| s.pump.heat_generated());
+
+            test_bed.command_element(|s| s.pump.set_wear(Ratio::new::<ratio>(1.)));
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            assert!(test_bed.query_element(|s| s.pump.heat_generated()) > new_pump_heat);
+        }
+    }
 }
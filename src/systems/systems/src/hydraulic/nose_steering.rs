@@ -12,7 +12,7 @@ use uom::si::{
     length::meter,
     pressure::psi,
     ratio::ratio,
-    velocity::knot,
+    velocity::{knot, meter_per_second},
     volume::gallon,
 };
 
@@ -91,6 +91,12 @@ impl<const N: usize> SteeringRatioToAngle<N> {
     }
 }
 
+/// Nose wheel steering actuator.
+///
+/// When pressurised, the actuator drives the wheel toward the steering controller request.
+/// Without pressure, or when the steering is disconnected for towing, the wheel is free to caster:
+/// it aligns with its direction of travel at a rate given by the ground speed and the caster trail,
+/// limited by the anti-shimmy damping of the unpressurised actuator.
 pub struct SteeringActuator {
     position_id: VariableIdentifier,
 
@@ -108,6 +114,9 @@ pub struct SteeringActuator {
     total_volume_to_reservoir: Volume,
 
     actuator_area: Area,
+
+    caster_trail: Length,
+    anti_shimmy_max_speed: AngularVelocity,
 }
 impl SteeringActuator {
     const MIN_PRESSURE_ALLOWING_STEERING_PSI: f64 = 300.;
@@ -123,6 +132,8 @@ impl SteeringActuator {
         nominal_speed: AngularVelocity,
         actuator_diameter: Length,
         angular_to_linear_ratio: Ratio,
+        caster_trail: Length,
+        anti_shimmy_max_speed: AngularVelocity,
     ) -> Self {
         Self {
             position_id: context.get_identifier("NOSE_WHEEL_POSITION_RATIO".to_owned()),
@@ -144,6 +155,9 @@ impl SteeringActuator {
             actuator_area: std::f64::consts::PI
                 * (actuator_diameter / 2.)
                 * (actuator_diameter / 2.),
+
+            caster_trail,
+            anti_shimmy_max_speed,
         }
     }
 
@@ -154,9 +168,12 @@ impl SteeringActuator {
         steering_controller: &impl SteeringController,
         pushback_tug: &impl Pushback,
     ) {
-        if !pushback_tug.is_nose_wheel_steering_pin_inserted() {
-            self.update_max_speed(context, current_pressure);
+        self.update_max_speed(context, current_pressure);
 
+        if pushback_tug.is_nose_wheel_steering_pin_inserted() {
+            // Towed: wheel casters in the direction the tug is pulling it
+            self.update_castering(context, pushback_tug.steering_angle());
+        } else if Self::is_pressurised(current_pressure) {
             let limited_requested_angle = steering_controller
                 .requested_position()
                 .min(self.max_half_angle)
@@ -166,10 +183,15 @@ impl SteeringActuator {
 
             self.update_final_speed_position(context, limited_requested_angle);
         } else {
-            self.update_speed_position_during_pushback(pushback_tug);
+            // Anti-shimmy damping mode: wheel casters back in line with aircraft travel
+            self.update_castering(context, Angle::new::<radian>(0.));
         }
 
-        self.update_flow(context, pushback_tug);
+        self.update_flow(context, pushback_tug, current_pressure);
+    }
+
+    fn is_pressurised(current_pressure: Pressure) -> bool {
+        current_pressure.get::<psi>() > Self::MIN_PRESSURE_ALLOWING_STEERING_PSI
     }
 
     fn update_final_speed_position(&mut self, context: &UpdateContext, requested_angle: Angle) {
@@ -194,10 +216,32 @@ impl SteeringActuator {
         }
     }
 
-    fn update_speed_position_during_pushback(&mut self, pushback_tug: &impl Pushback) {
-        self.current_speed
-            .reset(AngularVelocity::new::<radian_per_second>(0.));
-        self.current_position = pushback_tug.steering_angle();
+    fn update_castering(&mut self, context: &UpdateContext, travel_direction: Angle) {
+        let heading_error = self.current_position - travel_direction;
+
+        // A trailing wheel realigns with its travel direction faster as ground speed increases
+        let castering_speed = AngularVelocity::new::<radian_per_second>(
+            -context.ground_speed().get::<meter_per_second>().abs()
+                / self.caster_trail.get::<meter>()
+                * heading_error.get::<radian>().sin(),
+        );
+
+        let damped_castering_speed = castering_speed
+            .min(self.anti_shimmy_max_speed)
+            .max(-self.anti_shimmy_max_speed);
+
+        self.current_speed.reset(damped_castering_speed);
+        self.current_position += Angle::new::<radian>(
+            damped_castering_speed.get::<radian_per_second>() * context.delta_as_secs_f64(),
+        );
+
+        // Wheel can't overshoot its travel direction
+        if (self.current_position - travel_direction).get::<radian>()
+            * heading_error.get::<radian>()
+            < 0.
+        {
+            self.current_position = travel_direction;
+        }
     }
 
     fn update_current_speed(&mut self, context: &UpdateContext, requested_angle: Angle) {
@@ -213,21 +257,31 @@ impl SteeringActuator {
     }
 
     fn update_max_speed(&mut self, context: &UpdateContext, current_pressure: Pressure) {
-        let mut new_max_speed =
-            if current_pressure.get::<psi>() > Self::MIN_PRESSURE_ALLOWING_STEERING_PSI {
-                self.nominal_speed * current_pressure.get::<psi>().sqrt() * 1.
-                    / Self::REFERENCE_PRESS_FOR_NOMINAL_SPEED_PSI.sqrt()
-            } else {
-                AngularVelocity::new::<radian_per_second>(0.)
-            };
+        let mut new_max_speed = if Self::is_pressurised(current_pressure) {
+            self.nominal_speed * current_pressure.get::<psi>().sqrt() * 1.
+                / Self::REFERENCE_PRESS_FOR_NOMINAL_SPEED_PSI.sqrt()
+        } else {
+            AngularVelocity::new::<radian_per_second>(0.)
+        };
 
         new_max_speed = new_max_speed.min(self.nominal_speed);
 
         self.max_speed.update(context.delta(), new_max_speed);
     }
 
-    fn update_flow(&mut self, context: &UpdateContext, pushback_tug: &impl Pushback) {
-        if !pushback_tug.is_nose_wheel_steering_pin_inserted() {
+    fn update_flow(
+        &mut self,
+        context: &UpdateContext,
+        pushback_tug: &impl Pushback,
+        current_pressure: Pressure,
+    ) {
+        // Castering only moves fluid between actuator chambers through the anti-shimmy restrictor
+        if pushback_tug.is_nose_wheel_steering_pin_inserted()
+            || !Self::is_pressurised(current_pressure)
+        {
+            self.total_volume_to_actuator = Volume::new::<gallon>(0.);
+            self.total_volume_to_reservoir = Volume::new::<gallon>(0.);
+        } else {
             let angular_position_delta_abs = Angle::new::<radian>(
                 self.current_speed.output().get::<radian_per_second>().abs()
                     * context.delta_as_secs_f64(),
@@ -278,7 +332,7 @@ mod tests {
 
     use super::*;
 
-    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed};
+    use crate::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
     use crate::simulation::{Aircraft, SimulationElement, SimulationElementVisitor};
     use std::time::Duration;
     use uom::si::{angle::degree, pressure::psi};
@@ -485,6 +539,7 @@ mod tests {
         test_bed.command(|a| a.command_steer_angle(Angle::new::<degree>(90.)));
         test_bed.command(|a| a.set_pushback());
         test_bed.command(|a| a.command_pushback_angle(Angle::new::<degree>(12.)));
+        test_bed.write_by_name("GPS GROUND SPEED", 3.);
 
         test_bed.run_multiple_frames(Duration::from_secs(1));
 
@@ -494,6 +549,95 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn steering_does_not_caster_toward_pushback_angle_when_not_moving() {
+        let mut test_bed =
+            SimulationTestBed::new(|context| TestAircraft::new(steering_actuator(context)));
+
+        test_bed.command(|a| a.set_pushback());
+        test_bed.command(|a| a.command_pushback_angle(Angle::new::<degree>(12.)));
+
+        test_bed.run_multiple_frames(Duration::from_secs(1));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(0.)
+        ));
+    }
+
+    #[test]
+    fn steering_castering_speed_is_limited_by_anti_shimmy_damping() {
+        let mut test_bed =
+            SimulationTestBed::new(|context| TestAircraft::new(steering_actuator(context)));
+
+        test_bed.command(|a| a.set_pushback());
+        test_bed.command(|a| a.command_pushback_angle(Angle::new::<degree>(60.)));
+        test_bed.write_by_name("GPS GROUND SPEED", 10.);
+
+        test_bed.run_with_delta(Duration::from_millis(1000));
+
+        // 0.3 rad/s damping limit gives 17.2 degrees in one second whatever the ground speed
+        let castering_angle = test_bed.query(|a| a.steering_actuator.position_feedback());
+        assert!(castering_angle > Angle::new::<degree>(17.));
+        assert!(castering_angle < Angle::new::<degree>(17.4));
+
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(60.)
+        ));
+    }
+
+    #[test]
+    fn steering_casters_back_to_center_when_pressure_lost_while_rolling() {
+        let mut test_bed =
+            SimulationTestBed::new(|context| TestAircraft::new(steering_actuator(context)));
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(3000.)));
+        test_bed.command(|a| a.command_steer_angle(Angle::new::<degree>(30.)));
+
+        test_bed.run_multiple_frames(Duration::from_secs(3));
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(30.)
+        ));
+
+        test_bed.command(|a| a.set_pressure(Pressure::new::<psi>(0.)));
+        test_bed.run_multiple_frames(Duration::from_secs(3));
+
+        // No ground speed, wheel stays where it was
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(30.)
+        ));
+
+        test_bed.write_by_name("GPS GROUND SPEED", 5.);
+        test_bed.run_multiple_frames(Duration::from_secs(3));
+
+        assert!(is_equal_angle(
+            test_bed.query(|a| a.steering_actuator.position_feedback()),
+            Angle::new::<degree>(0.)
+        ));
+    }
+
+    #[test]
+    fn steering_castering_uses_no_hydraulic_fluid() {
+        let mut test_bed =
+            SimulationTestBed::new(|context| TestAircraft::new(steering_actuator(context)));
+
+        test_bed.command(|a| a.set_pushback());
+        test_bed.command(|a| a.command_pushback_angle(Angle::new::<degree>(60.)));
+        test_bed.write_by_name("GPS GROUND SPEED", 5.);
+
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        assert!(
+            test_bed.query(|a| a.steering_actuator.position_feedback()) > Angle::new::<degree>(1.)
+        );
+        assert!(test_bed.query(|a| a.steering_actuator.used_volume()) == Volume::default());
+    }
+
     #[test]
     fn steering_moving_with_pressure_to_max_pos_less_than_5s() {
         let mut test_bed =
@@ -634,6 +778,8 @@ mod tests {
             AngularVelocity::new::<radian_per_second>(0.35),
             Length::new::<meter>(0.05),
             Ratio::new::<ratio>(0.15),
+            Length::new::<meter>(0.1),
+            AngularVelocity::new::<radian_per_second>(0.3),
         )
     }
 
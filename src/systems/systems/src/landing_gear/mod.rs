@@ -11,37 +11,44 @@ use uom::si::{
     ratio::{percent, ratio},
 };
 
+#[derive(Clone, Copy, PartialEq)]
 pub enum GearWheel {
     CENTER = 0,
     LEFT = 1,
     RIGHT = 2,
 }
+
+/// Provides the lock state of each gear of a simulated gear system,
+/// as seen by the proximity sensors of the up and down locks.
+pub trait GearSystemSensors {
+    fn is_wheel_id_up_and_locked(&self, wheel_id: GearWheel) -> bool;
+    fn is_wheel_id_down_and_locked(&self, wheel_id: GearWheel) -> bool;
+}
+
 /// Represents a landing gear on Airbus aircraft.
 /// Note that this type somewhat hides the gear's position.
 /// The real aircraft also can only check whether or not the gear is up and
 /// locked or down and locked. No in between state.
+/// The lock states are provided by the simulated gear system.
 /// It provides as well the state of all weight on wheel sensors
 pub struct LandingGear {
-    center_position_id: VariableIdentifier,
-    left_position_id: VariableIdentifier,
-    right_position_id: VariableIdentifier,
     center_compression_id: VariableIdentifier,
     left_compression_id: VariableIdentifier,
     right_compression_id: VariableIdentifier,
 
-    center_position: Ratio,
-    left_position: Ratio,
-    right_position: Ratio,
+    center_up_and_locked: bool,
+    left_up_and_locked: bool,
+    right_up_and_locked: bool,
+
+    center_down_and_locked: bool,
+    left_down_and_locked: bool,
+    right_down_and_locked: bool,
 
     center_compression: Ratio,
     left_compression: Ratio,
     right_compression: Ratio,
 }
 impl LandingGear {
-    const GEAR_CENTER_POSITION: &'static str = "GEAR CENTER POSITION";
-    const GEAR_LEFT_POSITION: &'static str = "GEAR LEFT POSITION";
-    const GEAR_RIGHT_POSITION: &'static str = "GEAR RIGHT POSITION";
-
     pub const GEAR_CENTER_COMPRESSION: &'static str = "GEAR ANIMATION POSITION";
     pub const GEAR_LEFT_COMPRESSION: &'static str = "GEAR ANIMATION POSITION:1";
     pub const GEAR_RIGHT_COMPRESSION: &'static str = "GEAR ANIMATION POSITION:2";
@@ -51,16 +58,17 @@ impl LandingGear {
 
     pub fn new(context: &mut InitContext) -> Self {
         Self {
-            center_position_id: context.get_identifier(Self::GEAR_CENTER_POSITION.to_owned()),
-            left_position_id: context.get_identifier(Self::GEAR_LEFT_POSITION.to_owned()),
-            right_position_id: context.get_identifier(Self::GEAR_RIGHT_POSITION.to_owned()),
             center_compression_id: context.get_identifier(Self::GEAR_CENTER_COMPRESSION.to_owned()),
             left_compression_id: context.get_identifier(Self::GEAR_LEFT_COMPRESSION.to_owned()),
             right_compression_id: context.get_identifier(Self::GEAR_RIGHT_COMPRESSION.to_owned()),
 
-            center_position: Ratio::new::<percent>(0.),
-            left_position: Ratio::new::<percent>(0.),
-            right_position: Ratio::new::<percent>(0.),
+            center_up_and_locked: false,
+            left_up_and_locked: false,
+            right_up_and_locked: false,
+
+            center_down_and_locked: true,
+            left_down_and_locked: true,
+            right_down_and_locked: true,
 
             center_compression: Ratio::new::<percent>(0.),
             left_compression: Ratio::new::<percent>(0.),
@@ -68,12 +76,30 @@ impl LandingGear {
         }
     }
 
+    pub fn update(&mut self, gear_system: &impl GearSystemSensors) {
+        self.center_up_and_locked = gear_system.is_wheel_id_up_and_locked(GearWheel::CENTER);
+        self.left_up_and_locked = gear_system.is_wheel_id_up_and_locked(GearWheel::LEFT);
+        self.right_up_and_locked = gear_system.is_wheel_id_up_and_locked(GearWheel::RIGHT);
+
+        self.center_down_and_locked = gear_system.is_wheel_id_down_and_locked(GearWheel::CENTER);
+        self.left_down_and_locked = gear_system.is_wheel_id_down_and_locked(GearWheel::LEFT);
+        self.right_down_and_locked = gear_system.is_wheel_id_down_and_locked(GearWheel::RIGHT);
+    }
+
     fn is_wheel_id_up_and_locked(&self, wheel_id: GearWheel) -> bool {
-        (self.wheel_id_position(wheel_id).get::<percent>() - 0.).abs() < f64::EPSILON
+        match wheel_id {
+            GearWheel::CENTER => self.center_up_and_locked,
+            GearWheel::LEFT => self.left_up_and_locked,
+            GearWheel::RIGHT => self.right_up_and_locked,
+        }
     }
 
     fn is_wheel_id_down_and_locked(&self, wheel_id: GearWheel) -> bool {
-        (self.wheel_id_position(wheel_id).get::<percent>() - 100.).abs() < f64::EPSILON
+        match wheel_id {
+            GearWheel::CENTER => self.center_down_and_locked,
+            GearWheel::LEFT => self.left_down_and_locked,
+            GearWheel::RIGHT => self.right_down_and_locked,
+        }
     }
 
     fn is_wheel_id_compressed(&self, wheel_id: GearWheel) -> bool {
@@ -81,14 +107,6 @@ impl LandingGear {
             > Ratio::new::<ratio>(Self::COMPRESSION_THRESHOLD_FOR_WEIGHT_ON_WHEELS_RATIO)
     }
 
-    fn wheel_id_position(&self, wheel_id: GearWheel) -> Ratio {
-        match wheel_id {
-            GearWheel::CENTER => self.center_position,
-            GearWheel::LEFT => self.left_position,
-            GearWheel::RIGHT => self.right_position,
-        }
-    }
-
     fn wheel_id_compression(&self, wheel_id: GearWheel) -> Ratio {
         match wheel_id {
             GearWheel::CENTER => self.center_compression,
@@ -112,10 +130,6 @@ impl LandingGearRealPosition for LandingGear {
 }
 impl SimulationElement for LandingGear {
     fn read(&mut self, reader: &mut SimulatorReader) {
-        self.center_position = reader.read(&self.center_position_id);
-        self.left_position = reader.read(&self.left_position_id);
-        self.right_position = reader.read(&self.right_position_id);
-
         self.center_compression = reader.read(&self.center_compression_id);
         self.left_compression = reader.read(&self.left_compression_id);
         self.right_compression = reader.read(&self.right_compression_id);
@@ -274,44 +288,90 @@ mod tests {
     use crate::simulation::test::{ElementCtorFn, WriteByName};
     use crate::simulation::test::{SimulationTestBed, TestAircraft, TestBed};
 
+    #[derive(Clone, Copy)]
+    struct TestGearSystem {
+        up_and_locked: bool,
+        down_and_locked: bool,
+    }
+    impl TestGearSystem {
+        fn up_and_locked() -> Self {
+            Self {
+                up_and_locked: true,
+                down_and_locked: false,
+            }
+        }
+
+        fn down_and_locked() -> Self {
+            Self {
+                up_and_locked: false,
+                down_and_locked: true,
+            }
+        }
+
+        fn in_transit() -> Self {
+            Self {
+                up_and_locked: false,
+                down_and_locked: false,
+            }
+        }
+    }
+    impl GearSystemSensors for TestGearSystem {
+        fn is_wheel_id_up_and_locked(&self, _: GearWheel) -> bool {
+            self.up_and_locked
+        }
+
+        fn is_wheel_id_down_and_locked(&self, _: GearWheel) -> bool {
+            self.down_and_locked
+        }
+    }
+
     #[test]
-    fn is_up_and_locked_returns_false_when_fully_down() {
-        let test_bed = run_test_bed_on_with_position(Ratio::new::<percent>(100.));
+    fn is_down_and_locked_when_not_updated_yet() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(LandingGear::new));
+        test_bed.run();
 
+        assert!(test_bed.query_element(|e| e.is_down_and_locked()));
         assert!(!test_bed.query_element(|e| e.is_up_and_locked()));
     }
 
     #[test]
-    fn is_up_and_locked_returns_false_when_somewhat_down() {
-        let test_bed = run_test_bed_on_with_position(Ratio::new::<percent>(1.));
+    fn is_up_and_locked_returns_false_when_down_and_locked() {
+        let test_bed = run_test_bed_on_with_gear_system(TestGearSystem::down_and_locked());
 
         assert!(!test_bed.query_element(|e| e.is_up_and_locked()));
     }
 
     #[test]
-    fn is_up_and_locked_returns_true_when_fully_up() {
-        let test_bed = run_test_bed_on_with_position(Ratio::new::<percent>(0.));
+    fn is_up_and_locked_returns_false_when_in_transit() {
+        let test_bed = run_test_bed_on_with_gear_system(TestGearSystem::in_transit());
+
+        assert!(!test_bed.query_element(|e| e.is_up_and_locked()));
+    }
+
+    #[test]
+    fn is_up_and_locked_returns_true_when_up_and_locked() {
+        let test_bed = run_test_bed_on_with_gear_system(TestGearSystem::up_and_locked());
 
         assert!(test_bed.query_element(|e| e.is_up_and_locked()));
     }
 
     #[test]
-    fn is_down_and_locked_returns_false_when_fully_up() {
-        let test_bed = run_test_bed_on_with_position(Ratio::new::<percent>(0.));
+    fn is_down_and_locked_returns_false_when_up_and_locked() {
+        let test_bed = run_test_bed_on_with_gear_system(TestGearSystem::up_and_locked());
 
         assert!(!test_bed.query_element(|e| e.is_down_and_locked()));
     }
 
     #[test]
-    fn is_down_and_locked_returns_false_when_somewhat_up() {
-        let test_bed = run_test_bed_on_with_position(Ratio::new::<percent>(99.));
+    fn is_down_and_locked_returns_false_when_in_transit() {
+        let test_bed = run_test_bed_on_with_gear_system(TestGearSystem::in_transit());
 
         assert!(!test_bed.query_element(|e| e.is_down_and_locked()));
     }
 
     #[test]
-    fn is_down_and_locked_returns_true_when_fully_down() {
-        let test_bed = run_test_bed_on_with_position(Ratio::new::<percent>(100.));
+    fn is_down_and_locked_returns_true_when_down_and_locked() {
+        let test_bed = run_test_bed_on_with_gear_system(TestGearSystem::down_and_locked());
 
         assert!(test_bed.query_element(|e| e.is_down_and_locked()));
     }
@@ -355,13 +415,11 @@ mod tests {
         assert!(!test_bed.query_element(|e| e.is_wheel_id_compressed(GearWheel::RIGHT)));
     }
 
-    fn run_test_bed_on_with_position(
-        position: Ratio,
+    fn run_test_bed_on_with_gear_system(
+        gear_system: TestGearSystem,
     ) -> SimulationTestBed<TestAircraft<LandingGear>> {
-        let mut test_bed = SimulationTestBed::from(ElementCtorFn(LandingGear::new));
-        test_bed.write_by_name(LandingGear::GEAR_CENTER_POSITION, position);
-        test_bed.write_by_name(LandingGear::GEAR_LEFT_POSITION, position);
-        test_bed.write_by_name(LandingGear::GEAR_RIGHT_POSITION, position);
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(LandingGear::new))
+            .with_update_after_power_distribution(move |gear, _| gear.update(&gear_system));
 
         test_bed.run();
 
@@ -1,9 +1,10 @@
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
+    electrical::ComputerPowerSupply,
     overhead::{IndicationLight, OnOffFaultPushButton},
     shared::{
         arinc429::{Arinc429Word, SignStatus},
-        GroundSpeed, MachNumber,
+        ElectricalBusType, GroundSpeed, MachNumber,
     },
     simulation::{
         Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -312,6 +313,20 @@ impl AirDataInertialReferenceSystem {
     const CONFIGURED_ALIGN_TIME_KEY: &'static str = "CONFIG_ADIRS_IR_ALIGN_TIME";
     const USES_GPS_AS_PRIMARY_KEY: &'static str = "ADIRS_USES_GPS_AS_PRIMARY";
 
+    // Each ADIRU is supplied by an AC bus, backed up by a hot bus.
+    const ADIRU_1_POWERED_BY: [ElectricalBusType; 2] = [
+        ElectricalBusType::AlternatingCurrentEssential,
+        ElectricalBusType::DirectCurrentHot(2),
+    ];
+    const ADIRU_2_POWERED_BY: [ElectricalBusType; 2] = [
+        ElectricalBusType::AlternatingCurrent(2),
+        ElectricalBusType::DirectCurrentHot(2),
+    ];
+    const ADIRU_3_POWERED_BY: [ElectricalBusType; 2] = [
+        ElectricalBusType::AlternatingCurrent(1),
+        ElectricalBusType::DirectCurrentHot(1),
+    ];
+
     pub fn new(context: &mut InitContext) -> Self {
         Self {
             remaining_alignment_time_id: context
@@ -322,9 +337,9 @@ impl AirDataInertialReferenceSystem {
                 .get_identifier(Self::USES_GPS_AS_PRIMARY_KEY.to_owned()),
 
             adirus: [
-                AirDataInertialReferenceUnit::new(context, 1, true),
-                AirDataInertialReferenceUnit::new(context, 2, false),
-                AirDataInertialReferenceUnit::new(context, 3, true),
+                AirDataInertialReferenceUnit::new(context, 1, true, Self::ADIRU_1_POWERED_BY),
+                AirDataInertialReferenceUnit::new(context, 2, false, Self::ADIRU_2_POWERED_BY),
+                AirDataInertialReferenceUnit::new(context, 3, true, Self::ADIRU_3_POWERED_BY),
             ],
             configured_align_time: AlignTime::Realistic,
             simulator_data: AdirsSimulatorData::new(context),
@@ -399,13 +414,27 @@ struct AirDataInertialReferenceUnit {
 
     adr: AirDataReference,
     ir: InertialReference,
+    power_supply: ComputerPowerSupply,
 }
 impl AirDataInertialReferenceUnit {
-    fn new(context: &mut InitContext, number: usize, outputs_temperatures: bool) -> Self {
+    const POWER_HOLD_UP_TIME: Duration = Duration::from_millis(100);
+
+    fn new(
+        context: &mut InitContext,
+        number: usize,
+        outputs_temperatures: bool,
+        powered_by: [ElectricalBusType; 2],
+    ) -> Self {
         Self {
             state_id: context.get_identifier(Self::state_id(number)),
             adr: AirDataReference::new(context, number, outputs_temperatures),
             ir: InertialReference::new(context, number),
+            // The start-up of the ADR and IR is modelled by their initialisation and alignment.
+            power_supply: ComputerPowerSupply::new(
+                powered_by.to_vec(),
+                Self::POWER_HOLD_UP_TIME,
+                Duration::from_secs(0),
+            ),
         }
     }
 
@@ -416,9 +445,19 @@ impl AirDataInertialReferenceUnit {
         align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
     ) {
-        self.adr.update(context, overhead, simulator_data);
-        self.ir
-            .update(context, &self.adr, overhead, align_time, simulator_data);
+        self.power_supply.update(context);
+        let is_powered = self.power_supply.is_powered();
+
+        self.adr
+            .update(context, overhead, is_powered, simulator_data);
+        self.ir.update(
+            context,
+            &self.adr,
+            overhead,
+            is_powered,
+            align_time,
+            simulator_data,
+        );
     }
 
     fn is_fully_aligned(&self) -> bool {
@@ -455,6 +494,7 @@ impl SimulationElement for AirDataInertialReferenceUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.adr.accept(visitor);
         self.ir.accept(visitor);
+        self.power_supply.accept(visitor);
 
         visitor.visit(self);
     }
@@ -539,6 +579,7 @@ trait TrueAirspeedSource {
 struct AirDataReference {
     number: usize,
     is_on: bool,
+    is_powered: bool,
     outputs_temperatures: bool,
 
     altitude: AdirsData<Length>,
@@ -569,6 +610,7 @@ impl AirDataReference {
         Self {
             number,
             is_on: true,
+            is_powered: true,
             outputs_temperatures,
 
             altitude: AdirsData::new_adr(context, number, Self::ALTITUDE),
@@ -601,9 +643,11 @@ impl AirDataReference {
         &mut self,
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        is_powered: bool,
         simulator_data: AdirsSimulatorData,
     ) {
-        self.is_on = overhead.adr_is_on(self.number);
+        self.is_powered = is_powered;
+        self.is_on = is_powered && overhead.adr_is_on(self.number);
         self.update_remaining_initialisation_duration(context, overhead);
         self.update_values(context, simulator_data);
     }
@@ -616,7 +660,7 @@ impl AirDataReference {
         self.remaining_initialisation_duration = remaining_initialisation_duration(
             context,
             Self::INITIALISATION_DURATION,
-            overhead.mode_of(self.number),
+            operating_mode(overhead, self.number, self.is_powered),
             self.remaining_initialisation_duration,
         );
     }
@@ -735,6 +779,7 @@ impl From<f64> for AlignTime {
 struct InertialReference {
     number: usize,
     is_on: bool,
+    is_powered: bool,
     /// The remaining time to align, where 0 indicates the IR system is aligned.
     /// None indicates the IR system isn't aligning nor aligned.
     remaining_align_duration: Option<Duration>,
@@ -773,6 +818,7 @@ impl InertialReference {
         Self {
             number,
             is_on: true,
+            is_powered: true,
             // We start in an aligned state to support starting on the
             // runway or in the air.
             remaining_align_duration: Some(Duration::from_secs(0)),
@@ -797,10 +843,12 @@ impl InertialReference {
         context: &UpdateContext,
         true_airspeed_source: &impl TrueAirspeedSource,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        is_powered: bool,
         configured_align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
     ) {
-        self.is_on = overhead.ir_is_on(self.number);
+        self.is_powered = is_powered;
+        self.is_on = is_powered && overhead.ir_is_on(self.number);
 
         self.update_fault_flash_duration(context, overhead);
         self.update_remaining_attitude_align_duration(context, overhead);
@@ -821,7 +869,7 @@ impl InertialReference {
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
     ) {
-        if self.alignment_starting(overhead.mode_of(self.number)) {
+        if self.alignment_starting(self.mode(overhead)) {
             self.ir_fault_flash_duration = Some(Self::IR_FAULT_FLASH_DURATION);
         } else if let Some(flash_duration) = self.ir_fault_flash_duration {
             let remaining = subtract_delta_from_duration(context, flash_duration);
@@ -841,7 +889,7 @@ impl InertialReference {
         self.remaining_attitude_initialisation_duration = remaining_initialisation_duration(
             context,
             Self::ATTITUDE_INITIALISATION_DURATION,
-            overhead.mode_of(self.number),
+            self.mode(overhead),
             self.remaining_attitude_initialisation_duration,
         );
     }
@@ -853,7 +901,7 @@ impl InertialReference {
         configured_align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
     ) {
-        self.remaining_align_duration = match overhead.mode_of(self.number) {
+        self.remaining_align_duration = match self.mode(overhead) {
             InertialReferenceMode::Navigation => match self.remaining_align_duration {
                 Some(remaining) => Some(subtract_delta_from_duration(context, remaining)),
                 None => Some(Self::total_alignment_duration(
@@ -882,7 +930,7 @@ impl InertialReference {
     ) {
         let ssm = if self.is_on
             && (self.is_fully_aligned()
-                || (overhead.mode_of(self.number) == InertialReferenceMode::Attitude
+                || (self.mode(overhead) == InertialReferenceMode::Attitude
                     && self.is_attitude_aligned()))
         {
             SignStatus::NormalOperation
@@ -948,6 +996,13 @@ impl InertialReference {
         self.longitude.set_value(simulator_data.longitude, ssm);
     }
 
+    fn mode(
+        &self,
+        overhead: &AirDataInertialReferenceSystemOverheadPanel,
+    ) -> InertialReferenceMode {
+        operating_mode(overhead, self.number, self.is_powered)
+    }
+
     fn alignment_starting(&self, selected_mode: InertialReferenceMode) -> bool {
         selected_mode != InertialReferenceMode::Off
             && self.remaining_attitude_initialisation_duration == None
@@ -1009,6 +1064,21 @@ impl GroundSpeed for InertialReference {
     }
 }
 
+/// The mode in which an ADIRU operates. An unpowered ADIRU is off, regardless of the
+/// mode selected on the overhead panel. It has to initialise and align again once power
+/// returns.
+fn operating_mode(
+    overhead: &AirDataInertialReferenceSystemOverheadPanel,
+    number: usize,
+    is_powered: bool,
+) -> InertialReferenceMode {
+    if is_powered {
+        overhead.mode_of(number)
+    } else {
+        InertialReferenceMode::Off
+    }
+}
+
 fn remaining_initialisation_duration(
     context: &UpdateContext,
     starting_initialisation_duration: Duration,
@@ -1033,7 +1103,8 @@ mod tests {
     use super::*;
     use crate::simulation::test::{ReadByName, WriteByName};
     use crate::{
-        shared::arinc429::Arinc429Word,
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::{arinc429::Arinc429Word, PotentialOrigin},
         simulation::{
             test::{SimulationTestBed, TestBed},
             Aircraft, SimulationElementVisitor, SimulatorWriter, UpdateContext,
@@ -1050,18 +1121,45 @@ mod tests {
     };
 
     struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_hot_1_bus: ElectricalBus,
+        dc_hot_2_bus: ElectricalBus,
         adirs: AirDataInertialReferenceSystem,
         overhead: AirDataInertialReferenceSystemOverheadPanel,
     }
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::Battery(1),
+                ),
+                dc_hot_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(1)),
+                dc_hot_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentHot(2)),
                 adirs: AirDataInertialReferenceSystem::new(context),
                 overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
             }
         }
+
+        fn power(&mut self) {
+            self.electricity_source.power();
+        }
+
+        fn unpower(&mut self) {
+            self.electricity_source.unpower();
+        }
     }
     impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+            electricity.flow(&self.electricity_source, &self.dc_hot_1_bus);
+            electricity.flow(&self.electricity_source, &self.dc_hot_2_bus);
+        }
+
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
             self.adirs.update(context, &self.overhead);
             self.overhead.update(context, &self.adirs);
@@ -1069,6 +1167,8 @@ mod tests {
     }
     impl SimulationElement for TestAircraft {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.dc_hot_1_bus.accept(visitor);
+            self.dc_hot_2_bus.accept(visitor);
             self.adirs.accept(visitor);
             self.overhead.accept(visitor);
 
@@ -1101,6 +1201,16 @@ mod tests {
             self
         }
 
+        fn power_interrupted_for(mut self, duration: Duration) -> Self {
+            // The power supply has to be running before it can bridge an interruption.
+            self.run_with_delta(Duration::from_secs(0));
+            self.command(|a| a.unpower());
+            self.run_with_delta(duration);
+            self.command(|a| a.power());
+
+            self
+        }
+
         fn wait_for_alignment_of(mut self, adiru_number: usize) -> Self {
             while self.align_state(adiru_number) != AlignState::Aligned {
                 self.run();
@@ -1551,6 +1661,53 @@ mod tests {
         assert!(test_bed.is_aligned(adiru_number));
     }
 
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn adiru_remains_aligned_after_power_interruption_shorter_than_hold_up_time(
+        #[case] adiru_number: usize,
+    ) {
+        let mut test_bed =
+            all_adirus_aligned_test_bed_with().power_interrupted_for(Duration::from_millis(50));
+        test_bed.run();
+
+        assert!(test_bed.is_aligned(adiru_number));
+        assert!(test_bed
+            .computed_airspeed(adiru_number)
+            .is_normal_operation());
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn adiru_provides_no_data_while_unpowered(#[case] adiru_number: usize) {
+        let mut test_bed = all_adirus_aligned_test_bed();
+        test_bed.command(|a| a.unpower());
+        test_bed.run();
+
+        assert!(test_bed.align_state(adiru_number) == AlignState::Off);
+        assert!(test_bed
+            .computed_airspeed(adiru_number)
+            .is_no_computed_data());
+        assert!(test_bed.pitch(adiru_number).is_no_computed_data());
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn adiru_realigns_after_power_interruption_longer_than_hold_up_time(
+        #[case] adiru_number: usize,
+    ) {
+        let mut test_bed =
+            all_adirus_aligned_test_bed_with().power_interrupted_for(Duration::from_secs(1));
+        test_bed.run();
+
+        assert!(test_bed.is_aligning(adiru_number));
+    }
+
     #[rstest]
     #[case(1)]
     #[case(2)]
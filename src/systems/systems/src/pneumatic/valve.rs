@@ -462,6 +462,7 @@ mod tests {
             delta_time,
             Velocity::new::<knot>(0.),
             Velocity::new::<knot>(0.),
+            Velocity::new::<knot>(0.),
             altitude,
             InternationalStandardAtmosphere::temperature_at_altitude(altitude),
             true,
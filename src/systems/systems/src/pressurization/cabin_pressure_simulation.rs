@@ -18,6 +18,7 @@ pub(crate) struct CabinPressureSimulation {
     exterior_pressure: Pressure,
     outflow_valve_open_amount: Ratio,
     safety_valve_open_amount: Ratio,
+    cargo_doors_are_locked: bool,
     z_coefficient: f64,
     flow_coefficient: f64,
     cabin_flow_in: VolumeRate,
@@ -39,6 +40,7 @@ impl CabinPressureSimulation {
     const CABIN_VOLUME: f64 = 400.; // m3
     const OFV_SIZE: f64 = 0.03; // m2
     const SAFETY_VALVE_SIZE: f64 = 0.02; //m2
+    const UNLOCKED_CARGO_DOOR_LEAKAGE: f64 = 0.02; // m2
 
     pub(super) fn new() -> Self {
         Self {
@@ -50,6 +52,7 @@ impl CabinPressureSimulation {
             exterior_pressure: Pressure::new::<hectopascal>(1013.25),
             outflow_valve_open_amount: Ratio::new::<percent>(100.),
             safety_valve_open_amount: Ratio::new::<percent>(0.),
+            cargo_doors_are_locked: true,
             z_coefficient: 0.0011,
             flow_coefficient: 1.,
             cabin_flow_in: VolumeRate::new::<cubic_meter_per_second>(0.),
@@ -67,6 +70,7 @@ impl CabinPressureSimulation {
         packs_are_on: bool,
         lgciu_gear_compressed: bool,
         should_open_outflow_valve: bool,
+        cargo_doors_are_locked: bool,
     ) {
         if !self.initialized {
             self.cabin_pressure = self.initialize_cabin_pressure(context, lgciu_gear_compressed);
@@ -77,6 +81,7 @@ impl CabinPressureSimulation {
         self.flow_coefficient = self.calculate_flow_coefficient(should_open_outflow_valve);
         self.outflow_valve_open_amount = outflow_valve_open_amount;
         self.safety_valve_open_amount = safety_valve_open_amount;
+        self.cargo_doors_are_locked = cargo_doors_are_locked;
         self.cabin_flow_in = self.calculate_cabin_flow_in(packs_are_on, context);
         self.cabin_flow_out = self.calculate_cabin_flow_out();
         self.cabin_vs = self.calculate_cabin_vs();
@@ -182,7 +187,14 @@ impl CabinPressureSimulation {
     }
 
     fn calculate_cabin_flow_out(&self) -> VolumeRate {
+        // An unlocked cargo door is not pressed against its seals: the cabin can't hold pressure
+        let door_leakage = if self.cargo_doors_are_locked {
+            0.
+        } else {
+            Self::UNLOCKED_CARGO_DOOR_LEAKAGE
+        };
         let area_leakage = Self::AREA_LEAKAGE
+            + door_leakage
             + Self::SAFETY_VALVE_SIZE * self.safety_valve_open_amount.get::<ratio>();
         VolumeRate::new::<cubic_meter_per_second>(
             self.flow_coefficient * area_leakage * self.base_airflow_calculation(),
@@ -4,8 +4,12 @@ use self::{
     pressure_valve::{PressureValve, PressureValveSignal},
 };
 use crate::{
+    electrical::ComputerPowerSupply,
     overhead::{AutoManFaultPushButton, NormalOnPushButton, SpringLoadedSwitch, ValueKnob},
-    shared::{random_number, Cabin, ControllerSignal, EngineCorrectedN1, LgciuWeightOnWheels},
+    shared::{
+        random_number, Cabin, CargoDoorLocked, ControllerSignal, ElectricalBusType,
+        EngineCorrectedN1, LgciuWeightOnWheels,
+    },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
         SimulatorWriter, UpdateContext, VariableIdentifier, Write,
@@ -56,6 +60,7 @@ pub struct Pressurization {
 
     cabin_pressure_simulation: CabinPressureSimulation,
     cpc: [CabinPressureController; 2],
+    cpc_power_supply: [ComputerPowerSupply; 2],
     outflow_valve: PressureValve,
     safety_valve: PressureValve,
     residual_pressure_controller: ResidualPressureController,
@@ -70,6 +75,11 @@ pub struct Pressurization {
 }
 
 impl Pressurization {
+    const CPC_1_POWERED_BY: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
+    const CPC_2_POWERED_BY: ElectricalBusType = ElectricalBusType::DirectCurrent(2);
+    const CPC_POWER_HOLD_UP_TIME: Duration = Duration::from_millis(20);
+    const CPC_START_UP_TIME: Duration = Duration::from_secs(5);
+
     pub fn new(context: &mut InitContext) -> Self {
         let random = random_number();
         let mut active: usize = 1;
@@ -104,6 +114,18 @@ impl Pressurization {
                 CabinPressureController::new(),
                 CabinPressureController::new(),
             ],
+            cpc_power_supply: [
+                ComputerPowerSupply::new(
+                    vec![Self::CPC_1_POWERED_BY],
+                    Self::CPC_POWER_HOLD_UP_TIME,
+                    Self::CPC_START_UP_TIME,
+                ),
+                ComputerPowerSupply::new(
+                    vec![Self::CPC_2_POWERED_BY],
+                    Self::CPC_POWER_HOLD_UP_TIME,
+                    Self::CPC_START_UP_TIME,
+                ),
+            ],
             outflow_valve: PressureValve::new_outflow_valve(),
             safety_valve: PressureValve::new_safety_valve(),
             residual_pressure_controller: ResidualPressureController::new(),
@@ -124,11 +146,16 @@ impl Pressurization {
         press_overhead: &PressurizationOverheadPanel,
         engines: [&impl EngineCorrectedN1; 2],
         lgciu: [&impl LgciuWeightOnWheels; 2],
+        cargo_doors: &impl CargoDoorLocked,
     ) {
         let lgciu_gears_compressed = lgciu
             .iter()
             .all(|&a| a.left_and_right_gear_compressed(true));
 
+        self.cpc_power_supply
+            .iter_mut()
+            .for_each(|power_supply| power_supply.update(context));
+
         self.cabin_pressure_simulation.update(
             context,
             self.outflow_valve.open_amount(),
@@ -137,6 +164,7 @@ impl Pressurization {
             lgciu_gears_compressed,
             self.cpc[self.active_system - 1].should_open_outflow_valve()
                 && !press_overhead.is_in_man_mode(),
+            cargo_doors.all_cargo_doors_locked(),
         );
 
         if !press_overhead.ldg_elev_is_auto() {
@@ -208,6 +236,14 @@ impl Pressurization {
             .for_each(|controller| {
                 controller.reset_cpc_switch();
             });
+
+        // When the active system loses power, the other system takes over control.
+        let other_system = if self.active_system == 1 { 2 } else { 1 };
+        if !self.cpc_power_supply[self.active_system - 1].is_operational()
+            && self.cpc_power_supply[other_system - 1].is_operational()
+        {
+            self.active_system = other_system;
+        }
     }
 }
 
@@ -222,6 +258,12 @@ impl Cabin for Pressurization {
 }
 
 impl SimulationElement for Pressurization {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.cpc_power_supply, visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.active_cpc_sys_id, self.active_system);
         writer.write(
@@ -391,7 +433,8 @@ mod tests {
     use super::*;
     use crate::simulation::{Aircraft, SimulationElement, SimulationElementVisitor};
     use crate::{
-        shared::EngineCorrectedN1,
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::{EngineCorrectedN1, PotentialOrigin},
         simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
     };
 
@@ -499,24 +542,58 @@ mod tests {
         }
     }
 
+    struct TestCargoDoors {
+        locked: bool,
+    }
+    impl TestCargoDoors {
+        fn new() -> Self {
+            Self { locked: true }
+        }
+
+        fn set_locked(&mut self, locked: bool) {
+            self.locked = locked;
+        }
+    }
+
+    impl CargoDoorLocked for TestCargoDoors {
+        fn all_cargo_doors_locked(&self) -> bool {
+            self.locked
+        }
+    }
+
     pub struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_ess_bus: ElectricalBus,
+        dc_2_bus: ElectricalBus,
+        is_dc_ess_powered: bool,
+        is_dc_2_powered: bool,
         pressurization: Pressurization,
         pressurization_overhead: PressurizationOverheadPanel,
         engine_1: TestEngine,
         engine_2: TestEngine,
         lgciu1: TestLgciu,
         lgciu2: TestLgciu,
+        cargo_doors: TestCargoDoors,
     }
 
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
             let mut test_aircraft = Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_ess_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+                dc_2_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+                is_dc_ess_powered: true,
+                is_dc_2_powered: true,
                 pressurization: Pressurization::new(context),
                 pressurization_overhead: PressurizationOverheadPanel::new(context),
                 engine_1: TestEngine::new(Ratio::new::<percent>(0.)),
                 engine_2: TestEngine::new(Ratio::new::<percent>(0.)),
                 lgciu1: TestLgciu::new(false),
                 lgciu2: TestLgciu::new(false),
+                cargo_doors: TestCargoDoors::new(),
             };
             test_aircraft.pressurization.active_system = 1;
             test_aircraft.set_engine_n1(Ratio::new::<percent>(30.));
@@ -532,21 +609,52 @@ mod tests {
             self.lgciu1.set_on_ground(on_ground);
             self.lgciu2.set_on_ground(on_ground);
         }
+
+        fn set_dc_ess_is_powered(&mut self, is_powered: bool) {
+            self.is_dc_ess_powered = is_powered;
+        }
+
+        fn set_cargo_doors_locked(&mut self, locked: bool) {
+            self.cargo_doors.set_locked(locked);
+        }
+
+        fn set_dc_2_is_powered(&mut self, is_powered: bool) {
+            self.is_dc_2_powered = is_powered;
+        }
     }
 
     impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_dc_ess_powered {
+                electricity.flow(&self.electricity_source, &self.dc_ess_bus);
+            }
+
+            if self.is_dc_2_powered {
+                electricity.flow(&self.electricity_source, &self.dc_2_bus);
+            }
+        }
+
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
             self.pressurization.update(
                 context,
                 &self.pressurization_overhead,
                 [&self.engine_1, &self.engine_2],
                 [&self.lgciu1, &self.lgciu2],
+                &self.cargo_doors,
             );
         }
     }
 
     impl SimulationElement for TestAircraft {
         fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
+            self.dc_ess_bus.accept(visitor);
+            self.dc_2_bus.accept(visitor);
             self.pressurization.accept(visitor);
             self.pressurization_overhead.accept(visitor);
 
@@ -667,6 +775,22 @@ mod tests {
             self.command(|a| a.set_on_ground(on_ground));
         }
 
+        fn set_dc_ess_is_powered(&mut self, is_powered: bool) {
+            self.command(|a| a.set_dc_ess_is_powered(is_powered));
+        }
+
+        fn set_cargo_doors_locked(&mut self, locked: bool) {
+            self.command(|a| a.set_cargo_doors_locked(locked));
+        }
+
+        fn set_dc_2_is_powered(&mut self, is_powered: bool) {
+            self.command(|a| a.set_dc_2_is_powered(is_powered));
+        }
+
+        fn active_system(&self) -> usize {
+            self.query(|a| a.pressurization.active_system)
+        }
+
         fn iterate(mut self, delta: usize) -> Self {
             for _ in 0..delta {
                 self.run();
@@ -747,6 +871,58 @@ mod tests {
         assert!(test_bed.query(|a| a.pressurization.active_system == 2));
     }
 
+    #[test]
+    fn cpc_switches_when_active_cpc_loses_power() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+        assert_eq!(test_bed.active_system(), 1);
+
+        test_bed.set_dc_ess_is_powered(false);
+        test_bed.run();
+
+        assert_eq!(test_bed.active_system(), 2);
+    }
+
+    #[test]
+    fn cpc_does_not_switch_on_short_power_interruption() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        test_bed.set_dc_ess_is_powered(false);
+        test_bed.run_with_delta(Duration::from_millis(10));
+        test_bed.set_dc_ess_is_powered(true);
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert_eq!(test_bed.active_system(), 1);
+    }
+
+    #[test]
+    fn cpc_does_not_switch_to_unpowered_cpc() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        test_bed.set_dc_2_is_powered(false);
+        test_bed.run();
+        test_bed.set_dc_ess_is_powered(false);
+        test_bed.run();
+
+        assert_eq!(test_bed.active_system(), 1);
+    }
+
+    #[test]
+    fn cpc_does_not_switch_back_to_restarting_cpc() {
+        let mut test_bed = test_bed();
+        test_bed.run();
+
+        test_bed.set_dc_ess_is_powered(false);
+        test_bed.run();
+        test_bed.set_dc_ess_is_powered(true);
+        test_bed.set_dc_2_is_powered(false);
+        test_bed.run();
+
+        assert_eq!(test_bed.active_system(), 2);
+    }
+
     #[test]
     fn fifty_five_seconds_after_landing_outflow_valve_opens() {
         let mut test_bed = test_bed();
@@ -1324,6 +1500,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cabin_depressurises_when_cargo_door_unlocked_in_cruise() {
+        let mut test_bed = test_bed_in_cruise();
+
+        assert!(test_bed.cabin_delta_p() > Pressure::new::<psi>(5.));
+
+        test_bed.set_cargo_doors_locked(false);
+
+        let mut previous_cabin_altitude =
+            test_bed.query(|a| a.pressurization.cpc[0].cabin_altitude());
+        for _ in 0..2400 {
+            test_bed.run_with_delta(Duration::from_millis(100));
+
+            let cabin_altitude = test_bed.query(|a| a.pressurization.cpc[0].cabin_altitude());
+            assert!(cabin_altitude >= previous_cabin_altitude);
+            previous_cabin_altitude = cabin_altitude;
+        }
+
+        assert!(test_bed.cabin_delta_p() < Pressure::new::<psi>(0.5));
+    }
+
+    #[test]
+    fn pressure_does_not_build_up_when_ofv_closed_with_cargo_door_unlocked() {
+        let mut test_bed = test_bed();
+
+        test_bed.set_cargo_doors_locked(false);
+        test_bed = test_bed.iterate(10);
+        test_bed = test_bed.command_ditching_pb_on();
+        test_bed = test_bed.command_packs_on();
+        test_bed = test_bed.iterate(100);
+
+        assert!(test_bed.cabin_delta_p() < Pressure::new::<psi>(0.1));
+    }
+
     #[test]
     fn safety_valve_stays_closed_when_delta_p_is_less_than_8_6_psi() {
         let mut test_bed = test_bed();
@@ -31,6 +31,7 @@ pub trait AuxiliaryPowerUnitElectrical:
     ControllerSignal<ContactorSignal> + ApuAvailable + ElectricalElement + ElectricitySource
 {
     fn output_within_normal_parameters(&self) -> bool;
+    fn requires_load_shedding(&self) -> bool;
 }
 
 pub trait ApuAvailable {
@@ -74,6 +75,13 @@ pub trait FeedbackPositionPickoffUnit {
     fn angle(&self) -> Angle;
 }
 
+/// Position of each wing end of a flap or slat transmission, as seen by the
+/// Asymmetry Position Pickoff Units (APPU) located at the wing tips.
+pub trait AsymmetryPositionPickoffUnit {
+    fn left_angle(&self) -> Angle;
+    fn right_angle(&self) -> Angle;
+}
+
 pub trait LandingGearRealPosition {
     fn is_up_and_locked(&self) -> bool;
     fn is_down_and_locked(&self) -> bool;
@@ -115,6 +123,10 @@ pub trait Cabin {
     fn pressure(&self) -> Pressure;
 }
 
+pub trait CargoDoorLocked {
+    fn all_cargo_doors_locked(&self) -> bool;
+}
+
 pub trait PneumaticBleed {
     fn apu_bleed_is_on(&self) -> bool;
     fn engine_crossbleed_is_on(&self) -> bool;
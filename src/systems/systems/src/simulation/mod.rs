@@ -1,7 +1,10 @@
 use std::time::Duration;
 
 mod update_context;
-use crate::electrical::{ElectricalElementIdentifier, ElectricalElementIdentifierProvider};
+use crate::electrical::{
+    ElectricalElementDescription, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
+    ElectricalTopology, PowerConsumerDescription, PowerConsumptionBreakdown,
+};
 use crate::shared::{from_bool, ElectricalBusType};
 use crate::{
     electrical::Electricity,
@@ -248,6 +251,22 @@ pub trait SimulationElement {
 
     /// Receives a failure in order to activate or deactivate it.
     fn receive_failure(&mut self, _failure_type: FailureType, _is_active: bool) {}
+
+    /// Describes the element when it is part of the electrical network,
+    /// such that it can be included in the [`ElectricalTopology`].
+    ///
+    /// [`ElectricalTopology`]: ../electrical/struct.ElectricalTopology.html
+    fn describe_electrical_element(&self) -> Option<ElectricalElementDescription> {
+        None
+    }
+
+    /// Describes the element when it is a named power consumer,
+    /// such that it can be included in the [`PowerConsumptionBreakdown`].
+    ///
+    /// [`PowerConsumptionBreakdown`]: ../electrical/struct.PowerConsumptionBreakdown.html
+    fn describe_power_consumer(&self) -> Option<PowerConsumerDescription> {
+        None
+    }
 }
 
 /// Trait for visitors that visit the aircraft's system simulation to call
@@ -389,6 +408,24 @@ impl<T: Aircraft> Simulation<T> {
             ));
     }
 
+    /// Starts recording the aircraft's electrical network, such that its topology is
+    /// available after the next tick.
+    pub fn start_recording_electrical_topology(&mut self) {
+        self.electricity.start_recording_connections();
+    }
+
+    /// Returns the topology of the aircraft's electrical network as it was during the last tick.
+    /// Connections are only included once [`Self::start_recording_electrical_topology`] was called.
+    pub fn electrical_topology(&mut self) -> ElectricalTopology {
+        self.electricity.topology_of(&mut self.aircraft)
+    }
+
+    /// Returns the power consumed by each named power consumer during the last tick.
+    pub fn power_consumption_breakdown(&mut self) -> PowerConsumptionBreakdown {
+        self.electricity
+            .consumption_breakdown_of(&mut self.aircraft)
+    }
+
     fn electricity(&self) -> &Electricity {
         &self.electricity
     }
@@ -12,7 +12,7 @@ use uom::si::{
 };
 
 use crate::{
-    electrical::{Electricity, Potential},
+    electrical::{ElectricalTopology, Electricity, Potential, PowerConsumptionBreakdown},
     failures::FailureType,
 };
 
@@ -44,6 +44,10 @@ pub trait TestBed {
         self.test_bed_mut().run_with_delta(delta);
     }
 
+    fn run_with_fixed_frames(&mut self, delta: Duration, frame: Duration) {
+        self.test_bed_mut().run_with_fixed_frames(delta, frame);
+    }
+
     fn fail(&mut self, failure_type: FailureType) {
         self.test_bed_mut().fail(failure_type);
     }
@@ -67,6 +71,18 @@ pub trait TestBed {
         self.test_bed().query_elec_ref(func)
     }
 
+    fn start_recording_electrical_topology(&mut self) {
+        self.test_bed_mut().start_recording_electrical_topology()
+    }
+
+    fn electrical_topology(&mut self) -> ElectricalTopology {
+        self.test_bed_mut().electrical_topology()
+    }
+
+    fn power_consumption_breakdown(&mut self) -> PowerConsumptionBreakdown {
+        self.test_bed_mut().power_consumption_breakdown()
+    }
+
     fn set_indicated_airspeed(&mut self, indicated_airspeed: Velocity) {
         self.test_bed_mut()
             .set_indicated_airspeed(indicated_airspeed);
@@ -244,6 +260,19 @@ impl<T: Aircraft> SimulationTestBed<T> {
         self.simulation.tick(delta, &mut self.reader_writer);
     }
 
+    /// Runs [Simulation] ticks of the given frame duration until given delta is covered.
+    /// Unlike [`run_multiple_frames`], results are repeatable, which suits tests of timings.
+    ///
+    /// [`run_multiple_frames`]: #method.run_multiple_frames
+    /// [`Simulation`]: ../struct.Simulation.html
+    pub fn run_with_fixed_frames(&mut self, delta: Duration, frame: Duration) {
+        let mut executed_duration = Duration::from_secs(0);
+        while executed_duration < delta {
+            self.simulation.tick(frame, &mut self.reader_writer);
+            executed_duration += frame;
+        }
+    }
+
     /// Runs a multiple [Simulation] ticks by subdividing given delta on the contained [Aircraft].
     ///
     /// [`Aircraft`]: ../trait.Aircraft.html
@@ -300,6 +329,18 @@ impl<T: Aircraft> SimulationTestBed<T> {
         (func)(self.simulation.aircraft(), self.simulation.electricity())
     }
 
+    fn start_recording_electrical_topology(&mut self) {
+        self.simulation.start_recording_electrical_topology()
+    }
+
+    fn electrical_topology(&mut self) -> ElectricalTopology {
+        self.simulation.electrical_topology()
+    }
+
+    fn power_consumption_breakdown(&mut self) -> PowerConsumptionBreakdown {
+        self.simulation.power_consumption_breakdown()
+    }
+
     fn set_indicated_airspeed(&mut self, indicated_airspeed: Velocity) {
         self.write_by_name(UpdateContext::INDICATED_AIRSPEED_KEY, indicated_airspeed);
     }
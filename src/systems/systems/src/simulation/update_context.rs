@@ -93,6 +93,7 @@ pub struct UpdateContext {
     ambient_temperature_id: VariableIdentifier,
     indicated_airspeed_id: VariableIdentifier,
     true_airspeed_id: VariableIdentifier,
+    ground_speed_id: VariableIdentifier,
     indicated_altitude_id: VariableIdentifier,
     is_on_ground_id: VariableIdentifier,
     ambient_pressure_id: VariableIdentifier,
@@ -107,6 +108,7 @@ pub struct UpdateContext {
     delta: Delta,
     indicated_airspeed: Velocity,
     true_airspeed: Velocity,
+    ground_speed: Velocity,
     indicated_altitude: Length,
     ambient_temperature: ThermodynamicTemperature,
     ambient_pressure: Pressure,
@@ -120,6 +122,7 @@ impl UpdateContext {
     pub(crate) const AMBIENT_TEMPERATURE_KEY: &'static str = "AMBIENT TEMPERATURE";
     pub(crate) const INDICATED_AIRSPEED_KEY: &'static str = "AIRSPEED INDICATED";
     pub(crate) const TRUE_AIRSPEED_KEY: &'static str = "AIRSPEED TRUE";
+    pub(crate) const GROUND_SPEED_KEY: &'static str = "GPS GROUND SPEED";
     pub(crate) const INDICATED_ALTITUDE_KEY: &'static str = "INDICATED ALTITUDE";
     pub(crate) const IS_ON_GROUND_KEY: &'static str = "SIM ON GROUND";
     pub(crate) const AMBIENT_PRESSURE_KEY: &'static str = "AMBIENT PRESSURE";
@@ -139,6 +142,7 @@ impl UpdateContext {
         delta: Duration,
         indicated_airspeed: Velocity,
         true_airspeed: Velocity,
+        ground_speed: Velocity,
         indicated_altitude: Length,
         ambient_temperature: ThermodynamicTemperature,
         is_on_ground: bool,
@@ -154,6 +158,7 @@ impl UpdateContext {
                 .get_identifier(Self::AMBIENT_TEMPERATURE_KEY.to_owned()),
             indicated_airspeed_id: context.get_identifier(Self::INDICATED_AIRSPEED_KEY.to_owned()),
             true_airspeed_id: context.get_identifier(Self::TRUE_AIRSPEED_KEY.to_owned()),
+            ground_speed_id: context.get_identifier(Self::GROUND_SPEED_KEY.to_owned()),
             indicated_altitude_id: context.get_identifier(Self::INDICATED_ALTITUDE_KEY.to_owned()),
             is_on_ground_id: context.get_identifier(Self::IS_ON_GROUND_KEY.to_owned()),
             ambient_pressure_id: context.get_identifier(Self::AMBIENT_PRESSURE_KEY.to_owned()),
@@ -168,6 +173,7 @@ impl UpdateContext {
             delta: delta.into(),
             indicated_airspeed,
             true_airspeed,
+            ground_speed,
             indicated_altitude,
             ambient_temperature,
             ambient_pressure: Pressure::new::<inch_of_mercury>(29.92),
@@ -188,6 +194,7 @@ impl UpdateContext {
             ambient_temperature_id: context.get_identifier("AMBIENT TEMPERATURE".to_owned()),
             indicated_airspeed_id: context.get_identifier("AIRSPEED INDICATED".to_owned()),
             true_airspeed_id: context.get_identifier("AIRSPEED TRUE".to_owned()),
+            ground_speed_id: context.get_identifier("GPS GROUND SPEED".to_owned()),
             indicated_altitude_id: context.get_identifier("INDICATED ALTITUDE".to_owned()),
             is_on_ground_id: context.get_identifier("SIM ON GROUND".to_owned()),
             ambient_pressure_id: context.get_identifier("AMBIENT PRESSURE".to_owned()),
@@ -202,6 +209,7 @@ impl UpdateContext {
             delta: Default::default(),
             indicated_airspeed: Default::default(),
             true_airspeed: Default::default(),
+            ground_speed: Default::default(),
             indicated_altitude: Default::default(),
             ambient_temperature: Default::default(),
             ambient_pressure: Default::default(),
@@ -218,6 +226,7 @@ impl UpdateContext {
         self.ambient_temperature = reader.read(&self.ambient_temperature_id);
         self.indicated_airspeed = reader.read(&self.indicated_airspeed_id);
         self.true_airspeed = reader.read(&self.true_airspeed_id);
+        self.ground_speed = reader.read(&self.ground_speed_id);
         self.indicated_altitude = reader.read(&self.indicated_altitude_id);
         self.is_on_ground = reader.read(&self.is_on_ground_id);
         self.ambient_pressure =
@@ -263,6 +272,10 @@ impl UpdateContext {
         self.true_airspeed
     }
 
+    pub fn ground_speed(&self) -> Velocity {
+        self.ground_speed
+    }
+
     pub fn indicated_altitude(&self) -> Length {
         self.indicated_altitude
     }
@@ -1,42 +1,97 @@
-use fxhash::FxHashMap;
 use msfs::legacy::NamedVariable;
 
-use systems::failures::FailureType;
+use systems::failures::{FailureRequestVariable, FailureRequests, FailureType};
 
 pub(super) struct Failures {
-    activate_sim_var: NamedVariable,
-    deactivate_sim_var: NamedVariable,
-    identifier_to_failure_type: FxHashMap<u64, FailureType>,
+    requests: FailureRequests<FailureVariable>,
+    active_count_variable: NamedVariable,
+    active_list_variables: Vec<NamedVariable>,
+    active_list_prefix: String,
+    previous_active_count: usize,
 }
 impl Failures {
-    pub(super) fn new(activate_sim_var: NamedVariable, deactivate_sim_var: NamedVariable) -> Self {
+    pub(super) fn new(key_prefix: &str) -> Self {
         Self {
-            activate_sim_var,
-            deactivate_sim_var,
-            identifier_to_failure_type: FxHashMap::default(),
+            requests: FailureRequests::new(
+                FailureVariable::new(&format!("{}{}", key_prefix, "FAILURE_ACTIVATE")),
+                FailureVariable::new(&format!("{}{}", key_prefix, "FAILURE_DEACTIVATE")),
+            ),
+            active_count_variable: NamedVariable::from(&format!(
+                "{}{}",
+                key_prefix, "FAILURES_ACTIVE_COUNT"
+            )),
+            active_list_variables: vec![],
+            active_list_prefix: format!("{}{}", key_prefix, "FAILURES_ACTIVE_"),
+            previous_active_count: 0,
         }
     }
 
     pub(super) fn add(&mut self, identifier: u64, failure_type: FailureType) {
-        self.identifier_to_failure_type
-            .insert(identifier, failure_type);
+        self.requests.add(identifier, failure_type);
+
+        // Every known failure could be active at the same time, thus the list
+        // of active failures needs as many entries as there are known failures.
+        while self.active_list_variables.len() < self.requests.len() {
+            self.active_list_variables
+                .push(NamedVariable::from(&format!(
+                    "{}{}",
+                    self.active_list_prefix,
+                    self.active_list_variables.len()
+                )));
+        }
     }
 
-    pub(super) fn read_failure_activate(&self) -> Option<FailureType> {
-        self.read_failure(&self.activate_sim_var)
+    /// Reads failure requests from the simulator into the queue.
+    /// Call this whenever possible, as each read allows the simulator
+    /// to write the next failure request.
+    pub(super) fn read(&mut self) {
+        self.requests.read();
     }
 
-    pub(super) fn read_failure_deactivate(&self) -> Option<FailureType> {
-        self.read_failure(&self.deactivate_sim_var)
+    /// Applies all queued failure requests.
+    pub(super) fn apply<T: FnMut(FailureType, bool)>(&mut self, apply_fn: T) {
+        self.requests.apply(apply_fn);
     }
 
-    fn read_failure(&self, from: &NamedVariable) -> Option<FailureType> {
-        let identifier: f64 = from.get_value();
-        if let Some(failure_type) = self.identifier_to_failure_type.get(&(identifier as u64)) {
-            from.set_value(0.);
-            Some(*failure_type)
-        } else {
-            None
+    /// Writes the identifiers of active failures to the simulator as a list,
+    /// consisting of a count variable and one variable per active failure.
+    pub(super) fn write_active(&mut self) {
+        let mut active_count = 0;
+        for (variable, identifier) in self
+            .active_list_variables
+            .iter()
+            .zip(self.requests.active_identifiers())
+        {
+            variable.set_value(*identifier as f64);
+            active_count += 1;
+        }
+
+        for variable in self
+            .active_list_variables
+            .iter()
+            .take(self.previous_active_count)
+            .skip(active_count)
+        {
+            variable.set_value(0.);
         }
+
+        self.active_count_variable.set_value(active_count as f64);
+        self.previous_active_count = active_count;
+    }
+}
+
+struct FailureVariable(NamedVariable);
+impl FailureVariable {
+    fn new(name: &str) -> Self {
+        Self(NamedVariable::from(name))
+    }
+}
+impl FailureRequestVariable for FailureVariable {
+    fn read(&self) -> f64 {
+        self.0.get_value()
+    }
+
+    fn write(&mut self, value: f64) {
+        self.0.set_value(value);
     }
 }
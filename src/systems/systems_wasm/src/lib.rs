@@ -95,11 +95,12 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         ))
     }
 
+    /// Adds the mapping between failure identifiers used by the simulator and failures within
+    /// Rust code. Failures are requested through the `FAILURE_ACTIVATE` and `FAILURE_DEACTIVATE`
+    /// variables. The active failures are written to `FAILURES_ACTIVE_COUNT` and
+    /// `FAILURES_ACTIVE_0` through `FAILURES_ACTIVE_{count - 1}` in ascending identifier order.
     pub fn with_failures(mut self, failures: Vec<(u64, FailureType)>) -> Self {
-        let mut f = Failures::new(
-            NamedVariable::from(&format!("{}{}", &self.key_prefix, "FAILURE_ACTIVATE")),
-            NamedVariable::from(&format!("{}{}", &self.key_prefix, "FAILURE_DEACTIVATE")),
-        );
+        let mut f = Failures::new(&self.key_prefix);
         for failure in failures {
             f.add(failure.0, failure.1);
         }
@@ -160,23 +161,41 @@ impl MsfsHandler {
                 if !self.time.is_pausing() {
                     let delta_time = self.time.take();
                     self.pre_tick(sim_connect, delta_time)?;
-                    if let Some(failures) = &self.failures {
+                    if let Some(failures) = &mut self.failures {
                         Self::read_failures_into_simulation(failures, simulation);
                     }
 
                     simulation.tick(delta_time, self);
                     self.post_tick(sim_connect)?;
+
+                    if let Some(failures) = &mut self.failures {
+                        failures.write_active();
+                    }
+                } else if let Some(failures) = &mut self.failures {
+                    // Keep accepting failure requests while paused, such
+                    // that they are all applied once the simulation resumes.
+                    failures.read();
                 }
             }
-            MSFSEvent::SimConnect(message) => match message {
-                SimConnectRecv::SimObjectData(data) if data.id() == SimulationTime::REQUEST_ID => {
-                    self.time
-                        .increment(data.into::<SimulationTime>(sim_connect).unwrap());
+            MSFSEvent::SimConnect(message) => {
+                if let Some(failures) = &mut self.failures {
+                    // Reading failure requests between frames allows more
+                    // than one request to be applied within a single frame.
+                    failures.read();
                 }
-                _ => {
-                    self.handle_message(&message);
+
+                match message {
+                    SimConnectRecv::SimObjectData(data)
+                        if data.id() == SimulationTime::REQUEST_ID =>
+                    {
+                        self.time
+                            .increment(data.into::<SimulationTime>(sim_connect).unwrap());
+                    }
+                    _ => {
+                        self.handle_message(&message);
+                    }
                 }
-            },
+            }
             _ => {}
         }
 
@@ -227,16 +246,17 @@ impl MsfsHandler {
     }
 
     fn read_failures_into_simulation<T: Aircraft>(
-        failures: &Failures,
+        failures: &mut Failures,
         simulation: &mut Simulation<T>,
     ) {
-        if let Some(failure_type) = failures.read_failure_activate() {
-            simulation.activate_failure(failure_type);
-        }
-
-        if let Some(failure_type) = failures.read_failure_deactivate() {
-            simulation.deactivate_failure(failure_type);
-        }
+        failures.read();
+        failures.apply(|failure_type, is_active| {
+            if is_active {
+                simulation.activate_failure(failure_type);
+            } else {
+                simulation.deactivate_failure(failure_type);
+            }
+        });
     }
 }
 impl SimulatorReaderWriter for MsfsHandler {